@@ -1,14 +1,31 @@
-use crate::highscores::{HighScore, Leaderboard};
+use crate::cli;
+use crate::game::mutators::Mutator;
+use crate::highscores::{
+    sanitize_name, Category, HighScore, Leaderboard, Moderation, RunMetadata,
+};
+use crate::telemetry::TelemetryEvent;
+use crate::version::Version;
+use chrono::{DateTime, Utc};
+use rand::Rng;
 use reqwest::{
     header::{AUTHORIZATION, CONTENT_TYPE},
     Client,
 };
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use std::cell::RefCell;
 use std::env;
-use std::time::Duration;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 const API_BASE_URL: &str = "https://data.flur.ee/fluree";
+const LEDGER: &str = "ajohnson/yeti-set-go";
 const TIMEOUT_SECONDS: u64 = 5;
+const SLOW_RESPONSE: Duration = Duration::from_secs(4); // A simulated stall, just under the timeout
 
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
 fn get_api_key() -> Result<String, ApiError> {
     // Try compile-time embedded key first
     if let Some(key) = option_env!("FLUREE_API_KEY") {
@@ -21,104 +38,393 @@ fn get_api_key() -> Result<String, ApiError> {
     env::var("FLUREE_API_KEY").map_err(|_| ApiError::MissingApiKey)
 }
 
+#[cfg(feature = "mock-api")]
+mod mock;
+
+// Builds with the `mock-api` feature talk to an in-process fake instead, so
+// the sync and submit flows run without credentials or a network
+#[cfg(feature = "mock-api")]
+pub type ApiClient = mock::MockClient;
+#[cfg(not(feature = "mock-api"))]
+pub type ApiClient = FlureeClient;
+
+// Network conditions set from dev mode and applied to every request before
+// it goes out, so loading states, retries and the offline fallbacks can be
+// watched doing their thing. All zero is a normal connection.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NetworkSimulation {
+    pub latency: Duration, // Added to every request
+    pub failure_rate: f32, // Chance, 0.0 to 1.0, that a request fails outright
+    pub slow_rate: f32,    // Chance that a request stalls for SLOW_RESPONSE on top
+}
+
+impl NetworkSimulation {
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    // For the dev overlay
+    pub fn summary(&self) -> String {
+        format!(
+            "NET SIM +{}ms  fail {:.0}%  slow {:.0}%",
+            self.latency.as_millis(),
+            self.failure_rate * 100.0,
+            self.slow_rate * 100.0,
+        )
+    }
+
+    // Requests run on their own threads (see ApiTasks), so sleeping here
+    // never holds up a frame
+    fn apply(&self) -> Result<(), ApiError> {
+        if !self.is_active() {
+            return Ok(());
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut delay = self.latency;
+        if rng.gen::<f32>() < self.slow_rate {
+            delay += SLOW_RESPONSE;
+        }
+        thread::sleep(delay);
+
+        if rng.gen::<f32>() < self.failure_rate {
+            return Err(ApiError::ServerError(503));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
-pub struct ApiClient {
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+pub struct FlureeClient {
     client: Client,
     enabled: bool,
+    // Shared by every clone, so a change reaches requests already queued
+    simulation: Arc<Mutex<NetworkSimulation>>,
 }
 
-impl ApiClient {
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+impl FlureeClient {
     pub fn new() -> Self {
         let client = Client::builder()
-            .user_agent("YetiSetGo/1.0")
+            .user_agent(concat!("YetiSetGo/", env!("CARGO_PKG_VERSION")))
             .timeout(Duration::from_secs(TIMEOUT_SECONDS))
             .build()
             .unwrap_or_else(|_| Client::new());
 
         // Check if API key is available on startup
-        let enabled = match get_api_key() {
-            Ok(_) => {
-                println!("✓ Fluree API key loaded successfully");
-                true
-            }
-            Err(_) => {
-                println!("⚠ Warning: FLUREE_API_KEY environment variable not set. API features disabled.");
-                false
+        let enabled = if cli::args().offline {
+            println!("Offline mode: API features disabled");
+            false
+        } else {
+            match get_api_key() {
+                Ok(_) => {
+                    println!("✓ Fluree API key loaded successfully");
+                    true
+                }
+                Err(_) => {
+                    println!("⚠ Warning: FLUREE_API_KEY environment variable not set. API features disabled.");
+                    false
+                }
             }
         };
 
-        Self { client, enabled }
-    }
-
-    pub async fn fetch_leaderboard(&self) -> Result<Vec<HighScore>, ApiError> {
-        if !self.enabled {
-            return Err(ApiError::Disabled);
+        Self {
+            client,
+            enabled,
+            simulation: Arc::default(),
         }
+    }
 
-        let api_key = get_api_key()?;
-
-        let query = serde_json::json!({
-            "from": "ajohnson/yeti-set-go",
-            "where": [
-                {
-                    "@id": "?s",
-                    "score": "?score",
-                }
-            ],
-            "select": { "?s": ["*"] },
-            "orderBy": "(desc ?score)",
-            "limit": 20
-        });
-
-        let url = format!("{}/query", API_BASE_URL);
-
-        let response = self
-            .client
-            .post(&url)
-            .header(AUTHORIZATION, format!("Bearer {}", api_key))
-            .header(CONTENT_TYPE, "application/json")
-            .json(&query)
-            .send()
-            .await
-            .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+    pub fn network_simulation(&self) -> NetworkSimulation {
+        *self.simulation.lock().unwrap()
+    }
 
-        if !response.status().is_success() {
-            return Err(ApiError::ServerError(response.status().as_u16()));
-        }
+    pub fn set_network_simulation(&self, simulation: NetworkSimulation) {
+        *self.simulation.lock().unwrap() = simulation;
+    }
 
-        let scores: Vec<HighScore> = response
-            .json()
-            .await
-            .map_err(|e| ApiError::ParseError(e.to_string()))?;
+    pub async fn fetch_leaderboard(&self, category: Category) -> Result<Vec<HighScore>, ApiError> {
+        // Ordered like HighScore::board_order, so ties at the cut-off always
+        // fall the same way. The oldest entries have no level, hence optional.
+        let mut pattern = serde_json::json!({ "@id": "?s" });
+        pattern[score_property(category)] = "?score".into();
+        let values: Vec<serde_json::Value> = self
+            .query(serde_json::json!({
+                "where": [
+                    pattern,
+                    ["optional", { "@id": "?s", "level": "?level" }],
+                    ["optional", { "@id": "?s", "timestamp": "?timestamp" }],
+                ],
+                "select": { "?s": ["*"] },
+                "orderBy": ["(desc ?score)", "(desc ?level)", "(asc ?timestamp)"],
+                "limit": 20
+            }))
+            .await?;
 
+        // Scores no run could have earned are left off like hidden ones
+        let mut scores = parse_score_records(values, category);
+        scores.retain(HighScore::is_plausible);
         Ok(scores)
     }
 
     pub async fn submit_score(&self, high_score: &HighScore) -> Result<(), ApiError> {
-        if !self.enabled {
-            return Err(ApiError::Disabled);
-        }
+        self.transact(serde_json::json!({ "insert": score_insert(high_score) }))
+            .await
+    }
 
-        let api_key = get_api_key()?;
+    // Challenge runs live under their own property so they never leak into the global board.
+    // Ties fall the same way as on the global board.
+    pub async fn fetch_challenge_leaderboard(
+        &self,
+        challenge_code: &str,
+    ) -> Result<Vec<HighScore>, ApiError> {
+        let records: Vec<ChallengeScoreRecord> = self
+            .query(serde_json::json!({
+                "where": [
+                    {
+                        "@id": "?s",
+                        "challenge": challenge_code,
+                        "challengeScore": "?score",
+                        "level": "?level",
+                        "timestamp": "?timestamp",
+                    }
+                ],
+                "select": { "?s": ["*"] },
+                "orderBy": ["(desc ?score)", "(desc ?level)", "(asc ?timestamp)"],
+                "limit": 20
+            }))
+            .await?;
 
-        let transaction = serde_json::json!({
-            "ledger": "ajohnson/yeti-set-go",
+        Ok(records
+            .into_iter()
+            .map(HighScore::from)
+            .filter(|score| !score.is_hidden())
+            .collect())
+    }
+
+    pub async fn submit_challenge_score(
+        &self,
+        challenge_code: &str,
+        high_score: &HighScore,
+    ) -> Result<(), ApiError> {
+        self.transact(serde_json::json!({
             "insert": {
-                "score": high_score.score,
+                "challenge": challenge_code,
+                "challengeScore": high_score.score,
                 "name": high_score.name,
                 "timestamp": high_score.timestamp,
                 "level": high_score.level,
+                "gameVersion": submitted_version(high_score),
+            }
+        }))
+        .await
+    }
+
+    // Every finished run, high score or not, so the community totals count
+    // all games rather than just the ones that made the board
+    pub async fn submit_run(&self, score: u32, level: u32, dodged: u32) -> Result<(), ApiError> {
+        self.transact(serde_json::json!({
+            "insert": {
+                "runScore": score,
+                "runLevel": level,
+                "runDodged": dodged,
+            }
+        }))
+        .await
+    }
+
+    // A flag for moderators to look at; the entry stays up until one of them
+    // hides or sanitizes it
+    pub async fn report_entry(&self, entry_id: &str, name: &str) -> Result<(), ApiError> {
+        self.transact(serde_json::json!({
+            "insert": {
+                "reportedEntry": { "@id": entry_id },
+                "reportedName": name,
+                "reportTimestamp": Utc::now(),
+            }
+        }))
+        .await
+    }
+
+    // Admin mode's removal: retracts everything about the entry. The ledger
+    // refuses it unless the API key is allowed to curate the board.
+    pub async fn delete_score(&self, entry_id: &str) -> Result<(), ApiError> {
+        self.transact(serde_json::json!({
+            "where": { "@id": entry_id, "?p": "?o" },
+            "delete": { "@id": entry_id, "?p": "?o" },
+        }))
+        .await
+    }
+
+    // Only ever sent after the player says yes on the main menu
+    pub async fn submit_crash_report(&self, report: &str) -> Result<(), ApiError> {
+        self.transact(serde_json::json!({
+            "insert": {
+                "crashVersion": env!("CARGO_PKG_VERSION"),
+                "crashTimestamp": Utc::now(),
+                "crashReport": report,
             }
+        }))
+        .await
+    }
+
+    // One transaction per batch; the server gets all of it or none
+    pub async fn submit_telemetry(&self, events: &[TelemetryEvent]) -> Result<(), ApiError> {
+        let sent_at = Utc::now();
+        let insert: Vec<_> = events
+            .iter()
+            .map(|event| {
+                serde_json::json!({
+                    "telemetryVersion": env!("CARGO_PKG_VERSION"),
+                    "telemetryTimestamp": sent_at,
+                    "telemetryEvent": event,
+                })
+            })
+            .collect();
+        self.transact(serde_json::json!({ "insert": insert })).await
+    }
+
+    pub async fn fetch_community_stats(&self) -> Result<CommunityStats, ApiError> {
+        let games_played = self.fetch_run_aggregate("(count ?run)").await?;
+        let items_dodged = self.fetch_run_aggregate("(sum ?dodged)").await?;
+        Ok(CommunityStats {
+            games_played,
+            items_dodged,
+        })
+    }
+
+    // Where a score stands on the whole board, not just the cached top: one
+    // more than the entries above it. Moderated entries still count, so it
+    // can be a place or two out on a board someone has tidied up.
+    pub async fn fetch_rank(&self, category: Category, score: u32) -> Result<usize, ApiError> {
+        let mut pattern = serde_json::json!({ "@id": "?s" });
+        pattern[score_property(category)] = "?score".into();
+        let filter = serde_json::json!(["filter", format!("(> ?score {})", score)]);
+        let above = self.fetch_aggregate(vec![pattern, filter], "(count ?s)").await?;
+        Ok(above as usize + 1)
+    }
+
+    // One aggregate over every reported run
+    async fn fetch_run_aggregate(&self, aggregate: &str) -> Result<u64, ApiError> {
+        let pattern = serde_json::json!({
+            "@id": "?run",
+            "runDodged": "?dodged",
         });
+        self.fetch_aggregate(vec![pattern], aggregate).await
+    }
+
+    async fn fetch_aggregate(
+        &self,
+        clauses: Vec<serde_json::Value>,
+        aggregate: &str,
+    ) -> Result<u64, ApiError> {
+        // A single row holding the one selected value; nothing to aggregate
+        // comes back as an empty result or a null
+        let rows: Vec<Vec<Option<f64>>> = self
+            .query(serde_json::json!({
+                "where": clauses,
+                "select": [aggregate],
+            }))
+            .await?;
+
+        let value = rows
+            .first()
+            .and_then(|row| row.first().copied().flatten())
+            .unwrap_or(0.0);
+        Ok(value.max(0.0) as u64)
+    }
+
+    // Replays are stored apart from the score records so leaderboard syncs
+    // never download them
+    pub async fn upload_replay(
+        &self,
+        high_score: &HighScore,
+        replay: &str,
+    ) -> Result<(), ApiError> {
+        self.transact(serde_json::json!({
+            "insert": {
+                "replayScore": high_score.score,
+                "replayName": high_score.name,
+                "replayTimestamp": high_score.timestamp,
+                "replay": replay,
+            }
+        }))
+        .await
+    }
+
+    // The highest-scoring run that has a replay, if anyone has uploaded one
+    pub async fn fetch_top_replay(&self) -> Result<Option<ReplayRecord>, ApiError> {
+        let mut records: Vec<ReplayRecord> = self
+            .query(serde_json::json!({
+                "where": [
+                    {
+                        "@id": "?r",
+                        "replayScore": "?score",
+                    }
+                ],
+                "select": { "?r": ["*"] },
+                "orderBy": "(desc ?score)",
+                "limit": 1
+            }))
+            .await?;
+
+        Ok(records.pop().map(|mut record| {
+            record.replay_name = sanitize_name(&record.replay_name);
+            record
+        }))
+    }
+
+    // The newest release anyone has published to the ledger, for the main
+    // menu's update banner. None when nothing readable has been published.
+    pub async fn fetch_latest_version(&self) -> Result<Option<Version>, ApiError> {
+        let versions: Vec<String> = self
+            .query(serde_json::json!({
+                "where": [{ "@id": "?r", "latestVersion": "?version" }],
+                "select": "?version",
+            }))
+            .await?;
+
+        Ok(versions.iter().filter_map(|text| Version::parse(text)).max())
+    }
+
+    // Writes to the game's ledger, which the helper fills in
+    async fn transact(&self, mut body: serde_json::Value) -> Result<(), ApiError> {
+        body["ledger"] = LEDGER.into();
+        self.post("transact", &body).await?;
+        Ok(())
+    }
 
-        let url = format!("{}/transact", API_BASE_URL);
+    // Reads from the game's ledger and decodes the rows into T
+    async fn query<T: DeserializeOwned>(&self, mut body: serde_json::Value) -> Result<T, ApiError> {
+        body["from"] = LEDGER.into();
+        self.post("query", &body)
+            .await?
+            .json()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string()))
+    }
+
+    // Everything a request goes through on its way out: the enabled check,
+    // the simulated network and the API key
+    async fn post(
+        &self,
+        endpoint: &str,
+        body: &serde_json::Value,
+    ) -> Result<reqwest::Response, ApiError> {
+        if !self.enabled {
+            return Err(ApiError::Disabled);
+        }
+        self.network_simulation().apply()?;
+
+        let api_key = get_api_key()?;
 
         let response = self
             .client
-            .post(&url)
+            .post(format!("{}/{}", API_BASE_URL, endpoint))
             .header(AUTHORIZATION, format!("Bearer {}", api_key))
             .header(CONTENT_TYPE, "application/json")
-            .json(&transaction)
+            .json(body)
             .send()
             .await
             .map_err(|e| ApiError::NetworkError(e.to_string()))?;
@@ -127,7 +433,11 @@ impl ApiClient {
             return Err(ApiError::ServerError(response.status().as_u16()));
         }
 
-        Ok(())
+        Ok(response)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
     }
 
     pub fn disable(&mut self) {
@@ -135,6 +445,297 @@ impl ApiClient {
     }
 }
 
+// Requests running in the background. Each gets a thread with its own Tokio
+// runtime; the handles are kept so quitting can wait for them to land.
+#[derive(Default)]
+pub struct ApiTasks {
+    handles: RefCell<Vec<JoinHandle<()>>>,
+}
+
+impl ApiTasks {
+    pub fn spawn(&self, task: impl Future<Output = ()> + Send + 'static) {
+        let handle = thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(task);
+        });
+        let mut handles = self.handles.borrow_mut();
+        handles.retain(|handle| !handle.is_finished());
+        handles.push(handle);
+    }
+
+    // Blocks until everything has finished or `timeout` runs out. Returns how
+    // many were still going; they're abandoned when the process exits.
+    pub fn wait(&self, timeout: Duration) -> usize {
+        let deadline = Instant::now() + timeout;
+        let mut handles = self.handles.borrow_mut();
+        loop {
+            handles.retain(|handle| !handle.is_finished());
+            if handles.is_empty() || Instant::now() >= deadline {
+                return handles.len();
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+}
+
+// Totals across every player's reported runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommunityStats {
+    pub games_played: u64,
+    pub items_dodged: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayRecord {
+    pub replay_name: String,
+    pub replay_score: u32,
+    pub replay: String, // Replay::encode output
+}
+
+// Global board entries, as each version of the game has written them.
+// Entries stay on the server in whatever shape wrote them, so every version
+// is still read and migrated into a HighScore on fetch. When the fields
+// change, add a record type, bump SCORE_SCHEMA_VERSION and write it with
+// the submission.
+const SCORE_SCHEMA_VERSION: u32 = 3;
+
+// Version 1: name, score and level, plus what was added before versions
+// were written (mutators, moderation). Anything missing gets a default
+// rather than failing the entry.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScoreRecordV1 {
+    #[serde(default, rename = "@id")]
+    id: Option<String>,
+    #[serde(default)]
+    name: String,
+    score: u32,
+    #[serde(default = "first_level")]
+    level: u32,
+    #[serde(default)]
+    timestamp: DateTime<Utc>,
+    #[serde(default, deserialize_with = "lenient_list")]
+    mutators: Vec<Mutator>,
+    #[serde(default, deserialize_with = "lenient")]
+    moderation: Option<Moderation>,
+    // Version 3 added the item magnet flag. Runs without metadata are still
+    // written as version 1, so it's read from every version.
+    #[serde(default)]
+    assisted: bool,
+}
+
+// Version 2: run metadata for plausibility checks and the detail view
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScoreRecordV2 {
+    #[serde(flatten)]
+    base: ScoreRecordV1,
+    duration: f32,
+    collected: u32,
+    dodged: u32,
+    #[serde(default, deserialize_with = "lenient_list")]
+    level_times: Vec<f32>,
+    #[serde(default)]
+    seed: Option<String>, // Text, since JSON numbers can't hold every u64
+    #[serde(default)]
+    game_version: String,
+}
+
+#[derive(Debug)]
+enum ScoreRecord {
+    V1(ScoreRecordV1),
+    V2(ScoreRecordV2), // Also version 3, whose one new field V1 reads
+}
+
+impl ScoreRecord {
+    fn parse(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        // Entries from before the version was written are v1 unless they
+        // already carry run metadata. Versions newer than this client read
+        // as the newest it knows, ignoring the fields it doesn't.
+        let unversioned = if value.get("duration").is_some() {
+            2
+        } else {
+            1
+        };
+        let version = value
+            .get("schemaVersion")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(unversioned);
+        if version >= 2 {
+            serde_json::from_value(value).map(ScoreRecord::V2)
+        } else {
+            serde_json::from_value(value).map(ScoreRecord::V1)
+        }
+    }
+}
+
+impl From<ScoreRecordV1> for HighScore {
+    fn from(record: ScoreRecordV1) -> Self {
+        Self {
+            id: record.id,
+            // Other clients may not have cleaned their names up
+            name: sanitize_name(&record.name),
+            score: record.score,
+            level: record.level,
+            timestamp: record.timestamp,
+            duration: None,
+            score_history: Vec::new(),
+            mutators: record.mutators,
+            assisted: record.assisted,
+            category: Category::Standard,
+            moderation: record.moderation,
+            run: None,
+        }
+    }
+}
+
+impl From<ScoreRecordV2> for HighScore {
+    fn from(record: ScoreRecordV2) -> Self {
+        HighScore::from(record.base).with_metadata(RunMetadata {
+            duration: record.duration,
+            collected: record.collected,
+            dodged: record.dodged,
+            level_times: record.level_times,
+            seed: record.seed.and_then(|seed| seed.parse().ok()).unwrap_or(0),
+            version: record.game_version,
+        })
+    }
+}
+
+impl From<ScoreRecord> for HighScore {
+    fn from(record: ScoreRecord) -> Self {
+        match record {
+            ScoreRecord::V1(record) => record.into(),
+            ScoreRecord::V2(record) => record.into(),
+        }
+    }
+}
+
+// Hardcore entries keep their score under their own property, like
+// challenge runs, so clients from before the category never rank them on
+// the global board. Otherwise they're written like any other entry.
+fn score_property(category: Category) -> &'static str {
+    match category {
+        Category::Standard => "score",
+        Category::Hardcore => "hardcoreScore",
+    }
+}
+
+// One entry the client can't read shouldn't cost it the whole board
+fn parse_score_records(values: Vec<serde_json::Value>, category: Category) -> Vec<HighScore> {
+    let property = score_property(category);
+    values
+        .into_iter()
+        .map(|mut value| {
+            if let Some(score) = value.get(property).cloned() {
+                value["score"] = score;
+            }
+            value
+        })
+        .filter_map(|value| match ScoreRecord::parse(value) {
+            Ok(record) => Some(HighScore::from(record).with_category(category)),
+            Err(e) => {
+                println!("Skipping unreadable leaderboard entry: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+// What submit_score writes, in the newest shape the entry has data for
+fn score_insert(high_score: &HighScore) -> serde_json::Value {
+    let mut insert = serde_json::json!({
+        "schemaVersion": 1,
+        "name": high_score.name,
+        "timestamp": high_score.timestamp,
+        "level": high_score.level,
+        "mutators": high_score.mutators,
+        "gameVersion": submitted_version(high_score),
+        "assisted": high_score.assisted,
+    });
+    insert[score_property(high_score.category)] = high_score.score.into();
+    if let Some(run) = &high_score.run {
+        insert["schemaVersion"] = SCORE_SCHEMA_VERSION.into();
+        insert["duration"] = run.duration.into();
+        insert["collected"] = run.collected.into();
+        insert["dodged"] = run.dodged.into();
+        insert["levelTimes"] = run.level_times.clone().into();
+        insert["seed"] = run.seed.to_string().into();
+    }
+    insert
+}
+
+// Every submission says which release sent it, run metadata or not
+fn submitted_version(high_score: &HighScore) -> String {
+    high_score
+        .run
+        .as_ref()
+        .map_or_else(|| Version::current().to_string(), |run| run.version.clone())
+}
+
+fn first_level() -> u32 {
+    1
+}
+
+// Fluree hands a one-item list back as a bare value, and newer clients may
+// write values this one has never heard of; keep whatever parses
+fn lenient_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let values = match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Array(values) => values,
+        serde_json::Value::Null => Vec::new(),
+        value => vec![value],
+    };
+    Ok(values
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect())
+}
+
+// An unknown value reads as none at all
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).ok())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChallengeScoreRecord {
+    name: String,
+    challenge_score: u32,
+    level: u32,
+    timestamp: DateTime<Utc>,
+    #[serde(default)]
+    moderation: Option<Moderation>,
+}
+
+impl From<ChallengeScoreRecord> for HighScore {
+    fn from(record: ChallengeScoreRecord) -> Self {
+        Self {
+            id: None,
+            name: sanitize_name(&record.name),
+            score: record.challenge_score,
+            level: record.level,
+            timestamp: record.timestamp,
+            duration: None,
+            score_history: Vec::new(),
+            mutators: Vec::new(),
+            assisted: false,
+            category: Category::Standard,
+            moderation: record.moderation,
+            run: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ApiError {
     NetworkError(String),
@@ -162,22 +763,6 @@ impl std::fmt::Display for ApiError {
 impl std::error::Error for ApiError {}
 
 // High-level API functions with fallback behavior
-pub async fn load_leaderboard_with_fallback(
-    api_client: &ApiClient,
-    local_leaderboard: &mut Leaderboard,
-) -> bool {
-    match api_client.fetch_leaderboard().await {
-        Ok(remote_scores) => {
-            local_leaderboard.merge_remote_scores(remote_scores);
-            true // Successfully loaded from API
-        }
-        Err(e) => {
-            println!("Failed to load remote leaderboard: {}", e);
-            false // Using local fallback
-        }
-    }
-}
-
 pub async fn submit_score_with_fallback(
     api_client: &ApiClient,
     high_score: &HighScore,
@@ -192,7 +777,7 @@ pub async fn submit_score_with_fallback(
             println!("Score submitted successfully to remote API");
 
             // Re-query the leaderboard to get updated state from API
-            match api_client.fetch_leaderboard().await {
+            match api_client.fetch_leaderboard(high_score.category).await {
                 Ok(remote_scores) => {
                     local_leaderboard.merge_remote_scores(remote_scores);
                     println!("Leaderboard updated after score submission");
@@ -210,3 +795,153 @@ pub async fn submit_score_with_fallback(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Recorded query responses, one per schema, plus the odd cases
+    const V1_FIXTURE: &str = include_str!("../tests/fixtures/leaderboard_v1.json");
+    const V2_FIXTURE: &str = include_str!("../tests/fixtures/leaderboard_v2.json");
+    const MIXED_FIXTURE: &str = include_str!("../tests/fixtures/leaderboard_mixed.json");
+
+    fn parse_fixture(fixture: &str) -> Vec<HighScore> {
+        let values: Vec<serde_json::Value> =
+            serde_json::from_str(fixture).expect("fixture is valid JSON");
+        parse_score_records(values, Category::Standard)
+    }
+
+    fn find<'a>(scores: &'a [HighScore], name: &str) -> &'a HighScore {
+        scores
+            .iter()
+            .find(|score| score.name == name)
+            .unwrap_or_else(|| panic!("{} missing from the parsed board", name))
+    }
+
+    #[test]
+    fn v1_entries_migrate_without_run_metadata() {
+        let scores = parse_fixture(V1_FIXTURE);
+        assert_eq!(scores.len(), 4);
+        assert!(scores
+            .iter()
+            .all(|score| score.run.is_none() && score.id.is_some()));
+
+        let ninja = find(&scores, "CodeNinja");
+        assert_eq!((ninja.score, ninja.level), (4210, 7));
+        assert_eq!(ninja.mutators, vec![Mutator::DoubleSpeed, Mutator::Mirror]);
+
+        // A one-item list comes back from Fluree as a bare value
+        assert_eq!(
+            find(&scores, "PipelinePro").mutators,
+            vec![Mutator::LowGravity]
+        );
+
+        let cleaned = find(&scores, "BuildMaster");
+        assert_eq!(cleaned.moderation, Some(Moderation::Sanitized));
+
+        let sparse = find(&scores, "DeployDemon");
+        assert_eq!(sparse.level, 1);
+        assert!(sparse.mutators.is_empty());
+    }
+
+    #[test]
+    fn v2_entries_carry_run_metadata() {
+        let scores = parse_fixture(V2_FIXTURE);
+        assert_eq!(scores.len(), 2);
+
+        let hunter = find(&scores, "YetiHunter");
+        let run = hunter.run.as_ref().expect("v2 entries have run metadata");
+        assert_eq!((run.collected, run.dodged), (61, 38));
+        assert_eq!(run.level_times, vec![21.5, 47.25, 80.0]);
+        assert_eq!(run.seed, 14695981039346656037);
+        assert_eq!(run.version, "0.1.2");
+        assert!(hunter.is_plausible());
+        // Metadata from the board doesn't make an entry look like a local run
+        assert!(hunter.duration.is_none() && hunter.is_reportable());
+
+        let runner = find(&scores, "TestRunner");
+        assert_eq!(runner.run.as_ref().unwrap().level_times, vec![24.75]);
+        assert_eq!(runner.mutators, vec![Mutator::NoGoodItems]);
+        assert!(runner.is_hidden());
+    }
+
+    #[test]
+    fn mixed_versions_parse_tolerantly() {
+        let scores = parse_fixture(MIXED_FIXTURE);
+        // The entry with a non-numeric score is skipped, not the whole board
+        assert_eq!(scores.len(), 4);
+        assert!(scores.iter().all(|score| score.name != "Broken"));
+
+        assert!(find(&scores, "OldTimer").run.is_none());
+        // Written with run metadata before the schema version was
+        assert!(find(&scores, "EarlyAdopter").run.is_some());
+
+        // Newer schema: known fields kept, unknown values and fields dropped
+        let future = find(&scores, "FromTheFuture");
+        assert_eq!(future.mutators, vec![Mutator::DoubleSpeed]);
+        assert_eq!(future.moderation, None);
+        assert_eq!(future.run.as_ref().unwrap().version, "0.3.0");
+        assert!(future.is_plausible());
+
+        assert!(!find(&scores, "Impossible").is_plausible());
+    }
+
+    #[test]
+    fn submissions_read_back_unchanged() {
+        let run = RunMetadata {
+            duration: 58.5,
+            collected: 30,
+            dodged: 21,
+            level_times: vec![26.0],
+            seed: u64::MAX,
+            version: "0.1.2".to_string(),
+        };
+        let with_run = HighScore::new("Round Trip".to_string(), 640, 2)
+            .with_metadata(run)
+            .with_assist(true);
+        let without_run = HighScore::new("No Metadata".to_string(), 120, 1);
+        let assisted_without_run = HighScore::new("Magnet".to_string(), 300, 2).with_assist(true);
+
+        for submitted in [with_run, without_run, assisted_without_run] {
+            let insert = score_insert(&submitted);
+            let read = HighScore::from(ScoreRecord::parse(insert).expect("insert parses"));
+            assert_eq!(read.name, submitted.name);
+            assert_eq!((read.score, read.level), (submitted.score, submitted.level));
+            assert_eq!(read.timestamp, submitted.timestamp);
+            assert_eq!(read.mutators, submitted.mutators);
+            assert_eq!(read.assisted, submitted.assisted);
+            assert_eq!(read.run, submitted.run);
+        }
+    }
+
+    #[test]
+    fn every_submission_names_its_version() {
+        let without_run = HighScore::new("Old Style".to_string(), 120, 1);
+        let insert = score_insert(&without_run);
+        assert_eq!(insert["gameVersion"], env!("CARGO_PKG_VERSION"));
+
+        let run = RunMetadata {
+            duration: 10.0,
+            collected: 3,
+            dodged: 2,
+            level_times: Vec::new(),
+            seed: 7,
+            version: "0.1.1".to_string(),
+        };
+        let insert = score_insert(&without_run.with_metadata(run));
+        assert_eq!(insert["gameVersion"], "0.1.1");
+    }
+
+    #[test]
+    fn hardcore_entries_stay_off_the_global_board() {
+        let submitted =
+            HighScore::new("No Margin".to_string(), 910, 3).with_category(Category::Hardcore);
+        let insert = score_insert(&submitted);
+        assert!(insert.get("score").is_none());
+
+        let read = parse_score_records(vec![insert], Category::Hardcore);
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].score, submitted.score);
+        assert_eq!(read[0].category, Category::Hardcore);
+    }
+}
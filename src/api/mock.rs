@@ -0,0 +1,444 @@
+use super::{
+    parse_score_records, score_insert, score_property, ApiError, CommunityStats, NetworkSimulation,
+    ReplayRecord,
+};
+use crate::cli;
+use crate::highscores::{sanitize_name, Category, HighScore};
+use crate::telemetry::TelemetryEvent;
+use crate::version::Version;
+use std::cmp::Reverse;
+use std::sync::{Arc, Mutex};
+
+// What the board looks like before anything is submitted
+const CANNED_BOARDS: [&str; 2] = [
+    include_str!("../../tests/fixtures/leaderboard_v1.json"),
+    include_str!("../../tests/fixtures/leaderboard_v2.json"),
+];
+const CANNED_RUNS: u64 = 1_284; // Community totals to start from
+const CANNED_DODGED: u64 = 52_917;
+const BOARD_LIMIT: usize = 20; // Same as the real query
+
+// Everything the fake server has been sent, kept for as long as the process
+// runs. Scores are stored as the JSON a real submission would write, so
+// fetching them goes through the same record parsing as the real board.
+struct MockServer {
+    scores: Vec<serde_json::Value>,
+    challenges: Vec<(String, HighScore)>,
+    replays: Vec<ReplayRecord>,
+    runs: u64,
+    dodged: u64,
+    next_id: u32,
+}
+
+impl MockServer {
+    fn canned() -> Self {
+        let scores = CANNED_BOARDS
+            .iter()
+            .flat_map(|board| {
+                serde_json::from_str::<Vec<serde_json::Value>>(board).unwrap_or_default()
+            })
+            .collect();
+        Self {
+            scores,
+            challenges: Vec::new(),
+            replays: Vec::new(),
+            runs: CANNED_RUNS,
+            dodged: CANNED_DODGED,
+            next_id: 1,
+        }
+    }
+
+    fn next_id(&mut self) -> String {
+        let id = format!("_:mock{}", self.next_id);
+        self.next_id += 1;
+        id
+    }
+}
+
+// Stands in for FlureeClient under the `mock-api` feature, with the same
+// methods. Clones share one server, like clones of the real client share
+// the one backend, and dev mode's network simulation applies here too.
+#[derive(Clone)]
+pub struct MockClient {
+    server: Arc<Mutex<MockServer>>,
+    enabled: bool,
+    simulation: Arc<Mutex<NetworkSimulation>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        let enabled = if cli::args().offline {
+            println!("Offline mode: API features disabled");
+            false
+        } else {
+            println!("Mock API: using the in-process leaderboard, nothing leaves this machine");
+            true
+        };
+
+        Self {
+            server: Arc::new(Mutex::new(MockServer::canned())),
+            enabled,
+            simulation: Arc::default(),
+        }
+    }
+
+    pub fn network_simulation(&self) -> NetworkSimulation {
+        *self.simulation.lock().unwrap()
+    }
+
+    pub fn set_network_simulation(&self, simulation: NetworkSimulation) {
+        *self.simulation.lock().unwrap() = simulation;
+    }
+
+    pub async fn fetch_leaderboard(&self, category: Category) -> Result<Vec<HighScore>, ApiError> {
+        self.round_trip()?;
+
+        let property = score_property(category);
+        let mut values: Vec<serde_json::Value> = self
+            .server
+            .lock()
+            .unwrap()
+            .scores
+            .iter()
+            .filter(|value| value.get(property).is_some())
+            .cloned()
+            .collect();
+        // The real query's order: score, then level, then the earliest
+        values.sort_by_key(|value| {
+            let number = |key: &str| value.get(key).and_then(|number| number.as_u64());
+            let timestamp = value["timestamp"].as_str().map(str::to_string);
+            (
+                Reverse(number(property)),
+                Reverse(number("level")),
+                timestamp,
+            )
+        });
+        values.truncate(BOARD_LIMIT);
+
+        let mut scores = parse_score_records(values, category);
+        scores.retain(HighScore::is_plausible);
+        Ok(scores)
+    }
+
+    pub async fn submit_score(&self, high_score: &HighScore) -> Result<(), ApiError> {
+        self.round_trip()?;
+
+        let mut server = self.server.lock().unwrap();
+        let mut record = score_insert(high_score);
+        record["@id"] = server.next_id().into();
+        server.scores.push(record);
+        Ok(())
+    }
+
+    pub async fn fetch_challenge_leaderboard(
+        &self,
+        challenge_code: &str,
+    ) -> Result<Vec<HighScore>, ApiError> {
+        self.round_trip()?;
+
+        let server = self.server.lock().unwrap();
+        let mut scores: Vec<HighScore> = server
+            .challenges
+            .iter()
+            .filter(|(code, _)| code == challenge_code)
+            .map(|(_, score)| score.clone())
+            .collect();
+        scores.sort_by_key(|score| std::cmp::Reverse(score.score));
+        scores.truncate(BOARD_LIMIT);
+        Ok(scores)
+    }
+
+    pub async fn submit_challenge_score(
+        &self,
+        challenge_code: &str,
+        high_score: &HighScore,
+    ) -> Result<(), ApiError> {
+        self.round_trip()?;
+
+        let mut server = self.server.lock().unwrap();
+        let mut score = HighScore::new(
+            sanitize_name(&high_score.name),
+            high_score.score,
+            high_score.level,
+        );
+        score.id = Some(server.next_id());
+        score.timestamp = high_score.timestamp;
+        server.challenges.push((challenge_code.to_string(), score));
+        Ok(())
+    }
+
+    pub async fn submit_run(&self, _score: u32, _level: u32, dodged: u32) -> Result<(), ApiError> {
+        self.round_trip()?;
+
+        let mut server = self.server.lock().unwrap();
+        server.runs += 1;
+        server.dodged += dodged as u64;
+        Ok(())
+    }
+
+    // Accepted and logged; there are no moderators here to act on it
+    pub async fn report_entry(&self, entry_id: &str, name: &str) -> Result<(), ApiError> {
+        self.round_trip()?;
+        println!("Mock API: report received for {} ({})", name, entry_id);
+        Ok(())
+    }
+
+    // Any id that isn't on the board is a 404, as a retract of nothing would be
+    pub async fn delete_score(&self, entry_id: &str) -> Result<(), ApiError> {
+        self.round_trip()?;
+
+        let mut server = self.server.lock().unwrap();
+        let before = server.scores.len();
+        server
+            .scores
+            .retain(|score| score["@id"].as_str() != Some(entry_id));
+        if server.scores.len() == before {
+            return Err(ApiError::ServerError(404));
+        }
+        Ok(())
+    }
+
+    pub async fn submit_crash_report(&self, report: &str) -> Result<(), ApiError> {
+        self.round_trip()?;
+        println!("Mock API: crash report received ({} bytes)", report.len());
+        Ok(())
+    }
+
+    pub async fn submit_telemetry(&self, events: &[TelemetryEvent]) -> Result<(), ApiError> {
+        self.round_trip()?;
+        println!("Mock API: {} telemetry events received", events.len());
+        Ok(())
+    }
+
+    pub async fn fetch_community_stats(&self) -> Result<CommunityStats, ApiError> {
+        self.round_trip()?;
+
+        let server = self.server.lock().unwrap();
+        Ok(CommunityStats {
+            games_played: server.runs,
+            items_dodged: server.dodged,
+        })
+    }
+
+    pub async fn fetch_rank(&self, category: Category, score: u32) -> Result<usize, ApiError> {
+        self.round_trip()?;
+
+        let property = score_property(category);
+        let server = self.server.lock().unwrap();
+        let above = server
+            .scores
+            .iter()
+            .filter_map(|value| value.get(property)?.as_u64())
+            .filter(|other| *other > score as u64)
+            .count();
+        Ok(above + 1)
+    }
+
+    pub async fn upload_replay(
+        &self,
+        high_score: &HighScore,
+        replay: &str,
+    ) -> Result<(), ApiError> {
+        self.round_trip()?;
+
+        self.server.lock().unwrap().replays.push(ReplayRecord {
+            replay_name: high_score.name.clone(),
+            replay_score: high_score.score,
+            replay: replay.to_string(),
+        });
+        Ok(())
+    }
+
+    pub async fn fetch_top_replay(&self) -> Result<Option<ReplayRecord>, ApiError> {
+        self.round_trip()?;
+
+        let server = self.server.lock().unwrap();
+        Ok(server
+            .replays
+            .iter()
+            .max_by_key(|record| record.replay_score)
+            .map(|record| ReplayRecord {
+                replay_name: sanitize_name(&record.replay_name),
+                ..record.clone()
+            }))
+    }
+
+    // There's always a next minor release out, so the update banner shows
+    pub async fn fetch_latest_version(&self) -> Result<Option<Version>, ApiError> {
+        self.round_trip()?;
+
+        let current = Version::current();
+        Ok(Some(Version::new(current.major, current.minor + 1, 0)))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[cfg(test)]
+    pub fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    // What every request goes through before touching the server
+    fn round_trip(&self) -> Result<(), ApiError> {
+        if !self.enabled {
+            return Err(ApiError::Disabled);
+        }
+        self.network_simulation().apply()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::submit_score_with_fallback;
+    use crate::highscores::{Leaderboard, RunMetadata};
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Runtime::new().unwrap().block_on(future)
+    }
+
+    fn finished_run() -> HighScore {
+        HighScore::new("MockRunner".to_string(), 2_400, 3).with_metadata(RunMetadata {
+            duration: 75.0,
+            collected: 40,
+            dodged: 22,
+            level_times: vec![20.0, 48.5],
+            seed: 99,
+            version: "0.1.2".to_string(),
+        })
+    }
+
+    #[test]
+    fn sync_loads_the_canned_board() {
+        let client = MockClient::new();
+        let mut leaderboard = Leaderboard::new();
+
+        let remote = block_on(client.fetch_leaderboard(Category::Standard)).unwrap();
+        leaderboard.merge_remote_scores(remote);
+        assert!(!leaderboard.scores.is_empty());
+        assert!(leaderboard.scores.iter().all(|score| score.id.is_some()));
+    }
+
+    #[test]
+    fn submitted_score_comes_back_from_the_board() {
+        let client = MockClient::new();
+        let mut leaderboard = Leaderboard::new();
+        let score = finished_run();
+
+        assert!(block_on(submit_score_with_fallback(
+            &client,
+            &score,
+            &mut leaderboard
+        )));
+
+        let synced = leaderboard
+            .scores
+            .iter()
+            .find(|entry| entry.name == score.name && entry.score == score.score)
+            .expect("submitted score on the board");
+        assert!(synced
+            .id
+            .as_deref()
+            .is_some_and(|id| id.starts_with("_:mock")));
+
+        let remote = block_on(client.fetch_leaderboard(Category::Standard)).unwrap();
+        let entry = remote
+            .iter()
+            .find(|entry| entry.name == score.name)
+            .unwrap();
+        assert_eq!(entry.run, score.run);
+    }
+
+    #[test]
+    fn hardcore_scores_only_reach_the_hardcore_board() {
+        let client = MockClient::new();
+        let mut leaderboard = Leaderboard::new();
+        let score = finished_run().with_category(Category::Hardcore);
+
+        assert!(block_on(submit_score_with_fallback(
+            &client,
+            &score,
+            &mut leaderboard
+        )));
+
+        let standard = block_on(client.fetch_leaderboard(Category::Standard)).unwrap();
+        assert!(standard.iter().all(|entry| entry.name != score.name));
+        let hardcore = block_on(client.fetch_leaderboard(Category::Hardcore)).unwrap();
+        assert_eq!(hardcore.len(), 1);
+        assert_eq!(hardcore[0].category, Category::Hardcore);
+    }
+
+    #[test]
+    fn deleted_entries_leave_the_board() {
+        let client = MockClient::new();
+        let board = block_on(client.fetch_leaderboard(Category::Standard)).unwrap();
+        let id = board[0].id.clone().unwrap();
+
+        block_on(client.delete_score(&id)).unwrap();
+        let board = block_on(client.fetch_leaderboard(Category::Standard)).unwrap();
+        assert!(board
+            .iter()
+            .all(|entry| entry.id.as_deref() != Some(id.as_str())));
+        assert!(matches!(
+            block_on(client.delete_score(&id)),
+            Err(ApiError::ServerError(404))
+        ));
+    }
+
+    #[test]
+    fn rank_counts_past_the_top_of_the_board() {
+        let client = MockClient::new();
+        let mut leaderboard = Leaderboard::new();
+        for extra in 0..BOARD_LIMIT as u32 {
+            let score = HighScore::new(format!("Runner{}", extra), 50_000 + extra, 9);
+            block_on(submit_score_with_fallback(
+                &client,
+                &score,
+                &mut leaderboard,
+            ));
+        }
+
+        let rank = block_on(client.fetch_rank(Category::Standard, 40_000)).unwrap();
+        assert_eq!(rank, BOARD_LIMIT + 1);
+        let rank = block_on(client.fetch_rank(Category::Hardcore, 40_000)).unwrap();
+        assert_eq!(rank, 1);
+    }
+
+    #[test]
+    fn failed_submission_keeps_the_local_score() {
+        let client = MockClient::new();
+        client.set_network_simulation(NetworkSimulation {
+            failure_rate: 1.0,
+            ..NetworkSimulation::default()
+        });
+        let mut leaderboard = Leaderboard::new();
+        let score = finished_run();
+
+        assert!(!block_on(submit_score_with_fallback(
+            &client,
+            &score,
+            &mut leaderboard
+        )));
+        assert!(leaderboard
+            .scores
+            .iter()
+            .any(|entry| entry.name == score.name));
+
+        client.set_network_simulation(NetworkSimulation::default());
+        let remote = block_on(client.fetch_leaderboard(Category::Standard)).unwrap();
+        assert!(remote.iter().all(|entry| entry.name != score.name));
+    }
+
+    #[test]
+    fn disabled_client_never_reaches_the_server() {
+        let mut client = MockClient::new();
+        client.disable();
+
+        assert!(matches!(
+            block_on(client.fetch_leaderboard(Category::Standard)),
+            Err(ApiError::Disabled)
+        ));
+    }
+}
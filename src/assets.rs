@@ -1,7 +1,13 @@
+use crate::atlas::SpriteAtlas;
 use crate::design::GameFonts;
+use crate::entities::ItemCatalog;
+use crate::game::flavor::FlavorLines;
+use crate::game::levels::LevelCatalog;
+use crate::mods::ModPack;
+use macroquad::miniquad::conf::Icon;
 use macroquad::prelude::*;
 use rust_embed::RustEmbed;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(RustEmbed)]
 #[folder = "assets/"]
@@ -11,27 +17,103 @@ struct AssetFiles;
 #[folder = "generated_assets/"]
 struct GeneratedAssets;
 
+// Atlas name and embedded file for each yeti frame; mod skins replace these
+pub const YETI_FRAMES: [(&str, &str); 5] = [
+    ("yeti_run_1", "yeti_run_frame1_left_foot_forward_no_bg.png"),
+    ("yeti_run_2", "yeti_run_frame3_both_feet_contact_no_bg.png"),
+    ("yeti_jump", "yeti_jump_no_bg.png"),
+    ("yeti_cheer", "yeti_cheer_no_bg.png"),
+    ("yeti_stumble", "yeti_stumble_no_bg.png"),
+];
+
 pub struct GameAssets {
     pub textures: HashMap<String, Texture2D>,
+    pub sprites: SpriteAtlas,
     pub fonts: GameFonts,
 }
 
+impl Default for GameAssets {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GameAssets {
     pub fn new() -> Self {
         Self {
             textures: HashMap::new(),
+            sprites: SpriteAtlas::new(),
             fonts: GameFonts::new(),
         }
     }
 }
 
-pub async fn load_assets() -> GameAssets {
+pub async fn load_assets(
+    catalog: &ItemCatalog,
+    levels: &LevelCatalog,
+    mods: &[ModPack],
+) -> GameAssets {
     let mut assets = GameAssets::new();
-    assets.textures = load_textures().await;
+    assets.textures = load_textures(levels).await;
+    assets.sprites = load_sprites(catalog, mods);
     assets.fonts = load_fonts().await;
     assets
 }
 
+// Item definitions ship inside the binary, so a bad file is a build mistake
+pub fn load_item_catalog() -> ItemCatalog {
+    let data = AssetFiles::get("items.json").expect("items.json missing from embedded assets");
+    let text = std::str::from_utf8(&data.data).expect("items.json is not valid UTF-8");
+    ItemCatalog::from_json(text).unwrap_or_else(|e| panic!("Failed to load items.json: {}", e))
+}
+
+pub fn load_level_catalog() -> LevelCatalog {
+    let data = AssetFiles::get("levels.json").expect("levels.json missing from embedded assets");
+    let text = std::str::from_utf8(&data.data).expect("levels.json is not valid UTF-8");
+    LevelCatalog::from_json(text).unwrap_or_else(|e| panic!("Failed to load levels.json: {}", e))
+}
+
+pub fn load_flavor_lines() -> FlavorLines {
+    let data = AssetFiles::get("flavor.json").expect("flavor.json missing from embedded assets");
+    let text = std::str::from_utf8(&data.data).expect("flavor.json is not valid UTF-8");
+    FlavorLines::from_json(text).unwrap_or_else(|e| panic!("Failed to load flavor.json: {}", e))
+}
+
+pub fn load_changelog() -> String {
+    let data = AssetFiles::get("changelog.md").expect("changelog.md missing from embedded assets");
+    String::from_utf8_lossy(&data.data).into_owned()
+}
+
+pub fn load_voice_clip(file: &str) -> Option<Vec<u8>> {
+    AssetFiles::get(&format!("voice/{}", file)).map(|data| data.data.into_owned())
+}
+
+// The cheering yeti, fitted onto the square canvases the window icon needs.
+// Runs before the window exists, so it decodes straight from the embed.
+pub fn load_window_icon() -> Option<Icon> {
+    let data = GeneratedAssets::get("yeti_cheer_no_bg.png")?;
+    let img = image::load_from_memory(&data.data)
+        .map_err(|e| println!("Failed to load window icon: {}", e))
+        .ok()?;
+
+    let fit = |size: u32| -> Vec<u8> {
+        let scaled = img
+            .resize(size, size, image::imageops::FilterType::Triangle)
+            .to_rgba8();
+        let mut canvas = image::RgbaImage::new(size, size);
+        let x = (size - scaled.width()) / 2;
+        let y = (size - scaled.height()) / 2;
+        image::imageops::overlay(&mut canvas, &scaled, x as i64, y as i64);
+        canvas.into_raw()
+    };
+
+    Some(Icon {
+        small: fit(16).try_into().ok()?,
+        medium: fit(32).try_into().ok()?,
+        big: fit(64).try_into().ok()?,
+    })
+}
+
 async fn load_fonts() -> GameFonts {
     let mut fonts = GameFonts::new();
     
@@ -42,6 +124,7 @@ async fn load_fonts() -> GameFonts {
                 Ok(font) => {
                     println!("Successfully loaded Gotham-Medium font");
                     fonts.primary = Some(font);
+                    fonts.primary_chars = font_chars(&font_data.data);
                 }
                 Err(e) => {
                     println!("Failed to load primary font: {}", e);
@@ -57,63 +140,125 @@ async fn load_fonts() -> GameFonts {
     // macroquad doesn't provide direct access to system fonts, so we'll use None
     // and the typography system will fall back to the default font for monospace content
     fonts.monospace = None;
+
+    fonts.fallback = load_fallback_font();
     
     fonts
 }
 
-async fn load_textures() -> HashMap<String, Texture2D> {
-    let texture_files = vec![
-        ("yeti_run_1", "yeti_run_frame1_left_foot_forward_no_bg.png"),
-        ("yeti_run_2", "yeti_run_frame3_both_feet_contact_no_bg.png"),
-        ("yeti_jump", "yeti_jump_no_bg.png"),
-        ("yeti_cheer", "yeti_cheer_no_bg.png"),
-        ("yeti_stumble", "yeti_stumble_no_bg.png"),
-        ("item_pr_merged", "item_pr_merged.png"),
-        ("item_ci_pass", "item_ci_pass.png"),
-        ("item_deploy_success", "item_deploy_success.png"),
-        ("item_code_review", "item_code_review.png"),
-        ("item_tests_pass", "item_tests_pass.png"),
-        ("item_test_fail", "item_test_fail.png"),
-        ("item_merge_conflict", "item_merge_conflict.png"),
-        ("item_ci_fail", "item_ci_fail.png"),
-        ("item_security_vuln", "item_security_vuln.png"),
+// CJK fonts run to tens of megabytes, so rather than ship one the fallback
+// is the first of these the system has. Between them they cover CJK and the
+// other scripts names are most often typed in.
+const FALLBACK_FONTS: &[&str] = &[
+    // Linux
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+    // macOS
+    "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+    "/Library/Fonts/Arial Unicode.ttf",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    // Windows
+    "C:\\Windows\\Fonts\\msyh.ttc",
+    "C:\\Windows\\Fonts\\arialuni.ttf",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+];
+
+fn load_fallback_font() -> Option<Font> {
+    for path in FALLBACK_FONTS {
+        let Ok(data) = std::fs::read(path) else {
+            continue;
+        };
+        match load_ttf_font_from_bytes(&data) {
+            Ok(font) => {
+                println!("Using {} for characters Gotham-Medium lacks", path);
+                return Some(font);
+            }
+            Err(e) => println!("Failed to load fallback font {}: {}", path, e),
+        }
+    }
+    println!("No fallback font found; characters Gotham-Medium lacks will draw as boxes");
+    None
+}
+
+// Every character `data`'s font has a glyph for, so text can be split
+// between it and the fallback
+fn font_chars(data: &[u8]) -> HashSet<char> {
+    fontdue::Font::from_bytes(data, fontdue::FontSettings::default())
+        .map(|font| font.chars().keys().copied().collect())
+        .unwrap_or_default()
+}
+
+// Item and yeti sprites share one atlas texture; see atlas.rs
+fn load_sprites(catalog: &ItemCatalog, mods: &[ModPack]) -> SpriteAtlas {
+    let mut sprite_files = YETI_FRAMES.to_vec();
+
+    // Each item definition names its own sprite; mod items bring theirs below
+    let mod_sprites: Vec<_> = mods.iter().flat_map(|pack| &pack.sprites).collect();
+    for item in catalog.definitions() {
+        if !mod_sprites.iter().any(|(name, _)| *name == item.texture) {
+            sprite_files.push((item.texture.as_str(), item.texture_file.as_str()));
+        }
+    }
+
+    let mut images: Vec<(String, image::RgbaImage)> = sprite_files
+        .into_iter()
+        .filter_map(|(name, filename)| Some((name.to_string(), decode_image(filename)?)))
+        .collect();
+
+    // Later packs win when two skin the same frame
+    for (name, image) in mod_sprites {
+        images.retain(|(existing, _)| existing != name);
+        images.push((name.clone(), image.clone()));
+    }
+    SpriteAtlas::build(images)
+}
+
+async fn load_textures(levels: &LevelCatalog) -> HashMap<String, Texture2D> {
+    let mut texture_files = vec![
         ("pipeline_track", "pipeline_track.png"),
         ("background", "background.png"),
         ("ui_frame", "ui_frame.png"),
     ];
 
+    // Level themes can bring their own background layers
+    for layer in levels.background_layers() {
+        if !texture_files.iter().any(|(name, _)| *name == layer.texture) {
+            texture_files.push((layer.texture.as_str(), layer.file.as_str()));
+        }
+    }
+
     let mut textures = HashMap::new();
 
     for (name, filename) in texture_files {
-        match GeneratedAssets::get(filename) {
-            Some(texture_data) => {
-                match image::load_from_memory(&texture_data.data) {
-                    Ok(img) => {
-                        let image_data = img.to_rgba8();
-                        let width = img.width() as u16;
-                        let height = img.height() as u16;
-                        
-                        let image = Image {
-                            bytes: image_data.into_raw(),
-                            width,
-                            height,
-                        };
-                        
-                        let texture = Texture2D::from_image(&image);
-                        texture.set_filter(FilterMode::Nearest);
-                        textures.insert(name.to_string(), texture);
-                        println!("Successfully loaded texture: {}", filename);
-                    }
-                    Err(e) => {
-                        println!("Failed to load texture {}: {}", filename, e);
-                    }
-                }
-            }
-            None => {
-                println!("Texture file {} not found in embedded assets", filename);
-            }
+        if let Some(image) = decode_image(filename) {
+            let texture =
+                Texture2D::from_rgba8(image.width() as u16, image.height() as u16, image.as_raw());
+            texture.set_filter(FilterMode::Nearest);
+            textures.insert(name.to_string(), texture);
         }
     }
 
     textures
-}
\ No newline at end of file
+}
+
+fn decode_image(filename: &str) -> Option<image::RgbaImage> {
+    match GeneratedAssets::get(filename) {
+        Some(texture_data) => match image::load_from_memory(&texture_data.data) {
+            Ok(img) => {
+                println!("Successfully loaded texture: {}", filename);
+                Some(img.to_rgba8())
+            }
+            Err(e) => {
+                println!("Failed to load texture {}: {}", filename, e);
+                None
+            }
+        },
+        None => {
+            println!("Texture file {} not found in embedded assets", filename);
+            None
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use image::RgbaImage;
+use macroquad::prelude::*;
+use std::collections::HashMap;
+
+const ATLAS_WIDTH: u32 = 256;
+const PADDING: u32 = 2; // Transparent gap so neighbours never bleed in at odd offsets
+
+// A named region of the atlas texture
+#[derive(Debug, Clone)]
+pub struct Sprite {
+    pub texture: Texture2D,
+    pub source: Rect,
+}
+
+impl Sprite {
+    // draw_texture_ex with the source rect filled in; leave `source` unset
+    pub fn draw(&self, x: f32, y: f32, color: Color, params: DrawTextureParams) {
+        draw_texture_ex(
+            &self.texture,
+            x,
+            y,
+            color,
+            DrawTextureParams {
+                source: Some(self.source),
+                ..params
+            },
+        );
+    }
+}
+
+// Every item and yeti frame packed into one texture, so a crowded playfield
+// draws from a single texture instead of rebinding for each sprite.
+#[derive(Debug, Clone, Default)]
+pub struct SpriteAtlas {
+    sprites: HashMap<String, Sprite>,
+}
+
+impl SpriteAtlas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Shelf packing: tallest images first, left to right, starting a new row
+    // when one runs out of width. Good enough for a few dozen small sprites.
+    pub fn build(mut images: Vec<(String, RgbaImage)>) -> Self {
+        images.sort_by_key(|(_, image)| std::cmp::Reverse(image.height()));
+
+        let mut placements = Vec::with_capacity(images.len());
+        let (mut x, mut y, mut row_height) = (PADDING, PADDING, 0);
+        for (_, image) in &images {
+            if x + image.width() + PADDING > ATLAS_WIDTH && x > PADDING {
+                x = PADDING;
+                y += row_height + PADDING;
+                row_height = 0;
+            }
+            placements.push((x, y));
+            x += image.width() + PADDING;
+            row_height = row_height.max(image.height());
+        }
+        let height = y + row_height + PADDING;
+        let width = images
+            .iter()
+            .map(|(_, image)| image.width() + PADDING * 2)
+            .fold(ATLAS_WIDTH, u32::max);
+
+        let mut canvas = RgbaImage::new(width, height);
+        for ((_, image), &(x, y)) in images.iter().zip(&placements) {
+            image::imageops::replace(&mut canvas, image, x as i64, y as i64);
+        }
+
+        let texture = Texture2D::from_rgba8(width as u16, height as u16, canvas.as_raw());
+        texture.set_filter(FilterMode::Nearest);
+
+        let sprites = images
+            .into_iter()
+            .zip(placements)
+            .map(|((name, image), (x, y))| {
+                let source = Rect::new(
+                    x as f32,
+                    y as f32,
+                    image.width() as f32,
+                    image.height() as f32,
+                );
+                let sprite = Sprite {
+                    texture: texture.clone(),
+                    source,
+                };
+                (name, sprite)
+            })
+            .collect();
+
+        Self { sprites }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Sprite> {
+        self.sprites.get(name)
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(feature = "audio")]
+use macroquad::audio::{
+    load_sound_from_bytes, play_sound, set_sound_volume, PlaySoundParams, Sound,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelId(usize);
+
+// Looping channels with volume fades, plus one-shot sounds, over macroquad's
+// audio. Builds without
+// the `audio` feature keep the same bookkeeping and simply never make sound,
+// so game code doesn't need to care which one it's running in.
+pub struct Mixer {
+    channels: Vec<Channel>,
+    master: f32,
+}
+
+struct Channel {
+    #[cfg(feature = "audio")]
+    sound: Option<Sound>, // None if the backend couldn't load it
+    looped: bool,
+    volume: f32,
+    target: f32,
+    fade_rate: f32, // Volume change per second
+    applied: f32,   // Last volume handed to the backend
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mixer {
+    pub fn new() -> Self {
+        Self {
+            channels: Vec::new(),
+            master: 1.0,
+        }
+    }
+
+    pub async fn add_loop(&mut self, wav: &[u8]) -> ChannelId {
+        self.add_channel(wav, true).await
+    }
+
+    // A sound that only plays when asked, at whatever volume it's asked for
+    pub async fn add_sound(&mut self, wav: &[u8]) -> ChannelId {
+        self.add_channel(wav, false).await
+    }
+
+    async fn add_channel(&mut self, wav: &[u8], looped: bool) -> ChannelId {
+        #[cfg(feature = "audio")]
+        let sound = match load_sound_from_bytes(wav).await {
+            Ok(sound) => Some(sound),
+            Err(e) => {
+                println!("Failed to load sound: {}", e);
+                None
+            }
+        };
+        #[cfg(not(feature = "audio"))]
+        let _ = wav;
+
+        self.channels.push(Channel {
+            #[cfg(feature = "audio")]
+            sound,
+            looped,
+            volume: 0.0,
+            target: 0.0,
+            fade_rate: 1.0,
+            applied: 0.0,
+        });
+        ChannelId(self.channels.len() - 1)
+    }
+
+    // Start every loop at once, silent, so layered stems line up
+    pub fn start(&self) {
+        #[cfg(feature = "audio")]
+        for sound in self
+            .channels
+            .iter()
+            .filter(|channel| channel.looped)
+            .filter_map(|channel| channel.sound.as_ref())
+        {
+            play_sound(
+                sound,
+                PlaySoundParams {
+                    looped: true,
+                    volume: 0.0,
+                },
+            );
+        }
+    }
+
+    pub fn play_once(&self, id: ChannelId, volume: f32) {
+        let Some(channel) = self.channels.get(id.0) else {
+            return;
+        };
+        let volume = volume.clamp(0.0, 1.0) * self.master;
+        if volume <= 0.0 {
+            return;
+        }
+        #[cfg(feature = "audio")]
+        if let Some(sound) = &channel.sound {
+            play_sound(
+                sound,
+                PlaySoundParams {
+                    looped: false,
+                    volume,
+                },
+            );
+        }
+        #[cfg(not(feature = "audio"))]
+        let _ = channel;
+    }
+
+    pub fn fade_to(&mut self, id: ChannelId, volume: f32, seconds: f32) {
+        let Some(channel) = self.channels.get_mut(id.0) else {
+            return;
+        };
+        let volume = volume.clamp(0.0, 1.0);
+        if channel.target != volume {
+            channel.target = volume;
+            channel.fade_rate = (volume - channel.volume).abs() / seconds.max(0.01);
+        }
+    }
+
+    pub fn set_master(&mut self, volume: f32) {
+        self.master = volume.clamp(0.0, 1.0);
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        for channel in self.channels.iter_mut().filter(|channel| channel.looped) {
+            let step = channel.fade_rate * dt;
+            let difference = channel.target - channel.volume;
+            channel.volume += difference.clamp(-step, step);
+
+            // The backend call isn't free; skip it when nothing audible
+            // changed, but always land exactly on the target
+            let output = channel.volume * self.master;
+            let settled = channel.volume == channel.target;
+            if (output - channel.applied).abs() > 0.001 || (settled && output != channel.applied) {
+                channel.applied = output;
+                #[cfg(feature = "audio")]
+                if let Some(sound) = &channel.sound {
+                    set_sound_volume(sound, output);
+                }
+            }
+        }
+    }
+}
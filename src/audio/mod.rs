@@ -0,0 +1,7 @@
+pub mod mixer;
+pub mod music;
+pub mod synth;
+pub mod voice;
+
+pub use music::Music;
+pub use voice::Voice;
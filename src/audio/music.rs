@@ -0,0 +1,117 @@
+use super::mixer::{ChannelId, Mixer};
+use super::synth;
+use crate::game::{Game, GameState};
+
+const FADE_SECONDS: f32 = 1.5;
+const PAUSE_FADE_SECONDS: f32 = 0.3; // Pausing should feel immediate
+const MUFFLE_CUTOFF_HZ: f32 = 500.0;
+
+// Adaptive soundtrack: a base loop that always plays, with percussion and a
+// lead line layered in as the run gets faster. Pausing crossfades every
+// stem to a low-passed copy.
+pub struct Music {
+    mixer: Mixer,
+    stems: Option<Stems>,
+}
+
+struct Stems {
+    base: Layer,
+    percussion: Layer,
+    lead: Layer,
+}
+
+// One stem and its muffled twin
+struct Layer {
+    clear: ChannelId,
+    muffled: ChannelId,
+}
+
+impl Music {
+    pub async fn load() -> Self {
+        let mut mixer = Mixer::new();
+
+        // Synthesis takes a moment, so silent builds skip it
+        let stems = if cfg!(feature = "audio") {
+            let stems = Stems {
+                base: Layer::load(&mut mixer, synth::base_loop()).await,
+                percussion: Layer::load(&mut mixer, synth::percussion_loop()).await,
+                lead: Layer::load(&mut mixer, synth::lead_loop()).await,
+            };
+            mixer.start();
+            Some(stems)
+        } else {
+            None
+        };
+
+        Self { mixer, stems }
+    }
+
+    pub fn update(&mut self, game: &Game, dt: f32) {
+        self.mixer.set_master(game.settings.music_volume);
+
+        if let Some(stems) = &self.stems {
+            let (base, percussion, lead) = layer_volumes(game);
+            let paused = game.scenes.visible().contains(&GameState::Paused);
+            let fade = if paused {
+                PAUSE_FADE_SECONDS
+            } else {
+                FADE_SECONDS
+            };
+
+            stems.base.fade_to(&mut self.mixer, base, paused, fade);
+            stems
+                .percussion
+                .fade_to(&mut self.mixer, percussion, paused, fade);
+            stems.lead.fade_to(&mut self.mixer, lead, paused, fade);
+        }
+
+        self.mixer.update(dt);
+    }
+}
+
+impl Layer {
+    async fn load(mixer: &mut Mixer, samples: Vec<f32>) -> Self {
+        let muffled = synth::low_pass(&samples, MUFFLE_CUTOFF_HZ);
+        Self {
+            clear: mixer.add_loop(&synth::to_wav(&samples)).await,
+            muffled: mixer.add_loop(&synth::to_wav(&muffled)).await,
+        }
+    }
+
+    fn fade_to(&self, mixer: &mut Mixer, volume: f32, muffled: bool, seconds: f32) {
+        let (heard, silent) = if muffled {
+            (self.muffled, self.clear)
+        } else {
+            (self.clear, self.muffled)
+        };
+        mixer.fade_to(heard, volume, seconds);
+        mixer.fade_to(silent, 0.0, seconds);
+    }
+}
+
+// (base, percussion, lead) for what's on screen. Percussion comes in over
+// levels 2-3 and the lead once the items pick up speed past level 4.
+fn layer_volumes(game: &Game) -> (f32, f32, f32) {
+    let level = game.run.level as f32;
+    let percussion = ((level - 1.0) / 2.0).clamp(0.0, 1.0);
+    let lead = ((level - 3.0) / 2.0).clamp(0.0, 1.0);
+
+    match game.state() {
+        _ if game.is_demo => (0.5, 0.0, 0.0),
+        GameState::Playing | GameState::LevelComplete | GameState::Paused => {
+            (1.0, percussion, lead)
+        }
+        GameState::Settings | GameState::FeedbackHistory
+            if game.scenes.visible().contains(&GameState::Paused) =>
+        {
+            (1.0, percussion, lead)
+        }
+        // The band stops mid-bar when the yeti goes down
+        GameState::Dying => (0.4, 0.0, 0.0),
+        GameState::GameOver
+        | GameState::RunSummary
+        | GameState::NameInput
+        | GameState::VersusResults => (0.35, 0.0, 0.0),
+        _ => (0.6, 0.0, 0.0),
+    }
+}
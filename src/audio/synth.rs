@@ -0,0 +1,196 @@
+use ::rand::rngs::StdRng;
+use ::rand::{Rng, SeedableRng};
+use std::f32::consts::TAU;
+
+// Everything is synthesized at startup, so there are no music files to ship.
+// Each stem is the same four-bar loop; started together they stay in sync.
+pub const SAMPLE_RATE: u32 = 22_050;
+const BPM: f32 = 112.0;
+const BEATS_PER_BAR: usize = 4;
+
+// A minor, i-VI-III-VII (Am F C G), one chord per bar, as MIDI notes
+const CHORDS: [[u8; 3]; 4] = [[57, 60, 64], [53, 57, 60], [48, 52, 55], [55, 59, 62]];
+
+fn beat_seconds() -> f32 {
+    60.0 / BPM
+}
+
+fn loop_samples() -> usize {
+    CHORDS.len() * BEATS_PER_BAR * seconds_to_samples(beat_seconds())
+}
+
+fn seconds_to_samples(seconds: f32) -> usize {
+    (seconds * SAMPLE_RATE as f32) as usize
+}
+
+pub fn midi_to_hz(note: u8) -> f32 {
+    440.0 * 2f32.powf((note as f32 - 69.0) / 12.0)
+}
+
+// Add a sound into the loop, wrapping its tail around to the start so the
+// seam is inaudible
+fn mix_into(buffer: &mut [f32], start: usize, length: usize, mut sample: impl FnMut(f32) -> f32) {
+    let len = buffer.len();
+    for i in 0..length {
+        let t = i as f32 / SAMPLE_RATE as f32;
+        buffer[(start + i) % len] += sample(t);
+    }
+}
+
+// Warm pad on the chords plus a plucked bass note on every beat
+pub fn base_loop() -> Vec<f32> {
+    let mut buffer = vec![0.0; loop_samples()];
+    let beat = seconds_to_samples(beat_seconds());
+    let bar = beat * BEATS_PER_BAR;
+
+    for (bar_index, chord) in CHORDS.iter().enumerate() {
+        let bar_start = bar_index * bar;
+
+        for &note in chord {
+            let hz = midi_to_hz(note - 12);
+            mix_into(&mut buffer, bar_start, bar, |t| {
+                let attack = (t / 0.4).min(1.0);
+                let release = ((bar as f32 / SAMPLE_RATE as f32 - t) / 0.3).clamp(0.0, 1.0);
+                let tone = (TAU * hz * t).sin() + 0.3 * (TAU * hz * 2.0 * t).sin();
+                tone * attack * release * 0.05
+            });
+        }
+
+        let bass_hz = midi_to_hz(chord[0] - 24);
+        for beat_index in 0..BEATS_PER_BAR {
+            mix_into(&mut buffer, bar_start + beat_index * beat, beat, |t| {
+                let phase = TAU * bass_hz * t;
+                let tone = phase.sin() + 0.35 * (phase * 3.0).sin();
+                tone * (-t * 6.0).exp() * 0.22
+            });
+        }
+    }
+
+    buffer
+}
+
+// Kick on one and three, snare on two and four, hats on the eighths
+pub fn percussion_loop() -> Vec<f32> {
+    let mut buffer = vec![0.0; loop_samples()];
+    let beat = seconds_to_samples(beat_seconds());
+    let beats = CHORDS.len() * BEATS_PER_BAR;
+    let mut noise = StdRng::seed_from_u64(0x5EED);
+
+    for beat_index in 0..beats {
+        let start = beat_index * beat;
+
+        if beat_index % 2 == 0 {
+            mix_into(&mut buffer, start, beat / 2, |t| {
+                // Pitch drops fast for the thump
+                let hz = 45.0 + 90.0 * (-t * 30.0).exp();
+                (TAU * hz * t).sin() * (-t * 14.0).exp() * 0.45
+            });
+        } else {
+            let grain: Vec<f32> = (0..beat / 2).map(|_| noise.gen_range(-1.0..1.0)).collect();
+            let mut index = 0;
+            mix_into(&mut buffer, start, grain.len(), |t| {
+                let body = (TAU * 185.0 * t).sin() * 0.4;
+                let value = (grain[index] + body) * (-t * 22.0).exp() * 0.25;
+                index += 1;
+                value
+            });
+        }
+
+        for half in 0..2 {
+            let mut previous = 0.0;
+            mix_into(&mut buffer, start + half * beat / 2, beat / 4, |t| {
+                // First difference of white noise: a cheap high-pass
+                let sample: f32 = noise.gen_range(-1.0..1.0);
+                let hiss = sample - previous;
+                previous = sample;
+                hiss * (-t * 70.0).exp() * 0.06
+            });
+        }
+    }
+
+    buffer
+}
+
+// Sixteenth-note arpeggio an octave above the chords
+pub fn lead_loop() -> Vec<f32> {
+    const PATTERN: [usize; 8] = [0, 1, 2, 1, 0, 2, 1, 2];
+
+    let mut buffer = vec![0.0; loop_samples()];
+    let step = seconds_to_samples(beat_seconds() / 4.0);
+    let steps_per_bar = BEATS_PER_BAR * 4;
+
+    for (bar_index, chord) in CHORDS.iter().enumerate() {
+        for step_index in 0..steps_per_bar {
+            let note = chord[PATTERN[step_index % PATTERN.len()]] + 12;
+            let hz = midi_to_hz(note);
+            let start = (bar_index * steps_per_bar + step_index) * step;
+            mix_into(&mut buffer, start, step * 2, |t| {
+                // Triangle wave from the phase
+                let phase = (hz * t).fract();
+                let triangle = 4.0 * (phase - 0.5).abs() - 1.0;
+                triangle * (-t * 12.0).exp() * 0.09
+            });
+        }
+    }
+
+    buffer
+}
+
+// One blip of the yeti's gibberish voice: a buzzy tone that slides up a
+// little over its length, which is what makes it sound like a syllable
+pub fn voice_syllable(hz: f32, seconds: f32) -> Vec<f32> {
+    const GLIDE: f32 = 0.08; // Ends this much sharper than it starts
+
+    (0..seconds_to_samples(seconds))
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let progress = t / seconds;
+            // Integral of hz * (1 + GLIDE * t / seconds)
+            let phase = TAU * hz * (t + GLIDE * t * t / (2.0 * seconds));
+            let tone = phase.sin() + 0.5 * (phase * 2.0).sin() + 0.3 * (phase * 3.0).sin();
+            let envelope = (t / 0.008).min(1.0) * (1.0 - progress) * (1.0 - progress);
+            tone * envelope * 0.25
+        })
+        .collect()
+}
+
+// Two passes of a one-pole filter: the "through the wall" sound for pauses
+pub fn low_pass(samples: &[f32], cutoff_hz: f32) -> Vec<f32> {
+    let alpha = 1.0 - (-TAU * cutoff_hz / SAMPLE_RATE as f32).exp();
+    let mut output = samples.to_vec();
+    for _ in 0..2 {
+        // Start from the loop's last value so the seam doesn't click
+        let mut state = *output.last().unwrap_or(&0.0);
+        for sample in output.iter_mut() {
+            state += alpha * (*sample - state);
+            *sample = state;
+        }
+    }
+    output
+}
+
+// 16-bit mono PCM in a WAV container, which the audio backend can load
+pub fn to_wav(samples: &[f32]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // Mono
+    wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes()); // Bytes per second
+    wav.extend_from_slice(&2u16.to_le_bytes()); // Block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // Bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        wav.extend_from_slice(&value.to_le_bytes());
+    }
+    wav
+}
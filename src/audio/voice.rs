@@ -0,0 +1,180 @@
+use super::mixer::{ChannelId, Mixer};
+use super::synth;
+use crate::assets::load_voice_clip;
+use crate::entities::{ItemCatalog, ItemType};
+use std::collections::{HashMap, VecDeque};
+
+const SYLLABLE_SECONDS: f32 = 0.06;
+const STEP_SECONDS: f32 = 0.075; // Syllable plus a hair of gap
+const MAX_SYLLABLES: usize = 14; // Long lines are cut off, not sped up
+const QUIP_GAP_SECONDS: f32 = 1.5; // Quiet time after one quip before the next
+const CLIP_GAP_SECONDS: f32 = 2.5; // Recorded clips don't say how long they are
+
+// Major pentatonic, so any run of syllables sounds friendly rather than random
+const PENTATONIC: [u8; 5] = [0, 2, 4, 7, 9];
+const HIGH_ROOT: u8 = 72; // C5, for good news
+const LOW_ROOT: u8 = 57; // A3, for trouble
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    High,
+    Low,
+}
+
+// The yeti "reading out" its feedback lines in beepy gibberish, or a recorded
+// clip when an item ships one. A quip that arrives while another is still
+// playing, or too soon after it, is dropped rather than queued: by the time
+// it got its turn the item would be long gone.
+pub struct Voice {
+    mixer: Mixer,
+    syllables: Vec<ChannelId>, // High register pitches, then low
+    clips: HashMap<ItemType, ChannelId>,
+    queue: VecDeque<Option<ChannelId>>, // None is the pause between words
+    step_timer: f32,
+    cooldown: f32,
+}
+
+impl Default for Voice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Voice {
+    // No sounds at all; what the game starts with until `load` finishes
+    pub fn new() -> Self {
+        Self {
+            mixer: Mixer::new(),
+            syllables: Vec::new(),
+            clips: HashMap::new(),
+            queue: VecDeque::new(),
+            step_timer: 0.0,
+            cooldown: 0.0,
+        }
+    }
+
+    pub async fn load(catalog: &ItemCatalog) -> Self {
+        let mut voice = Self::new();
+        if !cfg!(feature = "audio") {
+            return voice;
+        }
+
+        for root in [HIGH_ROOT, LOW_ROOT] {
+            for step in PENTATONIC {
+                let samples =
+                    synth::voice_syllable(synth::midi_to_hz(root + step), SYLLABLE_SECONDS);
+                let id = voice.mixer.add_sound(&synth::to_wav(&samples)).await;
+                voice.syllables.push(id);
+            }
+        }
+
+        for item_type in catalog.types() {
+            let Some(file) = &catalog.get(item_type).voice_clip else {
+                continue;
+            };
+            match load_voice_clip(file) {
+                Some(wav) => {
+                    let id = voice.mixer.add_sound(&wav).await;
+                    voice.clips.insert(item_type, id);
+                }
+                None => println!("Voice clip {} not found in embedded assets", file),
+            }
+        }
+
+        voice
+    }
+
+    pub fn is_speaking(&self) -> bool {
+        !self.queue.is_empty() || self.cooldown > 0.0
+    }
+
+    // An item's feedback line: its own clip if it has one, gibberish if not
+    pub fn quip_item(&mut self, item_type: ItemType, line: &str, register: Register) {
+        if self.is_speaking() {
+            return;
+        }
+        match self.clips.get(&item_type) {
+            Some(&clip) => {
+                self.mixer.play_once(clip, 1.0);
+                self.cooldown = CLIP_GAP_SECONDS;
+            }
+            None => self.say(line, register),
+        }
+    }
+
+    pub fn say(&mut self, line: &str, register: Register) {
+        if self.is_speaking() || self.syllables.is_empty() {
+            return;
+        }
+
+        let offset = match register {
+            Register::High => 0,
+            Register::Low => PENTATONIC.len(),
+        };
+        self.queue = syllables_for(line)
+            .map(|pitch| pitch.map(|pitch| self.syllables[offset + pitch]))
+            .collect();
+        self.step_timer = 0.0;
+        self.cooldown = QUIP_GAP_SECONDS;
+    }
+
+    // Cut off whatever is playing; for moments that matter more than the
+    // rate limit, like a crash
+    pub fn interrupt(&mut self, line: &str, register: Register) {
+        self.queue.clear();
+        self.cooldown = 0.0;
+        self.say(line, register);
+    }
+
+    pub fn update(&mut self, dt: f32, volume: f32) {
+        self.mixer.set_master(volume);
+
+        if !self.queue.is_empty() {
+            self.step_timer -= dt;
+            if self.step_timer <= 0.0 {
+                self.step_timer += STEP_SECONDS;
+                if let Some(Some(syllable)) = self.queue.pop_front() {
+                    self.mixer.play_once(syllable, 1.0);
+                }
+            }
+            // The gap is counted from the end of the quip
+            return;
+        }
+
+        self.cooldown = (self.cooldown - dt).max(0.0);
+    }
+}
+
+// One pitch per vowel group, so "merge conflict" comes out as four blips, with
+// a rest between words. The pitch comes from the letters around the vowel, so
+// the same line always sounds the same.
+fn syllables_for(line: &str) -> impl Iterator<Item = Option<usize>> {
+    let mut syllables = Vec::new();
+
+    for word in line.split_whitespace() {
+        let mut previous = ' ';
+        let mut in_vowel = false;
+        let start = syllables.len();
+
+        for c in word.chars().filter(|c| c.is_ascii_alphabetic()) {
+            let c = c.to_ascii_lowercase();
+            let vowel = matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+            if vowel && !in_vowel {
+                syllables.push(Some((previous as usize + c as usize) % PENTATONIC.len()));
+            }
+            in_vowel = vowel;
+            previous = c;
+        }
+
+        // "CI" and "PR" still get said
+        if syllables.len() == start && word.chars().any(|c| c.is_ascii_alphanumeric()) {
+            syllables.push(Some(word.len() % PENTATONIC.len()));
+        }
+        if syllables.len() > start {
+            syllables.push(None);
+        }
+    }
+
+    syllables.pop(); // No rest after the last word
+    syllables.into_iter().take(MAX_SYLLABLES)
+}
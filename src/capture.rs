@@ -0,0 +1,335 @@
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{ColorTheme, GameFonts, TypographyStyle, UIComponent};
+use crate::game::{Game, GameState};
+use crate::highscores::Category;
+use crate::themes::ThemeProvider;
+use crate::ui::share_card::{self, CARD_HEIGHT, CARD_WIDTH};
+use crate::ui::{Renderer, Viewport};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::imageops::{self, FilterType};
+use image::{Delay, Frame, RgbaImage};
+use macroquad::prelude::*;
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+const CLIP_SECONDS: f32 = 5.0;
+const CLIP_FRAME_INTERVAL: f32 = 0.1; // 10 FPS keeps the GIF small
+const CLIP_FRAMES: usize = (CLIP_SECONDS / CLIP_FRAME_INTERVAL) as usize;
+const CLIP_SCALE: u32 = 2; // Clip frames are stored at half resolution
+const MESSAGE_SECONDS: f32 = 3.0;
+const PHOTO_SCALE: u32 = 4; // Photo mode saves at 2560x1080
+
+// F12 screenshots plus a rolling buffer of the last few seconds of play,
+// written out as a GIF when a run ends, the game-over share card, and CSV
+// exports of the leaderboard. Files land in the captures folder; encoding
+// happens on a worker thread so the game never stalls on disk.
+pub struct Capture {
+    clip: VecDeque<RgbaImage>,
+    clip_timer: f32,
+    screenshot_pending: bool,
+    last_state: GameState,
+    message: String,
+    message_timer: f32,
+    results: mpsc::Receiver<String>,
+    sender: mpsc::Sender<String>,
+    // Kept alive because on X11 the copied image disappears with its owner
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl Default for Capture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Capture {
+    pub fn new() -> Self {
+        let (sender, results) = mpsc::channel();
+        Self {
+            clip: VecDeque::with_capacity(CLIP_FRAMES),
+            clip_timer: 0.0,
+            screenshot_pending: false,
+            last_state: GameState::MainMenu,
+            message: String::new(),
+            message_timer: 0.0,
+            results,
+            sender,
+            clipboard: None,
+        }
+    }
+
+    // Runs before the frame is presented: stamps the score on a frame that is
+    // about to be saved, otherwise shows the result of the last capture
+    pub fn draw(&mut self, game: &Game, dt: f32) {
+        if is_key_pressed(KeyCode::F12) {
+            self.screenshot_pending = true;
+        }
+
+        if let Ok(message) = self.results.try_recv() {
+            self.message = message;
+            self.message_timer = MESSAGE_SECONDS;
+        }
+        self.message_timer = (self.message_timer - dt).max(0.0);
+
+        if self.screenshot_pending {
+            draw_stamp(game, &game.fonts);
+        } else if self.message_timer > 0.0 {
+            UIComponent::draw_text(
+                &self.message,
+                8.0,
+                SCREEN_HEIGHT - 8.0,
+                TypographyStyle::UICaption,
+                ColorTheme::Technical,
+                &game.fonts,
+            );
+        }
+    }
+
+    // Runs after the frame is presented, once the virtual screen holds the
+    // finished image
+    pub fn grab(&mut self, game: &Game, viewport: &Viewport, dt: f32) {
+        if self.screenshot_pending {
+            self.screenshot_pending = false;
+            self.save_screenshot(snapshot(viewport));
+        }
+
+        let state = game.state();
+        let recording = !game.is_demo
+            && matches!(
+                state,
+                GameState::Playing | GameState::Dying | GameState::LevelComplete
+            );
+
+        if recording {
+            self.clip_timer -= dt;
+            if self.clip_timer <= 0.0 {
+                self.clip_timer = CLIP_FRAME_INTERVAL;
+                self.record_clip_frame(viewport);
+            }
+        } else if self.last_state == GameState::Dying {
+            self.save_clip();
+        } else if state == GameState::MainMenu {
+            // Quitting from pause shouldn't leak into the next run's clip
+            self.clip.clear();
+        }
+        self.last_state = state;
+    }
+
+    fn record_clip_frame(&mut self, viewport: &Viewport) {
+        let frame = snapshot(viewport);
+        let frame = imageops::resize(
+            &frame,
+            frame.width() / CLIP_SCALE,
+            frame.height() / CLIP_SCALE,
+            FilterType::Nearest,
+        );
+        if self.clip.len() == CLIP_FRAMES {
+            self.clip.pop_front();
+        }
+        self.clip.push_back(frame);
+    }
+
+    fn save_screenshot(&self, image: RgbaImage) {
+        self.spawn_writer("shot", "png", "Saved screenshot", move |path| {
+            image.save(path).map_err(|e| e.to_string())
+        });
+    }
+
+    // Render the share card offscreen, copy it to the clipboard and save it.
+    // Call outside any other camera, after the frame has been presented.
+    pub fn share_card(&mut self, game: &Game) {
+        let target = render_target(CARD_WIDTH as u32, CARD_HEIGHT as u32);
+        let mut camera = Camera2D::from_display_rect(Rect::new(0.0, 0.0, CARD_WIDTH, CARD_HEIGHT));
+        camera.render_target = Some(target.clone());
+
+        set_camera(&camera);
+        let rank = game.run_rank().map(|(rank, _)| rank);
+        share_card::draw_share_card(
+            &game.run,
+            game.is_new_high_score,
+            rank,
+            game.hardcore,
+            &game.sprites,
+            &game.fonts,
+        );
+        set_default_camera(); // Flushes the card into the target
+
+        let card = upright(target.texture.get_texture_data());
+        let label = if self.copy_to_clipboard(&card) {
+            "Copied share card, saved to"
+        } else {
+            "Saved share card to"
+        };
+        self.spawn_writer("card", "png", label, move |path| {
+            card.save(path).map_err(|e| e.to_string())
+        });
+    }
+
+    // Render photo mode's view offscreen at PHOTO_SCALE and save it. Call
+    // outside any other camera, after the frame has been presented.
+    pub fn save_photo(&mut self, game: &Game, renderer: &Renderer) {
+        let target = render_target(
+            SCREEN_WIDTH as u32 * PHOTO_SCALE,
+            SCREEN_HEIGHT as u32 * PHOTO_SCALE,
+        );
+        let mut camera =
+            Camera2D::from_display_rect(Rect::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT));
+        camera.render_target = Some(target.clone());
+
+        set_camera(&camera);
+        renderer.draw_photo(game);
+        set_default_camera(); // Flushes the photo into the target
+
+        let photo = upright(target.texture.get_texture_data());
+        self.spawn_writer("photo", "png", "Saved photo to", move |path| {
+            photo.save(path).map_err(|e| e.to_string())
+        });
+    }
+
+    // The leaderboard tab on show, as a CSV for event organizers
+    pub fn export_leaderboard(&self, game: &Game) {
+        let category = game.leaderboard.category;
+        let kind = match category {
+            Category::Standard => "leaderboard",
+            Category::Hardcore => "hardcore-leaderboard",
+        };
+        let csv = game.leaderboard.board_for(category).to_csv();
+        self.spawn_writer(kind, "csv", "Exported leaderboard to", move |path| {
+            fs::write(path, csv).map_err(|e| e.to_string())
+        });
+    }
+
+    fn copy_to_clipboard(&mut self, image: &RgbaImage) -> bool {
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(e) => {
+                    println!("Clipboard unavailable: {}", e);
+                    return false;
+                }
+            }
+        }
+        let Some(clipboard) = self.clipboard.as_mut() else {
+            return false;
+        };
+
+        let data = arboard::ImageData {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            bytes: image.as_raw().into(),
+        };
+        match clipboard.set_image(data) {
+            Ok(()) => true,
+            Err(e) => {
+                println!("Failed to copy share card: {}", e);
+                false
+            }
+        }
+    }
+
+    fn save_clip(&mut self) {
+        if self.clip.is_empty() {
+            return;
+        }
+        let frames: Vec<RgbaImage> = self.clip.drain(..).collect();
+        self.spawn_writer("clip", "gif", "Saved clip", move |path| {
+            let file = File::create(path).map_err(|e| e.to_string())?;
+            let mut encoder = GifEncoder::new_with_speed(file, 10);
+            encoder
+                .set_repeat(Repeat::Infinite)
+                .map_err(|e| e.to_string())?;
+            let delay = Delay::from_numer_denom_ms((CLIP_FRAME_INTERVAL * 1000.0) as u32, 1);
+            encoder
+                .encode_frames(
+                    frames
+                        .into_iter()
+                        .map(|frame| Frame::from_parts(frame, 0, 0, delay)),
+                )
+                .map_err(|e| e.to_string())
+        });
+    }
+
+    // Write a timestamped file off the main thread and report back through
+    // the results channel
+    fn spawn_writer<F>(&self, kind: &'static str, extension: &'static str, label: &str, write: F)
+    where
+        F: FnOnce(&PathBuf) -> Result<(), String> + Send + 'static,
+    {
+        if cfg!(target_arch = "wasm32") {
+            let _ = self
+                .sender
+                .send("Captures need the desktop build".to_string());
+            return;
+        }
+
+        let Some(dir) = captures_dir() else {
+            let _ = self.sender.send("No folder for captures".to_string());
+            return;
+        };
+
+        let sender = self.sender.clone();
+        let label = label.to_string();
+        std::thread::spawn(move || {
+            let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+            let path = dir.join(format!("yeti-{}-{}.{}", kind, timestamp, extension));
+            let result = fs::create_dir_all(&dir)
+                .map_err(|e| e.to_string())
+                .and_then(|_| write(&path));
+
+            let message = match result {
+                Ok(()) => format!("{} {}", label, path.display()),
+                Err(e) => {
+                    println!("Failed to save capture {}: {}", path.display(), e);
+                    "Capture failed, see log".to_string()
+                }
+            };
+            let _ = sender.send(message);
+        });
+    }
+}
+
+fn captures_dir() -> Option<PathBuf> {
+    dirs::picture_dir()
+        .or_else(dirs::data_dir)
+        .map(|dir| dir.join("yeti-set-go").join("captures"))
+}
+
+// The virtual screen as an upright RGBA image
+fn snapshot(viewport: &Viewport) -> RgbaImage {
+    upright(viewport.snapshot())
+}
+
+// Render targets come out upside down, same as when presenting
+fn upright(data: Image) -> RgbaImage {
+    let image = RgbaImage::from_raw(data.width as u32, data.height as u32, data.bytes)
+        .expect("render target data matches its size");
+    imageops::flip_vertical(&image)
+}
+
+// Score corner for screenshots, so a shared image says what it was
+fn draw_stamp(game: &Game, fonts: &GameFonts) {
+    let text = format!(
+        "YETI SET GO  {} pts  Level {}",
+        game.run.score, game.run.level
+    );
+    let style = TypographyStyle::UICaption;
+    let dimensions = style.measure_text(&text, fonts);
+    let width = dimensions.width + 12.0;
+    let height = dimensions.height + 10.0;
+    let x = SCREEN_WIDTH - width - 6.0;
+    let y = SCREEN_HEIGHT - height - 6.0;
+
+    draw_rectangle(x, y, width, height, ThemeProvider::overlay());
+    draw_rectangle_lines(x, y, width, height, 1.0, VIBRANT_BLUE);
+    UIComponent::draw_text(
+        &text,
+        x + 6.0,
+        y + height - 6.0,
+        style,
+        ColorTheme::Primary,
+        fonts,
+    );
+}
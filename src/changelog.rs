@@ -0,0 +1,43 @@
+use crate::assets::load_changelog;
+use crate::version::Version;
+
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// One row of the "What's new" screen, flattened out of changelog.md
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangelogLine {
+    Version(String), // "## 0.1.2"
+    Heading(String), // "### Balance"
+    Item(String),    // "- Spawns are planned ahead"
+}
+
+// Every release when `since` is None, otherwise only the ones after it.
+// Anything that isn't a release, heading or bullet is a note to editors.
+pub fn lines_since(since: Option<&str>) -> Vec<ChangelogLine> {
+    let mut lines = Vec::new();
+    let mut included = false;
+    for line in load_changelog().lines().map(str::trim) {
+        if let Some(version) = line.strip_prefix("## ") {
+            included = since.is_none_or(|since| is_newer(version, since));
+            if included {
+                lines.push(ChangelogLine::Version(version.trim().to_string()));
+            }
+        } else if !included {
+            continue;
+        } else if let Some(heading) = line.strip_prefix("### ") {
+            lines.push(ChangelogLine::Heading(heading.trim().to_string()));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            lines.push(ChangelogLine::Item(item.trim().to_string()));
+        }
+    }
+    lines
+}
+
+// A heading that isn't a version is never news; a remembered version that
+// can't be read means everything is
+fn is_newer(version: &str, than: &str) -> bool {
+    match (Version::parse(version), Version::parse(than)) {
+        (Some(version), Some(than)) => version.is_newer_than(&than),
+        (version, _) => version.is_some(),
+    }
+}
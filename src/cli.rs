@@ -0,0 +1,96 @@
+use crate::game::difficulty::Difficulty;
+use clap::Parser;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+// Launch options for testers and booth operators. Everything is optional;
+// with no arguments the game starts exactly as it always has.
+#[derive(Debug, Default, Parser)]
+#[command(
+    version,
+    about = "Yeti, Set, Go! A CI/CD pipeline runner for impatient devs"
+)]
+pub struct Args {
+    #[arg(long, help = "Start in fullscreen for this launch")]
+    pub fullscreen: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Use this seed for every regular run, so items arrive in the same order"
+    )]
+    pub seed: Option<u64>,
+
+    #[arg(
+        long,
+        value_name = "LEVEL",
+        value_parser = parse_difficulty,
+        help = "Difficulty for regular runs: easy, normal or hard"
+    )]
+    pub difficulty: Option<Difficulty>,
+
+    #[arg(long, help = "Never contact the leaderboard API, even with a key set")]
+    pub offline: bool,
+
+    #[arg(long, help = "Booth mode, with kiosk.json's options when there is one")]
+    pub kiosk: bool,
+
+    #[arg(
+        long,
+        help = "Let the leaderboard screen remove entries, with an audit log"
+    )]
+    pub admin: bool,
+
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Play as this profile, creating it if it doesn't exist"
+    )]
+    pub profile: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Watch a saved run, such as last_run.replay"
+    )]
+    pub replay: Option<PathBuf>,
+
+    #[arg(long, help = "Run the stress test, print its report and quit")]
+    pub benchmark: bool,
+
+    // Set by embed(), never from the command line
+    #[arg(skip)]
+    pub embedded: bool,
+}
+
+static ARGS: OnceLock<Args> = OnceLock::new();
+
+// Parsed the first time anything asks, which is window_conf before the
+// window opens. Bad arguments print usage and exit there. Test binaries get
+// the defaults instead of reading the harness's own flags.
+pub fn args() -> &'static Args {
+    ARGS.get_or_init(|| {
+        if cfg!(test) {
+            Args::default()
+        } else {
+            Args::parse()
+        }
+    })
+}
+
+// Running inside another app: the command line is the host's, so the game
+// takes the defaults and leaves the window alone. Has to come before
+// anything reads args().
+pub fn embed() {
+    let _ = ARGS.set(Args {
+        embedded: true,
+        ..Args::default()
+    });
+}
+
+fn parse_difficulty(text: &str) -> Result<Difficulty, String> {
+    Difficulty::ALL
+        .into_iter()
+        .find(|difficulty| difficulty.name().eq_ignore_ascii_case(text))
+        .ok_or_else(|| format!("expected easy, normal or hard, not '{}'", text))
+}
@@ -50,11 +50,98 @@ pub const UI_BACKGROUND: Color = PEAK;
 pub const UI_BORDER: Color = DEEP;
 pub const UI_HIGHLIGHT: Color = VIBRANT_BLUE;
 
+// Identicon colors, bright enough to read against the dark boards
+pub const IDENTICON_COLORS: [Color; 8] = [
+    VIBRANT_BLUE,
+    VIOLET,
+    TEAL,
+    EMBER,
+    PEAK,
+    FLUREE_SAFE_BLUE,
+    ICE_BLUE,
+    GREY,
+];
+
 // Success/Error colors (maintaining brand consistency while using recognizable colors)
 pub const SUCCESS_GREEN: Color = Color::new(0.0, 0.667, 0.0, 1.0); // #00AA00 (standard green)
 pub const ERROR_RED: Color = EMBER; // Use brand Ember for errors
 pub const WARNING_YELLOW: Color = Color::new(1.0, 0.843, 0.0, 1.0); // #FFD700 (gold for warnings)
 
+// Colorblind-safe stand-ins (Okabe-Ito), distinguishable with deuteranopia/protanopia
+pub const SAFE_SUCCESS_BLUE: Color = Color::new(0.337, 0.706, 0.914, 1.0); // #56B4E9 (sky blue)
+pub const SAFE_ERROR_VERMILLION: Color = Color::new(0.835, 0.369, 0.0, 1.0); // #D55E00 (vermillion)
+pub const SAFE_WARNING_YELLOW: Color = Color::new(0.941, 0.894, 0.259, 1.0); // #F0E442 (yellow)
+
+// Which set of status colors the UI and items are drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Palette {
+    #[default]
+    Standard,
+    ColorblindSafe,
+}
+
+impl Palette {
+    pub fn success(self) -> Color {
+        match self {
+            Palette::Standard => SUCCESS_GREEN,
+            Palette::ColorblindSafe => SAFE_SUCCESS_BLUE,
+        }
+    }
+
+    pub fn error(self) -> Color {
+        match self {
+            Palette::Standard => ERROR_RED,
+            Palette::ColorblindSafe => SAFE_ERROR_VERMILLION,
+        }
+    }
+
+    pub fn warning(self) -> Color {
+        match self {
+            Palette::Standard => WARNING_YELLOW,
+            Palette::ColorblindSafe => SAFE_WARNING_YELLOW,
+        }
+    }
+}
+
+// Photo mode's duotone filters, each a shadow and a highlight from the palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PhotoFilter {
+    #[default]
+    None,
+    Glacier,
+    Ember,
+    Ultraviolet,
+}
+
+impl PhotoFilter {
+    pub fn name(self) -> &'static str {
+        match self {
+            PhotoFilter::None => "None",
+            PhotoFilter::Glacier => "Glacier",
+            PhotoFilter::Ember => "Ember",
+            PhotoFilter::Ultraviolet => "Ultraviolet",
+        }
+    }
+
+    pub fn tones(self) -> Option<(Color, Color)> {
+        match self {
+            PhotoFilter::None => None,
+            PhotoFilter::Glacier => Some((DEEP, ICE_BLUE)),
+            PhotoFilter::Ember => Some((PLUM, EMBER)),
+            PhotoFilter::Ultraviolet => Some((DEEP, VIOLET)),
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            PhotoFilter::None => PhotoFilter::Glacier,
+            PhotoFilter::Glacier => PhotoFilter::Ember,
+            PhotoFilter::Ember => PhotoFilter::Ultraviolet,
+            PhotoFilter::Ultraviolet => PhotoFilter::None,
+        }
+    }
+}
+
 // Medal colors for leaderboard
 pub const MEDAL_GOLD: Color = WARNING_YELLOW;
 pub const MEDAL_SILVER: Color = ICE_BLUE;
@@ -1,20 +1,35 @@
+use crate::assets::load_window_icon;
+use crate::cli;
+use crate::settings::Settings;
 use macroquad::prelude::*;
 
 // Screen dimensions
 pub const SCREEN_WIDTH: f32 = 640.0;
 pub const SCREEN_HEIGHT: f32 = 270.0;
 pub const GROUND_Y: f32 = 210.0;
+pub const VERSUS_TOP_GROUND_Y: f32 = 105.0;
+
+// Multi-lane mode: extra lanes sit behind the main one, further up the screen
+pub const MAX_LANES: usize = 3;
+pub const LANE_SPACING: f32 = 28.0;
+pub const LANE_SHIFT_SPEED: f32 = 220.0;
 
 // Game physics
 pub const JUMP_VELOCITY: f32 = -350.0;
 pub const GRAVITY: f32 = 800.0;
 pub const COLLISION_GRACE_MARGIN: f32 = 8.0;
+pub const STUMBLE_VELOCITY: f32 = -220.0;
+pub const STUMBLE_GRAVITY: f32 = 1400.0;
+pub const STUMBLE_DRIFT_SPEED: f32 = 60.0;
+pub const STUMBLE_SPIN_SPEED: f32 = -6.0;
 
 // Game mechanics
 pub const INITIAL_SPAWN_RATE: f32 = 2.0;
 pub const MIN_SPAWN_RATE: f32 = 0.5;
 pub const SPEED_INCREASE_PER_LEVEL: f32 = 20.0;
 pub const BASE_ITEM_SPEED: f32 = 200.0;
+pub const SPAWN_RATE_SCALE: f32 = 1.0; // Applied on top of every level's pacing
+pub const ITEM_SPEED_SCALE: f32 = 1.0;
 pub const PIPELINE_BASE_SPEED: f32 = 100.0;
 pub const PIPELINE_SPEED_INCREASE: f32 = 10.0;
 pub const PIPELINE_SCROLL_RESET: f32 = 128.0;
@@ -25,16 +40,30 @@ pub const YETI_HEIGHT: f32 = 48.0;
 pub const ITEM_WIDTH: f32 = 32.0;
 pub const ITEM_HEIGHT: f32 = 32.0;
 
+// Attract mode
+pub const ATTRACT_MODE_IDLE_TIME: f32 = 20.0;
+
 // Probabilities
 pub const GOOD_ITEM_PROBABILITY: f32 = 0.65;
 
+// Adaptive difficulty: how far full pressure either way can move pacing
+pub const ADAPTIVE_SPAWN_RANGE: f32 = 0.2; // Fraction of the spawn interval
+pub const ADAPTIVE_GOOD_ITEM_RANGE: f32 = 0.1; // Good item chance, either way
+
 // UI constants
 pub const FEEDBACK_BOX_WIDTH: f32 = 300.0;
 pub const FEEDBACK_BOX_HEIGHT: f32 = 60.0;
-pub const FEEDBACK_DISPLAY_TIME: f32 = 10.0;
+pub const FEEDBACK_STRIP_HEIGHT: f32 = 18.0; // Lower-priority messages under the box
+pub const FEEDBACK_GAP: f32 = 8.0;
 pub const COLLISION_GRACE_TIME: f32 = 0.5;
+pub const LEVEL_COMPLETE_DISPLAY_TIME: f32 = 2.5;
+pub const DEATH_SEQUENCE_TIME: f32 = 1.5;
+pub const DEATH_SLOW_MOTION_SCALE: f32 = 0.2;
+pub const RANK_TOAST_TIME: f32 = 6.0; // Rank-change news on the main menu
+pub const CONFETTI_PARTICLE_COUNT: usize = 60;
 
-// Platform-specific configurations
+// Platform-specific configurations. Touch platforms always get the
+// on-screen keyboard for names.
 #[cfg(target_os = "android")]
 pub const TOUCH_ENABLED: bool = true;
 
@@ -49,11 +78,20 @@ pub const DEV_MODE_ENABLED: bool = false; // Set to false to disable dev mode co
 
 // Window configuration
 pub fn window_conf() -> Conf {
-    Conf {
+    // Read before the window opens so it starts in the player's last mode
+    let settings = Settings::load();
+
+    let mut conf = Conf {
         window_title: "Yeti, Set, Go!".to_owned(),
         window_width: SCREEN_WIDTH as i32,
         window_height: SCREEN_HEIGHT as i32,
         window_resizable: false,
+        fullscreen: settings.fullscreen || cli::args().fullscreen,
+        high_dpi: true, // Only so the automatic UI scale can read the DPI
+
+        icon: load_window_icon(),
         ..Default::default()
-    }
+    };
+    conf.platform.swap_interval = Some(if settings.vsync { 1 } else { 0 });
+    conf
 }
@@ -0,0 +1,153 @@
+use crate::game::{Game, GameState};
+use crate::settings::Settings;
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::{self, PanicHookInfo};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// What the player was doing, refreshed every frame so the panic hook can
+// describe the moment of the crash without touching the game itself
+#[derive(Debug, Clone)]
+struct CrashContext {
+    state: GameState,
+    level: u32,
+    score: u32,
+    lanes: usize,
+    mutators: u8,
+    versus: bool,
+    demo: bool,
+    profile: Option<String>, // Folder, for finding the settings in use
+}
+
+static CONTEXT: Mutex<Option<CrashContext>> = Mutex::new(None);
+
+pub fn record_context(game: &Game) {
+    let context = CrashContext {
+        state: game.state(),
+        level: game.run.level,
+        score: game.run.score,
+        lanes: game.lane_count,
+        mutators: game.mutators.bits(),
+        versus: game.versus.is_some(),
+        demo: game.is_demo,
+        profile: game.profile.as_ref().map(|profile| profile.folder.clone()),
+    };
+    if let Ok(mut current) = CONTEXT.lock() {
+        *current = Some(context);
+    }
+}
+
+// Keeps the usual stderr message, then leaves a report on disk for the next
+// launch to offer up
+pub fn install_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+
+        let Some(path) = report_path() else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&path, build_report(info)));
+        match result {
+            Ok(()) => eprintln!("Crash report saved to {}", path.display()),
+            Err(e) => eprintln!("Failed to save crash report: {}", e),
+        }
+    }));
+}
+
+pub fn pending_report() -> Option<String> {
+    fs::read_to_string(report_path()?).ok()
+}
+
+pub fn discard_report() {
+    if let Some(path) = report_path() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn report_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("yeti-set-go").join("crash_report.txt"))
+}
+
+fn build_report(info: &PanicHookInfo) -> String {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "(no message)".to_string());
+    let location = info
+        .location()
+        .map(|location| location.to_string())
+        .unwrap_or_else(|| "(unknown)".to_string());
+
+    let context = CONTEXT.lock().ok().and_then(|context| context.clone());
+    let game = match &context {
+        Some(c) => format!(
+            "{:?}, level {}, score {}, {} lane(s), mutator bits {:#06b}, versus {}, demo {}",
+            c.state, c.level, c.score, c.lanes, c.mutators, c.versus, c.demo
+        ),
+        None => "Crashed before the first frame".to_string(),
+    };
+    let profile = context.and_then(|c| c.profile);
+    let settings = settings_summary(&Settings::load_for(profile.as_deref()));
+
+    let report = format!(
+        "Yeti, Set, Go! {} crash report\n\
+         Time: {}\n\
+         OS: {} {}\n\
+         Panic: {}\n\
+         At: {}\n\
+         Game: {}\n\n\
+         Settings:\n{}\n\n\
+         Backtrace:\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        chrono::Utc::now().to_rfc3339(),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        message,
+        location,
+        game,
+        settings,
+        Backtrace::force_capture(),
+    );
+    anonymize(&report)
+}
+
+// Only the display, accessibility and audio options, which say nothing about
+// who was playing. Names, the board standing and anything typed into the
+// settings file stay out.
+fn settings_summary(settings: &Settings) -> String {
+    format!(
+        "Display: theme {:?}, fullscreen {}, vsync {}, frame cap {}, UI scale {}, text scale {}, CRT filter {}\n\
+         Accessibility: colorblind {}, high contrast {}, reduced motion {}, flash reduction {}\n\
+         Audio: music {:.2}, voice {:.2}",
+        settings.ui_theme,
+        settings.fullscreen,
+        settings.vsync,
+        settings.frame_cap,
+        settings.ui_scale,
+        settings.text_scale,
+        settings.crt_filter,
+        settings.colorblind_mode,
+        settings.high_contrast,
+        settings.reduced_motion,
+        settings.flash_reduction,
+        settings.music_volume,
+        settings.voice_volume,
+    )
+}
+
+// Paths under the home directory would give away the account name
+fn anonymize(report: &str) -> String {
+    match dirs::home_dir() {
+        Some(home) if !home.as_os_str().is_empty() => {
+            report.replace(home.to_string_lossy().as_ref(), "~")
+        }
+        _ => report.to_string(),
+    }
+}
@@ -1,4 +1,12 @@
+use crate::colors::Palette;
+use crate::config::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::text_cache;
+use crate::themes::{ThemeProvider, UiTheme};
+use crate::tween::Tween;
 use macroquad::prelude::*;
+use std::cell::Cell;
+use std::collections::HashSet;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub fn ordinal_suffix(n: usize) -> String {
     let suffix = match n % 100 {
@@ -13,10 +21,175 @@ pub fn ordinal_suffix(n: usize) -> String {
     format!("{}{}", n, suffix)
 }
 
+// m:ss for run timers
+pub fn format_duration(seconds: f32) -> String {
+    let total = seconds.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+// m:ss.cc for speedrun splits
+pub fn format_split(seconds: f64) -> String {
+    let hundredths = (seconds.max(0.0) * 100.0).round() as u64;
+    format!(
+        "{}:{:02}.{:02}",
+        hundredths / 6000,
+        hundredths / 100 % 60,
+        hundredths % 100
+    )
+}
+
+// Against a best split: "-1.25" ahead, "+0.40" behind
+pub fn format_split_delta(seconds: f64) -> String {
+    format!("{:+.2}", seconds)
+}
+
+// 42850 -> "42,850"
+pub fn format_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
+
+// Shorten to at most `max` user-visible characters, ending in an ellipsis
+// when cut. Works on graphemes so accents and CJK never split mid-character.
+pub fn truncate_graphemes(text: &str, max: usize) -> String {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    if graphemes.len() <= max {
+        return text.to_string();
+    }
+    let kept = graphemes[..max.saturating_sub(1)].concat();
+    format!("{}…", kept)
+}
+
+// Presentation-wide options the styling helpers read when drawing. Pushed
+// from the player's settings at startup and whenever they change.
+#[derive(Debug, Clone, Copy)]
+pub struct DesignContext {
+    pub palette: Palette,
+    pub high_contrast: bool,   // Pure foregrounds and near-opaque backdrops
+    pub reduced_motion: bool,  // No particles, scrolling tracks or bouncing
+    pub flash_reduction: bool, // Gentle fades instead of hard flashes, see FlashPolicy
+    pub text_scale: f32,       // Multiplies every TypographyStyle size
+    pub ui_scale: f32,         // For the display: type, Spacing and the HUD's SafeArea
+    pub theme: UiTheme,        // The color set ColorTheme and ThemeProvider draw from
+}
+
+impl Default for DesignContext {
+    fn default() -> Self {
+        Self {
+            palette: Palette::Standard,
+            high_contrast: false,
+            reduced_motion: false,
+            flash_reduction: false,
+            text_scale: 1.0,
+            ui_scale: 1.0,
+            theme: UiTheme::Brand,
+        }
+    }
+}
+
+thread_local! {
+    static DESIGN_CONTEXT: Cell<DesignContext> = Cell::new(DesignContext::default());
+}
+
+impl DesignContext {
+    pub fn current() -> Self {
+        DESIGN_CONTEXT.with(|context| context.get())
+    }
+
+    pub fn set(context: DesignContext) {
+        DESIGN_CONTEXT.with(|current| current.set(context));
+    }
+
+    pub fn palette() -> Palette {
+        Self::current().palette
+    }
+
+    pub fn reduced_motion() -> bool {
+        Self::current().reduced_motion
+    }
+
+    // What every TypographyStyle size is multiplied by
+    pub fn type_scale() -> f32 {
+        let context = Self::current();
+        context.text_scale * context.ui_scale
+    }
+
+    // Full-screen dims and panels behind text; opaque enough in high
+    // contrast that the playfield can't bleed through
+    pub fn backdrop(color: Color) -> Color {
+        if Self::current().high_contrast {
+            Color {
+                a: color.a.max(0.95),
+                ..color
+            }
+        } else {
+            color
+        }
+    }
+}
+
+// Strongest a hit tint gets with flash reduction on
+const REDUCED_TINT_STRENGTH: f32 = 0.35;
+// Blinks with flash reduction on pulse down to this instead of vanishing
+const REDUCED_BLINK_FLOOR: f32 = 0.35;
+
+// Every flashing effect asks here how hard it's allowed to flash, so the
+// photosensitivity rules live in one place. With flash reduction on, a
+// full-sprite tint becomes a partial one that fades out, and a hard on/off
+// blink becomes a slow pulse that never quite disappears.
+pub struct FlashPolicy;
+
+impl FlashPolicy {
+    // `strength` runs from 1.0 at the moment of impact down to 0.0
+    pub fn tint(base: Color, flash: Color, strength: f32) -> Color {
+        let strength = strength.clamp(0.0, 1.0);
+        if strength <= 0.0 {
+            return base;
+        }
+        if !DesignContext::current().flash_reduction {
+            return flash;
+        }
+
+        let amount = REDUCED_TINT_STRENGTH * strength;
+        Color::new(
+            base.r + (flash.r - base.r) * amount,
+            base.g + (flash.g - base.g) * amount,
+            base.b + (flash.b - base.b) * amount,
+            base.a + (flash.a - base.a) * amount,
+        )
+    }
+
+    // Opacity from 0.0 to 1.0 for something that blinks every `period`
+    // seconds, visible for the first `on_fraction` of each cycle
+    pub fn blink(time: f64, period: f64, on_fraction: f64) -> f32 {
+        let context = DesignContext::current();
+        let phase = (time % period) / period;
+        if context.reduced_motion {
+            1.0
+        } else if context.flash_reduction {
+            let wave = 0.5 + 0.5 * (phase * std::f64::consts::TAU).cos() as f32;
+            REDUCED_BLINK_FLOOR + (1.0 - REDUCED_BLINK_FLOOR) * wave
+        } else if phase < on_fraction {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GameFonts {
     pub primary: Option<Font>, // Gotham-Medium.otf - for headings, UI elements, scores
     pub monospace: Option<Font>, // For code, technical feedback, instructions
+    pub fallback: Option<Font>, // CJK and other scripts the others lack, from the system
+    pub primary_chars: HashSet<char>, // Everything the primary font has a glyph for
 }
 
 impl GameFonts {
@@ -24,12 +197,131 @@ impl GameFonts {
         Self {
             primary: None,
             monospace: None,
+            fallback: None,
+            primary_chars: HashSet::new(),
         }
     }
+
+    // Whether `font` can draw `ch`. No font means macroquad's built-in one,
+    // which is ASCII only.
+    fn has_glyph(&self, font: Option<&Font>, ch: char) -> bool {
+        if ch.is_whitespace() {
+            return true;
+        }
+        match font {
+            None => ch.is_ascii(),
+            Some(font) if self.is_primary(font) => self.primary_chars.contains(&ch),
+            Some(_) => true,
+        }
+    }
+
+    fn is_primary(&self, font: &Font) -> bool {
+        self.primary
+            .as_ref()
+            .is_some_and(|primary| std::ptr::eq(primary, font))
+    }
+
+    // `text` split where it moves between `font` and the fallback font, each
+    // piece with the font to draw it in. One piece when there's no fallback.
+    pub fn runs<'a>(
+        &'a self,
+        text: &'a str,
+        font: Option<&'a Font>,
+    ) -> Vec<(&'a str, Option<&'a Font>)> {
+        let Some(fallback) = &self.fallback else {
+            return vec![(text, font)];
+        };
+        split_runs(text, |ch| !self.has_glyph(font, ch))
+            .into_iter()
+            .map(|(run, missing)| (run, if missing { Some(fallback) } else { font }))
+            .collect()
+    }
+}
+
+impl Default for GameFonts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// `text` in pieces that are all `missing` or all not, in order
+fn split_runs(text: &str, missing: impl Fn(char) -> bool) -> Vec<(&str, bool)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut in_missing = false;
+    for (index, ch) in text.char_indices() {
+        let is_missing = missing(ch);
+        if is_missing != in_missing && index > start {
+            runs.push((&text[start..index], in_missing));
+            start = index;
+        }
+        in_missing = is_missing;
+    }
+    runs.push((&text[start..], in_missing));
+    runs
+}
+
+// draw_text_ex, but any characters missing from `params.font` come from the
+// fallback font instead of drawing as empty boxes
+pub fn draw_text_runs(
+    text: &str,
+    x: f32,
+    y: f32,
+    params: TextParams,
+    fonts: &GameFonts,
+) -> TextDimensions {
+    let mut dimensions = TextDimensions {
+        width: 0.0,
+        height: 0.0,
+        offset_y: 0.0,
+    };
+    for (run, font) in fonts.runs(text, params.font) {
+        let drawn = draw_text_ex(
+            run,
+            x + dimensions.width,
+            y,
+            TextParams {
+                font,
+                ..params.clone()
+            },
+        );
+        dimensions = join_dimensions(dimensions, drawn);
+    }
+    dimensions
+}
+
+// measure_text to match draw_text_runs
+pub fn measure_text_runs(
+    text: &str,
+    font: Option<&Font>,
+    font_size: u16,
+    font_scale: f32,
+    fonts: &GameFonts,
+) -> TextDimensions {
+    let mut dimensions = TextDimensions {
+        width: 0.0,
+        height: 0.0,
+        offset_y: 0.0,
+    };
+    for (run, font) in fonts.runs(text, font) {
+        dimensions = join_dimensions(dimensions, measure_text(run, font, font_size, font_scale));
+    }
+    dimensions
+}
+
+// Two runs side by side: their widths add up, and they share a baseline
+fn join_dimensions(left: TextDimensions, right: TextDimensions) -> TextDimensions {
+    let above = left.offset_y.max(right.offset_y);
+    let below = (left.height - left.offset_y).max(right.height - right.offset_y);
+    TextDimensions {
+        width: left.width + right.width,
+        height: above + below,
+        offset_y: above,
+    }
 }
 
 // Typography styles based on semantic meaning
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TypographyStyle {
     // Display styles - for large, prominent text
     DisplayLarge,  // Game title, major headings
@@ -55,28 +347,7 @@ pub enum TypographyStyle {
 
 impl TypographyStyle {
     pub fn get_params<'a>(&self, fonts: &'a GameFonts, color: Color) -> TextParams<'a> {
-        let (font, size) = match self {
-            // Display styles use primary font with large sizes
-            TypographyStyle::DisplayLarge => (fonts.primary.as_ref(), 40),
-            TypographyStyle::DisplayMedium => (fonts.primary.as_ref(), 32),
-            TypographyStyle::DisplaySmall => (fonts.primary.as_ref(), 24),
-
-            // Body styles use primary font with medium sizes
-            TypographyStyle::BodyLarge => (fonts.primary.as_ref(), 20),
-            TypographyStyle::BodyMedium => (fonts.primary.as_ref(), 16),
-            TypographyStyle::BodySmall => (fonts.primary.as_ref(), 14),
-
-            // Technical styles use monospace font
-            TypographyStyle::CodeLarge => (fonts.monospace.as_ref(), 18),
-            TypographyStyle::CodeMedium => (fonts.monospace.as_ref(), 16),
-            TypographyStyle::CodeSmall => (fonts.monospace.as_ref(), 14),
-
-            // UI styles use primary font with specific sizing
-            TypographyStyle::UIButton => (fonts.primary.as_ref(), 18),
-            TypographyStyle::UILabel => (fonts.primary.as_ref(), 16),
-            TypographyStyle::UIInput => (fonts.primary.as_ref(), 18),
-            TypographyStyle::UICaption => (fonts.primary.as_ref(), 12),
-        };
+        let (font, size) = self.font_and_size(fonts);
 
         TextParams {
             font,
@@ -86,24 +357,51 @@ impl TypographyStyle {
         }
     }
 
+    // Cached per string and size; see text_cache.rs
     pub fn measure_text(&self, text: &str, fonts: &GameFonts) -> TextDimensions {
+        let (font, size) = self.font_and_size(fonts);
+        text_cache::measure(text, *self, font, size, fonts)
+    }
+
+    // Cut the text short, with an ellipsis, until it fits in `width`
+    pub fn fit_text(&self, text: &str, width: f32, fonts: &GameFonts) -> String {
+        let mut fitted = text.to_string();
+        let mut max = text.graphemes(true).count();
+        while max > 1 && self.measure_text(&fitted, fonts).width > width {
+            max -= 1;
+            fitted = truncate_graphemes(text, max);
+        }
+        fitted
+    }
+
+    fn font_and_size<'a>(&self, fonts: &'a GameFonts) -> (Option<&'a Font>, u16) {
         let (font, size) = match self {
+            // Display styles use primary font with large sizes
             TypographyStyle::DisplayLarge => (fonts.primary.as_ref(), 40),
             TypographyStyle::DisplayMedium => (fonts.primary.as_ref(), 32),
             TypographyStyle::DisplaySmall => (fonts.primary.as_ref(), 24),
+
+            // Body styles use primary font with medium sizes
             TypographyStyle::BodyLarge => (fonts.primary.as_ref(), 20),
             TypographyStyle::BodyMedium => (fonts.primary.as_ref(), 16),
             TypographyStyle::BodySmall => (fonts.primary.as_ref(), 14),
+
+            // Technical styles use monospace font
             TypographyStyle::CodeLarge => (fonts.monospace.as_ref(), 18),
             TypographyStyle::CodeMedium => (fonts.monospace.as_ref(), 16),
             TypographyStyle::CodeSmall => (fonts.monospace.as_ref(), 14),
+
+            // UI styles use primary font with specific sizing
             TypographyStyle::UIButton => (fonts.primary.as_ref(), 18),
             TypographyStyle::UILabel => (fonts.primary.as_ref(), 16),
             TypographyStyle::UIInput => (fonts.primary.as_ref(), 18),
             TypographyStyle::UICaption => (fonts.primary.as_ref(), 12),
         };
 
-        measure_text(text, font, size, 1.0)
+        (
+            font,
+            (size as f32 * DesignContext::type_scale()).round() as u16,
+        )
     }
 }
 
@@ -119,7 +417,7 @@ pub enum ColorTheme {
     Technical, // Code/system feedback
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct ThemeColors {
     pub foreground: Color,
     pub background: Color,
@@ -129,51 +427,33 @@ pub struct ThemeColors {
 
 impl ColorTheme {
     pub fn get_colors(&self) -> ThemeColors {
-        use crate::colors::*;
+        let colors = ThemeProvider::colors(*self);
+        if DesignContext::current().high_contrast {
+            self.high_contrast(colors)
+        } else {
+            colors
+        }
+    }
 
-        match self {
-            ColorTheme::Primary => ThemeColors {
-                foreground: ICE_BLUE,
-                background: DEEP,
-                accent: VIBRANT_BLUE,
-                border: PEAK,
-            },
-            ColorTheme::Secondary => ThemeColors {
-                foreground: DEEP,
-                background: ICE_BLUE,
-                accent: PLUM,
-                border: PURPLE,
-            },
-            ColorTheme::Success => ThemeColors {
-                foreground: SUCCESS_GREEN,
-                background: Color::new(0.0, 0.4, 0.0, 0.1),
-                accent: SUCCESS_GREEN,
-                border: SUCCESS_GREEN,
-            },
-            ColorTheme::Warning => ThemeColors {
-                foreground: WARNING_YELLOW,
-                background: Color::new(1.0, 0.8, 0.0, 0.1),
-                accent: WARNING_YELLOW,
-                border: WARNING_YELLOW,
-            },
-            ColorTheme::Error => ThemeColors {
-                foreground: ERROR_RED,
-                background: Color::new(1.0, 0.3, 0.1, 0.1),
-                accent: ERROR_RED,
-                border: ERROR_RED,
-            },
-            ColorTheme::Neutral => ThemeColors {
-                foreground: GREY,
-                background: METAL,
-                accent: GREY,
-                border: GREY,
-            },
-            ColorTheme::Technical => ThemeColors {
-                foreground: TEAL,
-                background: Color::new(0.1, 0.1, 0.2, 0.8),
-                accent: VIBRANT_BLUE,
-                border: METAL,
+    // Push foregrounds to the extremes of their light/dark side, which
+    // depends on the UI theme
+    fn high_contrast(&self, colors: ThemeColors) -> ThemeColors {
+        let light = colors.foreground.r + colors.foreground.g + colors.foreground.b > 1.5;
+        let foreground = match (self, light) {
+            (ColorTheme::Primary | ColorTheme::Secondary, true) => WHITE,
+            (ColorTheme::Primary | ColorTheme::Secondary, false) => BLACK,
+            (ColorTheme::Neutral, true) => LIGHTGRAY,
+            (ColorTheme::Neutral, false) => DARKGRAY,
+            _ => colors.foreground,
+        };
+
+        ThemeColors {
+            foreground,
+            background: Color {
+                a: 1.0,
+                ..colors.background
             },
+            ..colors
         }
     }
 }
@@ -191,8 +471,222 @@ pub enum Spacing {
 }
 
 impl Spacing {
+    // Grows with the UI scale so gaps keep up with the text
     pub fn as_f32(self) -> f32 {
-        self as i32 as f32
+        self as i32 as f32 * DesignContext::current().ui_scale
+    }
+}
+
+// How long UI motion takes, so every screen change moves at the same pace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotionDuration {
+    Quick,
+    Standard,
+    Slow,
+}
+
+impl MotionDuration {
+    pub fn seconds(self) -> f32 {
+        match self {
+            MotionDuration::Quick => 0.2,
+            MotionDuration::Standard => 0.35,
+            MotionDuration::Slow => 0.6,
+        }
+    }
+}
+
+// A number on show that counts to each new value instead of jumping to it,
+// easing out as it lands. Reduced motion shows the value straight away.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CountUp {
+    tween: Tween,
+}
+
+impl CountUp {
+    const SECONDS: f32 = 0.5;
+
+    pub fn between(from: u32, to: u32) -> Self {
+        Self {
+            tween: Tween::new(from as f32, to as f32, Self::SECONDS),
+        }
+    }
+
+    // Counts on from whatever is on show, so a change mid-count doesn't jump
+    pub fn set(&mut self, target: u32) {
+        if target != self.target() {
+            *self = Self::between(self.value(), target);
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.tween.update(dt);
+    }
+
+    // For a key press that shouldn't have to wait on it
+    pub fn finish(&mut self) {
+        self.tween.finish();
+    }
+
+    pub fn is_counting(&self) -> bool {
+        self.value() != self.target()
+    }
+
+    pub fn value(&self) -> u32 {
+        if DesignContext::reduced_motion() {
+            return self.target();
+        }
+        self.tween.value().round() as u32
+    }
+
+    fn target(&self) -> u32 {
+        self.tween.target() as u32
+    }
+}
+
+// The part of the virtual screen the HUD keeps to. The margins grow with the
+// UI scale, so larger text doesn't end up hard against the edges of the
+// display or under a rounded corner.
+pub struct SafeArea;
+
+impl SafeArea {
+    const MARGIN: f32 = 8.0;
+
+    pub fn margin() -> f32 {
+        Self::MARGIN * DesignContext::current().ui_scale
+    }
+
+    pub fn left() -> f32 {
+        Self::margin()
+    }
+
+    pub fn top() -> f32 {
+        Self::margin()
+    }
+
+    pub fn right() -> f32 {
+        SCREEN_WIDTH - Self::margin()
+    }
+
+    pub fn bottom() -> f32 {
+        SCREEN_HEIGHT - Self::margin()
+    }
+}
+
+// Positions by where they sit on the screen, or in a part of it, rather
+// than by pixel, so a layout still lines up if the screen's size changes
+pub struct Layout;
+
+impl Layout {
+    pub fn screen() -> Rect {
+        Rect::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT)
+    }
+
+    // Shares of the screen's width and height, from 0 to 1
+    pub fn percent(x: f32, y: f32) -> Vec2 {
+        vec2(SCREEN_WIDTH * x, SCREEN_HEIGHT * y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Anchor {
+    // The anchor's point on the screen, moved `x` and `y` in from the edges
+    // it's tied to. Centered axes move right and down as usual.
+    pub fn inset(self, x: f32, y: f32) -> Vec2 {
+        self.inset_in(Layout::screen(), x, y)
+    }
+
+    pub fn inset_in(self, area: Rect, x: f32, y: f32) -> Vec2 {
+        let (across, down) = self.shares();
+        let along = |share: f32, start: f32, size: f32, inset: f32| {
+            let inward = if share == 1.0 { -inset } else { inset };
+            start + size * share + inward
+        };
+        vec2(
+            along(across, area.x, area.w, x),
+            along(down, area.y, area.h, y),
+        )
+    }
+
+    fn shares(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::TopCenter => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::CenterLeft => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::CenterRight => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::BottomCenter => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+// A table's columns, each starting a share of the way across it, so they
+// stretch with the table instead of sitting at fixed pixels
+#[derive(Debug, Clone, Copy)]
+pub struct Columns<const N: usize> {
+    x: f32,
+    width: f32,
+    starts: [f32; N],
+}
+
+impl<const N: usize> Columns<N> {
+    pub const fn new(x: f32, width: f32, starts: [f32; N]) -> Self {
+        Self { x, width, starts }
+    }
+
+    pub fn x(&self, column: usize) -> f32 {
+        self.x + self.width * self.starts[column]
+    }
+
+    pub fn left(&self) -> f32 {
+        self.x
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+// Places lines one under another, with a `Spacing` between each and the
+// next, so a column of text keeps its rhythm at any UI scale
+#[derive(Debug, Clone, Copy)]
+pub struct Stack {
+    cursor: Vec2,
+}
+
+impl Stack {
+    pub fn down(start: Vec2) -> Self {
+        Self { cursor: start }
+    }
+
+    // Where the next thing goes; the one after it starts `gap` further on
+    pub fn place(&mut self, gap: Spacing) -> Vec2 {
+        let at = self.cursor;
+        self.cursor.y += gap.as_f32();
+        at
+    }
+
+    // Extra room before the next thing
+    pub fn skip(&mut self, gap: Spacing) {
+        self.cursor.y += gap.as_f32();
     }
 }
 
@@ -211,7 +705,7 @@ impl UIComponent {
     ) {
         let colors = theme.get_colors();
         let params = style.get_params(fonts, colors.foreground);
-        draw_text_ex(text, x, y, params);
+        draw_text_runs(text, x, y, params, fonts);
     }
 
     // Draw centered text
@@ -228,6 +722,33 @@ impl UIComponent {
         Self::draw_text(text, x, y, style, theme, fonts);
     }
 
+    // For titles and headings that stay the same frame to frame: drawn from a
+    // cached texture instead of glyph by glyph
+    pub fn draw_static_text(
+        text: &str,
+        x: f32,
+        y: f32,
+        style: TypographyStyle,
+        theme: ColorTheme,
+        fonts: &GameFonts,
+    ) {
+        let colors = theme.get_colors();
+        text_cache::draw_static(text, x, y, style, colors.foreground, fonts);
+    }
+
+    pub fn draw_static_text_centered(
+        text: &str,
+        center_x: f32,
+        y: f32,
+        style: TypographyStyle,
+        theme: ColorTheme,
+        fonts: &GameFonts,
+    ) {
+        let size = style.measure_text(text, fonts);
+        let x = center_x - size.width / 2.0;
+        Self::draw_static_text(text, x, y, style, theme, fonts);
+    }
+
     // Draw text with background box
     pub fn draw_text_box(
         text: &str,
@@ -271,7 +792,7 @@ pub struct GameText;
 
 impl GameText {
     pub fn title(text: &str, x: f32, y: f32, fonts: &GameFonts) {
-        UIComponent::draw_text(
+        UIComponent::draw_static_text(
             text,
             x,
             y,
@@ -282,7 +803,7 @@ impl GameText {
     }
 
     pub fn title_centered(text: &str, center_x: f32, y: f32, fonts: &GameFonts) {
-        UIComponent::draw_text_centered(
+        UIComponent::draw_static_text_centered(
             text,
             center_x,
             y,
@@ -293,7 +814,7 @@ impl GameText {
     }
 
     pub fn heading(text: &str, x: f32, y: f32, fonts: &GameFonts) {
-        UIComponent::draw_text(
+        UIComponent::draw_static_text(
             text,
             x,
             y,
@@ -304,7 +825,7 @@ impl GameText {
     }
 
     pub fn heading_centered(text: &str, center_x: f32, y: f32, fonts: &GameFonts) {
-        UIComponent::draw_text_centered(
+        UIComponent::draw_static_text_centered(
             text,
             center_x,
             y,
@@ -391,3 +912,27 @@ impl GameText {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::split_runs;
+
+    #[test]
+    fn text_splits_where_glyphs_go_missing() {
+        let runs = split_runs("Yeti 雪男 #1", |ch| !ch.is_ascii());
+        assert_eq!(runs, vec![("Yeti ", false), ("雪男", true), (" #1", false)]);
+    }
+
+    #[test]
+    fn text_the_font_covers_stays_in_one_piece() {
+        assert_eq!(
+            split_runs("DevOpsGuru", |_| false),
+            vec![("DevOpsGuru", false)]
+        );
+        assert_eq!(split_runs("", |_| true), vec![("", false)]);
+        assert_eq!(
+            split_runs("日本", |ch| !ch.is_ascii()),
+            vec![("日本", true)]
+        );
+    }
+}
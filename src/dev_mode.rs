@@ -1,8 +1,18 @@
 use crate::colors::*;
 use crate::config::*;
-use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
+use crate::design::{ColorTheme, DesignContext, GameText, TypographyStyle, UIComponent};
+use crate::entities::{ItemType, Yeti};
+use crate::game::events::GameEvent;
+use crate::game::feedback::{Feedback, FeedbackQueue, Priority, Severity};
+use crate::game::mutators::Mutator;
+use crate::game::spawning;
+use crate::game::stats::RunStats;
+use crate::game::tuning::{Tuning, TuningParam};
 use crate::game::{Game, GameState};
-use crate::highscores::{HighScore, Leaderboard};
+use crate::highscores::{Category, HighScore, Leaderboard, Moderation};
+use crate::input::{FrameInput, LatencyProbe};
+use crate::overrides::{self, GameConfig, OVERRIDABLE};
+use crate::themes::{ThemeProvider, UiTheme};
 use chrono::Utc;
 use macroquad::prelude::*;
 
@@ -10,38 +20,47 @@ use macroquad::prelude::*;
 pub enum DevScreen {
     MainMenu,
     GameOver,
+    RunSummary,
     LevelComplete,
     NameInput,
     Leaderboard,
     Playing,
+    Paused,
     TypographyShowcase,
     ColorShowcase,
+    ConfigOverrides,
 }
 
 impl DevScreen {
     pub fn next(&self) -> DevScreen {
         match self {
             DevScreen::MainMenu => DevScreen::GameOver,
-            DevScreen::GameOver => DevScreen::LevelComplete,
+            DevScreen::GameOver => DevScreen::RunSummary,
+            DevScreen::RunSummary => DevScreen::LevelComplete,
             DevScreen::LevelComplete => DevScreen::NameInput,
             DevScreen::NameInput => DevScreen::Leaderboard,
             DevScreen::Leaderboard => DevScreen::Playing,
-            DevScreen::Playing => DevScreen::TypographyShowcase,
+            DevScreen::Playing => DevScreen::Paused,
+            DevScreen::Paused => DevScreen::TypographyShowcase,
             DevScreen::TypographyShowcase => DevScreen::ColorShowcase,
-            DevScreen::ColorShowcase => DevScreen::MainMenu,
+            DevScreen::ColorShowcase => DevScreen::ConfigOverrides,
+            DevScreen::ConfigOverrides => DevScreen::MainMenu,
         }
     }
 
     pub fn prev(&self) -> DevScreen {
         match self {
-            DevScreen::MainMenu => DevScreen::ColorShowcase,
+            DevScreen::MainMenu => DevScreen::ConfigOverrides,
             DevScreen::GameOver => DevScreen::MainMenu,
-            DevScreen::LevelComplete => DevScreen::GameOver,
+            DevScreen::RunSummary => DevScreen::GameOver,
+            DevScreen::LevelComplete => DevScreen::RunSummary,
             DevScreen::NameInput => DevScreen::LevelComplete,
             DevScreen::Leaderboard => DevScreen::NameInput,
             DevScreen::Playing => DevScreen::Leaderboard,
-            DevScreen::TypographyShowcase => DevScreen::Playing,
+            DevScreen::Paused => DevScreen::Playing,
+            DevScreen::TypographyShowcase => DevScreen::Paused,
             DevScreen::ColorShowcase => DevScreen::TypographyShowcase,
+            DevScreen::ConfigOverrides => DevScreen::ColorShowcase,
         }
     }
 
@@ -49,12 +68,15 @@ impl DevScreen {
         match self {
             DevScreen::MainMenu => "Main Menu",
             DevScreen::GameOver => "Game Over",
+            DevScreen::RunSummary => "Run Summary",
             DevScreen::LevelComplete => "Level Complete",
             DevScreen::NameInput => "Name Input",
             DevScreen::Leaderboard => "Leaderboard",
             DevScreen::Playing => "Playing",
+            DevScreen::Paused => "Paused",
             DevScreen::TypographyShowcase => "Typography Showcase",
             DevScreen::ColorShowcase => "Color Showcase",
+            DevScreen::ConfigOverrides => "Config Overrides",
         }
     }
 }
@@ -64,6 +86,23 @@ pub struct DevMode {
     pub current_screen: DevScreen,
     pub mock_game: Game,
     pub show_overlay: bool,
+    pub autopilot: bool,
+    pub show_tuning: bool, // Tuning panel over the live game
+    tuning_cursor: usize,
+    spawn_cursor: usize, // Catalog index for [F5]
+    status_message: String,
+    status_timer: f32,
+    show_physics: bool,              // Collision rects, jump arc and ground lines
+    frozen: bool,                    // Simulation paused for frame stepping
+    showcase_theme: Option<UiTheme>, // Previewed in the color showcase; None for the setting
+    show_latency: bool,              // Input latency panel over the live game
+    latency: LatencyProbe,
+}
+
+impl Default for DevMode {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DevMode {
@@ -76,6 +115,17 @@ impl DevMode {
             current_screen: DevScreen::MainMenu,
             mock_game,
             show_overlay: true,
+            autopilot: false,
+            show_tuning: false,
+            tuning_cursor: 0,
+            spawn_cursor: 0,
+            status_message: String::new(),
+            status_timer: 0.0,
+            show_physics: false,
+            frozen: false,
+            showcase_theme: None,
+            show_latency: false,
+            latency: LatencyProbe::default(),
         }
     }
 
@@ -110,87 +160,658 @@ impl DevMode {
         if is_key_pressed(KeyCode::H) {
             self.show_overlay = !self.show_overlay;
         }
+
+        if matches!(self.current_screen, DevScreen::ColorShowcase) && is_key_pressed(KeyCode::T) {
+            let theme = self
+                .showcase_theme
+                .unwrap_or(DesignContext::current().theme);
+            self.showcase_theme = Some(theme.step(1));
+        }
+
+        // Autopilot applies to the live game once dev mode is closed
+        if is_key_pressed(KeyCode::A) {
+            self.autopilot = !self.autopilot;
+        }
+    }
+
+    // Live tools work on the real game, so they run while dev mode's
+    // preview screens are closed
+    pub fn handle_live_input(&mut self, game: &mut Game, input: &FrameInput, dt: f32) {
+        self.status_timer = (self.status_timer - dt).max(0.0);
+        self.latency.poll(dt, input);
+        self.handle_live_commands(game);
+        self.handle_tuning_input(game);
+    }
+
+    fn set_status(&mut self, message: String) {
+        println!("[dev] {}", message);
+        self.status_message = message;
+        self.status_timer = 2.0;
+    }
+
+    // Function keys so nothing collides with gameplay or name entry. Shift
+    // reverses or enlarges the step where that makes sense.
+    fn handle_live_commands(&mut self, game: &mut Game) {
+        let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        let item_types: Vec<ItemType> = game.catalog.types().collect();
+
+        if is_key_pressed(KeyCode::F6) {
+            let count = item_types.len();
+            self.spawn_cursor = if shift {
+                (self.spawn_cursor + count - 1) % count
+            } else {
+                (self.spawn_cursor + 1) % count
+            };
+            let name = &game.catalog.get(item_types[self.spawn_cursor]).name;
+            self.set_status(format!("Spawn item: {}", name));
+        }
+
+        if is_key_pressed(KeyCode::F5) && game.state() == GameState::Playing {
+            let item_type = item_types[self.spawn_cursor];
+            spawning::spawn_item(game, item_type, game.yeti.lane);
+            let name = game.catalog.get(item_type).name.clone();
+            self.set_status(format!("Spawned {}", name));
+        }
+
+        if is_key_pressed(KeyCode::F7) {
+            let level = if shift {
+                game.run.level.saturating_sub(1)
+            } else {
+                game.run.level + 1
+            };
+            game.jump_to_level(level);
+            self.set_status(format!("Jumped to level {}", game.run.level));
+        }
+
+        if is_key_pressed(KeyCode::F8) {
+            let points = if shift { 10_000 } else { 1_000 };
+            game.run.score += points;
+            self.set_status(format!("+{} score", points));
+        }
+
+        if is_key_pressed(KeyCode::F9) {
+            self.force_high_score(game);
+        }
+
+        if is_key_pressed(KeyCode::F10) {
+            game.invincible = !game.invincible;
+            let state = if game.invincible { "ON" } else { "OFF" };
+            self.set_status(format!("Invincibility {}", state));
+        }
+
+        if is_key_pressed(KeyCode::F1) && game.state() != GameState::Benchmark {
+            game.start_benchmark();
+            self.set_status("Benchmark started".to_string());
+        }
+
+        if is_key_pressed(KeyCode::F2) {
+            self.show_physics = !self.show_physics;
+        }
+
+        if is_key_pressed(KeyCode::End) {
+            self.show_latency = !self.show_latency;
+        }
+
+        if is_key_pressed(KeyCode::F4) {
+            self.frozen = !self.frozen;
+            let state = if self.frozen {
+                "frozen, [.] to step"
+            } else {
+                "running"
+            };
+            self.set_status(format!("Simulation {}", state));
+        }
+
+        self.handle_network_input(game, shift);
+    }
+
+    // [PgUp] steps the API's simulated latency, [PgDn] its failure rate and
+    // [Home] how often a response stalls
+    fn handle_network_input(&mut self, game: &Game, shift: bool) {
+        const LATENCIES_MS: [u64; 4] = [0, 250, 1_000, 3_000];
+        const RATES: [f32; 4] = [0.0, 0.25, 0.5, 1.0];
+
+        fn step<T: PartialEq + Copy>(steps: &[T], current: T, back: bool) -> T {
+            let index = steps.iter().position(|s| *s == current).unwrap_or(0);
+            let count = steps.len();
+            steps[if back {
+                (index + count - 1) % count
+            } else {
+                (index + 1) % count
+            }]
+        }
+
+        let mut simulation = game.api.client.network_simulation();
+        if is_key_pressed(KeyCode::PageUp) {
+            let latency = step(&LATENCIES_MS, simulation.latency.as_millis() as u64, shift);
+            simulation.latency = std::time::Duration::from_millis(latency);
+        } else if is_key_pressed(KeyCode::PageDown) {
+            simulation.failure_rate = step(&RATES, simulation.failure_rate, shift);
+        } else if is_key_pressed(KeyCode::Home) {
+            simulation.slow_rate = step(&RATES, simulation.slow_rate, shift);
+        } else {
+            return;
+        }
+        game.api.client.set_network_simulation(simulation);
+        self.set_status(simulation.summary());
+    }
+
+    // How long the live game should advance this frame: the real dt, nothing
+    // while frozen, or one fixed 60 Hz tick when [.] steps it
+    pub fn simulation_dt(&self, dt: f32) -> Option<f32> {
+        if !self.frozen {
+            Some(dt)
+        } else if is_key_pressed(KeyCode::Period) {
+            Some(1.0 / 60.0)
+        } else {
+            None
+        }
+    }
+
+    // End the run (starting one if needed) with a score that makes the board
+    fn force_high_score(&mut self, game: &mut Game) {
+        if !matches!(game.state(), GameState::Playing | GameState::Paused) {
+            game.start_game();
+        }
+        // Autopilot runs never count as high scores
+        self.autopilot = false;
+        game.autopilot = false;
+
+        let top = game
+            .leaderboard
+            .board
+            .scores
+            .first()
+            .map_or(0, |entry| entry.score);
+        game.run.score = game.run.score.max(top + 1);
+        game.game_over();
+        self.set_status("Forced a new high score".to_string());
+    }
+
+    fn handle_tuning_input(&mut self, game: &mut Game) {
+        if is_key_pressed(KeyCode::T) {
+            self.show_tuning = !self.show_tuning;
+        }
+        if !self.show_tuning {
+            return;
+        }
+
+        let params = TuningParam::ALL.len();
+        if is_key_pressed(KeyCode::LeftBracket) {
+            self.tuning_cursor = (self.tuning_cursor + params - 1) % params;
+        } else if is_key_pressed(KeyCode::RightBracket) {
+            self.tuning_cursor = (self.tuning_cursor + 1) % params;
+        }
+
+        let steps = if is_key_pressed(KeyCode::Equal) || is_key_pressed(KeyCode::KpAdd) {
+            1
+        } else if is_key_pressed(KeyCode::Minus) || is_key_pressed(KeyCode::KpSubtract) {
+            -1
+        } else {
+            0
+        };
+        if steps != 0 {
+            let mut tuning = game.tuning;
+            TuningParam::ALL[self.tuning_cursor].adjust(&mut tuning, steps);
+            game.set_tuning(tuning);
+        }
+
+        if is_key_pressed(KeyCode::Backspace) {
+            game.set_tuning(Tuning::default());
+        }
+
+        if is_key_pressed(KeyCode::E) {
+            println!("# Tuning from dev mode, paste into yeti.toml");
+            println!("{}", game.tuning.config_snippet());
+            self.set_status("Tuning snippet printed to console".to_string());
+        }
+    }
+
+    pub fn draw_live_overlay(&self, game: &Game, fonts: &crate::design::GameFonts) {
+        if self.show_physics {
+            Self::draw_physics_debug(game);
+        }
+        if self.show_tuning {
+            self.draw_tuning_panel(game, fonts);
+        }
+        if self.show_latency {
+            self.draw_latency_panel(game, fonts);
+        }
+
+        if game.adaptive.active {
+            let summary = game.adaptive.summary();
+            let width = TypographyStyle::UICaption
+                .measure_text(&summary, fonts)
+                .width
+                + 12.0;
+            let background = Color::new(0.0, 0.0, 0.0, 0.8);
+            draw_rectangle(6.0, SCREEN_HEIGHT - 44.0, width, 18.0, background);
+            UIComponent::draw_text(
+                &summary,
+                12.0,
+                SCREEN_HEIGHT - 31.0,
+                TypographyStyle::UICaption,
+                ColorTheme::Technical,
+                fonts,
+            );
+        }
+
+        let network = game.api.client.network_simulation();
+        let network_summary = network.summary();
+        let status = if self.status_timer > 0.0 {
+            Some(self.status_message.as_str())
+        } else if self.frozen {
+            Some("FROZEN [F4]  [.] Step")
+        } else if game.invincible {
+            Some("INVINCIBLE [F10]")
+        } else if network.is_active() {
+            Some(network_summary.as_str())
+        } else {
+            None
+        };
+        if let Some(status) = status {
+            let width = TypographyStyle::UICaption.measure_text(status, fonts).width + 12.0;
+            let background = Color::new(0.0, 0.0, 0.0, 0.8);
+            draw_rectangle(6.0, SCREEN_HEIGHT - 24.0, width, 18.0, background);
+            UIComponent::draw_text(
+                status,
+                12.0,
+                SCREEN_HEIGHT - 11.0,
+                TypographyStyle::UICaption,
+                ColorTheme::Warning,
+                fonts,
+            );
+        }
+    }
+
+    // Sprite bounds in grey, collision rects in color. The band between the
+    // two is the collision grace margin: overlaps there are near misses.
+    fn draw_physics_debug(game: &Game) {
+        let palette = DesignContext::palette();
+
+        for lane in 0..game.lane_count.max(1) {
+            let ground_y = game.lane_ground_y(lane);
+            draw_line(0.0, ground_y, SCREEN_WIDTH, ground_y, 1.0, TEAL);
+        }
+
+        for item in &game.items {
+            let color = if item.is_good {
+                palette.success()
+            } else {
+                palette.error()
+            };
+            draw_rectangle_lines(
+                item.body.x,
+                item.body.y - item.body.height,
+                item.body.width,
+                item.body.height,
+                1.0,
+                GREY,
+            );
+            let (x, y, w, h) = item.get_collision_rect();
+            draw_rectangle_lines(x, y, w, h, 1.0, color);
+        }
+
+        let mut yetis = vec![&game.yeti];
+        if let Some(versus) = &game.versus {
+            yetis.push(&versus.player_two);
+        }
+        for yeti in yetis {
+            draw_rectangle_lines(
+                yeti.x,
+                yeti.y - yeti.height,
+                yeti.width,
+                yeti.height,
+                1.0,
+                GREY,
+            );
+            let (x, y, w, h) = yeti.get_collision_rect();
+            draw_rectangle_lines(x, y, w, h, 1.0, VIBRANT_BLUE);
+            Self::draw_jump_arc(game, yeti);
+        }
+    }
+
+    // Where the yeti's feet go if it jumps now (or where the current jump
+    // lands), plotted against the items' approach speed so it lines up with
+    // the obstacles it would clear and the terrain it would land on
+    fn draw_jump_arc(game: &Game, yeti: &Yeti) {
+        const STEP: f32 = 1.0 / 60.0;
+        let tuning = &game.tuning;
+        let speed = game.item_speed();
+
+        let mut velocity = if yeti.is_jumping {
+            yeti.velocity_y
+        } else {
+            tuning.jump_velocity
+        };
+        let mut y = yeti.y;
+        let mut x = yeti.x + yeti.width / 2.0;
+
+        for _ in 0..240 {
+            velocity += tuning.gravity * STEP;
+            let next_x = x + speed * STEP;
+            // Terrain reaches the yeti as the arc does, so read it at the arc's x
+            let floor = game
+                .terrain
+                .height_at(next_x)
+                .map_or(SCREEN_HEIGHT, |height| yeti.ground_y - height);
+            let next_y = (y + velocity * STEP).min(floor);
+            draw_line(x, y, next_x, next_y, 1.0, WARNING_YELLOW);
+            if next_y >= floor {
+                break;
+            }
+            x = next_x;
+            y = next_y;
+        }
+    }
+
+    fn draw_tuning_panel(&self, game: &Game, fonts: &crate::design::GameFonts) {
+        let x = 6.0;
+        let y = 6.0;
+        let width = 210.0;
+        let height = 40.0 + TuningParam::ALL.len() as f32 * 14.0;
+        draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.0, 0.8));
+
+        UIComponent::draw_text(
+            "TUNING  [ ] Select  -/+ Adjust",
+            x + 6.0,
+            y + 14.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Warning,
+            fonts,
+        );
+
+        let mut row_y = y + 30.0;
+        for (i, param) in TuningParam::ALL.iter().enumerate() {
+            let theme = if i == self.tuning_cursor {
+                ColorTheme::Success
+            } else {
+                ColorTheme::Primary
+            };
+            let marker = if i == self.tuning_cursor { ">" } else { " " };
+            UIComponent::draw_text(
+                &format!("{} {}", marker, param.label()),
+                x + 6.0,
+                row_y,
+                TypographyStyle::UICaption,
+                theme,
+                fonts,
+            );
+            UIComponent::draw_text(
+                &param.value_text(&game.tuning),
+                x + width - 50.0,
+                row_y,
+                TypographyStyle::UICaption,
+                theme,
+                fonts,
+            );
+            row_y += 14.0;
+        }
+
+        UIComponent::draw_text(
+            "[E] Export  [BKSP] Reset  [T] Hide",
+            x + 6.0,
+            row_y,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            fonts,
+        );
+    }
+
+    // Under the F3 overlay when that's up. Jump presses are what's timed, so
+    // tap [SPACE] anywhere to take readings.
+    fn draw_latency_panel(&self, game: &Game, fonts: &crate::design::GameFonts) {
+        let width = 170.0;
+        let x = SCREEN_WIDTH - width - 6.0;
+        let y = if game.settings.perf_overlay {
+            108.0
+        } else {
+            6.0
+        };
+        draw_rectangle(x, y, width, 82.0, Color::new(0.0, 0.0, 0.0, 0.8));
+
+        let ms = |reading: Option<f32>| reading.map_or("--".to_string(), |ms| format!("{:.1}", ms));
+        let lines = [
+            ("INPUT LATENCY  [END] Hide".to_string(), ColorTheme::Warning),
+            (
+                format!(
+                    "Last {} ms  Avg {} ms",
+                    ms(self.latency.last_ms()),
+                    ms(self.latency.average_ms())
+                ),
+                ColorTheme::Primary,
+            ),
+            (
+                format!("Worst {} ms", ms(self.latency.worst_ms())),
+                ColorTheme::Primary,
+            ),
+            (
+                format!("+ up to {:.1} ms before it's read", self.latency.frame_ms()),
+                ColorTheme::Neutral,
+            ),
+            (
+                format!("Input lead {} ms", game.input_lead_ms),
+                ColorTheme::Technical,
+            ),
+        ];
+        for (i, (text, theme)) in lines.iter().enumerate() {
+            UIComponent::draw_text(
+                text,
+                x + 6.0,
+                y + 14.0 + i as f32 * 14.0,
+                TypographyStyle::UICaption,
+                *theme,
+                fonts,
+            );
+        }
     }
 
-    pub fn get_current_game_state(&self) -> GameState {
+    // The scene stack to preview, bottom first
+    pub fn get_current_scenes(&self) -> &'static [GameState] {
         match self.current_screen {
-            DevScreen::MainMenu => GameState::MainMenu,
-            DevScreen::GameOver => GameState::GameOver,
-            DevScreen::LevelComplete => GameState::LevelComplete,
-            DevScreen::NameInput => GameState::NameInput,
-            DevScreen::Leaderboard => GameState::ViewingLeaderboard,
-            DevScreen::Playing => GameState::Playing,
-            DevScreen::TypographyShowcase | DevScreen::ColorShowcase => GameState::MainMenu,
+            DevScreen::MainMenu => &[GameState::MainMenu],
+            DevScreen::GameOver => &[GameState::GameOver],
+            DevScreen::RunSummary => &[GameState::RunSummary],
+            DevScreen::LevelComplete => &[GameState::LevelComplete],
+            DevScreen::NameInput => &[GameState::NameInput],
+            DevScreen::Leaderboard => &[GameState::ViewingLeaderboard],
+            DevScreen::Playing => &[GameState::Playing],
+            DevScreen::Paused => &[GameState::Playing, GameState::Paused],
+            DevScreen::TypographyShowcase
+            | DevScreen::ColorShowcase
+            | DevScreen::ConfigOverrides => &[GameState::MainMenu],
+        }
+    }
+
+    // Fed through the same events a real run produces
+    fn mock_run_stats(game: &Game) -> RunStats {
+        let mut rng = ::rand::thread_rng();
+        let mut stats = RunStats::new();
+        let seconds = 95;
+
+        for second in 1..=seconds {
+            let item_type = game.catalog.random(&mut rng, second % 4 != 0, |_| true);
+            let event = if second % 4 == 0 {
+                GameEvent::ItemDodged {
+                    item_type,
+                    player: 0,
+                    points: 5,
+                }
+            } else if second % 11 == 0 {
+                GameEvent::ItemMissed {
+                    item_type,
+                    player: 0,
+                }
+            } else {
+                GameEvent::ItemCollected {
+                    item_type,
+                    player: 0,
+                    x: 0.0,
+                    y: 0.0,
+                    points: 10,
+                }
+            };
+            stats.handle_event(&event);
+
+            if second % 14 == 0 {
+                stats.handle_event(&GameEvent::LevelUp {
+                    level: second / 14 + 1,
+                });
+            }
+            stats.tick(1.0, 38000 * second / seconds);
         }
+
+        let item_type = game.catalog.random(&mut rng, false, |_| true);
+        stats.handle_event(&GameEvent::Crashed {
+            item_type,
+            player: 0,
+        });
+        stats.finish(38000, game.run.score);
+        stats
     }
 
     fn populate_mock_data(game: &mut Game) {
         // Basic game stats
-        game.score = 42850;
-        game.level = 7;
-        game.checks_completed = 12;
-        game.checks_required = 15;
+        game.run.score = 42850;
+        game.run.level = 7;
+        game.current_level = game.levels.definition(game.run.level);
+        game.run.checks_completed = 12;
+        game.run.checks_required = 15;
         game.is_new_high_score = true;
         game.player_name_input = "DevMaster".to_string();
-        game.feedback_message =
-            "CI pipeline succeeded! All tests passing, deployment ready for staging environment."
-                .to_string();
-        game.feedback_timer = 3.0;
+        game.feedback.queue = FeedbackQueue::new();
+        game.feedback.queue.push(Feedback::new(
+            "CI pipeline succeeded! All tests passing, deployment ready for staging environment.",
+            Severity::Success,
+            Priority::Collision,
+            3.0,
+        ));
+        game.feedback.queue.push(Feedback::new(
+            "Nice dodge! Avoided a problem!",
+            Severity::Success,
+            Priority::Event,
+            3.0,
+        ));
         game.level_complete_message = "Issue #42 Done!".to_string();
         game.level_complete_submessage = "What else is assigned to me...".to_string();
         game.level_complete_timer = 2.0;
+        game.stats = Self::mock_run_stats(game);
 
         // Mock leaderboard data
         let mut leaderboard = Leaderboard::new();
 
         let mock_scores = vec![
             HighScore {
+                id: None,
                 name: "CodeNinja".to_string(),
                 score: 89650,
                 level: 15,
                 timestamp: Utc::now(),
+                duration: Some(game.stats.time_survived),
+                score_history: game.stats.score_samples(),
+                mutators: Vec::new(),
+                assisted: false,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "PipelinePro".to_string(),
                 score: 76420,
                 level: 12,
                 timestamp: Utc::now() - chrono::Duration::hours(2),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: vec![Mutator::DoubleSpeed],
+                assisted: false,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "DevOpsGuru".to_string(),
                 score: 68350,
                 level: 11,
                 timestamp: Utc::now() - chrono::Duration::days(1),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: Vec::new(),
+                assisted: true,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "GitMaster".to_string(),
                 score: 59870,
                 level: 10,
                 timestamp: Utc::now() - chrono::Duration::days(2),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: vec![Mutator::NoGoodItems, Mutator::Mirror],
+                assisted: false,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "TestRunner".to_string(),
                 score: 52140,
                 level: 9,
                 timestamp: Utc::now() - chrono::Duration::days(3),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: Vec::new(),
+                assisted: false,
+                category: Category::Standard,
+                moderation: Some(Moderation::Sanitized), // Shows how a blanked name looks
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "YetiHunter".to_string(),
                 score: 48920,
                 level: 8,
                 timestamp: Utc::now() - chrono::Duration::days(5),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: vec![Mutator::LowGravity],
+                assisted: false,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "BuildBot".to_string(),
                 score: 43750,
                 level: 7,
                 timestamp: Utc::now() - chrono::Duration::days(7),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: Vec::new(),
+                assisted: false,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
             HighScore {
+                id: None,
                 name: "MergeKing".to_string(),
                 score: 38640,
                 level: 6,
                 timestamp: Utc::now() - chrono::Duration::days(10),
+                duration: None,
+                score_history: Vec::new(),
+                mutators: Vec::new(),
+                assisted: false,
+                category: Category::Standard,
+                moderation: None,
+                run: None,
             },
         ];
 
@@ -198,7 +819,7 @@ impl DevMode {
             leaderboard.add_score(score);
         }
 
-        game.leaderboard = leaderboard;
+        game.leaderboard.board = leaderboard;
     }
 
     pub fn draw_dev_overlay(&self, fonts: &crate::design::GameFonts) {
@@ -231,27 +852,31 @@ impl DevMode {
 
         // Current screen info
         let screen_text = format!(
-            "DEV MODE: {} ({}/8)",
+            "DEV MODE: {} ({}/11)  Autopilot: {}",
             self.current_screen.name(),
-            self.get_screen_index() + 1
+            self.get_screen_index() + 1,
+            if self.autopilot { "ON" } else { "OFF" }
         );
         GameText::ui_label(&screen_text, 10.0, 25.0, fonts);
 
         // Navigation instructions
-        let nav_text = "[←/P] Prev  [→/N] Next  [H] Hide Overlay  [ESC] Exit  [D] Toggle Dev Mode";
-        GameText::instructions(&nav_text, 10.0, 45.0, fonts);
+        let nav_text = "[←/P] Prev  [→/N] Next  [A] Autopilot  [H] Hide  [ESC] Exit  [D] Dev Mode";
+        GameText::instructions(nav_text, 10.0, 45.0, fonts);
     }
 
     fn get_screen_index(&self) -> usize {
         match self.current_screen {
             DevScreen::MainMenu => 0,
             DevScreen::GameOver => 1,
-            DevScreen::LevelComplete => 2,
-            DevScreen::NameInput => 3,
-            DevScreen::Leaderboard => 4,
-            DevScreen::Playing => 5,
-            DevScreen::TypographyShowcase => 6,
-            DevScreen::ColorShowcase => 7,
+            DevScreen::RunSummary => 2,
+            DevScreen::LevelComplete => 3,
+            DevScreen::NameInput => 4,
+            DevScreen::Leaderboard => 5,
+            DevScreen::Playing => 6,
+            DevScreen::Paused => 7,
+            DevScreen::TypographyShowcase => 8,
+            DevScreen::ColorShowcase => 9,
+            DevScreen::ConfigOverrides => 10,
         }
     }
 
@@ -259,6 +884,7 @@ impl DevMode {
         match self.current_screen {
             DevScreen::TypographyShowcase => self.draw_typography_showcase(fonts),
             DevScreen::ColorShowcase => self.draw_color_showcase(fonts),
+            DevScreen::ConfigOverrides => self.draw_config_overrides(fonts),
             _ => {} // Regular screens are handled by normal rendering
         }
     }
@@ -270,7 +896,7 @@ impl DevMode {
             0.0,
             SCREEN_WIDTH,
             SCREEN_HEIGHT,
-            secondary_palette::BACKGROUND,
+            ThemeProvider::backdrop(),
         );
 
         let mut y = 80.0;
@@ -449,14 +1075,22 @@ impl DevMode {
         );
     }
 
+    // Drawn in the theme picked with [T], without touching the setting
     fn draw_color_showcase(&self, fonts: &crate::design::GameFonts) {
+        let theme = self
+            .showcase_theme
+            .unwrap_or(DesignContext::current().theme);
+        ThemeProvider::preview(theme, || Self::draw_color_swatches(theme, fonts));
+    }
+
+    fn draw_color_swatches(theme: UiTheme, fonts: &crate::design::GameFonts) {
         // Background
         draw_rectangle(
             0.0,
             0.0,
             SCREEN_WIDTH,
             SCREEN_HEIGHT,
-            secondary_palette::BACKGROUND,
+            ThemeProvider::backdrop(),
         );
 
         let mut y = 80.0;
@@ -464,7 +1098,17 @@ impl DevMode {
 
         // Title
         GameText::title_centered("Color Theme Showcase", SCREEN_WIDTH / 2.0, y, fonts);
-        y += 60.0;
+        y += 24.0;
+        let caption = format!("{} theme  //  [T] Next theme", theme.name());
+        UIComponent::draw_text_centered(
+            &caption,
+            SCREEN_WIDTH / 2.0,
+            y,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            fonts,
+        );
+        y += 36.0;
 
         let themes = [
             (ColorTheme::Primary, "Primary Theme"),
@@ -542,4 +1186,80 @@ impl DevMode {
             x += 80.0;
         }
     }
+
+    // Every key yeti.toml can set, with the overridden ones called out
+    fn draw_config_overrides(&self, fonts: &crate::design::GameFonts) {
+        draw_rectangle(
+            0.0,
+            0.0,
+            SCREEN_WIDTH,
+            SCREEN_HEIGHT,
+            ThemeProvider::backdrop(),
+        );
+
+        let loaded = overrides::loaded();
+        let defaults = GameConfig::default();
+
+        let header = match &loaded.source {
+            Some(path) => format!(
+                "{} ({} overridden)",
+                path.display(),
+                loaded.overridden.len()
+            ),
+            None => "No yeti.toml found, using config.rs defaults".to_string(),
+        };
+        UIComponent::draw_text(
+            &header,
+            20.0,
+            76.0,
+            TypographyStyle::BodySmall,
+            ColorTheme::Secondary,
+            fonts,
+        );
+
+        let rows_per_column = OVERRIDABLE.len().div_ceil(2);
+        let column_width = (SCREEN_WIDTH - 40.0) / 2.0;
+        for (i, entry) in OVERRIDABLE.iter().enumerate() {
+            let x = 20.0 + (i / rows_per_column) as f32 * column_width;
+            let y = 94.0 + (i % rows_per_column) as f32 * 13.0;
+
+            let value = entry.value(&loaded.values);
+            let (value_text, theme) = if loaded.is_overridden(entry.key) {
+                let default = entry.value(&defaults);
+                (format!("{} (was {})", value, default), ColorTheme::Warning)
+            } else {
+                (value.to_string(), ColorTheme::Neutral)
+            };
+
+            UIComponent::draw_text(entry.key, x, y, TypographyStyle::UICaption, theme, fonts);
+            let value_width = TypographyStyle::UICaption
+                .measure_text(&value_text, fonts)
+                .width;
+            UIComponent::draw_text(
+                &value_text,
+                x + column_width - 16.0 - value_width,
+                y,
+                TypographyStyle::UICaption,
+                theme,
+                fonts,
+            );
+        }
+
+        // First skipped line; the console lists them all
+        if let Some(problem) = loaded.problems.first() {
+            let text = if loaded.problems.len() > 1 {
+                format!("{} (+{} more)", problem, loaded.problems.len() - 1)
+            } else {
+                problem.clone()
+            };
+            UIComponent::draw_text(
+                &text,
+                20.0,
+                SCREEN_HEIGHT - 10.0,
+                TypographyStyle::UICaption,
+                ColorTheme::Error,
+                fonts,
+            );
+        }
+    }
 }
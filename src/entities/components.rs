@@ -0,0 +1,98 @@
+use macroquad::math::{vec2, Vec2};
+
+// The pieces entities are built from. Each kind of entity is a struct of the
+// components it needs, and the systems in game::systems take components
+// rather than entities, so anything new with a Body scrolls and collides the
+// same way items do, and anything with Motion and a Lifetime flies and fades
+// like a particle.
+
+// (x, y, width, height), top-left corner first
+pub type Rect = (f32, f32, f32, f32);
+
+pub fn overlaps(a: Rect, b: Rect) -> bool {
+    let (ax, ay, aw, ah) = a;
+    let (bx, by, bw, bh) = b;
+    ax < bx + bw && ax + aw > bx && ay < by + bh && ay + ah > by
+}
+
+// Where something is and how big. For things on the track, y is the ground
+// under them and they're drawn from y - height.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Body {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Body {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    // Middle of what's drawn
+    pub fn center(&self) -> Vec2 {
+        vec2(self.x + self.width / 2.0, self.y - self.height / 2.0)
+    }
+
+    pub fn bounds(&self) -> Rect {
+        (self.x, self.y, self.width, self.height)
+    }
+
+    // Bounds shrunk by margin on every side, for forgiving collisions
+    pub fn inset(&self, margin: f32) -> Rect {
+        (
+            self.x + margin,
+            self.y + margin,
+            self.width - (margin * 2.0),
+            self.height - (margin * 2.0),
+        )
+    }
+
+    pub fn is_off_screen(&self) -> bool {
+        self.x < -self.width
+    }
+}
+
+// Free flight, for things that move on their own rather than with the track
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Motion {
+    pub velocity: Vec2,
+    pub gravity: f32,
+    pub drag: f32, // Share of horizontal speed lost per second
+    pub rotation: f32,
+    pub spin: f32,
+}
+
+// Counts down to nothing; the last `fade` seconds fade out
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lifetime {
+    pub remaining: f32,
+    pub fade: f32,
+}
+
+impl Lifetime {
+    pub fn new(seconds: f32, fade: f32) -> Self {
+        Self {
+            remaining: seconds,
+            fade,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.remaining > 0.0
+    }
+
+    pub fn alpha(&self) -> f32 {
+        (self.remaining / self.fade).clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,130 @@
+use ::rand::distributions::{Distribution, WeightedIndex};
+use ::rand::Rng;
+use serde::Deserialize;
+
+// Handle into the catalog; cheap to copy around in items and events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemType(usize);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemDefinition {
+    pub id: String,
+    pub name: String,
+    pub good: bool,
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+    pub texture: String,
+    pub texture_file: String,
+    pub feedback: String,
+    #[serde(default)]
+    pub voice_clip: Option<String>, // WAV under assets/voice/, read instead of the beeps
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Deserialize)]
+struct CatalogFile {
+    items: Vec<ItemDefinition>,
+}
+
+#[derive(Debug)]
+pub enum CatalogError {
+    Parse(serde_json::Error),
+    MissingKind(&'static str), // Need at least one good and one bad item
+    DuplicateId(String),
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogError::Parse(e) => write!(f, "Invalid item data: {}", e),
+            CatalogError::MissingKind(kind) => write!(f, "No {} items defined", kind),
+            CatalogError::DuplicateId(id) => write!(f, "Item '{}' is defined twice", id),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+#[derive(Debug, Clone)]
+pub struct ItemCatalog {
+    items: Vec<ItemDefinition>,
+}
+
+impl ItemCatalog {
+    pub fn from_json(data: &str) -> Result<Self, CatalogError> {
+        let file: CatalogFile = serde_json::from_str(data).map_err(CatalogError::Parse)?;
+        let catalog = Self { items: file.items };
+
+        for (index, item) in catalog.items.iter().enumerate() {
+            if catalog.items[..index].iter().any(|other| other.id == item.id) {
+                return Err(CatalogError::DuplicateId(item.id.clone()));
+            }
+        }
+        if !catalog.items.iter().any(|item| item.good && item.weight > 0.0) {
+            return Err(CatalogError::MissingKind("good"));
+        }
+        if !catalog.items.iter().any(|item| !item.good && item.weight > 0.0) {
+            return Err(CatalogError::MissingKind("bad"));
+        }
+
+        Ok(catalog)
+    }
+
+    // Extra items from a mod pack, all or nothing
+    pub fn add_items(&mut self, items: Vec<ItemDefinition>) -> Result<(), CatalogError> {
+        for (index, item) in items.iter().enumerate() {
+            let mut taken = self.items.iter().chain(&items[..index]);
+            if taken.any(|other| other.id == item.id) {
+                return Err(CatalogError::DuplicateId(item.id.clone()));
+            }
+        }
+        self.items.extend(items);
+        Ok(())
+    }
+
+    pub fn get(&self, item_type: ItemType) -> &ItemDefinition {
+        &self.items[item_type.0]
+    }
+
+    pub fn find(&self, id: &str) -> Option<ItemType> {
+        self.items.iter().position(|item| item.id == id).map(ItemType)
+    }
+
+    pub fn definitions(&self) -> impl Iterator<Item = &ItemDefinition> {
+        self.items.iter()
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = ItemType> {
+        (0..self.items.len()).map(ItemType)
+    }
+
+    // Weighted pick among the good or bad items that pass `allowed`. If the
+    // filter leaves nothing to pick, every item of that kind is fair game.
+    pub fn random(
+        &self,
+        rng: &mut impl Rng,
+        good: bool,
+        allowed: impl Fn(&ItemDefinition) -> bool,
+    ) -> ItemType {
+        let of_kind = |index: &usize| {
+            let item = &self.items[*index];
+            item.good == good && item.weight > 0.0
+        };
+        let mut candidates: Vec<usize> = (0..self.items.len())
+            .filter(of_kind)
+            .filter(|&index| allowed(&self.items[index]))
+            .collect();
+        if candidates.is_empty() {
+            candidates = (0..self.items.len()).filter(of_kind).collect();
+        }
+        let weights = candidates
+            .iter()
+            .map(|&index| self.items[index].weight);
+
+        let picker = WeightedIndex::new(weights).expect("catalog was validated on load");
+        ItemType(candidates[picker.sample(rng)])
+    }
+}
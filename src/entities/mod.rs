@@ -1,5 +1,12 @@
 pub mod yeti;
+pub mod components;
 pub mod item;
+pub mod item_catalog;
+pub mod particle;
+pub mod terrain;
 
 pub use yeti::Yeti;
-pub use item::Item;
\ No newline at end of file
+pub use item::Item;
+pub use item_catalog::{ItemCatalog, ItemDefinition, ItemType};
+pub use particle::Particle;
+pub use terrain::{Feature, Terrain};
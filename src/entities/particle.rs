@@ -0,0 +1,67 @@
+use super::components::{Body, Lifetime, Motion};
+use crate::colors::{PEAK, SUCCESS_GREEN, TEAL, VIBRANT_BLUE, VIOLET, WARNING_YELLOW};
+use ::rand::{thread_rng, Rng};
+use macroquad::prelude::*;
+
+const CONFETTI_GRAVITY: f32 = 260.0;
+const AIR_DRAG: f32 = 1.5; // Light, so pieces flutter down instead of dropping like rocks
+const FADE_TIME: f32 = 0.5; // Seconds at the end of life spent fading out
+const CONFETTI_COLORS: [Color; 6] = [
+    VIBRANT_BLUE,
+    TEAL,
+    VIOLET,
+    WARNING_YELLOW,
+    SUCCESS_GREEN,
+    PEAK,
+];
+
+// Drawn as a small rotating slip of paper centered on the body's x and y
+#[derive(Debug, Clone)]
+pub struct Particle {
+    pub body: Body,
+    pub motion: Motion,
+    pub lifetime: Lifetime,
+    pub color: Color,
+}
+
+impl Particle {
+    pub fn confetti(x: f32, y: f32) -> Self {
+        let mut rng = thread_rng();
+        let lifetime = rng.gen_range(1.4..2.4);
+        let size = rng.gen_range(3.0..6.0);
+
+        Self {
+            body: Body::new(x, y, size, size * 0.6),
+            motion: Motion {
+                velocity: vec2(rng.gen_range(-140.0..140.0), rng.gen_range(-260.0..-120.0)),
+                gravity: CONFETTI_GRAVITY,
+                drag: AIR_DRAG,
+                rotation: rng.gen_range(0.0..std::f32::consts::TAU),
+                spin: rng.gen_range(-8.0..8.0),
+            },
+            lifetime: Lifetime::new(lifetime, FADE_TIME),
+            color: CONFETTI_COLORS[rng.gen_range(0..CONFETTI_COLORS.len())],
+        }
+    }
+
+    // Short-lived burst used when an item is collected
+    pub fn sparkle(x: f32, y: f32, color: Color) -> Self {
+        let mut rng = thread_rng();
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let speed = rng.gen_range(60.0..140.0);
+        let size = rng.gen_range(2.0..4.0);
+
+        Self {
+            body: Body::new(x, y, size, size * 0.6),
+            motion: Motion {
+                velocity: vec2(angle.cos() * speed, angle.sin() * speed - 60.0),
+                gravity: CONFETTI_GRAVITY,
+                drag: AIR_DRAG,
+                rotation: angle,
+                spin: rng.gen_range(-10.0..10.0),
+            },
+            lifetime: Lifetime::new(rng.gen_range(0.3..0.6), FADE_TIME),
+            color,
+        }
+    }
+}
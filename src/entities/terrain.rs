@@ -0,0 +1,69 @@
+use crate::config::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Feature {
+    Platform { height: f32 }, // Raised this far above the track; jump on, don't run in
+    Gap,                      // A break in the track; anything left in it falls through
+}
+
+// A stretch of non-flat track. Everywhere else the ground is the lane's
+// baseline, as before.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub x: f32, // Left edge
+    pub width: f32,
+    pub feature: Feature,
+}
+
+// The shape of the track under player one, scrolling with the items. Only
+// the classic single-lane track has any; everywhere else this stays empty
+// and the ground is flat.
+#[derive(Debug, Clone, Default)]
+pub struct Terrain {
+    pub segments: Vec<Segment>,
+}
+
+impl Terrain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clear(&mut self) {
+        self.segments.clear();
+    }
+
+    // New features come in at the right edge, like items
+    pub fn push(&mut self, feature: Feature, width: f32) {
+        self.segments.push(Segment {
+            x: SCREEN_WIDTH,
+            width,
+            feature,
+        });
+    }
+
+    pub fn update(&mut self, dt: f32, speed: f32) {
+        for segment in &mut self.segments {
+            segment.x -= speed * dt;
+        }
+        self.segments
+            .retain(|segment| segment.x + segment.width > 0.0);
+    }
+
+    // What's under this x; None for plain track
+    pub fn feature_at(&self, x: f32) -> Option<Feature> {
+        self.segments
+            .iter()
+            .find(|segment| (segment.x..segment.x + segment.width).contains(&x))
+            .map(|segment| segment.feature)
+    }
+
+    // Height of the surface at this x above the lane's baseline, or None
+    // over a gap
+    pub fn height_at(&self, x: f32) -> Option<f32> {
+        match self.feature_at(x) {
+            None => Some(0.0),
+            Some(Feature::Platform { height }) => Some(height),
+            Some(Feature::Gap) => None,
+        }
+    }
+}
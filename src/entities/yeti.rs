@@ -1,5 +1,12 @@
 use macroquad::prelude::*;
+use super::components::Rect;
+use super::terrain::{Feature, Terrain};
+use crate::atlas::{Sprite, SpriteAtlas};
 use crate::config::*;
+use crate::overrides::config;
+
+// How far the feet can be below a surface and still count as on it
+const SURFACE_TOLERANCE: f32 = 4.0;
 
 #[derive(Debug, Clone)]
 pub struct Yeti {
@@ -7,9 +14,23 @@ pub struct Yeti {
     pub y: f32,
     pub width: f32,
     pub height: f32,
+    pub ground_y: f32,
+    pub surface: f32, // Height above ground_y of what the yeti is standing on
+    pub lane: usize,
     pub velocity_y: f32,
     pub is_jumping: bool,
-    pub texture: Option<Texture2D>,
+    pub is_stumbling: bool,
+    pub rotation: f32,
+    pub sprite: Option<Sprite>,
+    pub trail: Vec<(Vec2, f32)>, // Recent centers, newest first, with their age in seconds
+    since_jump_press: Option<f32>, // Seconds since a press in the air, for the jump windows
+    since_ledge: Option<f32>,      // Seconds since running off an edge without jumping
+}
+
+impl Default for Yeti {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Yeti {
@@ -19,54 +40,213 @@ impl Yeti {
             y: GROUND_Y,
             width: YETI_WIDTH,
             height: YETI_HEIGHT,
+            ground_y: GROUND_Y,
+            surface: 0.0,
+            lane: 0,
             velocity_y: 0.0,
             is_jumping: false,
-            texture: None,
+            is_stumbling: false,
+            rotation: 0.0,
+            sprite: None,
+            trail: Vec::new(),
+            since_jump_press: None,
+            since_ledge: None,
         }
     }
 
     pub fn reset(&mut self) {
         self.x = 100.0;
-        self.y = GROUND_Y;
+        self.y = self.ground_y;
+        self.surface = 0.0;
         self.velocity_y = 0.0;
         self.is_jumping = false;
+        self.is_stumbling = false;
+        self.rotation = 0.0;
+        self.trail.clear();
+        self.since_jump_press = None;
+        self.since_ledge = None;
     }
 
-    pub fn jump(&mut self) {
+    pub fn jump(&mut self, velocity: f32) {
         if !self.is_jumping {
-            self.velocity_y = JUMP_VELOCITY;
+            self.velocity_y = velocity;
             self.is_jumping = true;
         }
     }
 
-    pub fn update(&mut self, dt: f32) {
+    // A jump press with `lead` seconds of slack, for screens that show the
+    // world late: pressed that soon before landing it goes on landing, and
+    // pressed that soon after running off an edge it still goes. With no
+    // lead only a press on the ground counts. Returns whether it jumped.
+    pub fn press_jump(&mut self, pressed: bool, velocity: f32, lead: f32) -> bool {
+        if pressed {
+            self.since_jump_press = Some(0.0);
+        }
+        let wanted = self.since_jump_press.is_some_and(|time| time <= lead);
+        let footing = !self.is_jumping || self.since_ledge.is_some_and(|time| time <= lead);
+        if !wanted || !footing {
+            return false;
+        }
+        self.since_jump_press = None;
+        self.since_ledge = None;
+        self.is_jumping = false;
+        self.jump(velocity);
+        true
+    }
+
+    // Lane changes only happen on the ground; the yeti then slides across
+    pub fn shift_lane(&mut self, lane: usize, ground_y: f32) {
+        if self.is_jumping || self.is_stumbling {
+            return;
+        }
+        self.lane = lane;
+        self.ground_y = ground_y;
+    }
+
+    pub fn stumble(&mut self) {
+        self.velocity_y = config().stumble_velocity;
+        self.is_jumping = false;
+        self.is_stumbling = true;
+        self.since_jump_press = None;
+        self.since_ledge = None;
+    }
+
+    // The terrain is read under the yeti's middle, so a foot hanging over an
+    // edge is still standing on it
+    pub fn update(&mut self, dt: f32, gravity: f32, terrain: &Terrain) {
+        if self.is_stumbling {
+            // Tumble backwards off the pipeline; no ground to land on anymore
+            self.velocity_y += config().stumble_gravity * dt;
+            self.y += self.velocity_y * dt;
+            self.x -= config().stumble_drift_speed * dt;
+            self.rotation += config().stumble_spin_speed * dt;
+            return;
+        }
+
+        let below = terrain.height_at(self.x + self.width / 2.0);
+        if !self.is_jumping && below.is_none_or(|height| height < self.surface) {
+            // Ran off the end of a platform, or out over a gap
+            self.is_jumping = true;
+            self.velocity_y = 0.0;
+            self.since_ledge = Some(0.0);
+        }
+        // After the edge check, so a frame spent over the edge already counts
+        for time in [&mut self.since_jump_press, &mut self.since_ledge]
+            .into_iter()
+            .flatten()
+        {
+            *time += dt;
+        }
+
         if self.is_jumping {
-            self.velocity_y += GRAVITY * dt;
+            let previous_y = self.y;
+            self.velocity_y += gravity * dt;
             self.y += self.velocity_y * dt;
 
-            if self.y >= GROUND_Y {
-                self.y = GROUND_Y;
-                self.velocity_y = 0.0;
-                self.is_jumping = false;
+            // Only from above: coming up against a platform's side isn't a landing
+            if let Some(height) = below {
+                let floor = self.ground_y - height;
+                if self.y >= floor && previous_y <= floor + SURFACE_TOLERANCE {
+                    self.y = floor;
+                    self.surface = height;
+                    self.velocity_y = 0.0;
+                    self.is_jumping = false;
+                    self.since_ledge = None;
+                }
+            }
+        } else {
+            let floor = self.ground_y - self.surface;
+            if self.y != floor {
+                let step = config().lane_shift_speed * dt;
+                let remaining = floor - self.y;
+                self.y += remaining.clamp(-step, step);
+            }
+        }
+    }
+
+    // What the yeti has run into, if anything: the side of a platform, or
+    // the bottom of a gap it didn't clear. Plain track only catches a yeti
+    // that's dropped below it, having fallen in just as the gap passed.
+    pub fn hit_terrain(&self, terrain: &Terrain) -> Option<Feature> {
+        if self.is_stumbling {
+            return None;
+        }
+        match terrain.feature_at(self.x + self.width / 2.0) {
+            Some(Feature::Platform { height }) => {
+                let top = self.ground_y - height;
+                (self.y > top + SURFACE_TOLERANCE).then_some(Feature::Platform { height })
+            }
+            _ => {
+                let sunk = self.is_jumping && self.y > self.ground_y + SURFACE_TOLERANCE;
+                sunk.then_some(Feature::Gap)
             }
         }
     }
 
-    pub fn update_texture(&mut self, textures: &std::collections::HashMap<String, Texture2D>) {
-        if self.is_jumping {
-            self.texture = textures.get("yeti_jump").cloned();
+    // Straight back onto the track, for dev mode's invincibility
+    pub fn recover(&mut self) {
+        self.y = self.ground_y;
+        self.surface = 0.0;
+        self.velocity_y = 0.0;
+        self.is_jumping = false;
+    }
+
+    // Purely visual, and kept apart from update() so replays never see it.
+    // Old points drift back with the pipeline, which is what makes a trail
+    // look like motion when the yeti itself never moves sideways.
+    pub fn update_trail(&mut self, dt: f32, scroll_speed: f32, length: f32) {
+        for (point, age) in &mut self.trail {
+            point.x -= scroll_speed * dt;
+            *age += dt;
+        }
+        self.trail.retain(|(_, age)| *age < length);
+        if length > 0.0 {
+            let center = vec2(self.x + self.width / 2.0, self.y - self.height / 2.0);
+            self.trail.insert(0, (center, 0.0));
+        }
+    }
+
+    // Seconds until the current jump comes down; None on the ground
+    pub fn time_to_land(&self, gravity: f32) -> Option<f32> {
+        if !self.is_jumping || gravity <= 0.0 {
+            return None;
+        }
+        // ground_y = y + v*t + g*t^2/2, solved for the falling root
+        let drop = (self.ground_y - self.y).max(0.0);
+        let v = self.velocity_y;
+        Some((-v + (v * v + 2.0 * gravity * drop).sqrt()) / gravity)
+    }
+
+    // Where the current jump comes down, in terms of the pipeline as it is
+    // now: whatever is at this x will be under the yeti when it lands
+    pub fn landing_x(&self, gravity: f32, scroll_speed: f32) -> Option<f32> {
+        self.time_to_land(gravity)
+            .map(|time| self.x + self.width / 2.0 + scroll_speed * time)
+    }
+
+    // `time` is animation time from the game clock, so the run cycle stops
+    // when the game does
+    pub fn update_sprite(&mut self, sprites: &SpriteAtlas, time: f64) {
+        if self.is_stumbling {
+            self.sprite = sprites.get("yeti_stumble").cloned();
+        } else if self.is_jumping {
+            self.sprite = sprites.get("yeti_jump").cloned();
         } else {
-            let run_frame = if (get_time() * 8.0) as i32 % 2 == 0 {
+            let run_frame = if (time * 8.0) as i32 % 2 == 0 {
                 "yeti_run_1"
             } else {
                 "yeti_run_2"
             };
-            self.texture = textures.get(run_frame).cloned();
+            self.sprite = sprites.get(run_frame).cloned();
         }
     }
 
-    pub fn get_collision_rect(&self) -> (f32, f32, f32, f32) {
-        let margin = COLLISION_GRACE_MARGIN;
+    pub fn get_collision_rect(&self) -> Rect {
+        self.collision_rect(config().collision_grace_margin)
+    }
+
+    // The sprite's bounds, `margin` in from every side
+    pub fn collision_rect(&self, margin: f32) -> Rect {
         (
             self.x + margin,
             self.y + margin,
@@ -0,0 +1,109 @@
+use super::events::GameEvent;
+use super::versus::PLAYER_ONE;
+use crate::overrides::config;
+use std::collections::VecDeque;
+
+const RECENT_RUNS: usize = 5; // Deaths remembered across the session
+const EXPECTED_LEVEL: f32 = 4.0; // Where a typical run ends; no adjustment
+const LEVEL_SPREAD: f32 = 3.0; // Levels either side of that for the full swing
+const WALL_PENALTY: f32 = 0.2; // Per recent death on the level being entered
+const CALM_CLOSE_CALLS: f32 = 0.25; // Share of dodges that were close, at par
+
+// Rubber-banding for players who opt in. Recent deaths (the level each run
+// ended on) and this run's close calls, bad items that brushed past inside
+// the collision grace margin, add up to a pressure between -1.0 (struggling,
+// give them a breather) and 1.0 (cruising, push back). Pressure is only
+// worked out as each level starts, so the pacing never shifts mid-level and
+// the spawn plan never has to be thrown away.
+#[derive(Debug, Clone, Default)]
+pub struct AdaptiveDifficulty {
+    pub active: bool, // This run adapts; never true for one that can rank
+    pressure: f32,
+    deaths: VecDeque<u32>, // Level each recent adaptive run ended on, oldest first
+    dodges: u32,
+    close_calls: u32,
+}
+
+impl AdaptiveDifficulty {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // History carries over between runs; the run's own counts don't
+    pub fn start_run(&mut self, active: bool) {
+        self.active = active;
+        self.dodges = 0;
+        self.close_calls = 0;
+        self.pressure = 0.0;
+    }
+
+    pub fn handle_event(&mut self, event: &GameEvent) {
+        if let GameEvent::ItemDodged { player, .. } = event {
+            if self.active && *player == PLAYER_ONE {
+                self.dodges += 1;
+            }
+        }
+    }
+
+    pub fn record_close_call(&mut self) {
+        if self.active {
+            self.close_calls += 1;
+        }
+    }
+
+    pub fn record_death(&mut self, level: u32) {
+        if !self.active {
+            return;
+        }
+        self.deaths.push_back(level);
+        if self.deaths.len() > RECENT_RUNS {
+            self.deaths.pop_front();
+        }
+    }
+
+    // Re-reads performance for the level about to start
+    pub fn enter_level(&mut self, level: u32) {
+        if !self.active {
+            self.pressure = 0.0;
+            return;
+        }
+
+        let history = if self.deaths.is_empty() {
+            0.0
+        } else {
+            let average = self.deaths.iter().sum::<u32>() as f32 / self.deaths.len() as f32;
+            (average - EXPECTED_LEVEL) / LEVEL_SPREAD
+        };
+        let walls = self.deaths.iter().filter(|died| **died == level).count() as f32;
+        let close = if self.dodges == 0 {
+            0.0
+        } else {
+            CALM_CLOSE_CALLS - self.close_calls as f32 / self.dodges as f32
+        };
+        self.pressure = (history - walls * WALL_PENALTY + close).clamp(-1.0, 1.0);
+    }
+
+    // Multiplies the seconds between spawns: under 1.0 is denser
+    pub fn spawn_interval_multiplier(&self) -> f32 {
+        1.0 - self.pressure * config().adaptive_spawn_range
+    }
+
+    // Added to the good item chance: negative means more bad items
+    pub fn good_item_shift(&self) -> f32 {
+        -self.pressure * config().adaptive_good_item_range
+    }
+
+    // For the dev overlay
+    pub fn summary(&self) -> String {
+        let deaths: Vec<String> = self.deaths.iter().map(|level| level.to_string()).collect();
+        format!(
+            "DDA {:+.2}  spawn x{:.2}  good {:+.0}%  close {}/{}  deaths [{}]",
+            self.pressure,
+            self.spawn_interval_multiplier(),
+            self.good_item_shift() * 100.0,
+            self.close_calls,
+            self.dodges,
+            deaths.join(" "),
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use crate::highscores::{Category, HighScore, Leaderboard};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+const AUDIT_FILE: &str = "admin_audit.log";
+
+// Leaderboard curation for whoever runs the board, on with --admin. An
+// entry removed here leaves the board on this machine straight away, and
+// remote entries are also deleted from the server, which only works with
+// an API key the ledger lets retract scores.
+#[derive(Debug, Default)]
+pub struct AdminMode {
+    pub confirming: bool,       // Asking "remove this entry?"
+    pub notice: Option<String>, // How the last removal went
+    removed: HashSet<(String, u32)>,
+}
+
+impl AdminMode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Keyed by name and score, like the merge, so a sync that lands before
+    // the server has caught up doesn't bring the entry back
+    pub fn remember(&mut self, entry: &HighScore) {
+        self.removed.insert((entry.name.clone(), entry.score));
+    }
+
+    pub fn drop_removed(&self, leaderboard: &mut Leaderboard) {
+        leaderboard
+            .scores
+            .retain(|entry| !self.removed.contains(&(entry.name.clone(), entry.score)));
+    }
+}
+
+// One line per removal, and one when the server answers, next to
+// settings.json. Also printed, in case the file can't be written.
+pub fn audit(message: &str) {
+    let line = format!("{} {}", chrono::Utc::now().to_rfc3339(), message);
+    println!("Admin: {}", line);
+
+    let Some(path) = audit_path() else {
+        return;
+    };
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| OpenOptions::new().create(true).append(true).open(&path))
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = written {
+        println!("Failed to write {}: {}", path.display(), e);
+    }
+}
+
+// What the audit log says about an entry
+pub fn describe(entry: &HighScore, category: Category) -> String {
+    format!(
+        "{:?} {} pts, level {}, {} board, {}",
+        entry.name,
+        entry.score,
+        entry.level,
+        category.name().to_lowercase(),
+        entry.id.as_deref().unwrap_or("local only")
+    )
+}
+
+fn audit_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("yeti-set-go").join(AUDIT_FILE))
+}
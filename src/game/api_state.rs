@@ -0,0 +1,123 @@
+use super::replay::ReplayPlayback;
+use crate::api::{ApiClient, ApiTasks, CommunityStats};
+use crate::highscores::{Category, HighScore};
+use crate::integrations::github::RepoEvent;
+use crate::version::Version;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+const SYNC_INTERVAL: Duration = Duration::from_secs(30); // Between leaderboard syncs
+
+#[derive(Debug)]
+pub enum ApiMessage {
+    LeaderboardSynced(Category, Option<Vec<HighScore>>), // None when the fetch failed
+    ScoreSubmitted(bool),                                // success flag
+    ChallengeBoardLoaded(String, Option<Vec<HighScore>>), // None when the fetch failed
+    CommunityStatsLoaded(CommunityStats),
+    GhostLoaded(Option<ReplayPlayback>), // None when there is nothing to watch
+    TelemetrySent(bool),                 // success flag
+    EntryReported(String, bool),         // Entry id, success flag
+    EntryDeleted(String, bool),          // Entry id, success flag
+    RankLoaded(Category, u32, usize),    // The board and score asked about, and its rank
+    RepoEventsLoaded(Option<Vec<RepoEvent>>), // None when the fetch failed
+    LatestVersionLoaded(Version),
+}
+
+// The connection to the leaderboard server: the client, the requests still
+// running in the background, the leaderboard sync, and the channel they all
+// answer on
+pub struct ApiState {
+    pub client: ApiClient,
+    pub tasks: ApiTasks,
+    pub loading: bool, // A leaderboard sync is on its way
+    receiver: mpsc::Receiver<ApiMessage>,
+    sender: mpsc::Sender<ApiMessage>,
+    sync_wake: Option<mpsc::Sender<()>>, // None until the sync is started, or with the API off
+}
+
+impl ApiState {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            client: ApiClient::new(),
+            tasks: ApiTasks::default(),
+            loading: false,
+            receiver,
+            sender,
+            sync_wake: None,
+        }
+    }
+
+    // For a background task to answer on
+    pub fn sender(&self) -> mpsc::Sender<ApiMessage> {
+        self.sender.clone()
+    }
+
+    // Everything answered since the last call
+    pub fn messages(&self) -> Vec<ApiMessage> {
+        self.receiver.try_iter().collect()
+    }
+
+    // Starts the leaderboard sync, which keeps going whatever is on screen
+    // until this is dropped. The first sync is right away.
+    pub fn start_sync(&mut self) {
+        if self.sync_wake.is_some() || !self.client.is_enabled() {
+            return;
+        }
+        self.loading = true;
+        self.sync_wake = Some(spawn_sync_worker(self.client.clone(), self.sender()));
+    }
+
+    // Sync now instead of waiting for the next one, when the board is about
+    // to matter, like at the end of a run
+    pub fn request_sync(&mut self) {
+        if let Some(wake) = &self.sync_wake {
+            self.loading = true;
+            let _ = wake.send(());
+        }
+    }
+}
+
+// The sync's own thread: every board and the community totals, then a wait
+// for SYNC_INTERVAL or a wake. It only fetches. Merging happens on the game
+// thread, so a score added to the board in the meantime is never lost.
+fn spawn_sync_worker(client: ApiClient, sender: mpsc::Sender<ApiMessage>) -> mpsc::Sender<()> {
+    let (wake, woken) = mpsc::channel();
+    thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        loop {
+            runtime.block_on(sync_boards(&client, &sender));
+            match woken.recv_timeout(SYNC_INTERVAL) {
+                Ok(()) | Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+            // Wakes that piled up meanwhile are all answered by the next sync
+            while woken.try_recv().is_ok() {}
+        }
+    });
+    wake
+}
+
+async fn sync_boards(client: &ApiClient, sender: &mpsc::Sender<ApiMessage>) {
+    for category in Category::ALL {
+        let scores = match client.fetch_leaderboard(category).await {
+            Ok(scores) => Some(scores),
+            Err(e) => {
+                println!("Failed to load remote leaderboard: {}", e);
+                None
+            }
+        };
+        // Sent either way, so loading completes even if the fetch failed
+        let _ = sender.send(ApiMessage::LeaderboardSynced(category, scores));
+    }
+
+    // Community totals ride along on the same cadence; a failed fetch keeps
+    // showing the last numbers
+    match client.fetch_community_stats().await {
+        Ok(stats) => {
+            let _ = sender.send(ApiMessage::CommunityStatsLoaded(stats));
+        }
+        Err(e) => println!("Failed to load community stats: {}", e),
+    }
+}
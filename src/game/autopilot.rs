@@ -0,0 +1,76 @@
+use crate::entities::Feature;
+use crate::game::state::Game;
+use crate::game::tuning::Tuning;
+use crate::input::PlayerActions;
+
+// Rule-based player used for attract mode, demos, and balancing runs.
+// It looks at the nearest bad item ahead and times a jump from the actual
+// jump physics so the item passes underneath around the apex. Terrain is
+// timed the same way: the apex over a gap's middle, or over a platform's
+// leading edge so there's still platform underneath on the way down.
+pub fn decide(game: &Game, player: usize) -> PlayerActions {
+    PlayerActions {
+        jump: should_jump(game, player),
+        ..PlayerActions::default()
+    }
+}
+
+fn should_jump(game: &Game, player: usize) -> bool {
+    let Some(yeti) = game.player_yeti(player) else {
+        return false;
+    };
+    if yeti.is_jumping {
+        return false;
+    }
+
+    let item_speed = game.item_speed();
+    let (yeti_x, _, yeti_w, _) = yeti.get_collision_rect();
+
+    let item_ahead = game
+        .items
+        .iter()
+        .filter(|item| item.lane == yeti.lane && !item.is_good && !item.was_passed)
+        .any(|item| {
+            let (item_x, _, item_w, item_h) = item.get_collision_rect();
+            let time_to_contact = (item_x - (yeti_x + yeti_w)) / item_speed;
+            let overlap_time = (yeti_w + item_w) / item_speed;
+            let lead_time = jump_lead_time(&game.tuning, overlap_time, item_h);
+            (0.0..=lead_time).contains(&time_to_contact)
+        });
+
+    // Terrain is read under the yeti's middle, like Yeti::update does
+    let center_x = yeti.x + yeti.width / 2.0;
+    let terrain_ahead = game.terrain.segments.iter().any(|segment| {
+        let time_to_contact = (segment.x - center_x) / item_speed;
+        let lead_time = match segment.feature {
+            Feature::Gap => jump_lead_time(&game.tuning, segment.width / item_speed, 0.0),
+            Feature::Platform { height } if height > yeti.surface => {
+                jump_lead_time(&game.tuning, 0.0, height - yeti.surface)
+            }
+            Feature::Platform { .. } => return false,
+        };
+        (0.0..=lead_time).contains(&time_to_contact)
+    });
+
+    item_ahead || terrain_ahead
+}
+
+// How long before contact to leave the ground so the overlap window is centered
+// on the apex, clamped to the earliest moment the yeti is high enough to clear.
+fn jump_lead_time(tuning: &Tuning, overlap_time: f32, clearance: f32) -> f32 {
+    let apex_time = -tuning.jump_velocity / tuning.gravity;
+    let centered = apex_time - overlap_time / 2.0;
+    centered.max(time_to_reach_height(tuning, clearance))
+}
+
+// Earliest time after take-off at which the yeti has risen by `height`
+fn time_to_reach_height(tuning: &Tuning, height: f32) -> f32 {
+    // height = v*t - g*t^2/2, solved for the rising root
+    let v = -tuning.jump_velocity;
+    let g = tuning.gravity;
+    let discriminant = v * v - 2.0 * g * height;
+    if discriminant <= 0.0 {
+        return v / g;
+    }
+    (v - discriminant.sqrt()) / g
+}
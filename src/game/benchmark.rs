@@ -0,0 +1,105 @@
+use super::state::Game;
+use crate::config::*;
+use crate::entities::{Item, ItemType, Particle};
+use ::rand::Rng;
+
+pub const BENCHMARK_SEED: u64 = 0x7e71; // Same scene every time
+pub const BENCHMARK_SECONDS: f32 = 10.0;
+const WARMUP_SECONDS: f32 = 1.0; // Texture uploads and the first hitches aren't measured
+const STRESS_ITEMS: usize = 300;
+const STRESS_PARTICLES: usize = 1500;
+
+// Dev tool: a deliberately overcrowded scene, run for a fixed time, so a
+// change to the renderer or the entity updates shows up as a number
+#[derive(Debug, Clone, Default)]
+pub struct Benchmark {
+    elapsed: f32,
+    frame_times: Vec<f32>, // Milliseconds, after the warmup
+    pub report: Option<BenchmarkReport>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkReport {
+    pub frames: usize,
+    pub average_ms: f32,
+    pub p95_ms: f32,
+    pub worst_ms: f32,
+}
+
+impl Benchmark {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Log one frame; returns the report on the frame the run finishes
+    pub fn record(&mut self, dt: f32) -> Option<BenchmarkReport> {
+        if self.report.is_some() {
+            return None;
+        }
+
+        self.elapsed += dt;
+        if self.elapsed > WARMUP_SECONDS {
+            self.frame_times.push(dt * 1000.0);
+        }
+        if self.elapsed >= WARMUP_SECONDS + BENCHMARK_SECONDS {
+            self.report = BenchmarkReport::from_frame_times(&self.frame_times);
+            return self.report;
+        }
+        None
+    }
+
+    // 0.0 to 1.0 over the measured part of the run
+    pub fn progress(&self) -> f32 {
+        ((self.elapsed - WARMUP_SECONDS) / BENCHMARK_SECONDS).clamp(0.0, 1.0)
+    }
+
+    pub fn is_warming_up(&self) -> bool {
+        self.elapsed <= WARMUP_SECONDS
+    }
+}
+
+impl BenchmarkReport {
+    fn from_frame_times(frame_times: &[f32]) -> Option<Self> {
+        if frame_times.is_empty() {
+            return None;
+        }
+
+        let mut sorted = frame_times.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let p95_index = ((sorted.len() as f32 * 0.95).ceil() as usize).clamp(1, sorted.len()) - 1;
+
+        Some(Self {
+            frames: sorted.len(),
+            average_ms: sorted.iter().sum::<f32>() / sorted.len() as f32,
+            p95_ms: sorted[p95_index],
+            worst_ms: sorted[sorted.len() - 1],
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} frames  avg {:.2} ms  p95 {:.2} ms  worst {:.2} ms",
+            self.frames, self.average_ms, self.p95_ms, self.worst_ms
+        )
+    }
+}
+
+// Keep the scene at full load: items scattered over every lane and a cloud
+// of confetti, replaced as fast as they leave
+pub fn fill_scene(game: &mut Game) {
+    let item_types: Vec<ItemType> = game.catalog.types().collect();
+    while game.items.len() < STRESS_ITEMS {
+        let item_type = item_types[game.rng.gen_range(0..item_types.len())];
+        let lane = game.rng.gen_range(0..game.lane_count);
+        let mut item = Item::new(item_type, &game.catalog, &game.sprites)
+            .in_lane(lane, game.lane_ground_y(lane));
+        item.body.x = game.rng.gen_range(0.0..SCREEN_WIDTH * 2.0);
+        game.items.push(item);
+    }
+
+    while game.particles.len() < STRESS_PARTICLES {
+        let x = game.rng.gen_range(0.0..SCREEN_WIDTH);
+        let y = game.rng.gen_range(SCREEN_HEIGHT * 0.3..SCREEN_HEIGHT);
+        game.particles.push(Particle::confetti(x, y));
+    }
+}
@@ -0,0 +1,107 @@
+use super::events::GameEvent;
+use super::feedback::Severity;
+use super::state::Game;
+use crate::entities::Feature;
+use std::collections::VecDeque;
+
+// Only the newest line is on show, and the one it slides in over
+const MAX_LINES: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub text: String,
+    pub severity: Severity,
+    pub at: f64, // Animation clock time it was printed, for the slide-in
+}
+
+// The HUD's optional "build log": one terse CI-style line per thing that
+// happened in the run. Like the feedback panel it only reacts to events.
+#[derive(Debug, Clone, Default)]
+pub struct BuildLog {
+    lines: VecDeque<LogLine>,
+}
+
+impl BuildLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn print(&mut self, severity: Severity, message: &str, at: f64) {
+        self.lines.push_back(LogLine {
+            text: format!("{} {}", tag(severity), message),
+            severity,
+            at,
+        });
+        while self.lines.len() > MAX_LINES {
+            self.lines.pop_front();
+        }
+    }
+
+    pub fn newest(&self) -> Option<&LogLine> {
+        self.lines.back()
+    }
+
+    // The line the newest one replaced
+    pub fn previous(&self) -> Option<&LogLine> {
+        self.lines
+            .len()
+            .checked_sub(2)
+            .and_then(|i| self.lines.get(i))
+    }
+}
+
+// The default font has no check marks, so it's all plain ASCII
+fn tag(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "[INFO]",
+        Severity::Success => "[PASS]",
+        Severity::Warning => "[WARN]",
+        Severity::Error => "[FAIL]",
+    }
+}
+
+pub fn handle_event(game: &mut Game, event: &GameEvent) {
+    let (severity, message) = match event {
+        GameEvent::ItemCollected {
+            item_type, points, ..
+        } => {
+            let id = &game.catalog.get(*item_type).id;
+            (Severity::Success, format!("{} ok +{}", id, points))
+        }
+        GameEvent::ItemDodged {
+            item_type, points, ..
+        } => {
+            let id = &game.catalog.get(*item_type).id;
+            (Severity::Success, format!("{} dodged +{}", id, points))
+        }
+        GameEvent::ItemMissed { item_type, .. } => {
+            let id = &game.catalog.get(*item_type).id;
+            (Severity::Warning, format!("{} skipped", id))
+        }
+        GameEvent::NextItemAnnounced { item_type } => {
+            let definition = game.catalog.get(*item_type);
+            if definition.good {
+                return;
+            }
+            let name = definition.name.to_lowercase();
+            (Severity::Warning, format!("{} ahead", name))
+        }
+        GameEvent::LevelUp { level } => (Severity::Info, format!("stage {} started", level)),
+        GameEvent::Crashed { item_type, .. } => {
+            let id = &game.catalog.get(*item_type).id;
+            (Severity::Error, format!("{}: build failed", id))
+        }
+        GameEvent::HitTerrain { feature, .. } => {
+            let cause = match feature {
+                Feature::Gap => "gap in the pipeline",
+                Feature::Platform { .. } => "raised section",
+            };
+            (Severity::Error, format!("{}: build failed", cause))
+        }
+        GameEvent::HighScore { .. } | GameEvent::NearMiss { .. } | GameEvent::Jumped { .. } => {
+            return
+        }
+    };
+    let at = game.clock.time();
+    game.build_log.print(severity, &message, at);
+}
@@ -0,0 +1,80 @@
+use crate::settings::MAX_INPUT_LEAD_MS;
+
+pub const TAPS: usize = 8;
+const BEAT: f32 = 0.75; // Seconds between flashes
+const LEAD_IN: f32 = 1.5; // Before the first, to find the rhythm
+
+// The tap test on the calibration screen. A marker flashes on a steady
+// beat and the player taps along; by the time a flash reaches their eyes
+// the display has already held it back, so taps land late by about that
+// much. The median of how late is the input lead to use.
+#[derive(Debug, Clone, Default)]
+pub struct Calibration {
+    elapsed: f32,
+    offsets: Vec<f32>, // Seconds from each tap's nearest beat; negative when early
+}
+
+impl Calibration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, dt: f32, tapped: bool) {
+        self.elapsed += dt;
+        // Taps before the first beat could belong to nothing
+        if tapped && !self.is_finished() && self.elapsed > LEAD_IN - BEAT / 2.0 {
+            let beats = ((self.elapsed - LEAD_IN) / BEAT).round().max(0.0);
+            self.offsets.push(self.elapsed - (LEAD_IN + beats * BEAT));
+        }
+    }
+
+    // 1.0 on a beat, fading to 0.0 before the next; 0.0 during the lead-in
+    pub fn pulse(&self) -> f32 {
+        if self.elapsed < LEAD_IN {
+            return 0.0;
+        }
+        1.0 - ((self.elapsed - LEAD_IN) % BEAT) / BEAT
+    }
+
+    pub fn taps(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.offsets.len() >= TAPS
+    }
+
+    // The median, so one missed beat doesn't drag it. Early tappers get no
+    // lead rather than a negative one.
+    pub fn lead_ms(&self) -> Option<u32> {
+        let median = self.median()?;
+        Some(((median * 1000.0).round().max(0.0) as u32).min(MAX_INPUT_LEAD_MS))
+    }
+
+    // How far taps typically sat from the median: small means a steady
+    // hand and a lead worth trusting
+    pub fn spread_ms(&self) -> Option<u32> {
+        let median = self.median()?;
+        let mut deviations: Vec<f32> = self
+            .offsets
+            .iter()
+            .map(|offset| (offset - median).abs())
+            .collect();
+        deviations.sort_by(f32::total_cmp);
+        Some((deviations[deviations.len() / 2] * 1000.0).round() as u32)
+    }
+
+    fn median(&self) -> Option<f32> {
+        if self.offsets.is_empty() {
+            return None;
+        }
+        let mut sorted = self.offsets.clone();
+        sorted.sort_by(f32::total_cmp);
+        let middle = sorted.len() / 2;
+        Some(if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2.0
+        } else {
+            sorted[middle]
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use super::difficulty::Difficulty;
+use crate::highscores::HighScore;
+use ::rand::{thread_rng, Rng};
+
+// Crockford base32: no I, L, O or U so codes survive being read aloud
+const CODE_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const CODE_LENGTH: usize = 8;
+
+// A shareable run: everyone who plays the same code gets the same item sequence.
+// Codes pack 32 bits of seed, 2 bits of difficulty and a 6-bit checksum into
+// eight base32 characters, shown as XXXX-XXXX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Challenge {
+    pub seed: u32,
+    pub difficulty: Difficulty,
+}
+
+impl Challenge {
+    pub fn new(seed: u32, difficulty: Difficulty) -> Self {
+        Self { seed, difficulty }
+    }
+
+    pub fn random(difficulty: Difficulty) -> Self {
+        Self::new(thread_rng().gen(), difficulty)
+    }
+
+    pub fn code(&self) -> String {
+        let payload = ((self.seed as u64) << 8)
+            | ((self.difficulty.index() as u64) << 6)
+            | checksum(self.seed, self.difficulty.index()) as u64;
+
+        let mut code = String::with_capacity(CODE_LENGTH + 1);
+        for i in (0..CODE_LENGTH).rev() {
+            let index = ((payload >> (i * 5)) & 0x1F) as usize;
+            code.push(CODE_ALPHABET[index] as char);
+            if i == CODE_LENGTH / 2 {
+                code.push('-');
+            }
+        }
+        code
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        let mut payload: u64 = 0;
+        let mut length = 0;
+
+        for ch in code.chars().filter(|ch| *ch != '-' && !ch.is_whitespace()) {
+            let normalized = match ch.to_ascii_uppercase() {
+                'I' | 'L' => '1',
+                'O' => '0',
+                other => other,
+            };
+            let value = CODE_ALPHABET.iter().position(|&c| c as char == normalized)?;
+            payload = (payload << 5) | value as u64;
+            length += 1;
+        }
+
+        if length != CODE_LENGTH {
+            return None;
+        }
+
+        let seed = (payload >> 8) as u32;
+        let difficulty_index = ((payload >> 6) & 0x3) as usize;
+        let check = (payload & 0x3F) as u8;
+
+        if check != checksum(seed, difficulty_index) {
+            return None;
+        }
+
+        Difficulty::from_index(difficulty_index).map(|difficulty| Self::new(seed, difficulty))
+    }
+}
+
+fn checksum(seed: u32, difficulty_index: usize) -> u8 {
+    let mixed = seed.wrapping_mul(0x9E37_79B1) ^ (difficulty_index as u32).wrapping_mul(0x85EB_CA6B);
+    ((mixed >> 26) ^ (mixed & 0x3F)) as u8 & 0x3F
+}
+
+// Everything the challenge screen needs between visits
+#[derive(Debug, Clone)]
+pub struct ChallengeMenu {
+    pub draft: Challenge,
+    pub code_input: String,
+    pub error: Option<String>,
+    pub board: Vec<HighScore>,
+    pub board_code: Option<String>,
+    pub board_loading: bool,
+}
+
+impl ChallengeMenu {
+    pub fn new() -> Self {
+        Self {
+            draft: Challenge::random(Difficulty::Normal),
+            code_input: String::new(),
+            error: None,
+            board: Vec::new(),
+            board_code: None,
+            board_loading: false,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// Time for animations, as opposed to the wall clock. It only moves while the
+// screen it's drawn on is running, and at that screen's pace: a pause stops
+// it dead and slow motion slows it down, so walk cycles and blinks stay in
+// step with everything else.
+#[derive(Debug, Clone, Default)]
+pub struct GameClock {
+    time: f64,
+    delta: f32, // How far the last tick moved it
+}
+
+impl GameClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tick(&mut self, dt: f32, time_scale: f32) {
+        self.delta = dt * time_scale;
+        self.time += self.delta as f64;
+    }
+
+    // Seconds of animation time since the game started
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+}
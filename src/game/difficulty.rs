@@ -0,0 +1,65 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.85,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.2,
+        }
+    }
+
+    // Multiplies the time between spawns, so lower is denser
+    pub fn spawn_interval_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.2,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.8,
+        }
+    }
+
+    // Shifts the base chance (good_item_probability from config unless tuned)
+    pub fn good_item_probability(&self, base: f32) -> f32 {
+        let chance = match self {
+            Difficulty::Easy => base + 0.1,
+            Difficulty::Normal => base,
+            Difficulty::Hard => base - 0.1,
+        };
+        chance.clamp(0.0, 1.0)
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            Difficulty::Easy => 0,
+            Difficulty::Normal => 1,
+            Difficulty::Hard => 2,
+        }
+    }
+
+    pub fn from_index(index: usize) -> Option<Difficulty> {
+        Self::ALL.get(index).copied()
+    }
+
+    pub fn next(&self) -> Difficulty {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(&self) -> Difficulty {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
@@ -0,0 +1,115 @@
+use super::events::GameEvent;
+use super::state::Game;
+use super::stats::DeathCause;
+use crate::config::*;
+use crate::design::{DesignContext, Layout};
+use crate::entities::{Feature, Item, Particle, Yeti};
+use crate::tween::{Ease, Timeline, Tween};
+use macroquad::math::Rect;
+
+const COLLECT_SPARKLE_COUNT: usize = 8;
+const POPUP_SECONDS: f32 = 0.8;
+const POPUP_RISE: f32 = 20.0;
+
+// "+25" rising off a collected item, fading out over the second half
+#[derive(Debug, Clone)]
+pub struct ScorePopup {
+    pub text: String,
+    pub x: f32,
+    pub y: f32,
+    rise: Tween,
+    fade: Timeline,
+}
+
+impl ScorePopup {
+    fn new(points: u32, x: f32, y: f32) -> Self {
+        let half = POPUP_SECONDS / 2.0;
+        Self {
+            text: format!("+{}", points),
+            x,
+            y,
+            rise: Tween::new(0.0, -POPUP_RISE, POPUP_SECONDS),
+            fade: Timeline::new()
+                .then(Tween::hold(1.0, half))
+                .then(Tween::new(1.0, 0.0, half).ease(Ease::InCubic)),
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.rise.update(dt);
+        self.fade.update(dt);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.fade.is_finished()
+    }
+
+    pub fn rise(&self) -> f32 {
+        self.rise.value()
+    }
+
+    pub fn alpha(&self) -> f32 {
+        self.fade.value()
+    }
+}
+
+// Where the run ended, for the freeze-frame behind the game-over screen.
+// The item is kept because the collision took it off the playfield.
+#[derive(Debug, Clone)]
+pub struct FatalHit {
+    pub cause: DeathCause,
+    pub bounds: Rect, // As drawn
+    pub item: Option<Item>,
+}
+
+impl FatalHit {
+    pub fn item(item: &Item) -> Self {
+        let body = item.body;
+        Self {
+            cause: DeathCause::Item(item.item_type),
+            bounds: Rect::new(body.x, body.y - body.height, body.width, body.height),
+            item: Some(item.clone()),
+        }
+    }
+
+    // The yeti is what's shown for terrain, as there's nothing else to point at
+    pub fn terrain(feature: Feature, yeti: &Yeti) -> Self {
+        Self {
+            cause: DeathCause::Terrain(feature),
+            bounds: Rect::new(yeti.x, yeti.y - yeti.height, yeti.width, yeti.height),
+            item: None,
+        }
+    }
+}
+
+// Spawns particles in response to simulation events
+pub fn handle_event(game: &mut Game, event: &GameEvent) {
+    if DesignContext::reduced_motion() {
+        return;
+    }
+
+    match event {
+        GameEvent::ItemCollected { x, y, points, .. } => {
+            let color = DesignContext::palette().success();
+            for _ in 0..COLLECT_SPARKLE_COUNT {
+                game.particles.push(Particle::sparkle(*x, *y, color));
+            }
+            if *points > 0 {
+                game.score_popups.push(ScorePopup::new(*points, *x, *y));
+            }
+        }
+        GameEvent::LevelUp { .. } if game.versus.is_none() => spawn_confetti(game),
+        GameEvent::HighScore { .. } => spawn_confetti(game),
+        _ => {}
+    }
+}
+
+fn spawn_confetti(game: &mut Game) {
+    game.particles.clear();
+    for i in 0..CONFETTI_PARTICLE_COUNT {
+        // Alternate between two bursts on either side of the celebration
+        let across = if i % 2 == 0 { 0.25 } else { 0.75 };
+        let burst = Layout::percent(across, 0.6);
+        game.particles.push(Particle::confetti(burst.x, burst.y));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::entities::{Feature, ItemType};
+
+// Things that happened in the simulation this frame. Gameplay code only emits
+// these; presentation systems (feedback, effects, ...) react to them afterwards.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    ItemCollected {
+        item_type: ItemType,
+        player: usize,
+        x: f32,
+        y: f32,
+        points: u32,
+    },
+    ItemDodged {
+        item_type: ItemType,
+        player: usize,
+        points: u32,
+    },
+    ItemMissed {
+        item_type: ItemType,
+        player: usize,
+    },
+    NearMiss {
+        item_type: ItemType,
+        player: usize,
+    },
+    LevelUp {
+        level: u32,
+    },
+    Crashed {
+        item_type: ItemType,
+        player: usize,
+    },
+    HitTerrain {
+        feature: Feature,
+        player: usize,
+    },
+    HighScore {
+        score: u32,
+    },
+    NextItemAnnounced {
+        item_type: ItemType,
+    },
+    Jumped {
+        player: usize,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct EventBus {
+    pending: Vec<GameEvent>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn emit(&mut self, event: GameEvent) {
+        self.pending.push(event);
+    }
+
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.pending)
+    }
+
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
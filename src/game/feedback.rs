@@ -0,0 +1,253 @@
+use super::events::GameEvent;
+use super::state::Game;
+use crate::colors::TEXT_PRIMARY;
+use crate::design::DesignContext;
+use crate::entities::{Feature, ItemType};
+use macroquad::color::Color;
+use std::collections::VecDeque;
+
+const HISTORY_LENGTH: usize = 20;
+const EVENT_DISPLAY_TIME: f32 = 2.0;
+pub const CRASH_DISPLAY_TIME: f32 = 4.0;
+
+// What kind of news a message is; the HUD picks its accent color from this
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn color(self) -> Color {
+        let palette = DesignContext::palette();
+        match self {
+            Severity::Info => TEXT_PRIMARY,
+            Severity::Success => palette.success(),
+            Severity::Warning => palette.warning(),
+            Severity::Error => palette.error(),
+        }
+    }
+}
+
+// Which message wins the main feedback box. Each priority holds at most one
+// message at a time; a new one replaces the old in its slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Event,     // Dodges, versus level-ups
+    Collision, // Something the yeti ran into
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feedback {
+    pub text: String,
+    pub severity: Severity,
+    pub priority: Priority,
+    pub source_item: Option<ItemType>, // The item the message is about, if any
+    pub ttl: f32,                      // Seconds left on screen
+}
+
+impl Feedback {
+    pub fn new(text: impl Into<String>, severity: Severity, priority: Priority, ttl: f32) -> Self {
+        Self {
+            text: text.into(),
+            severity,
+            priority,
+            source_item: None,
+            ttl,
+        }
+    }
+
+    pub fn about(mut self, item_type: ItemType) -> Self {
+        self.source_item = Some(item_type);
+        self
+    }
+}
+
+// Everything currently in the feedback panel, highest priority first
+#[derive(Debug, Clone, Default)]
+pub struct FeedbackQueue {
+    messages: Vec<Feedback>,
+}
+
+impl FeedbackQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, feedback: Feedback) {
+        self.clear(feedback.priority);
+        let index = self
+            .messages
+            .iter()
+            .position(|other| other.priority < feedback.priority)
+            .unwrap_or(self.messages.len());
+        self.messages.insert(index, feedback);
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        for message in &mut self.messages {
+            message.ttl -= dt;
+        }
+        self.messages.retain(|message| message.ttl > 0.0);
+    }
+
+    pub fn clear(&mut self, priority: Priority) {
+        self.messages.retain(|message| message.priority != priority);
+    }
+
+    pub fn clear_all(&mut self) {
+        self.messages.clear();
+    }
+
+    pub fn messages(&self) -> impl Iterator<Item = &Feedback> {
+        self.messages.iter()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedbackLine {
+    pub text: String,
+    pub severity: Severity,
+    pub time: f32, // Seconds into the run
+}
+
+// The last few feedback lines, for reading back from the pause menu what
+// flashed past at speed. Oldest lines fall off the front.
+#[derive(Debug, Clone, Default)]
+pub struct FeedbackHistory {
+    lines: VecDeque<FeedbackLine>,
+}
+
+impl FeedbackHistory {
+    pub fn push(&mut self, line: FeedbackLine) {
+        if self.lines.len() == HISTORY_LENGTH {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+
+    // Newest first
+    pub fn lines(&self) -> impl Iterator<Item = &FeedbackLine> {
+        self.lines.iter().rev()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+// The feedback panel and the history behind it. The HUD and the history
+// screen draw from this alone.
+#[derive(Debug, Clone, Default)]
+pub struct FeedbackState {
+    pub queue: FeedbackQueue,
+    pub history: FeedbackHistory,
+    pub history_scroll: usize, // Lines scrolled past on the history screen
+}
+
+impl FeedbackState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Put a message in the panel and keep it for the history screen, stamped
+    // with the seconds into the run
+    pub fn show(&mut self, feedback: Feedback, time: f32) {
+        self.history.push(FeedbackLine {
+            text: feedback.text.clone(),
+            severity: feedback.severity,
+            time,
+        });
+        self.queue.push(feedback);
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.queue.update(dt);
+    }
+
+    pub fn clear_all(&mut self) {
+        self.queue.clear_all();
+    }
+}
+
+// Turns simulation events into feedback panel messages
+pub fn handle_event(game: &mut Game, event: &GameEvent) {
+    match event {
+        GameEvent::ItemDodged { item_type, .. } => {
+            let feedback = Feedback::new(
+                "Nice dodge! Avoided a problem!",
+                Severity::Success,
+                Priority::Event,
+                EVENT_DISPLAY_TIME,
+            );
+            game.show_feedback(feedback.about(*item_type));
+        }
+        GameEvent::LevelUp { level } => {
+            // Versus matches don't stop for the level-complete screen
+            if game.versus.is_some() {
+                game.show_feedback(Feedback::new(
+                    format!("Level {}! Pipeline speeding up...", level),
+                    Severity::Info,
+                    Priority::Event,
+                    EVENT_DISPLAY_TIME,
+                ));
+            }
+        }
+        GameEvent::Crashed { item_type, .. } => {
+            let name = &game.catalog.get(*item_type).name;
+            let feedback = Feedback::new(
+                format!("{} broke the build!", name),
+                Severity::Error,
+                Priority::Collision,
+                CRASH_DISPLAY_TIME,
+            );
+            game.feedback.clear_all();
+            game.show_feedback(feedback.about(*item_type));
+        }
+        GameEvent::HitTerrain { feature, .. } => {
+            let message = match feature {
+                Feature::Gap => "Fell through a gap in the pipeline!",
+                Feature::Platform { .. } => "Tripped on a raised section!",
+            };
+            game.feedback.clear_all();
+            game.show_feedback(Feedback::new(
+                message.to_string(),
+                Severity::Error,
+                Priority::Collision,
+                CRASH_DISPLAY_TIME,
+            ));
+        }
+        GameEvent::ItemCollected {
+            item_type, points, ..
+        } => {
+            let name = &game.catalog.get(*item_type).name;
+            let feedback = Feedback::new(
+                format!("{}! +{}", name, points),
+                Severity::Success,
+                Priority::Collision,
+                EVENT_DISPLAY_TIME,
+            );
+            game.show_feedback(feedback.about(*item_type));
+        }
+        GameEvent::ItemMissed { item_type, .. } => {
+            let name = &game.catalog.get(*item_type).name;
+            let feedback = Feedback::new(
+                format!("Missed {}", name),
+                Severity::Warning,
+                Priority::Event,
+                EVENT_DISPLAY_TIME,
+            );
+            game.show_feedback(feedback.about(*item_type));
+        }
+        GameEvent::HighScore { .. }
+        | GameEvent::NextItemAnnounced { .. }
+        | GameEvent::NearMiss { .. }
+        | GameEvent::Jumped { .. } => {}
+    }
+}
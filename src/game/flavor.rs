@@ -0,0 +1,108 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::Deserialize;
+
+// Lines that only make sense at some hours. Night runs past midnight to
+// 5am, so a late session still gets asked why it's up.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Greetings {
+    #[serde(default)]
+    pub night: Vec<String>,
+    #[serde(default)]
+    pub morning: Vec<String>,
+    #[serde(default)]
+    pub afternoon: Vec<String>,
+    #[serde(default)]
+    pub evening: Vec<String>,
+}
+
+impl Greetings {
+    fn for_hour(&self, hour: u32) -> &[String] {
+        match hour {
+            5..=11 => &self.morning,
+            12..=16 => &self.afternoon,
+            17..=21 => &self.evening,
+            _ => &self.night,
+        }
+    }
+
+    fn extend(&mut self, other: Greetings) {
+        self.night.extend(other.night);
+        self.morning.extend(other.morning);
+        self.afternoon.extend(other.afternoon);
+        self.evening.extend(other.evening);
+    }
+}
+
+// The shape of flavor.json. Mod packs ship the same thing under "flavor",
+// and so can anything else with lines to add, like a translation.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FlavorLines {
+    #[serde(default)]
+    pub taglines: Vec<String>,
+    #[serde(default)]
+    pub greetings: Greetings,
+}
+
+impl FlavorLines {
+    pub fn from_json(data: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(data)
+    }
+
+    pub fn line_count(&self) -> usize {
+        let greetings = &self.greetings;
+        self.taglines.len()
+            + greetings.night.len()
+            + greetings.morning.len()
+            + greetings.afternoon.len()
+            + greetings.evening.len()
+    }
+}
+
+// The line under the main menu's title. It has its own rng, like the menu
+// vignette, so a seeded run never notices the menu was visited.
+#[derive(Debug, Clone)]
+pub struct FlavorText {
+    lines: FlavorLines,
+    current: String,
+    rng: StdRng,
+}
+
+impl FlavorText {
+    pub fn new(lines: FlavorLines) -> Self {
+        Self {
+            lines,
+            current: String::new(),
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    pub fn extend(&mut self, lines: FlavorLines) {
+        self.lines.taglines.extend(lines.taglines);
+        self.lines.greetings.extend(lines.greetings);
+    }
+
+    // A new line for this visit to the menu, never the one just shown. The
+    // first visit of a session greets if there's a greeting for the hour;
+    // after that greetings and taglines come up alike.
+    pub fn next_line(&mut self, hour: u32) {
+        let greetings = self.lines.greetings.for_hour(hour);
+        let pool: Vec<&String> = if self.current.is_empty() && !greetings.is_empty() {
+            greetings.iter().collect()
+        } else {
+            greetings
+                .iter()
+                .chain(&self.lines.taglines)
+                .filter(|line| **line != self.current)
+                .collect()
+        };
+        if let Some(line) = pool.choose(&mut self.rng) {
+            self.current = (*line).clone();
+        }
+    }
+
+    pub fn current(&self) -> &str {
+        &self.current
+    }
+}
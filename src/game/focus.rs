@@ -0,0 +1,60 @@
+// World speed while focusing: items, spawning and the track run at this
+// fraction of normal while the yeti still moves at full speed
+pub const FOCUS_TIME_SCALE: f32 = 0.4;
+const FOCUS_SECONDS: f32 = 2.0; // A full meter
+const RECHARGE_SECONDS: f32 = 6.0; // Empty to full
+
+// Hold-to-use bullet time. Draining the meter ends it, and it won't come
+// back on until the key is let go, so holding through an empty meter doesn't
+// stutter in and out as it recharges.
+#[derive(Debug, Clone)]
+pub struct Focus {
+    meter: f32, // Seconds of focus left
+    active: bool,
+    needs_release: bool,
+}
+
+impl Focus {
+    pub fn new() -> Self {
+        Self {
+            meter: FOCUS_SECONDS,
+            active: false,
+            needs_release: false,
+        }
+    }
+
+    // `dt` is unscaled, so the meter drains in real seconds
+    pub fn update(&mut self, held: bool, dt: f32) {
+        if !held {
+            self.needs_release = false;
+        }
+
+        self.active = held && !self.needs_release && self.meter > 0.0;
+        if self.active {
+            self.meter -= dt;
+            if self.meter <= 0.0 {
+                self.meter = 0.0;
+                self.needs_release = true;
+            }
+        } else {
+            self.meter = (self.meter + dt * FOCUS_SECONDS / RECHARGE_SECONDS).min(FOCUS_SECONDS);
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        if self.active {
+            FOCUS_TIME_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    // 0.0 (empty) to 1.0 (full), for the HUD meter
+    pub fn fraction(&self) -> f32 {
+        self.meter / FOCUS_SECONDS
+    }
+}
@@ -0,0 +1,199 @@
+use crate::api::CommunityStats;
+use crate::config::RANK_TOAST_TIME;
+use crate::highscores::{Category, HighScore, Leaderboard, RankChange};
+use std::collections::HashMap;
+
+const MINI_SCROLL_SPEED: f32 = 15.0; // Pixels a second on the main menu
+const MINI_ROW_HEIGHT: f32 = 20.0;
+const MINI_VISIBLE_ROWS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GhostStatus {
+    Idle,
+    Loading,
+    Unavailable,
+}
+
+// Where a report on someone else's leaderboard name got to, this session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportStatus {
+    Sending,
+    Sent,
+    Failed,
+}
+
+// The board and everything about browsing it: where the list is scrolled
+// to, the entry picked, and what the server has said lately. The leaderboard
+// screens and the menu's mini board draw from this alone.
+#[derive(Debug, Clone)]
+pub struct LeaderboardState {
+    pub board: Leaderboard, // The standard board, which everything else ranks against
+    pub hardcore_board: Leaderboard, // Only the leaderboard screens show it
+    pub category: Category, // The leaderboard screens' tab
+    pub scroll: f32,
+    pub cursor: usize,
+    pub mini_scroll: f32,        // The menu's auto-scrolling board
+    pub mini_hovered: bool,      // The mouse is over it, which opens it up
+    pub mini_focused: bool,      // Opened up from the keyboard
+    pub open_at: Option<usize>,  // Entry the leaderboard screen starts on
+    pub report_confirming: bool, // The detail panel is asking "report this name?"
+    pub reports: HashMap<String, ReportStatus>, // By entry id
+    pub ghost_status: GhostStatus,
+    pub community_stats: Option<CommunityStats>, // None until the first successful fetch
+    pub rank_toast: Option<(RankChange, f32)>,   // Latest rank change, and time left on show
+    pub export_requested: bool,                  // Picked up by the capture system after drawing
+}
+
+impl LeaderboardState {
+    pub fn new() -> Self {
+        Self {
+            board: Leaderboard::new(),
+            hardcore_board: Leaderboard::new(),
+            category: Category::Standard,
+            scroll: 0.0,
+            cursor: 0,
+            mini_scroll: 0.0,
+            mini_hovered: false,
+            mini_focused: false,
+            open_at: None,
+            report_confirming: false,
+            reports: HashMap::new(),
+            ghost_status: GhostStatus::Idle,
+            community_stats: None,
+            rank_toast: None,
+            export_requested: false,
+        }
+    }
+
+    pub fn board_for(&self, category: Category) -> &Leaderboard {
+        match category {
+            Category::Standard => &self.board,
+            Category::Hardcore => &self.hardcore_board,
+        }
+    }
+
+    pub fn board_for_mut(&mut self, category: Category) -> &mut Leaderboard {
+        match category {
+            Category::Standard => &mut self.board,
+            Category::Hardcore => &mut self.hardcore_board,
+        }
+    }
+
+    // The board on the current tab
+    pub fn shown(&self) -> &Leaderboard {
+        self.board_for(self.category)
+    }
+
+    // A new tab starts from the top of its board
+    pub fn show_category(&mut self, category: Category) {
+        self.category = category;
+        self.scroll = 0.0;
+        self.cursor = 0;
+        self.report_confirming = false;
+    }
+
+    // The entry under the cursor
+    pub fn selected(&self) -> Option<&HighScore> {
+        self.shown().scores.get(self.cursor)
+    }
+
+    pub fn report_status(&self, entry: &HighScore) -> Option<ReportStatus> {
+        entry
+            .id
+            .as_ref()
+            .and_then(|id| self.reports.get(id))
+            .copied()
+    }
+
+    pub fn show_rank_change(&mut self, change: RankChange) {
+        self.rank_toast = Some((change, RANK_TOAST_TIME));
+    }
+
+    pub fn update_rank_toast(&mut self, dt: f32) {
+        if let Some((_, time)) = &mut self.rank_toast {
+            *time -= dt;
+            if *time <= 0.0 {
+                self.rank_toast = None;
+            }
+        }
+    }
+
+    // The menu's board shows its top ten in a frame instead of scrolling
+    pub fn mini_expanded(&self) -> bool {
+        self.mini_hovered || self.mini_focused
+    }
+
+    // A slow scroll through the entries past the first few, starting over
+    // once it has gone through them all. It holds still while opened up.
+    pub fn scroll_mini_board(&mut self, dt: f32) {
+        let extra = self.board.scores.len().saturating_sub(MINI_VISIBLE_ROWS);
+        if extra == 0 || self.mini_expanded() {
+            return;
+        }
+        self.mini_scroll += MINI_SCROLL_SPEED * dt;
+        let max_scroll = extra as f32 * MINI_ROW_HEIGHT;
+        if self.mini_scroll > max_scroll + 60.0 {
+            self.mini_scroll = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_boards() -> LeaderboardState {
+        let mut state = LeaderboardState::new();
+        for (name, score) in [("ALICE", 900), ("BOB", 1500), ("CAROL", 1200)] {
+            state
+                .board
+                .add_score(HighScore::new(name.to_string(), score, 3));
+        }
+        let hardcore = HighScore::new("DAVE".to_string(), 400, 2).with_category(Category::Hardcore);
+        state.hardcore_board.add_score(hardcore);
+        state
+    }
+
+    #[test]
+    fn the_cursor_selects_from_the_shown_board() {
+        let mut state = state_with_boards();
+        state.cursor = 1;
+        assert_eq!(
+            state.selected().map(|entry| entry.name.as_str()),
+            Some("CAROL")
+        );
+
+        state.cursor = 5;
+        assert!(state.selected().is_none());
+    }
+
+    #[test]
+    fn switching_tabs_starts_from_the_top() {
+        let mut state = state_with_boards();
+        state.cursor = 2;
+        state.scroll = 50.0;
+        state.report_confirming = true;
+
+        state.show_category(Category::Hardcore);
+        assert_eq!(state.cursor, 0);
+        assert_eq!(state.scroll, 0.0);
+        assert!(!state.report_confirming);
+        assert_eq!(
+            state.selected().map(|entry| entry.name.as_str()),
+            Some("DAVE")
+        );
+    }
+
+    #[test]
+    fn reports_are_looked_up_by_entry_id() {
+        let mut state = LeaderboardState::new();
+        let mut entry = HighScore::new("EVE".to_string(), 700, 2);
+        assert_eq!(state.report_status(&entry), None);
+
+        entry.id = Some("score-1".to_string());
+        state
+            .reports
+            .insert("score-1".to_string(), ReportStatus::Sent);
+        assert_eq!(state.report_status(&entry), Some(ReportStatus::Sent));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::overrides::config;
+use serde::Deserialize;
+
+// levels.json only lists what a level changes. Numbers (checks, spawn rate,
+// speed) apply to that exact level and otherwise follow the usual scaling.
+// Theme fields (world, background, item pool, flavor text) carry forward
+// until a later entry replaces them, so one entry can start a whole world.
+// An empty item pool means every item in the catalog.
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackgroundLayer {
+    pub texture: String,
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct LevelEntry {
+    level: u32,
+    world: Option<String>,
+    checks_required: Option<u32>,
+    spawn_rate: Option<f32>,
+    item_speed: Option<f32>,
+    background_layers: Option<Vec<BackgroundLayer>>,
+    item_pool: Option<Vec<String>>,
+    complete_message: Option<String>,
+    complete_submessage: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LevelsFile {
+    levels: Vec<LevelEntry>,
+}
+
+// Everything the game needs to run one level, with gaps already filled in
+#[derive(Debug, Clone)]
+pub struct LevelDefinition {
+    pub level: u32,
+    pub world: String,
+    pub checks_required: u32,
+    pub spawn_rate: f32, // Seconds between spawns, before difficulty
+    pub item_speed: f32, // Before difficulty
+    pub background_layers: Vec<String>, // Texture names, drawn back to front
+    pub item_pool: Vec<String>,
+    pub complete_message: String,
+    pub complete_submessage: String,
+}
+
+impl LevelDefinition {
+    fn scaled(level: u32) -> Self {
+        let config = config();
+        let spawn_rate = if level <= 1 {
+            config.initial_spawn_rate
+        } else {
+            (config.initial_spawn_rate - (level as f32 * 0.1)).max(config.min_spawn_rate)
+        };
+
+        Self {
+            level,
+            world: String::new(),
+            checks_required: 5 + (level.max(1) - 1) * 3,
+            spawn_rate,
+            item_speed: config.base_item_speed + (level as f32 * config.speed_increase_per_level),
+            background_layers: vec!["background".to_string()],
+            item_pool: Vec::new(),
+            complete_message: "Issue #{level} Done!".to_string(),
+            complete_submessage: "What else is assigned to me...".to_string(),
+        }
+    }
+
+    pub fn allows_item(&self, id: &str) -> bool {
+        self.item_pool.is_empty() || self.item_pool.iter().any(|pooled| pooled == id)
+    }
+
+    pub fn complete_message(&self) -> String {
+        self.complete_message.replace("{level}", &self.level.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LevelCatalog {
+    entries: Vec<LevelEntry>,
+}
+
+impl LevelCatalog {
+    pub fn from_json(data: &str) -> Result<Self, serde_json::Error> {
+        let file: LevelsFile = serde_json::from_str(data)?;
+        let mut entries = file.levels;
+        entries.sort_by_key(|entry| entry.level);
+        Ok(Self { entries })
+    }
+
+    pub fn definition(&self, level: u32) -> LevelDefinition {
+        let mut definition = LevelDefinition::scaled(level);
+
+        for entry in self.entries.iter().take_while(|entry| entry.level <= level) {
+            if let Some(world) = &entry.world {
+                definition.world = world.clone();
+            }
+            if let Some(layers) = &entry.background_layers {
+                definition.background_layers =
+                    layers.iter().map(|layer| layer.texture.clone()).collect();
+            }
+            if let Some(pool) = &entry.item_pool {
+                definition.item_pool = pool.clone();
+            }
+            if let Some(message) = &entry.complete_message {
+                definition.complete_message = message.clone();
+            }
+            if let Some(submessage) = &entry.complete_submessage {
+                definition.complete_submessage = submessage.clone();
+            }
+
+            if entry.level == level {
+                if let Some(checks) = entry.checks_required {
+                    definition.checks_required = checks.max(1);
+                }
+                if let Some(rate) = entry.spawn_rate {
+                    definition.spawn_rate = rate.max(config().min_spawn_rate);
+                }
+                if let Some(speed) = entry.item_speed {
+                    definition.item_speed = speed;
+                }
+            }
+        }
+
+        definition
+    }
+
+    pub fn background_layers(&self) -> impl Iterator<Item = &BackgroundLayer> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.background_layers.as_ref())
+            .flatten()
+    }
+}
@@ -0,0 +1,119 @@
+use super::systems;
+use crate::atlas::SpriteAtlas;
+use crate::config::*;
+use crate::entities::{Item, ItemCatalog, Terrain, Yeti};
+use crate::overrides::config;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Half size, so the whole scene fits in the strip under the menu text
+const SCALE: f32 = 0.5;
+pub const VIGNETTE_GROUND_Y: f32 = SCREEN_HEIGHT - 3.0;
+const YETI_X: f32 = 56.0;
+const SPAWN_GAP: (f32, f32) = (1.6, 4.0); // Seconds between items
+const JUMP_DISTANCE: f32 = 14.0; // Gap to the next item when the yeti hops
+const SNOWFLAKES: usize = 36;
+
+#[derive(Debug, Clone)]
+pub struct Snowflake {
+    pub x: f32,
+    pub y: f32,
+    pub size: f32,
+    speed: f32,
+    sway: f32, // Phase, so the flakes don't drift in step
+}
+
+// The yeti running under the main menu. It's a toy run with nothing at
+// stake: items scroll past, the yeti hops every one of them, and snow falls
+// over it all. It has its own rng so it never touches a seeded run's.
+#[derive(Debug, Clone)]
+pub struct MenuVignette {
+    pub yeti: Yeti,
+    pub items: Vec<Item>,
+    pub snow: Vec<Snowflake>,
+    pub scroll: f32, // How far the ground has moved, for its markings
+    time: f32,
+    spawn_timer: f32,
+    rng: StdRng,
+}
+
+impl MenuVignette {
+    pub fn new() -> Self {
+        let mut rng = StdRng::from_entropy();
+        let snow = (0..SNOWFLAKES)
+            .map(|_| Snowflake {
+                x: rng.gen_range(0.0..SCREEN_WIDTH),
+                y: rng.gen_range(0.0..SCREEN_HEIGHT),
+                size: rng.gen_range(0.5..1.5),
+                speed: rng.gen_range(8.0..20.0),
+                sway: rng.gen_range(0.0..std::f32::consts::TAU),
+            })
+            .collect();
+
+        let mut yeti = Yeti::new();
+        yeti.x = YETI_X;
+        yeti.width *= SCALE;
+        yeti.height *= SCALE;
+        yeti.ground_y = VIGNETTE_GROUND_Y;
+        yeti.y = VIGNETTE_GROUND_Y;
+
+        Self {
+            yeti,
+            items: Vec::new(),
+            snow,
+            scroll: 0.0,
+            time: 0.0,
+            spawn_timer: 1.0,
+            rng,
+        }
+    }
+
+    // Back to an empty track, so returning to the menu doesn't catch the
+    // yeti mid-air
+    pub fn reset(&mut self) {
+        self.items.clear();
+        self.yeti.reset();
+        self.yeti.x = YETI_X;
+        self.spawn_timer = 1.0;
+    }
+
+    pub fn update(&mut self, dt: f32, catalog: &ItemCatalog, sprites: &SpriteAtlas) {
+        self.time += dt;
+        let speed = config().base_item_speed * SCALE;
+        self.scroll += speed * dt;
+
+        self.spawn_timer -= dt;
+        if self.spawn_timer <= 0.0 {
+            self.spawn_timer = self.rng.gen_range(SPAWN_GAP.0..SPAWN_GAP.1);
+            let good = self.rng.gen::<f32>() < config().good_item_probability;
+            let item_type = catalog.random(&mut self.rng, good, |_| true);
+            let mut item = Item::new(item_type, catalog, sprites).in_lane(0, VIGNETTE_GROUND_Y);
+            item.body.width *= SCALE;
+            item.body.height *= SCALE;
+            self.items.push(item);
+        }
+
+        systems::scroll(self.items.iter_mut().map(|item| &mut item.body), dt, speed);
+        self.items.retain(|item| !item.body.is_off_screen());
+
+        let yeti_right = self.yeti.x + self.yeti.width;
+        if self.items.iter().any(|item| {
+            let gap = item.body.x - yeti_right;
+            (0.0..JUMP_DISTANCE).contains(&gap)
+        }) {
+            self.yeti.jump(config().jump_velocity * SCALE);
+        }
+        self.yeti
+            .update(dt, config().gravity * SCALE, &Terrain::new());
+        self.yeti.update_sprite(sprites, self.time as f64);
+
+        for flake in &mut self.snow {
+            flake.y += flake.speed * dt;
+            flake.x += (self.time + flake.sway).sin() * 6.0 * dt;
+            if flake.y > SCREEN_HEIGHT {
+                flake.y -= SCREEN_HEIGHT;
+                flake.x = self.rng.gen_range(0.0..SCREEN_WIDTH);
+            }
+        }
+    }
+}
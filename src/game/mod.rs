@@ -1,6 +1,47 @@
-pub mod state;
+pub(crate) mod state;
+pub(crate) mod adaptive;
+pub(crate) mod api_state;
+pub(crate) mod autopilot;
+pub(crate) mod benchmark;
+pub(crate) mod build_log;
+pub(crate) mod calibration;
+pub(crate) mod challenge;
+pub(crate) mod clock;
+pub(crate) mod difficulty;
+pub(crate) mod effects;
+pub(crate) mod feedback;
+pub(crate) mod flavor;
+pub(crate) mod focus;
+pub(crate) mod leaderboard;
+pub(crate) mod levels;
+pub(crate) mod menu_vignette;
+pub(crate) mod modifier;
+pub(crate) mod objectives;
+pub(crate) mod photo;
+pub(crate) mod practice;
+pub(crate) mod quips;
+pub(crate) mod repo_feed;
+pub(crate) mod rumble;
+pub(crate) mod scene;
+pub(crate) mod snapshot;
+pub(crate) mod systems;
+pub(crate) mod stats;
+pub(crate) mod scoring;
+pub(crate) mod tuning;
+pub(crate) mod versus;
+
+// Simulation pieces with no rendering in them, public for headless use
+pub mod events;
+pub mod mutators;
 pub mod physics;
+pub mod run;
 pub mod spawning;
-pub mod scoring;
+pub mod speed_events;
+pub mod speedrun;
+
+// Run by the windowed game on request: --admin and --replay
+pub mod admin;
+pub mod replay;
 
-pub use state::{Game, GameState};
\ No newline at end of file
+pub(crate) use leaderboard::{GhostStatus, ReportStatus};
+pub use state::{Game, GameState};
@@ -0,0 +1,46 @@
+// "Push to prod without review?" Taking the gamble on the level-complete
+// screen scores the next level at 150%, but a single missed good item fails
+// the build.
+const UNREVIEWED_SCORE_MULTIPLIER: f32 = 1.5;
+
+// Rules for the current level only. Every level starts standard; the
+// level-complete screen can swap in the unreviewed rules for the next one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelModifier {
+    pub score_multiplier: f32,
+    pub misses_allowed: Option<u32>, // Good items that may slip past; None for no limit
+    pub misses: u32,
+}
+
+impl LevelModifier {
+    pub fn standard() -> Self {
+        Self {
+            score_multiplier: 1.0,
+            misses_allowed: None,
+            misses: 0,
+        }
+    }
+
+    pub fn unreviewed() -> Self {
+        Self {
+            score_multiplier: UNREVIEWED_SCORE_MULTIPLIER,
+            misses_allowed: Some(0),
+            misses: 0,
+        }
+    }
+
+    pub fn is_standard(&self) -> bool {
+        self.score_multiplier == 1.0 && self.misses_allowed.is_none()
+    }
+
+    pub fn apply(&self, points: u32) -> u32 {
+        (points as f32 * self.score_multiplier).round() as u32
+    }
+
+    // Count a missed good item; true once that's more than the level allows
+    pub fn record_miss(&mut self) -> bool {
+        self.misses += 1;
+        self.misses_allowed
+            .is_some_and(|allowed| self.misses > allowed)
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+// Optional rule changes picked before a run. Each one scales the points the
+// run earns, so harder mutators pay more and easier ones pay less.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mutator {
+    DoubleSpeed,
+    NoGoodItems,
+    LowGravity,
+    Mirror,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 4] = [
+        Mutator::DoubleSpeed,
+        Mutator::NoGoodItems,
+        Mutator::LowGravity,
+        Mutator::Mirror,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "Double speed",
+            Mutator::NoGoodItems => "No good items",
+            Mutator::LowGravity => "Low gravity",
+            Mutator::Mirror => "Mirror mode",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "The pipeline runs twice as fast",
+            Mutator::NoGoodItems => "Nothing to collect, only things to dodge",
+            Mutator::LowGravity => "Floaty jumps that hang in the air",
+            Mutator::Mirror => "Items come in from the left",
+        }
+    }
+
+    // Short enough for a leaderboard row
+    pub fn badge(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "2X",
+            Mutator::NoGoodItems => "NG",
+            Mutator::LowGravity => "LG",
+            Mutator::Mirror => "MIR",
+        }
+    }
+
+    pub fn score_multiplier(self) -> f32 {
+        match self {
+            Mutator::DoubleSpeed => 1.5,
+            Mutator::NoGoodItems => 1.25,
+            Mutator::LowGravity => 0.9,
+            Mutator::Mirror => 1.2,
+        }
+    }
+
+    // Profiles earn a mutator by reaching this level; guests get all of them
+    pub fn unlock_level(self) -> u32 {
+        match self {
+            Mutator::LowGravity => 2,
+            Mutator::Mirror => 3,
+            Mutator::NoGoodItems => 4,
+            Mutator::DoubleSpeed => 5,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << Mutator::ALL.iter().position(|&m| m == self).unwrap_or(0)
+    }
+}
+
+// The mutators switched on for a run, packed into bits so replays can carry them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MutatorSet {
+    bits: u8,
+}
+
+impl MutatorSet {
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn from_bits(bits: u8) -> Self {
+        let known = Mutator::ALL.iter().fold(0, |all, m| all | m.bit());
+        Self { bits: bits & known }
+    }
+
+    pub fn bits(self) -> u8 {
+        self.bits
+    }
+
+    pub fn contains(self, mutator: Mutator) -> bool {
+        self.bits & mutator.bit() != 0
+    }
+
+    pub fn toggle(&mut self, mutator: Mutator) {
+        self.bits ^= mutator.bit();
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Mutator> {
+        Mutator::ALL.into_iter().filter(move |&m| self.contains(m))
+    }
+
+    pub fn score_multiplier(self) -> f32 {
+        self.iter().map(Mutator::score_multiplier).product()
+    }
+
+    pub fn apply(self, points: u32) -> u32 {
+        (points as f32 * self.score_multiplier()).round() as u32
+    }
+}
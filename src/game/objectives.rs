@@ -0,0 +1,182 @@
+use super::events::GameEvent;
+use super::versus::PLAYER_ONE;
+
+pub const TIME_LIMIT: f32 = 20.0; // Seconds to finish each objective
+const GAP: f32 = 4.0; // Seconds between one objective ending and the next
+const FIRST_DELAY: f32 = 6.0; // Let the run get going before the first
+const BASE_BONUS: u32 = 50;
+const MAX_CHAIN: u32 = 5; // Completions in a row past this earn no extra
+const COMPLETED_DISPLAY_TIME: f32 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectiveKind {
+    CollectStreak, // Good items collected without letting one slip past
+    Dodge,
+    NoJump, // Seconds on the ground
+    Points,
+}
+
+impl ObjectiveKind {
+    // The order objectives come round in. Fixed rather than random, so the
+    // seeded run rng (and with it every replay) is never touched.
+    const ROTATION: [ObjectiveKind; 4] = [
+        ObjectiveKind::CollectStreak,
+        ObjectiveKind::NoJump,
+        ObjectiveKind::Dodge,
+        ObjectiveKind::Points,
+    ];
+
+    // Asks grow a little with each level
+    fn target(self, level: u32) -> u32 {
+        let step = level.saturating_sub(1).min(6);
+        match self {
+            ObjectiveKind::CollectStreak => 3 + step / 2,
+            ObjectiveKind::Dodge => 2 + step / 2,
+            ObjectiveKind::NoJump => 5 + step,
+            ObjectiveKind::Points => 60 + step * 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Objective {
+    pub kind: ObjectiveKind,
+    pub target: u32,
+    pub progress: f32,
+    pub time_left: f32,
+}
+
+impl Objective {
+    pub fn description(&self) -> String {
+        match self.kind {
+            ObjectiveKind::CollectStreak => format!("Collect {} in a row", self.target),
+            ObjectiveKind::Dodge => format!("Dodge {} problems", self.target),
+            ObjectiveKind::NoJump => format!("Don't jump for {} seconds", self.target),
+            ObjectiveKind::Points => format!("Score {} points", self.target),
+        }
+    }
+
+    // 0.0 to 1.0, for the HUD bar
+    pub fn fraction(&self) -> f32 {
+        (self.progress / self.target as f32).min(1.0)
+    }
+
+    fn is_complete(&self) -> bool {
+        self.progress >= self.target as f32
+    }
+}
+
+// Optional mini-objectives ("sprints") during a solo run. One is live at a
+// time; finishing it pays a bonus that grows with each one chained without
+// letting a sprint run out. Progress comes from the event stream, plus the
+// clock for the ones measured in seconds.
+#[derive(Debug, Clone)]
+pub struct Objectives {
+    pub current: Option<Objective>,
+    pub chain: u32,                       // Completed in a row
+    pub completed: Option<(String, f32)>, // Last one finished, and time left on show
+    next: usize,
+    delay: f32,
+    reward: u32,
+}
+
+impl Objectives {
+    pub fn new() -> Self {
+        Self {
+            current: None,
+            chain: 0,
+            completed: None,
+            next: 0,
+            delay: FIRST_DELAY,
+            reward: 0,
+        }
+    }
+
+    pub fn handle_event(&mut self, event: &GameEvent) {
+        let Some(objective) = &mut self.current else {
+            return;
+        };
+
+        match (objective.kind, event) {
+            (_, GameEvent::ItemCollected { player, .. })
+            | (_, GameEvent::ItemDodged { player, .. })
+            | (_, GameEvent::ItemMissed { player, .. })
+            | (_, GameEvent::Jumped { player })
+                if *player != PLAYER_ONE => {}
+            (ObjectiveKind::CollectStreak, GameEvent::ItemCollected { .. }) => {
+                objective.progress += 1.0;
+            }
+            (ObjectiveKind::CollectStreak, GameEvent::ItemMissed { .. }) => {
+                objective.progress = 0.0;
+            }
+            (ObjectiveKind::Dodge, GameEvent::ItemDodged { .. }) => objective.progress += 1.0,
+            (ObjectiveKind::NoJump, GameEvent::Jumped { .. }) => objective.progress = 0.0,
+            (ObjectiveKind::Points, GameEvent::ItemCollected { points, .. })
+            | (ObjectiveKind::Points, GameEvent::ItemDodged { points, .. }) => {
+                objective.progress += *points as f32;
+            }
+            _ => {}
+        }
+        self.check_complete();
+    }
+
+    // Called once per simulated frame while the run is live
+    pub fn tick(&mut self, dt: f32, level: u32) {
+        if let Some((_, time)) = &mut self.completed {
+            *time -= dt;
+            if *time <= 0.0 {
+                self.completed = None;
+            }
+        }
+
+        match &mut self.current {
+            Some(objective) => {
+                if objective.kind == ObjectiveKind::NoJump {
+                    objective.progress += dt;
+                }
+                objective.time_left -= dt;
+                self.check_complete();
+                if self.current.as_ref().is_some_and(|o| o.time_left <= 0.0) {
+                    self.current = None;
+                    self.chain = 0;
+                    self.delay = GAP;
+                }
+            }
+            None => {
+                self.delay -= dt;
+                if self.delay <= 0.0 {
+                    self.start_next(level);
+                }
+            }
+        }
+    }
+
+    // Bonus points earned since the last call
+    pub fn take_reward(&mut self) -> u32 {
+        std::mem::take(&mut self.reward)
+    }
+
+    fn start_next(&mut self, level: u32) {
+        let kind = ObjectiveKind::ROTATION[self.next % ObjectiveKind::ROTATION.len()];
+        self.next += 1;
+        self.current = Some(Objective {
+            kind,
+            target: kind.target(level),
+            progress: 0.0,
+            time_left: TIME_LIMIT,
+        });
+    }
+
+    fn check_complete(&mut self) {
+        if !self.current.as_ref().is_some_and(Objective::is_complete) {
+            return;
+        }
+        let Some(objective) = self.current.take() else {
+            return;
+        };
+        self.chain = (self.chain + 1).min(MAX_CHAIN);
+        self.reward += BASE_BONUS * self.chain;
+        self.completed = Some((objective.description(), COMPLETED_DISPLAY_TIME));
+        self.delay = GAP;
+    }
+}
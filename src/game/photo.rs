@@ -0,0 +1,62 @@
+use crate::colors::PhotoFilter;
+use crate::config::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use macroquad::math::{vec2, Mat4, Vec2};
+
+const MAX_ZOOM: f32 = 4.0;
+const PAN_SPEED: f32 = 240.0; // Screen pixels a second, whatever the zoom
+
+// Photo mode's free camera over the paused run, and how the shot is dressed.
+// The run itself is never touched; only the view of it moves.
+#[derive(Debug, Clone)]
+pub struct PhotoMode {
+    pub center: Vec2, // Point of the scene in the middle of the view
+    pub zoom: f32,
+    pub show_hud: bool,
+    pub filter: PhotoFilter,
+    pub drag_from: Option<Vec2>, // Mouse position while the view is dragged
+    pub export_requested: bool,  // Picked up by the capture system after drawing
+}
+
+impl PhotoMode {
+    pub fn new() -> Self {
+        Self {
+            center: vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0),
+            zoom: 1.0,
+            show_hud: true,
+            filter: PhotoFilter::None,
+            drag_from: None,
+            export_requested: false,
+        }
+    }
+
+    pub fn pan(&mut self, direction: Vec2, dt: f32) {
+        self.center += direction * PAN_SPEED * dt / self.zoom;
+        self.keep_in_scene();
+    }
+
+    // Move the scene along with a drag of `delta` screen pixels
+    pub fn drag(&mut self, delta: Vec2) {
+        self.center -= delta / self.zoom;
+        self.keep_in_scene();
+    }
+
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.zoom = (self.zoom * factor).clamp(1.0, MAX_ZOOM);
+        self.keep_in_scene();
+    }
+
+    // Scene to screen: the center lands mid-screen, scaled up by the zoom
+    pub fn view(&self) -> Mat4 {
+        let screen_center = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+        Mat4::from_translation(screen_center.extend(0.0))
+            * Mat4::from_scale(Vec2::splat(self.zoom).extend(1.0))
+            * Mat4::from_translation((-self.center).extend(0.0))
+    }
+
+    // Nothing is drawn past the edges, so the view never shows beyond them
+    fn keep_in_scene(&mut self) {
+        let screen = vec2(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let half_view = screen / (2.0 * self.zoom);
+        self.center = self.center.clamp(half_view, screen - half_view);
+    }
+}
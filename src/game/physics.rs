@@ -1,35 +1,125 @@
+use crate::entities::components::overlaps;
+use crate::game::effects::FatalHit;
+use crate::game::events::GameEvent;
 use crate::game::state::Game;
+use crate::game::versus::PLAYER_ONE;
+use macroquad::math::vec2;
+
+const MAGNET_RADIUS: f32 = 56.0; // Between the yeti's and an item's middles
+const MAGNET_SPEED: f32 = 260.0; // On top of the item's own scrolling
+
+// The item magnet assist, run before the collision check: good items near
+// the yeti drift toward it until they touch and get collected. Only in the
+// yeti's lane, and never for an item that has already gone past.
+pub fn attract_items(game: &mut Game, dt: f32) {
+    if !game.assisted {
+        return;
+    }
+
+    let (x, y, width, height) = game.yeti.get_collision_rect();
+    let target = vec2(x + width / 2.0, y + height / 2.0);
+    let lane = game.yeti.lane;
+    for item in &mut game.items {
+        if !item.is_good || item.was_passed || item.lane != lane {
+            continue;
+        }
+        let (x, y, width, height) = item.get_collision_rect();
+        let offset = target - vec2(x + width / 2.0, y + height / 2.0);
+        let distance = offset.length();
+        if distance == 0.0 || distance > MAGNET_RADIUS {
+            continue;
+        }
+        let pull = offset / distance * (MAGNET_SPEED * dt).min(distance);
+        item.body.x += pull.x;
+        item.body.y += pull.y;
+    }
+}
 
 pub fn check_collisions(game: &mut Game) {
     let mut items_to_remove = Vec::new();
-    let mut should_game_over = false;
-
-    let (yeti_x, yeti_y, yeti_w, yeti_h) = game.yeti.get_collision_rect();
+    let mut collected = Vec::new();
+    let mut crashed = None;
+    let mut grazed = Vec::new();
+    let margin = game.collision_margin();
 
     for (i, item) in game.items.iter().enumerate() {
-        let (item_x, item_y, item_w, item_h) = item.get_collision_rect();
-
-        if yeti_x < item_x + item_w
-            && yeti_x + yeti_w > item_x
-            && yeti_y < item_y + item_h
-            && yeti_y + yeti_h > item_y
-        {
+        // Items only collide with the yeti running in their lane
+        let Some((player, yeti)) = game.player_in_lane(item.lane) else {
+            continue;
+        };
+        if overlaps(yeti.collision_rect(margin), item.collision_rect(margin)) {
             if item.is_good {
-                game.score += 10;
-                game.checks_completed += 1;
-            } else {
-                should_game_over = true;
+                let center = item.body.center();
+                collected.push((item.item_type, player, center.x, center.y));
+            } else if game.invincible {
+                // Dev mode: bad items pass straight through and count as dodged
+                continue;
+            } else if crashed.is_none() {
+                crashed = Some((item.clone(), player));
             }
 
             items_to_remove.push(i);
+        } else if !item.is_good && !item.grazed {
+            // Inside the grace margin but not the collision rect: a close call
+            let yeti_bounds = (yeti.x, yeti.y, yeti.width, yeti.height);
+            if overlaps(yeti_bounds, item.body.bounds()) {
+                grazed.push((i, player));
+            }
         }
     }
 
+    for (i, player) in grazed {
+        game.items[i].grazed = true;
+        game.adaptive.record_close_call();
+        game.events.emit(GameEvent::NearMiss {
+            item_type: game.items[i].item_type,
+            player,
+        });
+    }
+
     for &i in items_to_remove.iter().rev() {
         game.items.remove(i);
     }
 
-    if should_game_over {
-        game.game_over();
+    for (item_type, player, x, y) in collected {
+        let points = game.award_points(player, 10);
+        game.complete_check(player);
+        game.events.emit(GameEvent::ItemCollected {
+            item_type,
+            player,
+            x,
+            y,
+            points,
+        });
+    }
+
+    if let Some((item, player)) = crashed {
+        let event = GameEvent::Crashed {
+            item_type: item.item_type,
+            player,
+        };
+        crash(game, event, player, FatalHit::item(&item));
+    } else if let Some(feature) = game.yeti.hit_terrain(&game.terrain) {
+        if game.invincible {
+            game.yeti.recover();
+        } else {
+            let event = GameEvent::HitTerrain {
+                feature,
+                player: PLAYER_ONE,
+            };
+            let hit = FatalHit::terrain(feature, &game.yeti);
+            crash(game, event, PLAYER_ONE, hit);
+        }
+    }
+}
+
+// Practice runs wind back a few seconds instead of ending
+fn crash(game: &mut Game, event: GameEvent, player: usize, hit: FatalHit) {
+    if game.practice.is_some() {
+        game.rewind_practice();
+    } else {
+        game.events.emit(event);
+        game.fatal_hit = Some(hit);
+        game.start_dying(player);
     }
 }
@@ -0,0 +1,59 @@
+use super::snapshot::GameSnapshot;
+use super::state::Game;
+use std::collections::VecDeque;
+
+pub const MAX_START_LEVEL: u32 = 20;
+pub const REWIND_SECONDS: f32 = 3.0;
+const SNAPSHOT_INTERVAL: f32 = 0.25; // Seconds of play between snapshots
+const KEPT_SNAPSHOTS: usize = 16; // A little over the rewind, at that interval
+
+// A run for rehearsing, started at any level, where a crash winds the clock
+// back a few seconds instead of ending it. Snapshots go by the run's time
+// survived, which rewinds along with everything else.
+#[derive(Debug, Clone, Default)]
+pub struct Practice {
+    snapshots: VecDeque<GameSnapshot>,
+    pub rewinds: u32,
+}
+
+impl Practice {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Called once per simulated frame while the run is live
+    pub fn record(&mut self, game: &Game) {
+        let now = game.stats.time_survived;
+        if self
+            .snapshots
+            .back()
+            .is_some_and(|snapshot| now - snapshot.time_survived() < SNAPSHOT_INTERVAL)
+        {
+            return;
+        }
+        self.snapshots.push_back(game.snapshot());
+        if self.snapshots.len() > KEPT_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+    }
+
+    // Back to the newest snapshot at least REWIND_SECONDS old, or the oldest
+    // there is; anything after it is forgotten. Returns the seconds undone.
+    pub fn rewind(&mut self, game: &mut Game) -> f32 {
+        let now = game.stats.time_survived;
+        let Some(index) = self
+            .snapshots
+            .iter()
+            .rposition(|snapshot| now - snapshot.time_survived() >= REWIND_SECONDS)
+            .or((!self.snapshots.is_empty()).then_some(0))
+        else {
+            return 0.0;
+        };
+        self.snapshots.truncate(index + 1);
+
+        let snapshot = &self.snapshots[index];
+        game.restore(snapshot);
+        self.rewinds += 1;
+        now - snapshot.time_survived()
+    }
+}
@@ -0,0 +1,35 @@
+use super::events::GameEvent;
+use super::state::Game;
+use crate::audio::voice::Register;
+
+// Turns simulation events into things the yeti says out loud. The voice
+// rate-limits itself, so this just says everything worth saying.
+pub fn handle_event(game: &mut Game, event: &GameEvent) {
+    // The attract-mode demo plays with the sound down
+    if game.is_demo {
+        return;
+    }
+
+    match event {
+        GameEvent::NextItemAnnounced { item_type } => {
+            let definition = game.catalog.get(*item_type);
+            let register = if definition.good {
+                Register::High
+            } else {
+                Register::Low
+            };
+            game.voice
+                .quip_item(*item_type, &definition.feedback, register);
+        }
+        GameEvent::ItemDodged { .. } => game.voice.say("Nice dodge!", Register::High),
+        GameEvent::LevelUp { .. } => game.voice.say("Woo hoo!", Register::High),
+        GameEvent::Crashed { .. } | GameEvent::HitTerrain { .. } => {
+            game.voice.interrupt("Oh no!", Register::Low)
+        }
+        GameEvent::ItemCollected { .. }
+        | GameEvent::ItemMissed { .. }
+        | GameEvent::HighScore { .. }
+        | GameEvent::NearMiss { .. }
+        | GameEvent::Jumped { .. } => {}
+    }
+}
@@ -0,0 +1,232 @@
+use super::mutators::MutatorSet;
+use crate::input::PlayerActions;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FORMAT_VERSION: u32 = 9; // 9: the calibrated input lead
+const FRAME_CHARS: usize = 10;
+const MAX_FRAMES: usize = 60 * 60 * 30; // Half an hour at 60 FPS
+const MAX_FRAME_DT: f32 = 1.0; // Longer than any hitch a real run records
+
+// Everything needed to play a run back: the seed fixes every random roll, and
+// the frame times plus the actions taken each frame fix everything else.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Replay {
+    pub seed: u64,
+    pub lane_count: usize,
+    pub mutators: MutatorSet,
+    pub assisted: bool,     // Item magnet on, which moves items
+    pub input_lead_ms: u32, // Widens the jump windows
+    frames: Vec<ReplayFrame>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ReplayFrame {
+    dt: f32,
+    actions: PlayerActions,
+}
+
+impl Replay {
+    pub fn new(seed: u64, lane_count: usize, mutators: MutatorSet) -> Self {
+        Self {
+            seed,
+            lane_count,
+            mutators,
+            assisted: false,
+            input_lead_ms: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    // Start a frame; the actions are filled in once the yeti has read input
+    pub fn record(&mut self, dt: f32) {
+        if self.frames.len() < MAX_FRAMES {
+            self.frames.push(ReplayFrame {
+                dt,
+                actions: PlayerActions::default(),
+            });
+        }
+    }
+
+    pub fn record_actions(&mut self, actions: PlayerActions) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.actions = actions;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    // "version;seed;lanes;mutators;assisted;lead;frames" where each frame is the dt's bits
+    // as eight hex digits plus two hex digits of action flags. Exact bits matter: a
+    // rounded dt drifts the simulation and the ghost crashes early.
+    pub fn encode(&self) -> String {
+        let mut frames = String::with_capacity(self.frames.len() * FRAME_CHARS);
+        for frame in &self.frames {
+            frames.push_str(&format!(
+                "{:08x}{:02x}",
+                frame.dt.to_bits(),
+                action_bits(frame.actions)
+            ));
+        }
+        format!(
+            "{};{};{};{};{};{};{}",
+            FORMAT_VERSION,
+            self.seed,
+            self.lane_count,
+            self.mutators.bits(),
+            u8::from(self.assisted),
+            self.input_lead_ms,
+            frames
+        )
+    }
+
+    // Replays come off the shared board, so anything malformed is None
+    // rather than a panic or a frame time the simulation can't step
+    pub fn decode(text: &str) -> Option<Self> {
+        let mut parts = text.splitn(7, ';');
+        let version: u32 = parts.next()?.parse().ok()?;
+        if version != FORMAT_VERSION {
+            return None;
+        }
+        let seed = parts.next()?.parse().ok()?;
+        let lane_count = parts.next()?.parse().ok()?;
+        let mutators = MutatorSet::from_bits(parts.next()?.parse().ok()?);
+        let assisted = parts.next()?.parse::<u8>().ok()? != 0;
+        let input_lead_ms = parts.next()?.parse().ok()?;
+        let encoded = parts.next()?;
+        // All hex digits, so each chunk below is whole characters
+        if !encoded.bytes().all(|byte| byte.is_ascii_hexdigit())
+            || !encoded.len().is_multiple_of(FRAME_CHARS)
+        {
+            return None;
+        }
+
+        let frames = encoded
+            .as_bytes()
+            .chunks(FRAME_CHARS)
+            .map(|chunk| {
+                let chunk = std::str::from_utf8(chunk).ok()?;
+                let dt = f32::from_bits(u32::from_str_radix(&chunk[..8], 16).ok()?);
+                if !dt.is_finite() || dt <= 0.0 || dt > MAX_FRAME_DT {
+                    return None;
+                }
+                let bits = u8::from_str_radix(&chunk[8..], 16).ok()?;
+                Some(ReplayFrame {
+                    dt,
+                    actions: actions_from_bits(bits),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            seed,
+            lane_count,
+            mutators,
+            assisted,
+            input_lead_ms,
+            frames,
+        })
+    }
+}
+
+// A downloaded run being played back in place of the player's input
+#[derive(Debug, Clone)]
+pub struct ReplayPlayback {
+    pub name: String,
+    pub score: u32,
+    replay: Replay,
+    cursor: usize,
+    actions: PlayerActions,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay, name: String, score: u32) -> Self {
+        Self {
+            name,
+            score,
+            replay,
+            cursor: 0,
+            actions: PlayerActions::default(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.replay.seed
+    }
+
+    pub fn lane_count(&self) -> usize {
+        self.replay.lane_count
+    }
+
+    pub fn mutators(&self) -> MutatorSet {
+        self.replay.mutators
+    }
+
+    pub fn assisted(&self) -> bool {
+        self.replay.assisted
+    }
+
+    pub fn input_lead_ms(&self) -> u32 {
+        self.replay.input_lead_ms
+    }
+
+    // Advance one recorded frame and return its dt, or None once the
+    // recording has run out
+    pub fn next_frame(&mut self) -> Option<f32> {
+        let frame = self.replay.frames.get(self.cursor)?;
+        self.cursor += 1;
+        self.actions = frame.actions;
+        Some(frame.dt)
+    }
+
+    // What the player did on the current frame
+    pub fn actions(&self) -> PlayerActions {
+        self.actions
+    }
+}
+
+// A finished run on disk: the final score on the first line, then the
+// encoded replay. Solo runs leave one behind as last_run.replay.
+pub fn save_run(replay: &Replay, score: u32, path: &Path) -> std::io::Result<()> {
+    path.parent().map_or(Ok(()), fs::create_dir_all)?;
+    fs::write(path, format!("{}\n{}\n", score, replay.encode()))
+}
+
+pub fn load_run(path: &Path) -> Result<ReplayPlayback, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let (score, encoded) = text.split_once('\n').ok_or("not a replay file")?;
+    let score = score.trim().parse().map_err(|_| "not a replay file")?;
+    let replay =
+        Replay::decode(encoded.trim()).ok_or("unreadable replay, possibly from another version")?;
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    Ok(ReplayPlayback::new(replay, name, score))
+}
+
+pub fn last_run_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("yeti-set-go").join("last_run.replay"))
+}
+
+fn action_bits(actions: PlayerActions) -> u8 {
+    u8::from(actions.jump)
+        | u8::from(actions.lane_up) << 1
+        | u8::from(actions.lane_down) << 2
+        | u8::from(actions.focus) << 3
+        | u8::from(actions.accept) << 4
+        | u8::from(actions.decline) << 5
+}
+
+fn actions_from_bits(bits: u8) -> PlayerActions {
+    PlayerActions {
+        jump: bits & 1 != 0,
+        lane_up: bits & 2 != 0,
+        lane_down: bits & 4 != 0,
+        focus: bits & 8 != 0,
+        accept: bits & 16 != 0,
+        decline: bits & 32 != 0,
+    }
+}
@@ -0,0 +1,78 @@
+use crate::entities::{ItemCatalog, ItemDefinition, ItemType};
+use crate::integrations::github::RepoEvent;
+use std::collections::{HashSet, VecDeque};
+
+// Seconds between polls on the main menu. Each poll is two requests, and
+// GitHub allows sixty an hour without a token.
+const POLL_INTERVAL: f32 = 300.0;
+const MAX_PENDING: usize = 12;
+
+// Real events from the player's GitHub repo, waiting to turn up in a run as
+// the items they map to. Each one is only used once a session.
+#[derive(Debug)]
+pub struct RepoFeed {
+    pending: VecDeque<RepoEvent>, // Oldest first
+    seen: HashSet<String>,        // Ids of everything ever queued
+    pub loading: bool,
+    since_poll: f32,
+}
+
+impl RepoFeed {
+    pub fn new() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            seen: HashSet::new(),
+            loading: false,
+            since_poll: POLL_INTERVAL, // The first visit to the menu polls
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.since_poll += dt;
+    }
+
+    pub fn poll_due(&self) -> bool {
+        self.since_poll >= POLL_INTERVAL && !self.loading
+    }
+
+    pub fn start_poll(&mut self) {
+        self.loading = true;
+        self.since_poll = 0.0;
+    }
+
+    // Events come newest first; the ones not seen before queue up in the
+    // order they happened. Failed polls just try again next time.
+    pub fn receive(&mut self, events: Option<Vec<RepoEvent>>) {
+        self.loading = false;
+        for event in events.into_iter().flatten().rev() {
+            if self.seen.insert(event.id.clone()) {
+                self.pending.push_back(event);
+            }
+        }
+        while self.pending.len() > MAX_PENDING {
+            self.pending.pop_front();
+        }
+    }
+
+    // The oldest waiting event that maps to an item of the kind asked for,
+    // and that `allowed` lets through, along with that item
+    pub fn take(
+        &mut self,
+        catalog: &ItemCatalog,
+        good: bool,
+        allowed: impl Fn(&ItemDefinition) -> bool,
+    ) -> Option<(ItemType, RepoEvent)> {
+        let fits = |event: &RepoEvent| {
+            event.item_type(catalog).filter(|item_type| {
+                let definition = catalog.get(*item_type);
+                definition.good == good && allowed(definition)
+            })
+        };
+        let index = self
+            .pending
+            .iter()
+            .position(|event| fits(event).is_some())?;
+        let event = self.pending.remove(index)?;
+        fits(&event).map(|item_type| (item_type, event))
+    }
+}
@@ -0,0 +1,33 @@
+use super::events::GameEvent;
+use super::state::Game;
+
+// Seconds each pulse lasts
+const CRASH_PULSE: f32 = 0.45;
+const NEAR_MISS_PULSE: f32 = 0.08;
+const LEVEL_UP_PULSE: f32 = 0.25;
+
+// Turns simulation events into controller rumble: a heavy thud on a crash,
+// a flick for a close call, and a level complete that grows with the level
+pub fn handle_event(game: &mut Game, event: &GameEvent) {
+    if !game.settings.rumble || game.is_demo || game.spectating.is_some() {
+        return;
+    }
+
+    let (strength, seconds) = match event {
+        GameEvent::Crashed { .. } | GameEvent::HitTerrain { .. } => (1.0, CRASH_PULSE),
+        GameEvent::NearMiss { .. } => (0.3, NEAR_MISS_PULSE),
+        GameEvent::LevelUp { level } => (level_up_strength(*level), LEVEL_UP_PULSE),
+        GameEvent::ItemCollected { .. }
+        | GameEvent::ItemDodged { .. }
+        | GameEvent::ItemMissed { .. }
+        | GameEvent::HighScore { .. }
+        | GameEvent::NextItemAnnounced { .. }
+        | GameEvent::Jumped { .. } => return,
+    };
+    game.haptics.rumble(strength, seconds);
+}
+
+// Level 2 is a nudge; by level 10 it's as strong as it gets short of a crash
+fn level_up_strength(level: u32) -> f32 {
+    (0.3 + level.saturating_sub(2) as f32 * 0.05).min(0.7)
+}
@@ -0,0 +1,40 @@
+// Where the current run stands: its score, and how far it is through the
+// level. Versus matches keep their scores in VersusState instead but share
+// the level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunState {
+    pub score: u32,
+    pub level: u32,
+    pub checks_completed: u32,
+    pub checks_required: u32, // To finish the current level
+}
+
+impl RunState {
+    pub fn new(checks_required: u32) -> Self {
+        Self {
+            score: 0,
+            level: 1,
+            checks_completed: 0,
+            checks_required,
+        }
+    }
+
+    // Back to the start of the first level
+    pub fn reset(&mut self) {
+        self.score = 0;
+        self.level = 1;
+    }
+
+    pub fn enter_level(&mut self, checks_required: u32) {
+        self.checks_completed = 0;
+        self.checks_required = checks_required;
+    }
+
+    pub fn complete_check(&mut self) {
+        self.checks_completed += 1;
+    }
+
+    pub fn level_done(&self) -> bool {
+        self.checks_completed >= self.checks_required
+    }
+}
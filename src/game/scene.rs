@@ -0,0 +1,95 @@
+use super::{Game, GameState};
+
+// One screen per GameState. Screens hold no data of their own; everything
+// they need lives on Game so the stack can swap them freely.
+pub trait Screen {
+    fn update(&self, game: &mut Game, dt: f32);
+    fn draw(&self, game: &Game);
+
+    fn on_enter(&self, _game: &mut Game) {}
+    fn on_exit(&self, _game: &mut Game) {}
+
+    // Overlays are drawn on top of whatever screen sits beneath them
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+pub struct SceneStack {
+    stack: Vec<GameState>,
+}
+
+impl SceneStack {
+    pub fn new(root: GameState) -> Self {
+        Self { stack: vec![root] }
+    }
+
+    pub fn current(&self) -> GameState {
+        *self.stack.last().expect("scene stack is never empty")
+    }
+
+    // Screens to draw this frame, bottom first: the topmost full screen and
+    // every overlay pushed above it
+    pub fn visible(&self) -> &[GameState] {
+        let base = self
+            .stack
+            .iter()
+            .rposition(|state| !state.screen().is_overlay())
+            .unwrap_or(0);
+        &self.stack[base..]
+    }
+
+    pub fn contains(&self, state: GameState) -> bool {
+        self.stack.contains(&state)
+    }
+
+    // Swap the whole stack without running any hooks (dev mode previews)
+    pub fn reset(&mut self, states: &[GameState]) {
+        assert!(!states.is_empty(), "scene stack needs a root screen");
+        self.stack = states.to_vec();
+    }
+
+    fn push(&mut self, state: GameState) {
+        self.stack.push(state);
+    }
+}
+
+impl Game {
+    pub fn state(&self) -> GameState {
+        self.scenes.current()
+    }
+
+    // Replace the current screen
+    pub fn set_state(&mut self, state: GameState) {
+        let previous = self.state();
+        previous.screen().on_exit(self);
+        self.scenes.stack.pop();
+        self.scenes.push(state);
+        state.screen().on_enter(self);
+    }
+
+    // Cover the current screen; it stays frozen until the overlay pops
+    pub fn push_state(&mut self, state: GameState) {
+        self.scenes.push(state);
+        state.screen().on_enter(self);
+    }
+
+    // Resume the screen underneath. Its on_enter doesn't run again.
+    pub fn pop_state(&mut self) {
+        let top = self.state();
+        if self.scenes.stack.len() > 1 {
+            top.screen().on_exit(self);
+            self.scenes.stack.pop();
+        }
+    }
+
+    // Tear down every screen and start fresh from `state`
+    pub fn reset_state(&mut self, state: GameState) {
+        while let Some(top) = self.scenes.stack.last().copied() {
+            top.screen().on_exit(self);
+            self.scenes.stack.pop();
+        }
+        self.scenes.push(state);
+        state.screen().on_enter(self);
+    }
+}
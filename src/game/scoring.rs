@@ -1,40 +1,59 @@
+pub use crate::scoring::{calculate_level_score_bonus, calculate_total_score_with_bonuses};
+
+use crate::game::events::GameEvent;
+use crate::game::feedback::{Feedback, Priority, Severity, CRASH_DISPLAY_TIME};
 use crate::game::state::Game;
-use crate::colors::*;
+use crate::game::versus::PLAYER_ONE;
 
 pub fn update_item_scoring(game: &mut Game, _dt: f32) {
-    
-    for item in &mut game.items {
+    // All yetis share the same x, so one pass line works for every lane
+    let pass_x = game.yeti.x;
+    let mut dodged = Vec::new();
+    let mut missed = Vec::new();
+    let owners: Vec<usize> = game.items.iter().map(|item| game.lane_owner(item.lane)).collect();
+
+    for (item, &player) in game.items.iter_mut().zip(&owners) {
         // Check if item has passed the yeti (item's right edge is behind yeti's left edge)
-        if item.x + item.width < game.yeti.x && !item.was_passed {
+        if item.body.right() < pass_x && !item.was_passed {
             item.was_passed = true;
-            
-            // If it's a bad item that we successfully avoided, award points
-            if !item.is_good {
-                game.score += 5; // Less than collision bonus, but still rewarding
-                game.checks_completed += 1;
-                
-                // Show feedback for successful avoidance
-                game.feedback_message = "Nice dodge! Avoided a problem!".to_string();
-                game.feedback_timer = 2.0;
-                game.feedback_color = SUCCESS_GREEN;
+
+            if item.is_good {
+                missed.push((item.item_type, player));
+            } else {
+                dodged.push((item.item_type, player));
             }
         }
     }
-}
 
-pub fn calculate_level_score_bonus(level: u32) -> u32 {
-    // Bonus points for completing a level
-    50 + (level * 25)
-}
+    for (item_type, player) in dodged {
+        // If it's a bad item that we successfully avoided, award points
+        // Less than collision bonus, but still rewarding
+        let points = game.award_points(player, 5);
+        game.complete_check(player);
+        game.events.emit(GameEvent::ItemDodged {
+            item_type,
+            player,
+            points,
+        });
+    }
 
-pub fn calculate_total_score_with_bonuses(base_score: u32, level: u32, checks_completed: u32) -> u32 {
-    let level_bonus = if level > 1 { 
-        (1..level).map(calculate_level_score_bonus).sum::<u32>()
-    } else { 
-        0 
-    };
-    
-    let completion_bonus = checks_completed * 2; // Small bonus for each check completed
-    
-    base_score + level_bonus + completion_bonus
-}
\ No newline at end of file
+    let mut build_failed = false;
+    for (item_type, player) in missed {
+        game.events.emit(GameEvent::ItemMissed { item_type, player });
+        // Pushed without review, one dropped change is all it takes
+        if game.versus.is_none() && !game.invincible && game.level_modifier.record_miss() {
+            build_failed = true;
+        }
+    }
+
+    if build_failed {
+        game.feedback.clear_all();
+        game.show_feedback(Feedback::new(
+            "Pushed to prod without review. It broke.",
+            Severity::Error,
+            Priority::Collision,
+            CRASH_DISPLAY_TIME,
+        ));
+        game.start_dying(PLAYER_ONE);
+    }
+}
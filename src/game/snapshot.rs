@@ -0,0 +1,119 @@
+use super::adaptive::AdaptiveDifficulty;
+use super::difficulty::Difficulty;
+use super::focus::Focus;
+use super::levels::LevelDefinition;
+use super::modifier::LevelModifier;
+use super::mutators::MutatorSet;
+use super::objectives::Objectives;
+use super::run::RunState;
+use super::spawning::SpawnSchedule;
+use super::speed_events::SpeedTimeline;
+use super::state::Game;
+use super::stats::RunStats;
+use super::versus::VersusState;
+use crate::entities::{Item, ItemType, Terrain, Yeti};
+use ::rand::rngs::StdRng;
+
+// The simulation at one moment: everything a run needs to carry on from
+// there and play out exactly as it would have. Presentation (particles,
+// feedback, the screens showing) and anything outside the run (assets,
+// settings, the API, the replay being recorded) aren't part of it.
+#[derive(Debug, Clone)]
+pub struct GameSnapshot {
+    yeti: Yeti,
+    items: Vec<Item>,
+    terrain: Terrain,
+    rng: StdRng,
+    spawn_schedule: SpawnSchedule,
+    speed_events: SpeedTimeline,
+    focus: Focus,
+    stats: RunStats,
+    objectives: Objectives,
+    adaptive: AdaptiveDifficulty,
+    versus: Option<VersusState>,
+    run: RunState,
+    current_level: LevelDefinition,
+    level_modifier: LevelModifier,
+    spawn_rate: f32,
+    pipeline_scroll: f32,
+    collision_grace: f32,
+    death_timer: f32,
+    level_complete_timer: f32,
+    wager_pending: bool,
+    next_item: Option<ItemType>,
+    lane_count: usize,
+    mutators: MutatorSet,
+    difficulty: Difficulty,
+    assisted: bool,
+}
+
+impl GameSnapshot {
+    // Seconds into the run it was taken
+    pub fn time_survived(&self) -> f32 {
+        self.stats.time_survived
+    }
+}
+
+impl Game {
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            yeti: self.yeti.clone(),
+            items: self.items.clone(),
+            terrain: self.terrain.clone(),
+            rng: self.rng.clone(),
+            spawn_schedule: self.spawn_schedule.clone(),
+            speed_events: self.speed_events.clone(),
+            focus: self.focus.clone(),
+            stats: self.stats.clone(),
+            objectives: self.objectives.clone(),
+            adaptive: self.adaptive.clone(),
+            versus: self.versus.clone(),
+            run: self.run.clone(),
+            current_level: self.current_level.clone(),
+            level_modifier: self.level_modifier,
+            spawn_rate: self.spawn_rate,
+            pipeline_scroll: self.pipeline_scroll,
+            collision_grace: self.collision_grace,
+            death_timer: self.death_timer,
+            level_complete_timer: self.level_complete_timer,
+            wager_pending: self.wager_pending,
+            next_item: self.next_item,
+            lane_count: self.lane_count,
+            mutators: self.mutators,
+            difficulty: self.difficulty,
+            assisted: self.assisted,
+        }
+    }
+
+    // Events still waiting belong to the timeline being left, so they go.
+    // The screen stack is left alone; callers move between screens themselves.
+    pub fn restore(&mut self, snapshot: &GameSnapshot) {
+        let snapshot = snapshot.clone();
+        self.yeti = snapshot.yeti;
+        self.items = snapshot.items;
+        self.terrain = snapshot.terrain;
+        self.rng = snapshot.rng;
+        self.spawn_schedule = snapshot.spawn_schedule;
+        self.speed_events = snapshot.speed_events;
+        self.focus = snapshot.focus;
+        self.stats = snapshot.stats;
+        self.objectives = snapshot.objectives;
+        self.adaptive = snapshot.adaptive;
+        self.versus = snapshot.versus;
+        self.run = snapshot.run;
+        self.current_level = snapshot.current_level;
+        self.level_modifier = snapshot.level_modifier;
+        self.spawn_rate = snapshot.spawn_rate;
+        self.pipeline_scroll = snapshot.pipeline_scroll;
+        self.collision_grace = snapshot.collision_grace;
+        self.death_timer = snapshot.death_timer;
+        self.level_complete_timer = snapshot.level_complete_timer;
+        self.wager_pending = snapshot.wager_pending;
+        self.next_item = snapshot.next_item;
+        self.lane_count = snapshot.lane_count;
+        self.mutators = snapshot.mutators;
+        self.difficulty = snapshot.difficulty;
+        self.assisted = snapshot.assisted;
+        self.events.clear();
+    }
+}
@@ -1,16 +1,233 @@
-use crate::entities::Item;
+use crate::entities::{Feature, Item, ItemType};
+use crate::game::feedback::{Feedback, Priority, Severity};
+use crate::game::mutators::Mutator;
 use crate::game::state::Game;
+use crate::game::versus::{lane_ground_y, PLAYER_TWO};
+use ::rand::Rng;
+use std::collections::VecDeque;
+
+// How far ahead spawns are decided. Long enough for the radar to be useful,
+// short enough that a level-up only throws away a few seconds of plan.
+pub const LOOK_AHEAD_SECONDS: f32 = 4.0;
+
+// Terrain: raised platforms and gaps in the classic single-lane track
+const TERRAIN_FROM_LEVEL: u32 = 2;
+const TERRAIN_CHANCE: f64 = 0.2; // Per planned item, for a feature after it
+const GAP_CHANCE: f64 = 0.5; // Of a feature, that it's a gap
+const GAP_SECONDS: (f32, f32) = (0.2, 0.35); // How long a gap takes to pass under
+const PLATFORM_SECONDS: (f32, f32) = (0.6, 1.2);
+const PLATFORM_HEIGHT: (f32, f32) = (14.0, 30.0);
+const GAP_AIRTIME_SHARE: f32 = 0.45; // Widest gap, as a share of a jump's airtime
+const PLATFORM_APEX_SHARE: f32 = 0.5; // Tallest platform, as a share of a jump's height
+const CLEARANCE_AIRTIME: f32 = 1.1; // Item-free run-up and run-out, in airtimes
+
+const REPO_EVENT_DISPLAY_TIME: f32 = 3.0;
+
+// An item that has been decided on but hasn't entered the screen yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlannedSpawn {
+    pub item_type: ItemType,
+    pub lane: usize,
+}
+
+// Upcoming spawns, each stamped with the spawn clock time it's due. Every
+// spawn goes through here: the radar reads it, seeded runs replay it, and
+// anything that wants to check or shape what's coming works on the queue
+// before items exist.
+#[derive(Debug, Clone, Default)]
+pub struct SpawnSchedule {
+    clock: f32,    // Seconds of play this run
+    last_at: f32,  // When the most recent spawn was due
+    clear_at: f32, // No item is due before this, so terrain gets a clear run
+    queue: VecDeque<(f32, PlannedSpawn)>,
+    terrain: VecDeque<(f32, Feature, f32)>, // Due time, feature and width
+}
+
+impl SpawnSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Forget the plan but keep the clock, for when pacing or the item pool
+    // changes; the next spawn is still one interval after the last. Planned
+    // terrain stays, along with the clear run around it.
+    pub fn replan(&mut self) {
+        self.queue.clear();
+    }
+
+    // Planned spawns with the seconds until each one enters the screen
+    pub fn upcoming(&self) -> impl Iterator<Item = (PlannedSpawn, f32)> + '_ {
+        self.queue
+            .iter()
+            .map(|(at, planned)| (*planned, (at - self.clock).max(0.0)))
+    }
+
+    fn next_at(&self, interval: f32) -> f32 {
+        let after = self.queue.back().map_or(self.last_at, |(at, _)| *at) + interval;
+        after.max(self.clear_at)
+    }
+}
 
 pub fn spawn_items(game: &mut Game, dt: f32) {
-    game.spawn_timer += dt;
+    game.spawn_schedule.clock += dt;
+    plan_spawns(game);
+
+    while let Some(&(at, feature, width)) = game.spawn_schedule.terrain.front() {
+        if at > game.spawn_schedule.clock {
+            break;
+        }
+        game.spawn_schedule.terrain.pop_front();
+        game.terrain.push(feature, width);
+    }
+
+    // One per frame at most, so a hitch can't stack items on top of each other
+    let schedule = &mut game.spawn_schedule;
+    if let Some(&(at, planned)) = schedule.queue.front() {
+        if at <= schedule.clock {
+            schedule.queue.pop_front();
+            schedule.last_at = at;
+            spawn_planned_item(game, planned);
+        }
+    }
+}
+
+// Top the queue up to the look-ahead. Rolls come off the run's seeded rng in
+// spawn order, so seeded runs still see the same items.
+fn plan_spawns(game: &mut Game) {
+    let horizon = game.spawn_schedule.clock + LOOK_AHEAD_SECONDS;
+    loop {
+        let at = game.spawn_schedule.next_at(game.spawn_rate);
+        if at > horizon {
+            break;
+        }
+        let planned = roll_spawn(game);
+        game.spawn_schedule.queue.push_back((at, planned));
+        if allows_terrain(game) {
+            plan_terrain(game, at);
+        }
+    }
+}
+
+fn allows_terrain(game: &Game) -> bool {
+    game.lane_count == 1 && game.versus.is_none() && game.run.level >= TERRAIN_FROM_LEVEL
+}
 
-    if game.spawn_timer >= game.spawn_rate {
-        game.spawn_timer = 0.0;
-        spawn_random_item(game);
+// Maybe a platform or gap after the item due at `at`. Sizes come from the
+// current jump, so every feature can be cleared: gaps narrower than the
+// time in the air, platforms lower than the top of a jump. Items are held
+// back either side so there's never an item and a feature to jump at once.
+fn plan_terrain(game: &mut Game, at: f32) {
+    if !game.rng.gen_bool(TERRAIN_CHANCE) {
+        return;
     }
+
+    let gravity = game.gravity();
+    let velocity = game.tuning.jump_velocity.abs();
+    let airtime = 2.0 * velocity / gravity;
+    let apex = velocity * velocity / (2.0 * gravity);
+
+    let (feature, seconds) = if game.rng.gen_bool(GAP_CHANCE) {
+        let widest = GAP_SECONDS.1.min(airtime * GAP_AIRTIME_SHARE);
+        let seconds = game
+            .rng
+            .gen_range(GAP_SECONDS.0..=widest.max(GAP_SECONDS.0));
+        (Feature::Gap, seconds)
+    } else {
+        let tallest = PLATFORM_HEIGHT.1.min(apex * PLATFORM_APEX_SHARE);
+        let height = game
+            .rng
+            .gen_range(PLATFORM_HEIGHT.0..=tallest.max(PLATFORM_HEIGHT.0));
+        let seconds = game.rng.gen_range(PLATFORM_SECONDS.0..=PLATFORM_SECONDS.1);
+        (Feature::Platform { height }, seconds)
+    };
+
+    let clearance = airtime * CLEARANCE_AIRTIME;
+    let start = at + clearance;
+    let width = game.base_item_speed() * seconds;
+    let schedule = &mut game.spawn_schedule;
+    schedule.terrain.push_back((start, feature, width));
+    schedule.clear_at = start + seconds + clearance;
 }
 
-fn spawn_random_item(game: &mut Game) {
-    let item = Item::random(&game.textures);
+fn roll_spawn(game: &mut Game) -> PlannedSpawn {
+    let good_probability = if game.mutators.contains(Mutator::NoGoodItems) {
+        0.0
+    } else {
+        let base = game.tuning.good_item_probability + game.adaptive.good_item_shift();
+        game.difficulty.good_item_probability(base)
+    };
+    let is_good = game.rng.gen_bool(good_probability as f64);
+    let level = &game.current_level;
+    let item_type = game
+        .catalog
+        .random(&mut game.rng, is_good, |item| level.allows_item(&item.id));
+
+    // Multi-lane runs drop each item into one of the parallel lanes
+    let lane = if game.lane_count > 1 {
+        game.rng.gen_range(0..game.lane_count)
+    } else {
+        0
+    };
+
+    PlannedSpawn { item_type, lane }
+}
+
+fn spawn_planned_item(game: &mut Game, mut planned: PlannedSpawn) {
+    if game.weaves_repo_events() {
+        weave_repo_event(game, &mut planned);
+    }
+
+    let mut item = Item::new(planned.item_type, &game.catalog, &game.sprites);
+    if game.lane_count > 1 {
+        item = item.in_lane(planned.lane, game.lane_ground_y(planned.lane));
+    }
+
+    push_item(game, item);
+}
+
+// A real event from the player's repo stands in for the item, if one is
+// waiting that maps to an item of the same kind the level allows. Good
+// stays good and bad stays bad, so the run plays out as the seed has it.
+fn weave_repo_event(game: &mut Game, planned: &mut PlannedSpawn) {
+    let good = game.catalog.get(planned.item_type).good;
+    let level = &game.current_level;
+    let Some((item_type, event)) = game
+        .repo_feed
+        .take(&game.catalog, good, |item| level.allows_item(&item.id))
+    else {
+        return;
+    };
+    planned.item_type = item_type;
+
+    let severity = if good {
+        Severity::Success
+    } else {
+        Severity::Warning
+    };
+    game.show_feedback(
+        Feedback::new(
+            event.headline,
+            severity,
+            Priority::Event,
+            REPO_EVENT_DISPLAY_TIME,
+        )
+        .about(item_type),
+    );
+}
+
+// Dev tools: a specific item, straight into the given lane
+pub fn spawn_item(game: &mut Game, item_type: ItemType, lane: usize) {
+    let item = Item::new(item_type, &game.catalog, &game.sprites)
+        .in_lane(lane, game.lane_ground_y(lane));
+    push_item(game, item);
+}
+
+fn push_item(game: &mut Game, item: Item) {
+    // Versus players share one item stream, mirrored onto the top lane
+    if game.versus.is_some() {
+        let mirrored = item.clone().in_lane(PLAYER_TWO, lane_ground_y(PLAYER_TWO));
+        game.items.push(mirrored);
+    }
+
     game.items.push(item);
 }
\ No newline at end of file
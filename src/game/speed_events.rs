@@ -0,0 +1,127 @@
+use ::rand::Rng;
+use std::collections::VecDeque;
+
+const FROM_LEVEL: u32 = 2; // Level 1 stays at a steady pace
+const START_AFTER: (f32, f32) = (5.0, 12.0); // Seconds into the level
+const DURATION: (f32, f32) = (3.0, 5.0);
+const RUSH_CHANCE: f64 = 0.6; // Of an event, that it speeds things up
+const EASE_TIME: f32 = 0.5; // Into and out of the new speed
+pub const WARNING_TIME: f32 = 2.0; // Banner shown this long before it starts
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeedEventKind {
+    Rush,   // A hotfix is being rushed through
+    Freeze, // Change freeze; everything crawls
+}
+
+impl SpeedEventKind {
+    pub fn multiplier(self) -> f32 {
+        match self {
+            SpeedEventKind::Rush => 1.5,
+            SpeedEventKind::Freeze => 0.6,
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            SpeedEventKind::Rush => "HOTFIX RUSH",
+            SpeedEventKind::Freeze => "CHANGE FREEZE",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            SpeedEventKind::Rush => "Pipeline speeding up",
+            SpeedEventKind::Freeze => "Pipeline slowing down",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedEvent {
+    pub kind: SpeedEventKind,
+    start: f32,
+    duration: f32,
+}
+
+impl SpeedEvent {
+    fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+}
+
+// Short stretches where the pipeline runs faster or slower than the level's
+// pace, planned as each level starts. Times are on the timeline's own clock,
+// which only runs during play, so pausing or the level-complete screen
+// doesn't use an event up.
+#[derive(Debug, Clone, Default)]
+pub struct SpeedTimeline {
+    clock: f32,
+    events: VecDeque<SpeedEvent>,
+}
+
+impl SpeedTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Drops anything not yet started; one already under way plays out.
+    // Rolls come off the run's seeded rng, so replays see the same events.
+    pub fn plan_level(&mut self, level: u32, rng: &mut impl Rng) {
+        let clock = self.clock;
+        self.events.retain(|event| event.start <= clock);
+        if level < FROM_LEVEL {
+            return;
+        }
+
+        let kind = if rng.gen_bool(RUSH_CHANCE) {
+            SpeedEventKind::Rush
+        } else {
+            SpeedEventKind::Freeze
+        };
+        let after = self.events.back().map_or(clock, SpeedEvent::end);
+        self.events.push_back(SpeedEvent {
+            kind,
+            start: after + rng.gen_range(START_AFTER.0..START_AFTER.1),
+            duration: rng.gen_range(DURATION.0..DURATION.1),
+        });
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        self.clock += dt;
+        while self
+            .events
+            .front()
+            .is_some_and(|event| event.end() <= self.clock)
+        {
+            self.events.pop_front();
+        }
+    }
+
+    // Scales item and pipeline speed; eases in and out rather than snapping
+    pub fn multiplier(&self) -> f32 {
+        let Some(event) = self.events.front() else {
+            return 1.0;
+        };
+        if self.clock < event.start {
+            return 1.0;
+        }
+        let ease = ((self.clock - event.start) / EASE_TIME)
+            .min((event.end() - self.clock) / EASE_TIME)
+            .clamp(0.0, 1.0);
+        1.0 + (event.kind.multiplier() - 1.0) * ease
+    }
+
+    // The event to warn about: seconds until it starts, or None once it has
+    pub fn banner(&self) -> Option<(SpeedEventKind, Option<f32>)> {
+        let event = self.events.front()?;
+        let until = event.start - self.clock;
+        if until > WARNING_TIME {
+            None
+        } else if until > 0.0 {
+            Some((event.kind, Some(until)))
+        } else {
+            Some((event.kind, None))
+        }
+    }
+}
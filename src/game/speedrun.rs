@@ -0,0 +1,70 @@
+// The one category: any% to level 10. The clock starts with level 1 and
+// stops the moment level 10 is reached, so it takes nine splits.
+pub const FINISH_LEVEL: u32 = 10;
+pub const SPLIT_COUNT: usize = FINISH_LEVEL as usize - 1;
+
+// A run's speedrun clock and its splits, the time each level was completed,
+// compared against the splits of the fastest finished run so far. It only
+// runs while the run is being played, so pauses and the level-complete
+// screens don't count, and it adds up in f64 so a long run doesn't drift.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpeedrunTimer {
+    elapsed: f64,
+    splits: Vec<f64>,
+    best: Vec<f64>, // Empty until a run has finished
+}
+
+impl SpeedrunTimer {
+    pub fn new(best: Vec<f64>) -> Self {
+        // A best that never finished can't be compared against
+        let best = if best.len() == SPLIT_COUNT {
+            best
+        } else {
+            Vec::new()
+        };
+        Self {
+            elapsed: 0.0,
+            splits: Vec::new(),
+            best,
+        }
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        if !self.is_finished() {
+            self.elapsed += dt as f64;
+        }
+    }
+
+    // A level was just completed
+    pub fn split(&mut self) {
+        if !self.is_finished() {
+            self.splits.push(self.elapsed);
+        }
+    }
+
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn splits(&self) -> &[f64] {
+        &self.splits
+    }
+
+    pub fn best(&self) -> &[f64] {
+        &self.best
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.splits.len() >= SPLIT_COUNT
+    }
+
+    // Seconds behind (positive) or ahead of (negative) the best at a split
+    pub fn delta(&self, split: usize) -> Option<f64> {
+        Some(self.splits.get(split)? - self.best.get(split)?)
+    }
+
+    // Finished, and faster than any finish before it
+    pub fn is_new_best(&self) -> bool {
+        self.is_finished() && self.best.last().is_none_or(|best| self.elapsed < *best)
+    }
+}
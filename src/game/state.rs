@@ -1,458 +1,1886 @@
-use super::{physics, scoring, spawning};
-use crate::api::{ApiClient, load_leaderboard_with_fallback, submit_score_with_fallback};
-use crate::colors::*;
+use super::adaptive::AdaptiveDifficulty;
+use super::admin::{self, AdminMode};
+use super::api_state::{ApiMessage, ApiState};
+use super::benchmark::{Benchmark, BENCHMARK_SEED};
+use super::build_log::{self, BuildLog};
+use super::calibration::Calibration;
+use super::challenge::{Challenge, ChallengeMenu};
+use super::clock::GameClock;
+use super::difficulty::Difficulty;
+use super::effects::{FatalHit, ScorePopup};
+use super::events::{EventBus, GameEvent};
+use super::feedback::{Feedback, FeedbackState, Priority, Severity};
+use super::flavor::FlavorText;
+use super::focus::Focus;
+use super::leaderboard::{GhostStatus, LeaderboardState, ReportStatus};
+use super::levels::{LevelCatalog, LevelDefinition};
+use super::menu_vignette::MenuVignette;
+use super::modifier::LevelModifier;
+use super::mutators::{Mutator, MutatorSet};
+use super::objectives::Objectives;
+use super::photo::PhotoMode;
+use super::practice::{self, Practice};
+use super::replay::{self, Replay, ReplayPlayback};
+use super::repo_feed::RepoFeed;
+use super::run::RunState;
+use super::scene::SceneStack;
+use super::spawning::SpawnSchedule;
+use super::speed_events::SpeedTimeline;
+use super::speedrun::SpeedrunTimer;
+use super::stats::{DeathCause, RunStats};
+use super::tuning::Tuning;
+use super::versus::{VersusState, PLAYER_ONE, PLAYER_TWO};
+use super::{autopilot, effects, feedback, quips, rumble, scoring, systems, versus};
+use crate::api::submit_score_with_fallback;
+use crate::assets::{load_flavor_lines, load_item_catalog, load_level_catalog};
+use crate::atlas::SpriteAtlas;
+use crate::audio::Voice;
+use crate::changelog::{self, ChangelogLine};
+use crate::cli;
 use crate::config::*;
-use crate::design::GameFonts;
-use crate::entities::{Item, Yeti};
-use crate::highscores::{HighScore, Leaderboard};
+use crate::crash;
+use crate::design::{CountUp, DesignContext, GameFonts};
+use crate::entities::{Item, ItemCatalog, ItemType, Particle, Terrain, Yeti};
+use crate::highscores::{validate_name, BoardStanding, Category, HighScore, RunMetadata};
+use crate::input::{FrameInput, Haptics, NoHaptics, PlayerActions, TouchKeyboard};
+use crate::integrations::github::{self, GithubClient};
+use crate::kiosk::Kiosk;
+use crate::mods::{self, load_mods, ModPack};
+use crate::overrides::config;
+use crate::profiles::{self, Profile, ProfileMenu};
+use crate::settings::Settings;
+use crate::telemetry::{Telemetry, TelemetryEvent};
+use crate::version::Version;
+use ::rand::rngs::StdRng;
+use ::rand::{thread_rng, Rng, SeedableRng};
 use macroquad::prelude::*;
 use std::collections::HashMap;
-use std::sync::mpsc;
+use std::path::PathBuf;
+use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameState {
     MainMenu,
     Playing,
+    Paused,
+    Dying,
     LevelComplete,
     GameOver,
+    RunSummary,
     NameInput,
     ViewingLeaderboard,
-}
-
-#[derive(Debug)]
-pub enum ApiMessage {
-    LeaderboardSynced(Leaderboard),
-    ScoreSubmitted(bool), // success flag
+    LeaderboardDetail,
+    VersusResults,
+    Challenge,
+    Settings,
+    Calibration,
+    FeedbackHistory,
+    Mutators,
+    Benchmark,
+    Mods,
+    TelemetryConsent,
+    Profiles,
+    SignIn,
+    WhatsNew,
+    QuitConfirm,
+    PhotoMode,
 }
 
 pub struct Game {
     pub yeti: Yeti,
     pub items: Vec<Item>,
-    pub score: u32,
-    pub level: u32,
-    pub checks_completed: u32,
-    pub checks_required: u32,
-    pub spawn_timer: f32,
+    pub terrain: Terrain,
+    pub particles: Vec<Particle>,
+    pub score_popups: Vec<ScorePopup>,
+    pub fatal_hit: Option<FatalHit>, // What ended the last run, for the game-over screen
+    pub events: EventBus,
+    pub clock: GameClock,
+    pub stats: RunStats,
+    pub speedrun: SpeedrunTimer,
+    pub objectives: Objectives,
+    pub speed_events: SpeedTimeline,
+    pub adaptive: AdaptiveDifficulty,
+    pub run: RunState,
     pub spawn_rate: f32,
+    pub spawn_schedule: SpawnSchedule,
+    pub focus: Focus,
     pub textures: HashMap<String, Texture2D>,
+    pub sprites: SpriteAtlas,
+    pub catalog: ItemCatalog,
+    pub mods: Vec<ModPack>, // Every pack found, including skipped ones
+    pub levels: LevelCatalog,
+    pub current_level: LevelDefinition,
     pub fonts: GameFonts,
-    pub state: GameState,
-    pub leaderboard: Leaderboard,
-    pub api_client: ApiClient,
+    pub voice: Voice,
+    pub haptics: Box<dyn Haptics>,
+    pub scenes: SceneStack,
+    pub leaderboard: LeaderboardState,
+    pub api: ApiState,
+    pub quit_requested: bool, // The main loop exits and runs shutdown() when set
     pub pipeline_scroll: f32,
     pub collision_grace: f32,
-    pub feedback_message: String,
-    pub feedback_timer: f32,
-    pub feedback_color: Color,
+    pub feedback: FeedbackState,
+    pub build_log: BuildLog, // The HUD's CI-style ticker, when it's switched on
+    pub next_item: Option<ItemType>, // Nearest item still ahead of the yeti
+    pub whats_new: Vec<ChangelogLine>,
+    pub whats_new_scroll: usize,
+    pub hud_score: CountUp, // The score as the HUD shows it, catching up
+    pub tally: CountUp,     // The number the level complete or game over screen counts up
     pub level_complete_timer: f32,
+    pub level_modifier: LevelModifier,
+    pub wager_pending: bool, // The level-complete gamble is waiting on an answer
+    pub death_timer: f32,
     pub level_complete_message: String,
     pub level_complete_submessage: String,
     pub player_name_input: String,
+    pub input: FrameInput, // This frame's, as handed to update()
+    pub touch_keyboard: TouchKeyboard,
     pub is_new_high_score: bool,
-    pub leaderboard_scroll: f32,
+    pub global_rank: Option<usize>, // The finished run's place on the whole board, once known
+    pub share_card_requested: bool, // Picked up by the capture system after drawing
+    pub pending_crash_report: Option<String>, // Left by the last session's panic hook
+    pub update_available: Option<Version>, // A newer release, for the main menu's banner
     pub menu_time: f32,
-    pub mini_leaderboard_scroll: f32,
-    pub api_loading: bool,
-    pub last_api_sync: f32, // Time since last sync attempt
-    api_receiver: mpsc::Receiver<ApiMessage>,
-    api_sender: mpsc::Sender<ApiMessage>,
+    pub menu_idle_time: f32,
+    pub menu_vignette: MenuVignette,
+    pub flavor: FlavorText, // The menu's tagline or greeting, new each visit
+    pub is_demo: bool,
+    pub autopilot: bool,
+    pub assisted: bool,     // Item magnet on for this run
+    pub input_lead_ms: u32, // Jump window slack for this run, from calibration
+    pub hardcore: bool,     // No grace margin or assists this run; ranks on its own board
+    pub versus: Option<VersusState>,
+    pub lane_setting: usize, // Lanes picked on the menu for regular runs
+    pub lane_count: usize,   // Lanes in the current run
+    pub mutator_selection: MutatorSet, // Picked on the mutators screen for regular runs
+    pub mutators: MutatorSet, // Active in the current run
+    pub mutator_cursor: usize,
+    pub rng: StdRng,
+    pub difficulty: Difficulty,
+    pub run_difficulty: Difficulty, // For regular runs; --difficulty can change it
+    pub run_seed: Option<u64>,      // --seed: every regular run starts from it
+    pub challenge: Option<Challenge>,
+    pub challenge_menu: ChallengeMenu,
+    pub settings: Settings,
+    pub profile: Option<Profile>, // None for the guest
+    pub profile_menu: ProfileMenu,
+    pub kiosk: Option<Kiosk>, // Booth mode, set up by main from kiosk.json
+    pub admin: Option<AdminMode>, // --admin: leaderboard curation
+    pub tuning: Tuning,
+    pub telemetry: Telemetry, // Empty and idle unless the player opted in
+    pub repo_feed: RepoFeed,  // The player's GitHub repo events, when that's set up
+    pub invincible: bool,     // Dev mode only
+    pub settings_cursor: usize,
+    pub replay: Replay,                     // The current run, as it's played
+    pub spectating: Option<ReplayPlayback>, // Set while watching a ghost run
+    pub benchmark: Option<Benchmark>,       // Dev mode's stress test, while it runs
+    pub calibration: Option<Calibration>,   // The tap test, while its screen is open
+    pub practice: Option<Practice>,         // Set while a practice run is going
+    pub photo: Option<PhotoMode>,           // Set while photo mode is open over a paused run
+    pub practice_level: u32,                // Where practice runs start, picked on the menu
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Game {
+    // The player's own game: their saved settings and installed mods
     pub fn new() -> Self {
-        let (api_sender, api_receiver) = mpsc::channel();
-        
+        // The frontend applies the settings to the display once it has a window
+        Self::with_data(Settings::load(), &mods::mod_dirs())
+    }
+
+    // A game that reads nothing of the player's: the settings it's given and
+    // mods from `mod_dirs` only. Telemetry stays off unless `settings` says
+    // the player agreed to it. Headless runs and tests start here.
+    pub fn with_data(settings: Settings, mod_dirs: &[PathBuf]) -> Self {
+        let levels = load_level_catalog();
+        let current_level = levels.definition(1);
+        let mut catalog = load_item_catalog();
+        let mods = load_mods(&mut catalog, mod_dirs);
+        let mut flavor = FlavorText::new(load_flavor_lines());
+        for pack in &mods {
+            flavor.extend(pack.flavor.clone());
+        }
+        let telemetry = Telemetry::load(settings.telemetry_enabled());
+
         let mut game = Self {
             yeti: Yeti::new(),
             items: Vec::new(),
-            score: 0,
-            level: 1,
-            checks_completed: 0,
-            checks_required: 5,
-            spawn_timer: 0.0,
-            spawn_rate: INITIAL_SPAWN_RATE,
+            terrain: Terrain::new(),
+            particles: Vec::new(),
+            score_popups: Vec::new(),
+            fatal_hit: None,
+            events: EventBus::new(),
+            clock: GameClock::new(),
+            stats: RunStats::new(),
+            speedrun: SpeedrunTimer::default(),
+            objectives: Objectives::new(),
+            speed_events: SpeedTimeline::new(),
+            adaptive: AdaptiveDifficulty::new(),
+            run: RunState::new(current_level.checks_required),
+            spawn_rate: config().initial_spawn_rate,
+            spawn_schedule: SpawnSchedule::new(),
+            focus: Focus::new(),
             textures: HashMap::new(),
+            sprites: SpriteAtlas::new(),
+            catalog,
+            mods,
+            levels,
+            current_level,
             fonts: GameFonts::new(),
-            state: GameState::MainMenu,
-            leaderboard: Leaderboard::new(),
-            api_client: ApiClient::new(),
+            voice: Voice::new(),
+            haptics: Box::new(NoHaptics),
+            scenes: SceneStack::new(GameState::MainMenu),
+            leaderboard: LeaderboardState::new(),
+            api: ApiState::new(),
+            quit_requested: false,
             pipeline_scroll: 0.0,
             collision_grace: 0.0,
-            feedback_message: String::new(),
-            feedback_timer: 0.0,
-            feedback_color: TEXT_PRIMARY,
+            feedback: FeedbackState::new(),
+            build_log: BuildLog::new(),
+            next_item: None,
+            whats_new: Vec::new(),
+            whats_new_scroll: 0,
+            hud_score: CountUp::default(),
+            tally: CountUp::default(),
             level_complete_timer: 0.0,
+            level_modifier: LevelModifier::standard(),
+            wager_pending: false,
+            death_timer: 0.0,
             level_complete_message: String::new(),
             level_complete_submessage: String::new(),
             player_name_input: String::new(),
+            input: FrameInput::default(),
+            touch_keyboard: TouchKeyboard::default(),
             is_new_high_score: false,
-            leaderboard_scroll: 0.0,
+            global_rank: None,
+            share_card_requested: false,
+            pending_crash_report: None,
+            update_available: None,
             menu_time: 0.0,
-            mini_leaderboard_scroll: 0.0,
-            api_loading: false,
-            last_api_sync: 0.0,
-            api_receiver,
-            api_sender,
+            menu_idle_time: 0.0,
+            menu_vignette: MenuVignette::new(),
+            flavor,
+            is_demo: false,
+            autopilot: false,
+            assisted: false,
+            input_lead_ms: 0,
+            hardcore: false,
+            versus: None,
+            lane_setting: 1,
+            mutator_selection: MutatorSet::none(),
+            mutators: MutatorSet::none(),
+            mutator_cursor: 0,
+            lane_count: 1,
+            rng: StdRng::from_entropy(),
+            difficulty: Difficulty::Normal,
+            run_difficulty: Difficulty::Normal,
+            run_seed: None,
+            challenge: None,
+            challenge_menu: ChallengeMenu::new(),
+            settings,
+            profile: None,
+            profile_menu: ProfileMenu::default(),
+            kiosk: None,
+            admin: None,
+            tuning: Tuning::default(),
+            telemetry,
+            repo_feed: RepoFeed::new(),
+            invincible: false,
+            settings_cursor: 0,
+            replay: Replay::default(),
+            spectating: None,
+            benchmark: None,
+            calibration: None,
+            practice: None,
+            photo: None,
+            practice_level: 1,
         };
-        
-        // Trigger initial leaderboard sync on startup
-        game.sync_leaderboard_with_api();
-        
+
+        game.flush_telemetry(); // Anything left over from an offline session
+
         game
     }
 
-    pub fn update(&mut self, dt: f32) {
+    pub fn update(&mut self, dt: f32, input: FrameInput) {
+        self.input = input;
         // Process any pending API messages
         self.process_api_messages();
-        
-        match self.state {
-            GameState::MainMenu => {
-                self.menu_time += dt;
-                self.last_api_sync += dt;
-                self.update_mini_leaderboard_scroll(dt);
-                
-                // Sync with API every 30 seconds when on main menu
-                if self.last_api_sync > 30.0 && !self.api_loading {
-                    self.sync_leaderboard_with_api();
-                }
+        self.touch_keyboard.update(&self.input);
 
-                if is_key_pressed(KeyCode::Space) {
-                    self.start_game();
-                } else if is_key_pressed(KeyCode::L) {
-                    self.state = GameState::ViewingLeaderboard;
+        if self.settings.telemetry_enabled() {
+            self.telemetry.tick(dt);
+        }
+
+        // Any input during the attract-mode demo hands control back to the menu
+        if self.is_demo && self.input.any_pressed() {
+            self.is_demo = false;
+            self.reset_game();
+            return;
+        }
+
+        if self.kiosk_idle_reset(dt) {
+            return;
+        }
+
+        // A ghost run replays the recorded frame times so it unfolds exactly
+        // as it was played; live runs record theirs
+        let state = self.state();
+        let dt = if matches!(
+            state,
+            GameState::Playing | GameState::Dying | GameState::LevelComplete
+        ) {
+            match &mut self.spectating {
+                Some(playback) => match playback.next_frame() {
+                    Some(recorded) => recorded,
+                    None => {
+                        self.stop_spectating();
+                        return;
+                    }
+                },
+                None => {
+                    if self.records_replay() {
+                        self.replay.record(dt);
+                    }
+                    dt
                 }
             }
-            GameState::Playing => {
-                self.update_yeti(dt);
-                self.update_items(dt);
-                scoring::update_item_scoring(self, dt);
-                spawning::spawn_items(self, dt);
-                physics::check_collisions(self);
-                self.check_level_completion();
-                self.update_pipeline_animation(dt);
-                self.update_collision_grace(dt);
-                self.update_feedback_message(dt);
-                self.update_next_item_feedback();
-            }
-            GameState::LevelComplete => {
-                self.level_complete_timer -= dt;
-                if self.level_complete_timer <= 0.0 {
-                    self.state = GameState::Playing;
-                }
+        } else {
+            dt
+        };
+
+        self.clock.tick(dt, self.animation_time_scale());
+        self.update_particles(self.clock.delta());
+
+        // Only the top screen runs; anything it covers stays frozen
+        state.screen().update(self, dt);
+
+        self.dispatch_events();
+    }
+
+    // Let each presentation system react to what the simulation did this frame
+    fn dispatch_events(&mut self) {
+        for event in self.events.drain() {
+            self.stats.handle_event(&event);
+            feedback::handle_event(self, &event);
+            build_log::handle_event(self, &event);
+            effects::handle_event(self, &event);
+            quips::handle_event(self, &event);
+            rumble::handle_event(self, &event);
+            self.telemetry.handle_event(&event);
+            self.adaptive.handle_event(&event);
+            self.objectives.handle_event(&event);
+        }
+    }
+
+    pub fn update_yeti(&mut self, dt: f32) {
+        let actions = if let Some(playback) = &self.spectating {
+            playback.actions()
+        } else if self.autopilot_active() {
+            autopilot::decide(self, PLAYER_ONE)
+        } else if self.versus.is_some() {
+            PlayerActions::from_keys(&self.input, &[KeyCode::Space])
+        } else {
+            PlayerActions::from_input(&self.input)
+        };
+        if self.records_replay() {
+            self.replay.record_actions(actions);
+        }
+        self.apply_actions(PLAYER_ONE, actions);
+        // Focus would slow both players down, so versus goes without
+        if self.versus.is_none() {
+            self.focus.update(actions.focus, dt);
+        }
+
+        let gravity = self.gravity();
+        self.yeti.update(dt, gravity, &self.terrain);
+        self.yeti.update_sprite(&self.sprites, self.clock.time());
+
+        if self.versus.is_some() {
+            let actions = if self.autopilot_active() {
+                autopilot::decide(self, PLAYER_TWO)
+            } else {
+                PlayerActions::from_keys(&self.input, &[KeyCode::Up, KeyCode::W])
+            };
+            self.apply_actions(PLAYER_TWO, actions);
+
+            if let Some(versus) = &mut self.versus {
+                versus.player_two.update(dt, gravity, &self.terrain);
+                versus
+                    .player_two
+                    .update_sprite(&self.sprites, self.clock.time());
             }
-            GameState::GameOver => {
-                if self.is_new_high_score && is_key_pressed(KeyCode::Space) {
-                    self.state = GameState::NameInput;
-                } else if is_key_pressed(KeyCode::Space) {
-                    self.reset_game();
-                } else if is_key_pressed(KeyCode::L) {
-                    self.state = GameState::ViewingLeaderboard;
-                }
+        }
+        self.update_trails(dt);
+    }
+
+    // The trail follows the settings rather than anything in the run, so
+    // it's left out of the simulation proper
+    fn update_trails(&mut self, dt: f32) {
+        let speed = self.item_speed();
+        let length = self.settings.trail_length;
+        self.yeti.update_trail(dt, speed, length);
+        if let Some(versus) = &mut self.versus {
+            versus.player_two.update_trail(dt, speed, length);
+        }
+    }
+
+    pub fn apply_actions(&mut self, player: usize, actions: PlayerActions) {
+        let lane_count = self.lane_count;
+        let jump_velocity = self.tuning.jump_velocity;
+        let lead = self.input_lead_ms as f32 / 1000.0;
+        let Some(yeti) = self.player_yeti_mut(player) else {
+            return;
+        };
+
+        // Only a jump that leaves the ground counts, not one held mid-air
+        let jumped = yeti.press_jump(actions.jump, jump_velocity, lead);
+
+        let target = if actions.lane_up && yeti.lane + 1 < lane_count {
+            Some(yeti.lane + 1)
+        } else if actions.lane_down && yeti.lane > 0 {
+            Some(yeti.lane - 1)
+        } else {
+            None
+        };
+        if jumped {
+            self.events.emit(GameEvent::Jumped { player });
+        }
+        if let Some(lane) = target {
+            let ground_y = self.lane_ground_y(lane);
+            if let Some(yeti) = self.player_yeti_mut(player) {
+                yeti.shift_lane(lane, ground_y);
             }
-            GameState::NameInput => {
-                self.handle_name_input();
+        }
+    }
+
+    pub fn player_yeti(&self, player: usize) -> Option<&Yeti> {
+        match (player, &self.versus) {
+            (PLAYER_ONE, _) => Some(&self.yeti),
+            (PLAYER_TWO, Some(versus)) => Some(&versus.player_two),
+            _ => None,
+        }
+    }
+
+    fn player_yeti_mut(&mut self, player: usize) -> Option<&mut Yeti> {
+        match (player, &mut self.versus) {
+            (PLAYER_ONE, _) => Some(&mut self.yeti),
+            (PLAYER_TWO, Some(versus)) => Some(&mut versus.player_two),
+            _ => None,
+        }
+    }
+
+    // The player whose yeti is currently running in `lane`, if any
+    pub fn player_in_lane(&self, lane: usize) -> Option<(usize, &Yeti)> {
+        [PLAYER_ONE, PLAYER_TWO]
+            .into_iter()
+            .filter_map(|player| self.player_yeti(player).map(|yeti| (player, yeti)))
+            .find(|(_, yeti)| yeti.lane == lane)
+    }
+
+    // Who gets credit for items in `lane`: its versus player, or the solo player
+    pub fn lane_owner(&self, lane: usize) -> usize {
+        if self.versus.is_some() {
+            lane
+        } else {
+            PLAYER_ONE
+        }
+    }
+
+    pub fn lane_ground_y(&self, lane: usize) -> f32 {
+        if self.versus.is_some() {
+            versus::lane_ground_y(lane)
+        } else {
+            GROUND_Y - lane as f32 * config().lane_spacing
+        }
+    }
+
+    // Returns what was actually awarded, after the level's multiplier
+    pub fn award_points(&mut self, player: usize, points: u32) -> u32 {
+        match &mut self.versus {
+            Some(versus) => {
+                versus.scores[player] += points;
+                points
             }
-            GameState::ViewingLeaderboard => {
-                if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Space) {
-                    self.state = GameState::MainMenu;
-                }
-                self.handle_leaderboard_scroll(dt);
+            None => {
+                let points = self.mutators.apply(self.level_modifier.apply(points));
+                self.run.score += points;
+                points
             }
         }
     }
 
-    fn update_yeti(&mut self, dt: f32) {
-        if is_key_pressed(KeyCode::Space) || is_mouse_button_pressed(MouseButton::Left) {
-            self.yeti.jump();
+    pub fn complete_check(&mut self, player: usize) {
+        // Versus lanes mirror the same items, so level pacing follows player one's lane
+        if player == PLAYER_ONE {
+            self.run.complete_check();
         }
+    }
 
-        self.yeti.update(dt);
-        self.yeti.update_texture(&self.textures);
+    // Solo runs only: versus has a second player and demos aren't worth keeping.
+    // Adaptive pacing hangs on earlier runs, so those wouldn't play back true.
+    // Hardcore's tighter hitboxes aren't in the replay either.
+    fn records_replay(&self) -> bool {
+        !self.is_demo
+            && !self.hardcore
+            && self.versus.is_none()
+            && self.spectating.is_none()
+            && self.practice.is_none()
+            && !self.adaptive.active
     }
 
-    fn update_items(&mut self, dt: f32) {
-        for item in &mut self.items {
-            item.update(dt, self.level);
+    // Y or N on the level-complete gamble. The autopilot always plays it safe.
+    pub fn answer_wager(&mut self) -> PlayerActions {
+        let actions = if let Some(playback) = &self.spectating {
+            playback.actions()
+        } else if self.autopilot_active() {
+            PlayerActions {
+                decline: true,
+                ..Default::default()
+            }
+        } else {
+            PlayerActions::from_input(&self.input)
+        };
+        if self.records_replay() {
+            self.replay.record_actions(actions);
         }
+        actions
+    }
 
-        self.items.retain(|item| !item.is_off_screen());
+    // Attract mode always runs on autopilot; dev mode can switch it on for real runs
+    pub fn autopilot_active(&self) -> bool {
+        self.autopilot || self.is_demo
     }
 
-    fn check_level_completion(&mut self) {
-        if self.checks_completed >= self.checks_required {
+    pub fn update_items(&mut self, dt: f32) {
+        let speed = self.item_speed();
+        systems::scroll(self.items.iter_mut().map(|item| &mut item.body), dt, speed);
+        self.terrain.update(dt, speed);
+
+        self.items.retain(|item| !item.body.is_off_screen());
+    }
+
+    pub fn check_level_completion(&mut self) {
+        if self.run.level_done() {
             // Award level completion bonus
-            self.score += scoring::calculate_level_score_bonus(self.level);
+            self.run.score += scoring::calculate_level_score_bonus(self.run.level);
+
+            let completed = self.current_level.clone();
+            self.run.level += 1;
+            self.enter_level(self.run.level);
+            self.record_speedrun_split();
+
+            self.events.emit(GameEvent::LevelUp {
+                level: self.run.level,
+            });
 
-            self.level += 1;
-            self.checks_completed = 0;
-            self.checks_required = 5 + (self.level - 1) * 3;
-            self.spawn_rate = (INITIAL_SPAWN_RATE - (self.level as f32 * 0.1)).max(MIN_SPAWN_RATE);
+            // Versus matches keep running; both players just speed up
+            if self.versus.is_some() {
+                return;
+            }
 
             // Show level complete message
-            self.level_complete_message = format!("Issue #{} Done!", self.level - 1);
-            self.level_complete_submessage = "What else is assigned to me...".to_string();
+            self.level_complete_message = completed.complete_message();
+            self.level_complete_submessage = completed.complete_submessage;
+
+            self.level_complete_timer = config().level_complete_display_time;
+            self.set_state(GameState::LevelComplete);
+        }
+    }
 
-            self.level_complete_timer = 2.5; // Show for 2.5 seconds
-            self.state = GameState::LevelComplete;
+    // Every run keeps splits, but only one that counts can set the best
+    fn record_speedrun_split(&mut self) {
+        let was_finished = self.speedrun.is_finished();
+        self.speedrun.split();
+        if !was_finished && self.speedrun.is_new_best() && self.speedrun_eligible() {
+            self.settings.speedrun_best = self.speedrun.splits().to_vec();
+            self.settings.save();
         }
     }
 
+    // Any% is one lane, standard rules and no help: solo runs like that are
+    // the only ones the speedrun timer shows for or compares
+    pub fn speedrun_eligible(&self) -> bool {
+        self.records_replay()
+            && !self.autopilot
+            && !self.assisted
+            && self.difficulty == Difficulty::Normal
+            && self.lane_count == 1
+            && self.mutators.is_empty()
+    }
+
+    fn update_particles(&mut self, dt: f32) {
+        let movers = self
+            .particles
+            .iter_mut()
+            .map(|particle| (&mut particle.body, &mut particle.motion));
+        systems::fly(movers, dt);
+        systems::age(
+            self.particles
+                .iter_mut()
+                .map(|particle| &mut particle.lifetime),
+            dt,
+        );
+
+        self.particles
+            .retain(|particle| particle.lifetime.is_alive());
+
+        for popup in &mut self.score_popups {
+            popup.update(dt);
+        }
+        self.score_popups.retain(|popup| !popup.is_finished());
+    }
+
+    // Speed events included; what everything on screen actually moves at
+    pub fn item_speed(&self) -> f32 {
+        self.base_item_speed() * self.speed_events.multiplier()
+    }
+
+    // The level's pace, for planning ahead past any speed event
+    pub fn base_item_speed(&self) -> f32 {
+        let mutator = if self.mutators.contains(Mutator::DoubleSpeed) {
+            2.0
+        } else {
+            1.0
+        };
+        self.current_level.item_speed
+            * self.difficulty.speed_multiplier()
+            * self.tuning.item_speed_scale
+            * mutator
+    }
+
+    pub fn gravity(&self) -> f32 {
+        if self.mutators.contains(Mutator::LowGravity) {
+            self.tuning.gravity * 0.5
+        } else {
+            self.tuning.gravity
+        }
+    }
+
+    fn enter_level(&mut self, level: u32) {
+        self.current_level = self.levels.definition(level);
+        self.run.enter_level(self.current_level.checks_required);
+        self.level_modifier = LevelModifier::standard();
+        self.adaptive.enter_level(level);
+        // Like sprints, speed events are a solo extra
+        if self.versus.is_none() {
+            self.speed_events.plan_level(level, &mut self.rng);
+        }
+        self.update_spawn_rate();
+    }
+
+    // Also throws away the spawn plan, which was made under the old pacing
+    // and the old level's item pool
+    fn update_spawn_rate(&mut self) {
+        self.spawn_rate = self.current_level.spawn_rate
+            * self.difficulty.spawn_interval_multiplier()
+            * self.tuning.spawn_rate_scale
+            * self.adaptive.spawn_interval_multiplier();
+        self.spawn_schedule.replan();
+    }
+
+    // Dev tools: skip ahead (or back) without playing through
+    pub fn jump_to_level(&mut self, level: u32) {
+        self.run.level = level.max(1);
+        self.enter_level(self.run.level);
+    }
+
+    // Swap in new tuning mid-run; pacing picks it up immediately
+    pub fn set_tuning(&mut self, tuning: Tuning) {
+        self.tuning = tuning;
+        self.update_spawn_rate();
+    }
+
     pub fn start_game(&mut self) {
+        self.begin_run(
+            self.run_seed.unwrap_or_else(|| thread_rng().gen()),
+            self.run_difficulty,
+            self.lane_setting,
+            self.mutator_selection,
+        );
+        self.hardcore = self.settings.hardcore;
+
+        // Only regular runs adapt. An adaptive run can't rank, so booth and
+        // autopilot runs, which post their scores, keep to fixed pacing.
+        // Hardcore runs get no help of any kind.
+        if self.settings.adaptive_difficulty
+            && self.kiosk.is_none()
+            && !self.autopilot
+            && !self.hardcore
+        {
+            self.adaptive.start_run(true);
+            self.adaptive.enter_level(self.run.level);
+            self.update_spawn_rate();
+        }
+
+        // The assist stays ranked, so the score and replay carry it instead
+        self.assisted = self.settings.item_magnet && !self.hardcore;
+        self.replay.assisted = self.assisted;
+    }
+
+    // The board this run's score goes to
+    pub fn run_category(&self) -> Category {
+        if self.hardcore {
+            Category::Hardcore
+        } else {
+            Category::Standard
+        }
+    }
+
+    // How far inside the sprites hits are checked. Hardcore takes the
+    // margin away, so touching is crashing. There's only the one life
+    // either way, so that's all it changes about a crash.
+    pub fn collision_margin(&self) -> f32 {
+        if self.hardcore {
+            0.0
+        } else {
+            config().collision_grace_margin
+        }
+    }
+
+    // Same code, same item sequence: the seed drives every gameplay random roll
+    pub fn start_challenge(&mut self, challenge: Challenge) {
+        // Codes don't carry a lane count or mutators, so challenges always
+        // run one lane with standard rules
+        self.begin_run(
+            challenge.seed as u64,
+            challenge.difficulty,
+            1,
+            MutatorSet::none(),
+        );
+        self.challenge = Some(challenge);
+    }
+
+    fn begin_run(
+        &mut self,
+        seed: u64,
+        difficulty: Difficulty,
+        lane_count: usize,
+        mutators: MutatorSet,
+    ) {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.replay = Replay::new(seed, lane_count, mutators);
+        self.mutators = mutators;
+        self.assisted = false;
+        // Calibration is about the screen, not the run, so every kind gets it
+        self.input_lead_ms = self.settings.input_lead_ms;
+        self.replay.input_lead_ms = self.input_lead_ms;
+        self.hardcore = false;
+        self.spectating = None;
+        self.practice = None;
+        self.difficulty = difficulty;
+        self.challenge = None;
+        self.lane_count = lane_count.clamp(1, MAX_LANES);
+        self.yeti.lane = 0;
+        self.yeti.ground_y = GROUND_Y;
         self.yeti.reset();
         self.items.clear();
-        self.score = 0;
-        self.level = 1;
-        self.checks_completed = 0;
-        self.checks_required = 5;
-        self.spawn_timer = 0.0;
-        self.spawn_rate = INITIAL_SPAWN_RATE;
+        self.terrain.clear();
+        self.particles.clear();
+        self.score_popups.clear();
+        self.fatal_hit = None;
+        self.events.clear();
+        self.stats = RunStats::new();
+        self.speedrun = SpeedrunTimer::new(self.settings.speedrun_best.clone());
+        self.objectives = Objectives::new();
+        self.speed_events = SpeedTimeline::new();
+        self.adaptive.start_run(false);
+        self.run.reset();
+        self.enter_level(1);
+        self.spawn_schedule = SpawnSchedule::new();
+        self.focus = Focus::new();
         self.pipeline_scroll = 0.0;
         self.collision_grace = 0.0;
-        self.feedback_message = String::new();
-        self.feedback_timer = 0.0;
-        self.feedback_color = TEXT_PRIMARY;
+        self.feedback = FeedbackState::new();
+        self.build_log = BuildLog::new();
+        self.next_item = None;
+        self.hud_score = CountUp::default();
         self.level_complete_timer = 0.0;
+        self.wager_pending = false;
+        self.death_timer = 0.0;
         self.level_complete_message = String::new();
         self.is_new_high_score = false;
-        self.state = GameState::Playing;
+        self.global_rank = None;
+        self.is_demo = false;
+        self.versus = None;
+        self.reset_state(GameState::Playing);
+    }
+
+    pub fn start_versus(&mut self) {
+        self.begin_run(
+            thread_rng().gen(),
+            Difficulty::Normal,
+            1,
+            MutatorSet::none(),
+        );
+        self.versus = Some(VersusState::new());
+    }
+
+    // Replays only hold Normal-difficulty runs, the only kind uploaded
+    pub fn start_spectating(&mut self, playback: ReplayPlayback) {
+        self.begin_run(
+            playback.seed(),
+            Difficulty::Normal,
+            playback.lane_count(),
+            playback.mutators(),
+        );
+        self.assisted = playback.assisted();
+        self.input_lead_ms = playback.input_lead_ms();
+        self.spectating = Some(playback);
+    }
+
+    pub fn stop_spectating(&mut self) {
+        self.spectating = None;
+        self.leaderboard.ghost_status = GhostStatus::Idle;
+        self.reset_state(GameState::ViewingLeaderboard);
+    }
+
+    // Any level, no score kept, and a crash rewinds instead of ending it
+    pub fn start_practice(&mut self) {
+        self.begin_run(
+            thread_rng().gen(),
+            self.run_difficulty,
+            self.lane_setting,
+            MutatorSet::none(),
+        );
+        self.jump_to_level(self.practice_level);
+        self.practice = Some(Practice::new());
+    }
+
+    // Left and Right on the main menu
+    pub fn step_practice_level(&mut self, direction: i32) {
+        let level = self.practice_level as i32 + direction;
+        self.practice_level = level.clamp(1, practice::MAX_START_LEVEL as i32) as u32;
+    }
+
+    // Snapshots for rewinding, taken as the practice run plays
+    pub fn update_practice(&mut self) {
+        if let Some(mut practice) = self.practice.take() {
+            practice.record(self);
+            self.practice = Some(practice);
+        }
+    }
+
+    // In place of crashing during practice
+    pub fn rewind_practice(&mut self) {
+        let Some(mut practice) = self.practice.take() else {
+            return;
+        };
+        let seconds = practice.rewind(self);
+        self.practice = Some(practice);
+
+        self.particles.clear();
+        self.score_popups.clear();
+        self.feedback.clear_all();
+        self.show_feedback(Feedback::new(
+            format!("Rewound {:.1}s. Try that again!", seconds),
+            Severity::Warning,
+            Priority::Collision,
+            feedback::CRASH_DISPLAY_TIME,
+        ));
+    }
+
+    pub fn start_demo(&mut self) {
+        self.begin_run(
+            thread_rng().gen(),
+            Difficulty::Normal,
+            1,
+            MutatorSet::none(),
+        );
+        self.is_demo = true;
+    }
+
+    // Every lane, standard rules and a fixed seed, so runs compare
+    pub fn start_benchmark(&mut self) {
+        self.begin_run(
+            BENCHMARK_SEED,
+            Difficulty::Normal,
+            MAX_LANES,
+            MutatorSet::none(),
+        );
+        self.benchmark = Some(Benchmark::new());
+        self.reset_state(GameState::Benchmark);
+    }
+
+    // Someone playing a run of their own: not the demo, a ghost or the benchmark
+    fn is_mid_run(&self) -> bool {
+        let run_states = [
+            GameState::Playing,
+            GameState::Paused,
+            GameState::Dying,
+            GameState::LevelComplete,
+        ];
+        !self.is_demo
+            && self.spectating.is_none()
+            && self.benchmark.is_none()
+            && self.practice.is_none()
+            && run_states
+                .into_iter()
+                .any(|state| self.scenes.contains(state))
+    }
+
+    // The window's close button. Mid-run it asks first, unless turned off.
+    pub fn request_quit(&mut self) {
+        if self.settings.confirm_quit && self.is_mid_run() && self.state() != GameState::QuitConfirm
+        {
+            self.push_state(GameState::QuitConfirm);
+        } else {
+            self.quit_requested = true;
+        }
+    }
+
+    // The last thing before the window closes. A run in progress ends as if
+    // the yeti had crashed so its stats, unlocks and score aren't lost, and
+    // whatever is still being sent gets a moment to arrive.
+    pub fn shutdown(&mut self) {
+        const API_WAIT: Duration = Duration::from_secs(3);
+
+        if self.is_mid_run() {
+            self.game_over();
+        }
+
+        // Nobody got to type a name, so the profile's will do
+        let after_run = [
+            GameState::GameOver,
+            GameState::RunSummary,
+            GameState::NameInput,
+        ]
+        .into_iter()
+        .any(|state| self.scenes.contains(state));
+        if after_run && self.run_wants_name() {
+            if self.player_name_input.trim().is_empty() {
+                if let Some(profile) = &self.profile {
+                    self.player_name_input = profile.name.clone();
+                }
+            }
+            if validate_name(&self.player_name_input).is_ok() {
+                self.submit_high_score();
+            }
+        }
+
+        if self.settings.telemetry_enabled() {
+            self.telemetry.end_session();
+            self.flush_telemetry();
+        }
+
+        let unfinished = self.api.tasks.wait(API_WAIT);
+        if unfinished > 0 {
+            println!("Quitting with {} requests still in flight", unfinished);
+        }
+    }
+
+    // Challenge runs always ask for a name so the score can be posted.
+    // Kiosk runs were already posted under the name given up front.
+    pub fn run_wants_name(&self) -> bool {
+        (self.is_new_high_score || self.challenge.is_some()) && self.kiosk.is_none()
+    }
+
+    pub fn cycle_lane_setting(&mut self) {
+        self.lane_setting = self.lane_setting % MAX_LANES + 1;
+    }
+
+    // A booth visitor who walks away shouldn't leave their name or results
+    // up for the next one. Live runs end on their own. The name sticks
+    // around otherwise, so a quick retry doesn't mean typing it again.
+    fn kiosk_idle_reset(&mut self, dt: f32) -> bool {
+        let waiting = !self.is_demo
+            && !matches!(
+                self.state(),
+                GameState::MainMenu | GameState::Playing | GameState::Dying
+            );
+        let Some(kiosk) = &mut self.kiosk else {
+            return false;
+        };
+        if self.input.any_pressed() {
+            kiosk.idle_time = 0.0;
+            return false;
+        }
+
+        kiosk.idle_time += dt;
+        if kiosk.idle_time < kiosk.config.idle_reset {
+            return false;
+        }
+        self.player_name_input.clear();
+        if !waiting {
+            return false;
+        }
+
+        kiosk.idle_time = 0.0;
+        self.reset_game();
+        true
     }
 
     pub fn reset_game(&mut self) {
-        self.menu_time = 0.0;
-        self.mini_leaderboard_scroll = 0.0;
-        self.state = GameState::MainMenu;
+        self.reset_state(GameState::MainMenu);
     }
 
-    fn update_pipeline_animation(&mut self, dt: f32) {
-        let speed = PIPELINE_BASE_SPEED + (self.level as f32 * PIPELINE_SPEED_INCREASE);
-        self.pipeline_scroll += speed * dt;
+    pub fn update_pipeline_animation(&mut self, dt: f32) {
+        // The track holds still for reduced motion; items still move
+        if DesignContext::reduced_motion() {
+            return;
+        }
+
+        let config = config();
+        let speed =
+            config.pipeline_base_speed + (self.run.level as f32 * config.pipeline_speed_increase);
+        self.pipeline_scroll += speed * self.speed_events.multiplier() * dt;
 
         if self.pipeline_scroll >= PIPELINE_SCROLL_RESET {
             self.pipeline_scroll = 0.0;
         }
     }
 
-    fn update_collision_grace(&mut self, dt: f32) {
+    pub fn update_collision_grace(&mut self, dt: f32) {
         if self.collision_grace > 0.0 {
             self.collision_grace -= dt;
         }
     }
 
-    fn update_feedback_message(&mut self, dt: f32) {
-        if self.feedback_timer > 0.0 {
-            self.feedback_timer -= dt;
-            if self.feedback_timer <= 0.0 {
-                self.feedback_message.clear();
-            }
+    pub fn update_feedback(&mut self, dt: f32) {
+        self.feedback.update(dt);
+    }
+
+    // Sprints are a solo extra; versus is scored on the race alone
+    pub fn update_objectives(&mut self, dt: f32) {
+        if self.versus.is_some() {
+            return;
         }
+        self.objectives.tick(dt, self.run.level);
+        let bonus = self.objectives.take_reward();
+        if bonus > 0 {
+            self.award_points(PLAYER_ONE, bonus);
+        }
+    }
+
+    // Put a message in the feedback panel and keep it for the history screen
+    pub fn show_feedback(&mut self, feedback: Feedback) {
+        self.feedback.show(feedback, self.stats.time_survived);
     }
 
-    fn update_next_item_feedback(&mut self) {
-        let next_item = self
+    // Items still ahead of the yeti, nearest first
+    pub fn incoming_items(&self) -> impl Iterator<Item = &Item> {
+        let mut ahead: Vec<&Item> = self
             .items
             .iter()
-            .filter(|item| item.x + item.width > self.yeti.x)
-            .filter(|item| item.x < SCREEN_WIDTH)
-            .min_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
-
-        if let Some(item) = next_item {
-            let new_message = item.item_type.get_feedback_text().to_string();
-            if self.feedback_message != new_message {
-                self.feedback_message = new_message;
-                self.feedback_timer = FEEDBACK_DISPLAY_TIME;
-                self.feedback_color = TEXT_PRIMARY;
+            .filter(|item| item.body.right() > self.yeti.x)
+            .collect();
+        ahead.sort_by(|a, b| a.body.x.total_cmp(&b.body.x));
+        ahead.into_iter()
+    }
+
+    // Seconds until an item reaches the yeti at the current speed
+    pub fn seconds_until_reached(&self, item: &Item) -> f32 {
+        ((item.body.x - (self.yeti.x + YETI_WIDTH)) / self.item_speed()).max(0.0)
+    }
+
+    pub fn update_next_item(&mut self) {
+        let next_item = self.incoming_items().next().map(|item| item.item_type);
+        if next_item != self.next_item {
+            self.next_item = next_item;
+            if let Some(item_type) = next_item {
+                self.events.emit(GameEvent::NextItemAnnounced { item_type });
             }
-        } else {
-            self.feedback_message.clear();
+        }
+    }
+
+    pub fn start_dying(&mut self, player: usize) {
+        self.collision_grace = config().collision_grace_time;
+        self.death_timer = config().death_sequence_time;
+
+        // First to crash loses the versus match
+        if let Some(versus) = &mut self.versus {
+            versus.loser = Some(player);
+        }
+
+        let yeti = match (player, &mut self.versus) {
+            (PLAYER_TWO, Some(versus)) => &mut versus.player_two,
+            _ => &mut self.yeti,
+        };
+        yeti.stumble();
+        yeti.update_sprite(&self.sprites, self.clock.time());
+
+        self.set_state(GameState::Dying);
+    }
+
+    // Start in heavy slow motion and ease back to full speed as the yeti falls
+    fn death_time_scale(&self) -> f32 {
+        let config = config();
+        let progress = 1.0 - (self.death_timer / config.death_sequence_time).clamp(0.0, 1.0);
+        config.death_slow_motion_scale
+            + (1.0 - config.death_slow_motion_scale) * progress * progress
+    }
+
+    // How fast animations run on the current screen. Overlays freeze
+    // whatever is under them, so the clock stops with it.
+    fn animation_time_scale(&self) -> f32 {
+        let state = self.state();
+        if state.screen().is_overlay() {
+            return 0.0;
+        }
+        match state {
+            GameState::Playing => self.focus.time_scale(),
+            GameState::Dying => self.death_time_scale(),
+            _ => 1.0,
+        }
+    }
+
+    pub fn update_death_sequence(&mut self, dt: f32) {
+        self.death_timer -= dt;
+
+        let world_dt = dt * self.death_time_scale();
+
+        let gravity = self.gravity();
+        self.yeti.update(world_dt, gravity, &self.terrain);
+        if let Some(versus) = &mut self.versus {
+            versus.player_two.update(world_dt, gravity, &self.terrain);
+        }
+        self.update_trails(world_dt);
+        self.update_items(world_dt);
+        self.update_pipeline_animation(world_dt);
+        self.update_collision_grace(world_dt);
+
+        if self.death_timer <= 0.0 {
+            self.game_over();
         }
     }
 
     pub fn game_over(&mut self) {
-        self.collision_grace = COLLISION_GRACE_TIME;
+        let death = self.telemetry.take_death();
+
+        // Ghost runs hand back to the board they were started from
+        if self.spectating.is_some() {
+            self.stop_spectating();
+            return;
+        }
+
+        // Demo runs never reach the game-over screen or the leaderboard
+        if self.is_demo {
+            self.is_demo = false;
+            self.reset_game();
+            return;
+        }
+
+        // Practice only ends by quitting; nothing from it is kept
+        if self.practice.is_some() {
+            self.practice = None;
+            self.reset_game();
+            return;
+        }
+
+        // Versus matches go to their own results screen and skip the leaderboard
+        if self.versus.is_some() {
+            self.set_state(GameState::VersusResults);
+            return;
+        }
+
+        self.collision_grace = config().collision_grace_time;
+        self.adaptive.record_death(self.run.level);
 
         // Calculate final score with bonuses
         let final_score = scoring::calculate_total_score_with_bonuses(
-            self.score,
-            self.level,
-            self.checks_completed,
+            self.run.score,
+            self.run.level,
+            self.run.checks_completed,
         );
-        self.score = final_score;
+        self.stats.finish(self.run.score, final_score);
+        self.run.score = final_score;
+        self.save_last_run();
 
-        // Check if this is a new high score
-        self.is_new_high_score = self.leaderboard.is_high_score(self.score);
+        if !self.autopilot {
+            self.report_run_to_api();
+            self.record_telemetry_run(death);
+            self.record_profile_run();
+        }
 
-        self.state = GameState::GameOver;
+        // Booth players signed in before the run, so every run is posted
+        // straight away instead of only high scores
+        if self.kiosk.is_some() && !self.autopilot {
+            let high_score = self.run_high_score();
+            self.post_score(high_score);
+        }
+
+        // The board may have moved since the last sync; the check runs again
+        // when this one lands
+        self.api.request_sync();
+        if !self.autopilot {
+            self.fetch_global_rank();
+        }
+        self.is_new_high_score = self.run_makes_the_board();
+        if self.is_new_high_score {
+            self.events.emit(GameEvent::HighScore {
+                score: self.run.score,
+            });
+        }
+
+        // Counted up once, not again on coming back from the leaderboard
+        self.tally = CountUp::between(0, self.run.score);
+        self.set_state(GameState::GameOver);
     }
 
-    fn handle_name_input(&mut self) {
-        // Handle character input for name
-        if let Some(character) = get_last_key_pressed() {
-            match character {
-                KeyCode::Enter => {
-                    if !self.player_name_input.trim().is_empty() {
-                        self.submit_high_score();
-                    }
-                }
-                KeyCode::Backspace => {
-                    self.player_name_input.pop();
-                }
-                KeyCode::Escape => {
-                    self.reset_game();
-                }
-                _ => {
-                    // Convert keycode to character if possible
-                    if let Some(ch) = self.keycode_to_char(character) {
-                        if self.player_name_input.len() < 20 {
-                            // Limit name length
-                            self.player_name_input.push(ch);
-                        }
-                    }
+    // Autopilot and adaptive runs don't count
+    fn run_makes_the_board(&self) -> bool {
+        !self.autopilot
+            && !self.adaptive.active
+            && self
+                .leaderboard
+                .board_for(self.run_category())
+                .is_high_score(self.run.score)
+    }
+
+    // The finished run's rank and whether it's only the local estimate:
+    // the server's count when it has answered, otherwise the cached top of
+    // the board, which can't place anything below it
+    pub fn run_rank(&self) -> Option<(usize, bool)> {
+        if let Some(rank) = self.global_rank {
+            return Some((rank, false));
+        }
+        let board = self.leaderboard.board_for(self.run_category());
+        board.get_rank(self.run.score).map(|rank| (rank, true))
+    }
+
+    // The player's own entry on the standard board: the best the rank news
+    // follows, or else the best under the profile's name
+    pub fn own_entry_index(&self) -> Option<usize> {
+        let scores = &self.leaderboard.board.scores;
+        let standing = self.settings.board_standing.as_ref().and_then(|standing| {
+            scores
+                .iter()
+                .position(|entry| entry.name == standing.name && entry.score == standing.score)
+        });
+        standing.or_else(|| {
+            let name = &self.profile.as_ref()?.name;
+            scores.iter().position(|entry| &entry.name == name)
+        })
+    }
+
+    // The full leaderboard from the menu's mini board, on the player's own
+    // entry when they have one
+    pub fn view_own_entry(&mut self) {
+        self.leaderboard.show_category(Category::Standard);
+        self.leaderboard.open_at = self.own_entry_index();
+        self.set_state(GameState::ViewingLeaderboard);
+    }
+
+    fn fetch_global_rank(&self) {
+        if !self.api.client.is_enabled() {
+            return;
+        }
+        let category = self.run_category();
+        let score = self.run.score;
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            match api_client.fetch_rank(category, score).await {
+                Ok(rank) => {
+                    let _ = sender.send(ApiMessage::RankLoaded(category, score, rank));
                 }
+                Err(e) => println!("Failed to load leaderboard rank: {}", e),
             }
-        }
+        });
     }
 
-    fn keycode_to_char(&self, keycode: KeyCode) -> Option<char> {
-        match keycode {
-            KeyCode::A => Some('A'),
-            KeyCode::B => Some('B'),
-            KeyCode::C => Some('C'),
-            KeyCode::D => Some('D'),
-            KeyCode::E => Some('E'),
-            KeyCode::F => Some('F'),
-            KeyCode::G => Some('G'),
-            KeyCode::H => Some('H'),
-            KeyCode::I => Some('I'),
-            KeyCode::J => Some('J'),
-            KeyCode::K => Some('K'),
-            KeyCode::L => Some('L'),
-            KeyCode::M => Some('M'),
-            KeyCode::N => Some('N'),
-            KeyCode::O => Some('O'),
-            KeyCode::P => Some('P'),
-            KeyCode::Q => Some('Q'),
-            KeyCode::R => Some('R'),
-            KeyCode::S => Some('S'),
-            KeyCode::T => Some('T'),
-            KeyCode::U => Some('U'),
-            KeyCode::V => Some('V'),
-            KeyCode::W => Some('W'),
-            KeyCode::X => Some('X'),
-            KeyCode::Y => Some('Y'),
-            KeyCode::Z => Some('Z'),
-            KeyCode::Space => Some(' '),
-            _ => None,
+    // A sync that lands after the run ended, while its results are still up
+    // and before a name has been asked for, decides the high score afresh.
+    // Booth runs were posted already, so their board includes them.
+    fn recheck_high_score(&mut self) {
+        let results_up = matches!(self.state(), GameState::GameOver | GameState::RunSummary);
+        if results_up && self.kiosk.is_none() {
+            self.is_new_high_score = self.run_makes_the_board();
         }
     }
 
-    fn submit_high_score(&mut self) {
-        let high_score = HighScore::new(
+    // The finished run under the name typed for it
+    fn run_high_score(&self) -> HighScore {
+        HighScore::new(
             self.player_name_input.trim().to_string(),
-            self.score,
-            self.level,
-        );
+            self.run.score,
+            self.run.level,
+        )
+        .with_run_details(self.stats.time_survived, self.stats.score_samples())
+        .with_mutators(self.mutators)
+        .with_assist(self.assisted)
+        .with_category(self.run_category())
+        .with_metadata(RunMetadata {
+            duration: self.stats.time_survived,
+            collected: self.stats.total_collected(),
+            dodged: self.stats.dodges,
+            level_times: self.stats.level_times.clone(),
+            seed: self.replay.seed,
+            version: Version::current().to_string(),
+        })
+    }
 
-        // Submit to API with local fallback
-        self.submit_score_to_api(high_score);
+    pub fn submit_high_score(&mut self) {
+        let high_score = self.run_high_score();
 
         self.player_name_input.clear();
-        self.reset_game();
-    }
 
-    fn handle_leaderboard_scroll(&mut self, dt: f32) {
-        // Simple scroll handling - could be enhanced with mouse wheel support
-        if is_key_down(KeyCode::Up) {
-            self.leaderboard_scroll -= 100.0 * dt;
-        }
-        if is_key_down(KeyCode::Down) {
-            self.leaderboard_scroll += 100.0 * dt;
+        if let Some(challenge) = self.challenge {
+            // Challenge scores always go to the challenge board; the global
+            // board still only takes them if they made the cut
+            if self.is_new_high_score {
+                self.submit_score_to_api(high_score.clone());
+            }
+            self.submit_challenge_score(challenge.code(), high_score);
+            self.set_state(GameState::Challenge);
+            return;
         }
 
-        // Clamp scroll to reasonable bounds
-        self.leaderboard_scroll = self.leaderboard_scroll.clamp(0.0, 400.0);
+        // The board is next, open on the tab the score went to
+        self.leaderboard.show_category(high_score.category);
+        self.post_score(high_score);
+        self.reset_game();
     }
 
-    fn update_mini_leaderboard_scroll(&mut self, dt: f32) {
-        // Only scroll if we have more than 3 scores and have been on menu for 3+ seconds
-        if self.leaderboard.scores.len() > 3 && self.menu_time > 3.0 {
-            // Slow, smooth scroll
-            self.mini_leaderboard_scroll += 15.0 * dt;
-
-            // Reset scroll when we've scrolled through all extra entries
-            let max_scroll = (self.leaderboard.scores.len() - 3) as f32 * 20.0;
-            if self.mini_leaderboard_scroll > max_scroll + 60.0 {
-                self.mini_leaderboard_scroll = 0.0;
-            }
+    fn post_score(&mut self, high_score: HighScore) {
+        if self.settings.replay_upload && !self.replay.is_empty() {
+            self.upload_replay(&high_score);
         }
+
+        // Submit to API with local fallback
+        self.submit_score_to_api(high_score);
     }
 
     // Process messages from async API tasks
     fn process_api_messages(&mut self) {
-        while let Ok(message) = self.api_receiver.try_recv() {
+        for message in self.api.messages() {
             match message {
-                ApiMessage::LeaderboardSynced(updated_leaderboard) => {
-                    self.leaderboard = updated_leaderboard;
-                    self.api_loading = false;
+                ApiMessage::LeaderboardSynced(category, scores) => {
+                    self.api.loading = false;
+                    let Some(scores) = scores else {
+                        continue;
+                    };
+                    let board = self.leaderboard.board_for_mut(category);
+                    board.merge_remote_scores(scores);
+                    if let Some(admin) = &self.admin {
+                        admin.drop_removed(board);
+                    }
+                    if category.is_standard() {
+                        self.update_board_standing();
+                    }
+                    if category == self.run_category() {
+                        self.recheck_high_score();
+                    }
                     println!("Leaderboard synced successfully from API");
                 }
                 ApiMessage::ScoreSubmitted(success) => {
                     if success {
+                        // For the server's id on the entry, and anything else new
+                        self.api.request_sync();
                         println!("Score submitted successfully to API");
                     } else {
                         println!("Score submission failed, using local fallback");
                     }
                 }
+                ApiMessage::GhostLoaded(playback) => {
+                    // Only start if the player is still waiting on the board
+                    let waiting = self.leaderboard.ghost_status == GhostStatus::Loading
+                        && self.state() == GameState::ViewingLeaderboard;
+                    match playback {
+                        Some(playback) if waiting => {
+                            self.leaderboard.ghost_status = GhostStatus::Idle;
+                            self.start_spectating(playback);
+                        }
+                        Some(_) => self.leaderboard.ghost_status = GhostStatus::Idle,
+                        None => self.leaderboard.ghost_status = GhostStatus::Unavailable,
+                    }
+                }
+                ApiMessage::TelemetrySent(delivered) => {
+                    self.telemetry.batch_finished(delivered);
+                }
+                ApiMessage::EntryReported(id, sent) => {
+                    let status = if sent {
+                        ReportStatus::Sent
+                    } else {
+                        ReportStatus::Failed
+                    };
+                    self.leaderboard.reports.insert(id, status);
+                }
+                ApiMessage::RankLoaded(category, score, rank) => {
+                    // A later run may have finished in the meantime
+                    if category == self.run_category() && score == self.run.score {
+                        self.global_rank = Some(rank);
+                    }
+                }
+                ApiMessage::EntryDeleted(id, deleted) => {
+                    let outcome = if deleted { "deleted" } else { "NOT deleted" };
+                    admin::audit(&format!("server {} {}", outcome, id));
+                    if let Some(admin) = &mut self.admin {
+                        admin.notice = Some(if deleted {
+                            "Deleted from the server".to_string()
+                        } else {
+                            "Server delete failed, removed here only".to_string()
+                        });
+                    }
+                }
+                ApiMessage::CommunityStatsLoaded(stats) => {
+                    self.leaderboard.community_stats = Some(stats);
+                }
+                ApiMessage::ChallengeBoardLoaded(code, scores) => {
+                    let menu = &mut self.challenge_menu;
+                    if menu.board_code.as_deref() == Some(code.as_str()) {
+                        menu.board_loading = false;
+                        match scores {
+                            Some(scores) => menu.board = scores,
+                            None => println!("Challenge board unavailable, showing local results"),
+                        }
+                    }
+                }
+                ApiMessage::RepoEventsLoaded(events) => self.repo_feed.receive(events),
+                ApiMessage::LatestVersionLoaded(latest) => {
+                    if latest.is_newer_than(&Version::current()) {
+                        self.update_available = Some(latest);
+                    }
+                }
             }
         }
     }
 
-    // API sync methods
-    pub fn sync_leaderboard_with_api(&mut self) {
-        if self.api_loading {
-            return;
-        }
-        
-        self.api_loading = true;
-        self.last_api_sync = 0.0;
-        
-        let api_client = self.api_client.clone();
-        let sender = self.api_sender.clone();
-        let mut leaderboard = self.leaderboard.clone();
-        
-        // Spawn background thread with its own Tokio runtime
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async move {
-                let success = load_leaderboard_with_fallback(&api_client, &mut leaderboard).await;
-                
-                if success {
-                    // Send updated leaderboard back to main thread
-                    let _ = sender.send(ApiMessage::LeaderboardSynced(leaderboard));
-                } else {
-                    // Signal that loading is complete even if failed
-                    let _ = sender.send(ApiMessage::LeaderboardSynced(leaderboard));
+    // Real repo events only turn up in the player's own regular runs
+    pub fn weaves_repo_events(&self) -> bool {
+        self.settings.github_events
+            && !self.is_demo
+            && self.kiosk.is_none()
+            && self.challenge.is_none()
+            && self.versus.is_none()
+            && self.spectating.is_none()
+            && self.practice.is_none()
+    }
+
+    // Offline and mock-api builds keep everything on this machine, and the
+    // repo has to be set in the settings file first
+    pub fn poll_repo_events(&mut self) {
+        if !self.settings.github_events || cli::args().offline || cfg!(feature = "mock-api") {
+            return;
+        }
+        self.repo_feed.start_poll();
+        let repo = self.settings.github_repo.clone().unwrap_or_default();
+        if !github::is_valid_repo(&repo) {
+            println!("GitHub events: set github_repo to an owner/name repo in the settings file");
+            self.repo_feed.receive(None);
+            return;
+        }
+
+        let client = GithubClient::new(&repo, self.settings.github_token().as_deref());
+        let sender = self.api.sender();
+        self.api.tasks.spawn(async move {
+            let events = match client.recent_events().await {
+                Ok(events) => Some(events),
+                Err(e) => {
+                    println!("Failed to load GitHub events for {}: {}", repo, e);
+                    None
                 }
-            });
+            };
+            let _ = sender.send(ApiMessage::RepoEventsLoaded(events));
         });
     }
-    
+
     pub fn submit_score_to_api(&mut self, high_score: HighScore) {
         // Add to local leaderboard immediately for responsive UI
-        self.leaderboard.add_score(high_score.clone());
-        
-        let api_client = self.api_client.clone();
-        let sender = self.api_sender.clone();
-        let mut leaderboard = self.leaderboard.clone();
-        
-        // Spawn background thread with its own Tokio runtime
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async move {
-                let success = submit_score_with_fallback(&api_client, &high_score, &mut leaderboard).await;
-                
-                // Send result back to main thread
-                let _ = sender.send(ApiMessage::ScoreSubmitted(success));
-            });
+        let board = self.leaderboard.board_for_mut(high_score.category);
+        board.add_score(high_score.clone());
+        let mut leaderboard = board.clone();
+        self.track_board_standing(&high_score);
+
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            let success =
+                submit_score_with_fallback(&api_client, &high_score, &mut leaderboard).await;
+
+            // Send result back to main thread
+            let _ = sender.send(ApiMessage::ScoreSubmitted(success));
+        });
+    }
+
+    // Booth runs go up under whoever played them, so there's no one player to follow.
+    // The standing is only kept for the standard board.
+    fn track_board_standing(&mut self, high_score: &HighScore) {
+        if self.kiosk.is_some() || !high_score.category.is_standard() {
+            return;
+        }
+        if let Some(standing) =
+            BoardStanding::track(self.settings.board_standing.as_ref(), high_score)
+        {
+            self.settings.board_standing = Some(standing);
+            self.settings.save();
+        }
+    }
+
+    fn update_board_standing(&mut self) {
+        let Some(standing) = &mut self.settings.board_standing else {
+            return;
+        };
+        let previous = standing.rank;
+        if let Some(change) = standing.update(&self.leaderboard.board) {
+            println!("{}", change.message());
+            self.leaderboard.show_rank_change(change);
+        }
+        if standing.rank != previous {
+            self.settings.save();
+        }
+    }
+
+    fn upload_replay(&self, high_score: &HighScore) {
+        let api_client = self.api.client.clone();
+        let high_score = high_score.clone();
+        let replay = self.replay.encode();
+
+        self.api.tasks.spawn(async move {
+            if let Err(e) = api_client.upload_replay(&high_score, &replay).await {
+                println!("Failed to upload replay: {}", e);
+            }
+        });
+    }
+
+    // Experimental: download the best uploaded run and watch it play out
+    pub fn fetch_ghost_run(&mut self) {
+        if self.leaderboard.ghost_status == GhostStatus::Loading {
+            return;
+        }
+        self.leaderboard.ghost_status = GhostStatus::Loading;
+
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            let playback = match api_client.fetch_top_replay().await {
+                Ok(Some(record)) => Replay::decode(&record.replay).map(|replay| {
+                    ReplayPlayback::new(replay, record.replay_name, record.replay_score)
+                }),
+                Ok(None) => None,
+                Err(e) => {
+                    println!("Failed to load ghost run: {}", e);
+                    None
+                }
+            };
+            let _ = sender.send(ApiMessage::GhostLoaded(playback));
+        });
+    }
+
+    // Feed the community totals. Fire and forget: a lost report only makes
+    // the worldwide numbers a little low.
+    pub fn report_run_to_api(&self) {
+        let api_client = self.api.client.clone();
+        let (score, level, dodged) = (self.run.score, self.run.level, self.stats.dodges);
+
+        self.api.tasks.spawn(async move {
+            if let Err(e) = api_client.submit_run(score, level, dodged).await {
+                println!("Failed to report run stats: {}", e);
+            }
+        });
+    }
+
+    // Replays don't carry a difficulty, so only Normal runs play back true
+    fn save_last_run(&self) {
+        if !self.records_replay() || self.difficulty != Difficulty::Normal {
+            return;
+        }
+        if let Some(path) = replay::last_run_path() {
+            if let Err(e) = replay::save_run(&self.replay, self.run.score, &path) {
+                println!("Failed to save last run: {}", e);
+            }
+        }
+    }
+
+    fn record_profile_run(&mut self) {
+        let Some(profile) = &mut self.profile else {
+            return;
+        };
+        match self.stats.death_cause {
+            Some(DeathCause::Item(item_type)) => {
+                profile.record_death(Some(&self.catalog.get(item_type).id))
+            }
+            Some(DeathCause::Terrain(_)) => profile.record_death(None),
+            None => {}
+        }
+        let unlocked = profile.record_run(
+            self.run.score,
+            self.run.level,
+            self.stats.time_survived,
+            self.stats.total_collected(),
+            self.stats.dodges,
+        );
+        for mutator in unlocked {
+            println!("{} unlocked the {} mutator", profile.name, mutator.name());
+        }
+    }
+
+    // Switch whose best, settings and unlocks are in use. None is the guest.
+    pub fn select_profile(&mut self, profile: Option<Profile>) {
+        let folder = profile.as_ref().map(|profile| profile.folder.as_str());
+        let mut settings = Settings::load_for(folder);
+        if settings.fullscreen != self.settings.fullscreen {
+            settings.set_fullscreen(settings.fullscreen);
+        }
+        settings.apply();
+        self.settings = settings;
+        self.profile = profile;
+
+        // Whatever the last player picked may still be locked for this one
+        for mutator in Mutator::ALL {
+            if self.mutator_selection.contains(mutator) && !self.mutator_unlocked(mutator) {
+                self.mutator_selection.toggle(mutator);
+            }
+        }
+    }
+
+    // New profiles start from the settings in use, so a booth's display
+    // options carry over
+    pub fn create_profile(&mut self, name: &str) -> Result<(), String> {
+        let profile = profiles::create(name)?;
+        self.settings.for_profile(Some(&profile.folder)).save();
+        self.select_profile(Some(profile));
+        Ok(())
+    }
+
+    pub fn mutator_unlocked(&self, mutator: Mutator) -> bool {
+        self.profile
+            .as_ref()
+            .is_none_or(|profile| profile.is_unlocked(mutator))
+    }
+
+    // A profile's best is kept across launches; the guest's only lasts the session
+    pub fn personal_best(&self) -> u32 {
+        match &self.profile {
+            Some(profile) => profile.stats.best_score,
+            None => self.leaderboard.board.get_local_best_score(),
+        }
+    }
+
+    // The profile's nemesis by name, with its share of their crashes. The
+    // guest keeps no lifetime stats, so has none.
+    pub fn nemesis(&self) -> Option<(&str, f32)> {
+        let (id, share) = self.profile.as_ref()?.stats.nemesis()?;
+        let item_type = self.catalog.find(id)?;
+        Some((self.catalog.get(item_type).name.as_str(), share))
+    }
+
+    fn record_telemetry_run(&mut self, death: Option<ItemType>) {
+        if !self.settings.telemetry_enabled() {
+            return;
+        }
+        self.telemetry.record_run(TelemetryEvent::Run {
+            level: self.run.level,
+            seconds: self.stats.time_survived,
+            lanes: self.lane_count,
+            difficulty: self.difficulty.name().to_string(),
+            mutators: self.mutators.bits(),
+            death: death.map(|item_type| self.catalog.get(item_type).id.clone()),
+        });
+        self.flush_telemetry();
+    }
+
+    // Send the next batch if the player opted in. A failed send keeps it
+    // buffered on disk for the next try.
+    pub fn flush_telemetry(&mut self) {
+        if !self.settings.telemetry_enabled() || !self.api.client.is_enabled() {
+            return;
+        }
+        let Some(batch) = self.telemetry.take_batch() else {
+            return;
+        };
+
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            let delivered = match api_client.submit_telemetry(&batch).await {
+                Ok(()) => true,
+                Err(e) => {
+                    println!("Failed to send telemetry: {}", e);
+                    false
+                }
+            };
+            let _ = sender.send(ApiMessage::TelemetrySent(delivered));
+        });
+    }
+
+    // Another player's entry under the leaderboard cursor, not yet reported
+    // this session, unless that report failed
+    pub fn can_report_selected(&self) -> bool {
+        let Some(entry) = self.leaderboard.selected() else {
+            return false;
+        };
+        let status = self.leaderboard.report_status(entry);
+        self.api.client.is_enabled()
+            && entry.is_reportable()
+            && !matches!(status, Some(ReportStatus::Sending | ReportStatus::Sent))
+    }
+
+    pub fn report_selected_entry(&mut self) {
+        if !self.can_report_selected() {
+            return;
+        }
+        let Some(entry) = self.leaderboard.selected() else {
+            return;
+        };
+        let Some(id) = entry.id.clone() else {
+            return;
+        };
+
+        let name = entry.name.clone();
+        self.leaderboard
+            .reports
+            .insert(id.clone(), ReportStatus::Sending);
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            let sent = match api_client.report_entry(&id, &name).await {
+                Ok(()) => true,
+                Err(e) => {
+                    println!("Failed to report leaderboard entry: {}", e);
+                    false
+                }
+            };
+            let _ = sender.send(ApiMessage::EntryReported(id, sent));
+        });
+    }
+
+    // Admin mode: the entry under the cursor comes off the board on show,
+    // and off the server too when it came from there
+    pub fn remove_selected_entry(&mut self) {
+        let Some(entry) = self.leaderboard.selected().cloned() else {
+            return;
+        };
+        let Some(admin) = &mut self.admin else {
+            return;
+        };
+
+        let category = self.leaderboard.category;
+        admin.remember(&entry);
+        admin.drop_removed(self.leaderboard.board_for_mut(category));
+        admin::audit(&format!("removed {}", admin::describe(&entry, category)));
+
+        let Some(id) = entry.id.filter(|_| self.api.client.is_enabled()) else {
+            admin.notice = Some("Removed from this machine's board".to_string());
+            return;
+        };
+        admin.notice = Some("Deleting from the server...".to_string());
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            let deleted = match api_client.delete_score(&id).await {
+                Ok(()) => true,
+                Err(e) => {
+                    println!("Failed to delete leaderboard entry: {}", e);
+                    false
+                }
+            };
+            let _ = sender.send(ApiMessage::EntryDeleted(id, deleted));
+        });
+    }
+
+    // Queues the changelog screen; `since` narrows it to the releases after that version
+    pub fn show_whats_new(&mut self, since: Option<&str>) {
+        self.whats_new = changelog::lines_since(since);
+        if !self.whats_new.is_empty() {
+            self.push_state(GameState::WhatsNew);
+        }
+    }
+
+    // Once per update, covering everything since the version this player
+    // last ran. A fresh install just remembers the version.
+    pub fn show_whats_new_after_update(&mut self) {
+        let current = changelog::CURRENT_VERSION.to_string();
+        let previous = self.settings.last_seen_version.replace(current.clone());
+        if previous.as_ref() == Some(&current) {
+            return;
+        }
+        self.settings.save();
+        if let Some(previous) = previous {
+            self.show_whats_new(Some(&previous));
+        }
+    }
+
+    // Once at startup, in the background; nothing waits on the answer.
+    // Booth machines are updated by whoever runs them, so they never ask.
+    pub fn check_for_update(&self) {
+        if !self.settings.update_check || self.kiosk.is_some() || !self.api.client.is_enabled() {
+            return;
+        }
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            match api_client.fetch_latest_version().await {
+                Ok(Some(latest)) => {
+                    let _ = sender.send(ApiMessage::LatestVersionLoaded(latest));
+                }
+                Ok(None) => {}
+                Err(e) => println!("Failed to check for updates: {}", e),
+            }
+        });
+    }
+
+    // Called after the consent screen or the settings toggle changes the answer
+    pub fn apply_telemetry_consent(&mut self) {
+        if self.settings.telemetry_enabled() {
+            self.flush_telemetry();
+        } else {
+            self.telemetry.discard();
+        }
+    }
+
+    // Answer to the main menu's "last session crashed" prompt. The report
+    // stays on disk until it's actually delivered, so a failed send asks again
+    // next launch.
+    pub fn answer_crash_prompt(&mut self, send: bool) {
+        let Some(report) = self.pending_crash_report.take() else {
+            return;
+        };
+        if !send {
+            crash::discard_report();
+            return;
+        }
+
+        let api_client = self.api.client.clone();
+
+        self.api.tasks.spawn(async move {
+            match api_client.submit_crash_report(&report).await {
+                Ok(()) => crash::discard_report(),
+                Err(e) => println!("Failed to send crash report: {}", e),
+            }
+        });
+    }
+
+    pub fn submit_challenge_score(&mut self, code: String, high_score: HighScore) {
+        let menu = &mut self.challenge_menu;
+        if menu.board_code.as_deref() != Some(code.as_str()) {
+            menu.board.clear();
+        }
+
+        // Show the local result right away; the remote board replaces it on arrival
+        menu.board.push(high_score.clone());
+        menu.board.sort_by_key(|s| std::cmp::Reverse(s.score));
+        menu.board_code = Some(code.clone());
+        menu.board_loading = true;
+
+        let api_client = self.api.client.clone();
+        let sender = self.api.sender();
+
+        self.api.tasks.spawn(async move {
+            if let Err(e) = api_client.submit_challenge_score(&code, &high_score).await {
+                println!("Failed to submit challenge score: {}", e);
+            }
+
+            let scores = match api_client.fetch_challenge_leaderboard(&code).await {
+                Ok(scores) => Some(scores),
+                Err(e) => {
+                    println!("Failed to load challenge board: {}", e);
+                    None
+                }
+            };
+
+            let _ = sender.send(ApiMessage::ChallengeBoardLoaded(code, scores));
         });
     }
 }
@@ -0,0 +1,126 @@
+use super::events::GameEvent;
+use super::scoring;
+use super::versus::PLAYER_ONE;
+use crate::entities::{Feature, ItemCatalog, ItemType};
+
+const SCORE_SAMPLE_INTERVAL: f32 = 1.0;
+
+// What ended a run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeathCause {
+    Item(ItemType),
+    Terrain(Feature),
+}
+
+impl DeathCause {
+    pub fn name(&self, catalog: &ItemCatalog) -> String {
+        match self {
+            DeathCause::Item(item_type) => catalog.get(*item_type).name.clone(),
+            DeathCause::Terrain(Feature::Gap) => "Gap in the pipeline".to_string(),
+            DeathCause::Terrain(Feature::Platform { .. }) => "Raised section".to_string(),
+        }
+    }
+}
+
+// Everything the run summary needs, gathered from the event stream as the run
+// plays out. A streak counts items handled in a row (collected or dodged)
+// and breaks when a good item slips past.
+#[derive(Debug, Clone, Default)]
+pub struct RunStats {
+    pub collected: Vec<(ItemType, u32)>, // In the order first collected
+    pub dodges: u32,
+    pub missed: u32,
+    pub streak: u32,
+    pub longest_streak: u32,
+    pub level_bonuses: Vec<(u32, u32)>, // (level completed, bonus)
+    pub level_times: Vec<f32>,          // Seconds into the run each level was completed
+    pub final_bonus: u32,
+    pub time_survived: f32,
+    pub score_history: Vec<(f32, u32)>, // (seconds into the run, score)
+    pub death_cause: Option<DeathCause>,
+    sample_timer: f32,
+}
+
+impl RunStats {
+    pub fn new() -> Self {
+        Self {
+            score_history: vec![(0.0, 0)],
+            ..Self::default()
+        }
+    }
+
+    // Called once per simulated frame while the run is live
+    pub fn tick(&mut self, dt: f32, score: u32) {
+        self.time_survived += dt;
+        self.sample_timer += dt;
+        if self.sample_timer >= SCORE_SAMPLE_INTERVAL {
+            self.sample_timer -= SCORE_SAMPLE_INTERVAL;
+            self.score_history.push((self.time_survived, score));
+        }
+    }
+
+    pub fn finish(&mut self, score_before_bonus: u32, final_score: u32) {
+        self.final_bonus = final_score.saturating_sub(score_before_bonus);
+        self.score_history.push((self.time_survived, final_score));
+    }
+
+    pub fn score_samples(&self) -> Vec<u32> {
+        self.score_history.iter().map(|(_, score)| *score).collect()
+    }
+
+    pub fn total_collected(&self) -> u32 {
+        self.collected.iter().map(|(_, count)| count).sum()
+    }
+
+    pub fn total_level_bonus(&self) -> u32 {
+        self.level_bonuses.iter().map(|(_, bonus)| bonus).sum()
+    }
+
+    pub fn handle_event(&mut self, event: &GameEvent) {
+        match event {
+            // Versus has its own results screen; only player one's run is summarised
+            GameEvent::ItemCollected { player, .. }
+            | GameEvent::ItemDodged { player, .. }
+            | GameEvent::ItemMissed { player, .. }
+            | GameEvent::Crashed { player, .. }
+            | GameEvent::HitTerrain { player, .. }
+                if *player != PLAYER_ONE => {}
+            GameEvent::ItemCollected { item_type, .. } => {
+                match self.collected.iter_mut().find(|(kind, _)| kind == item_type) {
+                    Some((_, count)) => *count += 1,
+                    None => self.collected.push((*item_type, 1)),
+                }
+                self.extend_streak();
+            }
+            GameEvent::ItemDodged { .. } => {
+                self.dodges += 1;
+                self.extend_streak();
+            }
+            GameEvent::ItemMissed { .. } => {
+                self.missed += 1;
+                self.streak = 0;
+            }
+            GameEvent::LevelUp { level } => {
+                let completed = level - 1;
+                self.level_bonuses
+                    .push((completed, scoring::calculate_level_score_bonus(completed)));
+                self.level_times.push(self.time_survived);
+            }
+            GameEvent::Crashed { item_type, .. } => {
+                self.death_cause = Some(DeathCause::Item(*item_type));
+            }
+            GameEvent::HitTerrain { feature, .. } => {
+                self.death_cause = Some(DeathCause::Terrain(*feature));
+            }
+            GameEvent::HighScore { .. }
+            | GameEvent::NextItemAnnounced { .. }
+            | GameEvent::NearMiss { .. }
+            | GameEvent::Jumped { .. } => {}
+        }
+    }
+
+    fn extend_streak(&mut self) {
+        self.streak += 1;
+        self.longest_streak = self.longest_streak.max(self.streak);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::entities::components::{Body, Lifetime, Motion};
+
+// The per-frame work shared by every kind of entity, written against
+// components. Callers hand over whichever components they have, e.g.
+// `scroll(game.items.iter_mut().map(|item| &mut item.body), ..)`, and drop
+// anything finished themselves, since only they know what finished means.
+
+// The track carries everything on it left at one speed
+pub fn scroll<'a>(bodies: impl IntoIterator<Item = &'a mut Body>, dt: f32, speed: f32) {
+    for body in bodies {
+        body.x -= speed * dt;
+    }
+}
+
+pub fn fly<'a>(movers: impl IntoIterator<Item = (&'a mut Body, &'a mut Motion)>, dt: f32) {
+    for (body, motion) in movers {
+        motion.velocity.y += motion.gravity * dt;
+        motion.velocity.x *= 1.0 - (motion.drag * dt).min(1.0);
+        body.x += motion.velocity.x * dt;
+        body.y += motion.velocity.y * dt;
+        motion.rotation += motion.spin * dt;
+    }
+}
+
+pub fn age<'a>(lifetimes: impl IntoIterator<Item = &'a mut Lifetime>, dt: f32) {
+    for lifetime in lifetimes {
+        lifetime.remaining -= dt;
+    }
+}
@@ -0,0 +1,107 @@
+use crate::overrides::config;
+
+// Gameplay knobs the dev tuning panel can turn on a live run. Defaults come
+// from config.rs plus yeti.toml, so a fresh Tuning plays like a normal run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tuning {
+    pub gravity: f32,
+    pub jump_velocity: f32,
+    pub spawn_rate_scale: f32, // Multiplies each level's seconds between spawns
+    pub item_speed_scale: f32, // Multiplies each level's item speed
+    pub good_item_probability: f32,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self {
+            gravity: config().gravity,
+            jump_velocity: config().jump_velocity,
+            spawn_rate_scale: config().spawn_rate_scale,
+            item_speed_scale: config().item_speed_scale,
+            good_item_probability: config().good_item_probability,
+        }
+    }
+}
+
+impl Tuning {
+    // Ready to paste into yeti.toml
+    pub fn config_snippet(&self) -> String {
+        format!(
+            "jump_velocity = {:.1}\n\
+             gravity = {:.1}\n\
+             spawn_rate_scale = {:.2}\n\
+             item_speed_scale = {:.2}\n\
+             good_item_probability = {:.2}",
+            self.jump_velocity,
+            self.gravity,
+            self.spawn_rate_scale,
+            self.item_speed_scale,
+            self.good_item_probability,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuningParam {
+    Gravity,
+    JumpVelocity,
+    SpawnRate,
+    ItemSpeed,
+    GoodItemProbability,
+}
+
+impl TuningParam {
+    pub const ALL: [TuningParam; 5] = [
+        TuningParam::Gravity,
+        TuningParam::JumpVelocity,
+        TuningParam::SpawnRate,
+        TuningParam::ItemSpeed,
+        TuningParam::GoodItemProbability,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TuningParam::Gravity => "Gravity",
+            TuningParam::JumpVelocity => "Jump velocity",
+            TuningParam::SpawnRate => "Spawn interval",
+            TuningParam::ItemSpeed => "Item speed",
+            TuningParam::GoodItemProbability => "Good item chance",
+        }
+    }
+
+    pub fn value_text(&self, tuning: &Tuning) -> String {
+        match self {
+            TuningParam::Gravity => format!("{:.0}", tuning.gravity),
+            TuningParam::JumpVelocity => format!("{:.0}", tuning.jump_velocity),
+            TuningParam::SpawnRate => format!("x{:.2}", tuning.spawn_rate_scale),
+            TuningParam::ItemSpeed => format!("x{:.2}", tuning.item_speed_scale),
+            TuningParam::GoodItemProbability => {
+                format!("{:.0}%", tuning.good_item_probability * 100.0)
+            }
+        }
+    }
+
+    // One +/- press; `steps` is negative to go down
+    pub fn adjust(&self, tuning: &mut Tuning, steps: i32) {
+        let steps = steps as f32;
+        match self {
+            TuningParam::Gravity => {
+                tuning.gravity = (tuning.gravity + 50.0 * steps).max(50.0);
+            }
+            TuningParam::JumpVelocity => {
+                // Upward is negative; "+" should mean a bigger jump
+                tuning.jump_velocity = (tuning.jump_velocity - 25.0 * steps).min(-25.0);
+            }
+            TuningParam::SpawnRate => {
+                tuning.spawn_rate_scale = (tuning.spawn_rate_scale + 0.05 * steps).max(0.1);
+            }
+            TuningParam::ItemSpeed => {
+                tuning.item_speed_scale = (tuning.item_speed_scale + 0.05 * steps).max(0.1);
+            }
+            TuningParam::GoodItemProbability => {
+                tuning.good_item_probability =
+                    (tuning.good_item_probability + 0.05 * steps).clamp(0.0, 1.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::config::*;
+use crate::entities::Yeti;
+
+pub const PLAYER_ONE: usize = 0;
+pub const PLAYER_TWO: usize = 1;
+
+// Local two-player match: player one runs the usual bottom lane on `Game::yeti`,
+// player two runs a mirrored copy of the item stream on the top lane. Each
+// player owns exactly one lane, so lane and player indices line up.
+#[derive(Debug, Clone)]
+pub struct VersusState {
+    pub player_two: Yeti,
+    pub scores: [u32; 2],
+    pub loser: Option<usize>,
+}
+
+impl VersusState {
+    pub fn new() -> Self {
+        let mut player_two = Yeti::new();
+        player_two.ground_y = VERSUS_TOP_GROUND_Y;
+        player_two.lane = PLAYER_TWO;
+        player_two.reset();
+
+        Self {
+            player_two,
+            scores: [0, 0],
+            loser: None,
+        }
+    }
+
+    pub fn winner(&self) -> Option<usize> {
+        self.loser.map(|loser| 1 - loser)
+    }
+}
+
+pub fn lane_ground_y(lane: usize) -> f32 {
+    match lane {
+        PLAYER_TWO => VERSUS_TOP_GROUND_Y,
+        _ => GROUND_Y,
+    }
+}
+
+pub fn player_label(player: usize) -> &'static str {
+    match player {
+        PLAYER_ONE => "Player 1",
+        _ => "Player 2",
+    }
+}
@@ -1,27 +1,302 @@
+use crate::game::mutators::{Mutator, MutatorSet};
+use crate::scoring;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub const MAX_NAME_LENGTH: usize = 20; // In graphemes, not bytes
+pub const BOARD_SIZE: usize = 25; // Entries kept on the leaderboard
+
+// Shown in place of a name moderators have blanked
+pub const SANITIZED_NAME: &str = "Anonymous Yeti";
+
+// Generous ceilings for the plausibility check: 10 points a collect with
+// every multiplier stacked, and sprint bonuses paid out as fast as they come
+const MAX_POINTS_PER_ITEM: f32 = 40.0;
+const MAX_BONUS_PER_SECOND: f32 = 200.0;
+
+const CSV_HEADER: &str = "rank,name,score,level,date,mode";
+
+// Reserved so nobody can pose as the people running the board
+const RESERVED_NAMES: [&str; 5] = ["admin", "moderator", "mod", "system", "anonymous yeti"];
+
+// Set on an entry by moderators, server side, after a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Moderation {
+    Hidden,    // Left out of every board
+    Sanitized, // Still ranked, under SANITIZED_NAME
+}
+
+// Which board a run ranks on. Hardcore runs play without the grace margin
+// or any assist, so they only ever rank against each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    #[default]
+    Standard,
+    Hardcore,
+}
+
+impl Category {
+    pub const ALL: [Category; 2] = [Category::Standard, Category::Hardcore];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Standard => "Standard",
+            Category::Hardcore => "Hardcore",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Category::Standard => Category::Hardcore,
+            Category::Hardcore => Category::Standard,
+        }
+    }
+
+    pub fn is_standard(&self) -> bool {
+        *self == Category::Standard
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HighScore {
+    // The server's subject id; only remote entries have one, and only they can be reported
+    #[serde(default, rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub name: String,
     pub score: u32,
     pub level: u32,
     pub timestamp: DateTime<Utc>,
+    // Only known for runs finished on this machine; remote entries leave these empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub score_history: Vec<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutators: Vec<Mutator>,
+    // Played with the item magnet on; ranked, but marked on the board
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub assisted: bool,
+    #[serde(default, skip_serializing_if = "Category::is_standard")]
+    pub category: Category,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<Moderation>,
+    // Sent with the submission and read back from the board, so unlike
+    // `duration` above, remote entries from current clients have it too
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<RunMetadata>,
+}
+
+// What makes two copies of an entry the same one: the server's id once it
+// has one, otherwise the run itself. Two runs by one player that only share
+// a score are different entries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EntryKey {
+    Id(String),
+    Run {
+        name: String,
+        score: u32,
+        level: u32,
+        timestamp: i64, // Whole seconds, in case the server keeps less
+    },
+}
+
+// What a run looked like, for checking a score against and for the detail view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunMetadata {
+    pub duration: f32, // Seconds
+    pub collected: u32,
+    pub dodged: u32,
+    pub level_times: Vec<f32>, // Seconds into the run each level was completed
+    pub seed: u64,
+    pub version: String, // Game version that played it
+}
+
+impl RunMetadata {
+    // Catches scores no run could have earned: levels finished out of order
+    // or after the run ended, or more points than the items could give
+    pub fn is_plausible(&self, score: u32, level: u32) -> bool {
+        let splits_in_order = self.level_times.windows(2).all(|pair| pair[0] < pair[1]);
+        let splits_in_run = self
+            .level_times
+            .last()
+            .is_none_or(|last| *last <= self.duration);
+        if !splits_in_order || !splits_in_run || self.level_times.len() + 1 != level as usize {
+            return false;
+        }
+
+        let items = (self.collected + self.dodged) as f32;
+        let level_bonus: u32 = (1..level).map(scoring::calculate_level_score_bonus).sum();
+        let ceiling =
+            items * MAX_POINTS_PER_ITEM + self.duration * MAX_BONUS_PER_SECOND + level_bonus as f32;
+        score as f32 <= ceiling
+    }
 }
 
 impl HighScore {
     pub fn new(name: String, score: u32, level: u32) -> Self {
         Self {
-            name,
+            id: None,
+            name: sanitize_name(&name),
             score,
             level,
             timestamp: Utc::now(),
+            duration: None,
+            score_history: Vec::new(),
+            mutators: Vec::new(),
+            assisted: false,
+            category: Category::Standard,
+            moderation: None,
+            run: None,
+        }
+    }
+
+    // The name to draw, which isn't always the one submitted
+    pub fn display_name(&self) -> &str {
+        match self.moderation {
+            Some(Moderation::Sanitized) => SANITIZED_NAME,
+            _ => &self.name,
+        }
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.moderation == Some(Moderation::Hidden)
+    }
+
+    // Board order: the higher score, then the higher level, then whoever
+    // got there first. Only entries from the same second still tie, since
+    // that's all the server keeps.
+    pub fn board_order(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .cmp(&self.score)
+            .then(other.level.cmp(&self.level))
+            .then(self.timestamp.timestamp().cmp(&other.timestamp.timestamp()))
+    }
+
+    pub fn entry_key(&self) -> EntryKey {
+        match &self.id {
+            Some(id) => EntryKey::Id(id.clone()),
+            None => self.run_key(),
+        }
+    }
+
+    // The run alone, whatever id either copy has
+    pub fn run_key(&self) -> EntryKey {
+        EntryKey::Run {
+            name: self.name.clone(),
+            score: self.score,
+            level: self.level,
+            timestamp: self.timestamp.timestamp(),
+        }
+    }
+
+    // Entries from clients too old to send run metadata get the benefit of the doubt
+    pub fn is_plausible(&self) -> bool {
+        self.run
+            .as_ref()
+            .is_none_or(|run| run.is_plausible(self.score, self.level))
+    }
+
+    // Runs finished here carry their duration; anything else with an id
+    // came from another player
+    pub fn is_reportable(&self) -> bool {
+        self.id.is_some() && self.duration.is_none()
+    }
+
+    pub fn with_run_details(mut self, duration: f32, score_history: Vec<u32>) -> Self {
+        self.duration = Some(duration);
+        self.score_history = score_history;
+        self
+    }
+
+    pub fn with_mutators(mut self, mutators: MutatorSet) -> Self {
+        self.mutators = mutators.iter().collect();
+        self
+    }
+
+    pub fn with_assist(mut self, assisted: bool) -> Self {
+        self.assisted = assisted;
+        self
+    }
+
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.category = category;
+        self
+    }
+
+    pub fn with_metadata(mut self, run: RunMetadata) -> Self {
+        self.run = Some(run);
+        self
+    }
+
+    // How the run was played, in words: the board, then anything that
+    // changed the rules, like "Standard + Assisted + Mirror mode"
+    pub fn mode(&self) -> String {
+        let mut parts = vec![self.category.name()];
+        if self.assisted {
+            parts.push("Assisted");
         }
+        parts.extend(self.mutators.iter().map(|mutator| mutator.name()));
+        parts.join(" + ")
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Names can be any script, but no control characters and no more than
+// MAX_NAME_LENGTH graphemes, whatever they came from
+pub fn sanitize_name(raw: &str) -> String {
+    let cleaned: String = raw.chars().filter(|ch| !ch.is_control()).collect();
+    cleaned
+        .trim()
+        .graphemes(true)
+        .take(MAX_NAME_LENGTH)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+// Catches the usual junk before it reaches a public board; reports and the
+// server-side flags deal with whatever gets past. Returns the cleaned name.
+pub fn validate_name(raw: &str) -> Result<String, &'static str> {
+    let name = sanitize_name(raw);
+    let lower = name.to_lowercase();
+    if !name.chars().any(char::is_alphanumeric) {
+        return Err("Pick a name with a letter or number in it");
+    }
+    if RESERVED_NAMES.contains(&lower.as_str()) {
+        return Err("That name is reserved");
+    }
+    if lower.contains("://") || lower.contains("www.") || looks_like_domain(&lower) {
+        return Err("No links, please");
+    }
+    if has_long_run(&name) {
+        return Err("Too many of the same character in a row");
+    }
+    Ok(name)
+}
+
+fn looks_like_domain(name: &str) -> bool {
+    const TLDS: [&str; 8] = [".com", ".net", ".org", ".io", ".gg", ".ly", ".co", ".xyz"];
+    name.split_whitespace().any(|word| {
+        TLDS.iter()
+            .any(|tld| word.len() > tld.len() && word.ends_with(tld))
+    })
+}
+
+// "aaaaaaaa" and "!!!!!!!!" style keyboard mashing
+fn has_long_run(name: &str) -> bool {
+    const MAX_RUN: usize = 4;
+    let graphemes: Vec<&str> = name.graphemes(true).collect();
+    graphemes
+        .windows(MAX_RUN + 1)
+        .any(|window| window.iter().all(|g| *g == window[0]))
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Leaderboard {
     pub scores: Vec<HighScore>,
     pub local_best: Option<HighScore>,
@@ -29,10 +304,7 @@ pub struct Leaderboard {
 
 impl Leaderboard {
     pub fn new() -> Self {
-        Self {
-            scores: Vec::new(),
-            local_best: None,
-        }
+        Self::default()
     }
 
     pub fn add_score(&mut self, high_score: HighScore) {
@@ -41,18 +313,21 @@ impl Leaderboard {
             self.local_best = Some(high_score.clone());
         }
 
+        // A tie for last place on a full board stays off, whatever its level,
+        // as is_high_score says
+        if !self.is_high_score(high_score.score) {
+            return;
+        }
+
         // Add to scores and sort
         self.scores.push(high_score);
-        self.scores.sort_by(|a, b| b.score.cmp(&a.score));
+        self.scores.sort_by(HighScore::board_order);
 
-        // Keep only top 25
-        if self.scores.len() > 25 {
-            self.scores.truncate(25);
-        }
+        self.scores.truncate(BOARD_SIZE);
     }
 
     pub fn is_high_score(&self, score: u32) -> bool {
-        if self.scores.len() < 25 {
+        if self.scores.len() < BOARD_SIZE {
             return true;
         }
         score > self.scores.last().unwrap().score
@@ -62,34 +337,71 @@ impl Leaderboard {
         self.scores.iter().take(3).collect()
     }
 
+    // The rank shown for the entry at `index`. Equal scores share the rank
+    // of the first of them, whatever order the tie-break put them in; the
+    // flag says whether it's shared, for "#3=".
+    pub fn rank_at(&self, index: usize) -> Option<(usize, bool)> {
+        let score = self.scores.get(index)?.score;
+        let first = self.scores.partition_point(|entry| entry.score > score);
+        let shared = self
+            .scores
+            .get(first + 1)
+            .is_some_and(|next| next.score == score);
+        Some((first + 1, shared))
+    }
+
+    // Ties share the rank of the entry they tie with. A full board's last
+    // place can't be tied into, as add_score would drop the newcomer.
     pub fn get_rank(&self, score: u32) -> Option<usize> {
-        for (i, high_score) in self.scores.iter().enumerate() {
-            if score >= high_score.score {
-                return Some(i + 1);
-            }
-        }
-        if self.scores.len() < 25 {
-            Some(self.scores.len() + 1)
-        } else {
-            None
+        if !self.is_high_score(score) {
+            return None;
         }
+        let index = self
+            .scores
+            .iter()
+            .position(|high_score| score >= high_score.score)
+            .unwrap_or(self.scores.len());
+        Some(index + 1)
     }
 
     pub fn merge_remote_scores(&mut self, remote_scores: Vec<HighScore>) {
-        // Merge remote scores with local scores
-        let mut all_scores = self.scores.clone();
+        // Our runs the server hasn't given an id yet pick up the id of its
+        // copy, so both copies count as the same entry from here on
+        let mut local_scores = self.scores.clone();
+        for score in local_scores.iter_mut().filter(|score| score.id.is_none()) {
+            score.id = remote_scores
+                .iter()
+                .find(|remote| remote.id.is_some() && remote.run_key() == score.run_key())
+                .and_then(|remote| remote.id.clone());
+        }
+
+        // The server's moderation flags win over whatever our copy says,
+        // including for our own runs
+        let remote_moderation: HashMap<EntryKey, Option<Moderation>> = remote_scores
+            .iter()
+            .map(|score| (score.entry_key(), score.moderation))
+            .collect();
+
+        // Merge remote scores with local scores, in board order so the copy
+        // of a duplicate that's kept is its best one, and a second merge of
+        // the same scores keeps the same entries
+        let mut all_scores = local_scores;
         all_scores.extend(remote_scores);
+        // Between copies of one run, the one with the server's id comes first
+        all_scores.sort_by(|a, b| a.board_order(b).then(b.id.is_some().cmp(&a.id.is_some())));
 
-        // Remove duplicates based on name and score (in case of sync issues)
-        let mut seen = HashMap::new();
-        all_scores.retain(|score| {
-            let key = (score.name.clone(), score.score);
-            seen.insert(key, ()).is_none()
-        });
+        // Remove duplicates of the same entry (in case of sync issues)
+        let mut seen = HashSet::new();
+        all_scores.retain(|score| seen.insert(score.entry_key()));
+        for score in &mut all_scores {
+            if let Some(moderation) = remote_moderation.get(&score.entry_key()) {
+                score.moderation = *moderation;
+            }
+        }
+        all_scores.retain(|score| !score.is_hidden());
 
-        // Sort by score and keep top 25
-        all_scores.sort_by(|a, b| b.score.cmp(&a.score));
-        all_scores.truncate(25);
+        // Keep the top of the board
+        all_scores.truncate(BOARD_SIZE);
 
         self.scores = all_scores;
     }
@@ -97,4 +409,115 @@ impl Leaderboard {
     pub fn get_local_best_score(&self) -> u32 {
         self.local_best.as_ref().map_or(0, |score| score.score)
     }
+
+    // The board as a spreadsheet for event organizers, a header and then
+    // one line per entry, best first. Ties share a rank, as in rank_at.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+        for (index, entry) in self.scores.iter().enumerate() {
+            let (rank, _) = self.rank_at(index).unwrap_or((index + 1, false));
+            let fields = [
+                rank.to_string(),
+                csv_field(entry.display_name()),
+                entry.score.to_string(),
+                entry.level.to_string(),
+                entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                csv_field(&entry.mode()),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+// Quoted when it has to be. A name that starts like a formula gets a
+// leading quote mark, so a spreadsheet shows it instead of running it.
+fn csv_field(text: &str) -> String {
+    let text = if text.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", text)
+    } else {
+        text.to_string()
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+// The player's best entry on the global board and where it stood at the
+// last sync. Kept in the settings file, so a change while the game was
+// closed is still news at the next launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardStanding {
+    pub name: String,
+    pub score: u32,
+    pub rank: Option<usize>, // None until a sync places it, or once it falls off
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RankChange {
+    Climbed(usize),
+    Dropped {
+        rank: Option<usize>,       // None when pushed off the board entirely
+        beaten_by: Option<String>, // Newest entry now above the player
+    },
+}
+
+impl RankChange {
+    pub fn message(&self) -> String {
+        let (rank, beaten_by) = match self {
+            RankChange::Climbed(rank) => return format!("You climbed to #{}!", rank),
+            RankChange::Dropped { rank, beaten_by } => (rank, beaten_by),
+        };
+        let dropped = match rank {
+            Some(rank) => format!("You dropped to #{}", rank),
+            None => "You dropped off the board".to_string(),
+        };
+        match beaten_by {
+            Some(name) => format!("{} - {} beat you!", dropped, name),
+            None => format!("{}.", dropped),
+        }
+    }
+}
+
+impl BoardStanding {
+    // A new best replaces the tracked entry but keeps the old rank, so the
+    // next sync can announce the climb
+    pub fn track(previous: Option<&BoardStanding>, high_score: &HighScore) -> Option<Self> {
+        if previous.is_some_and(|standing| standing.score >= high_score.score) {
+            return None;
+        }
+        Some(Self {
+            name: high_score.name.clone(),
+            score: high_score.score,
+            rank: previous.and_then(|standing| standing.rank),
+        })
+    }
+
+    // Finds the entry on a freshly synced board and records its rank.
+    // Returns what changed, if there was a rank before to compare with.
+    pub fn update(&mut self, leaderboard: &Leaderboard) -> Option<RankChange> {
+        let position = leaderboard
+            .scores
+            .iter()
+            .position(|entry| entry.name == self.name && entry.score == self.score);
+        let rank = position.map(|index| index + 1);
+        let previous = std::mem::replace(&mut self.rank, rank)?;
+
+        match rank {
+            Some(rank) if rank < previous => Some(RankChange::Climbed(rank)),
+            Some(rank) if rank == previous => None,
+            _ => {
+                let above = &leaderboard.scores[..position.unwrap_or(leaderboard.scores.len())];
+                let beaten_by = above
+                    .iter()
+                    .filter(|entry| entry.name != self.name)
+                    .max_by_key(|entry| entry.timestamp)
+                    .map(|entry| entry.display_name().to_string());
+                Some(RankChange::Dropped { rank, beaten_by })
+            }
+        }
+    }
 }
@@ -0,0 +1,280 @@
+use crate::config::TOUCH_ENABLED;
+use crate::ui::viewport;
+use macroquad::prelude::*;
+use std::collections::{HashSet, VecDeque};
+use unicode_segmentation::UnicodeSegmentation;
+
+// One frame's keys, clicks and touches, read once at the top of the frame.
+// The window loop polls it and hands it to Game::update, so nothing the
+// game updates asks macroquad itself. A headless run hands it an empty one.
+#[derive(Debug, Clone, Default)]
+pub struct FrameInput {
+    pressed: HashSet<KeyCode>,
+    down: HashSet<KeyCode>,
+    typed: Vec<char>, // In typing order
+    clicked: Vec<MouseButton>,
+    held: Vec<MouseButton>,
+    mouse: (f32, f32), // In game coordinates
+    wheel: f32,
+    touched: bool,
+}
+
+impl FrameInput {
+    pub fn poll() -> Self {
+        const BUTTONS: [MouseButton; 3] =
+            [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+
+        // The char queue pops newest-first, so restore typing order
+        let mut typed = Vec::new();
+        while let Some(ch) = get_char_pressed() {
+            typed.push(ch);
+        }
+        typed.reverse();
+
+        Self {
+            pressed: get_keys_pressed(),
+            down: get_keys_down(),
+            typed,
+            clicked: BUTTONS
+                .into_iter()
+                .filter(|&button| is_mouse_button_pressed(button))
+                .collect(),
+            held: BUTTONS
+                .into_iter()
+                .filter(|&button| is_mouse_button_down(button))
+                .collect(),
+            mouse: viewport::mouse_position(),
+            wheel: mouse_wheel().1,
+            touched: !touches().is_empty(),
+        }
+    }
+
+    pub fn key_pressed(&self, key: KeyCode) -> bool {
+        self.pressed.contains(&key)
+    }
+
+    pub fn key_down(&self, key: KeyCode) -> bool {
+        self.down.contains(&key)
+    }
+
+    pub fn mouse_pressed(&self, button: MouseButton) -> bool {
+        self.clicked.contains(&button)
+    }
+
+    pub fn mouse_down(&self, button: MouseButton) -> bool {
+        self.held.contains(&button)
+    }
+
+    pub fn mouse_position(&self) -> (f32, f32) {
+        self.mouse
+    }
+
+    pub fn wheel(&self) -> f32 {
+        self.wheel
+    }
+
+    pub fn typed(&self) -> &[char] {
+        &self.typed
+    }
+
+    // Any key or click, for skipping ahead or waking an idle booth
+    pub fn any_pressed(&self) -> bool {
+        !self.pressed.is_empty()
+            || self.mouse_pressed(MouseButton::Left)
+            || self.mouse_pressed(MouseButton::Right)
+    }
+}
+
+// Everything the player can do during a run, independent of where it came from.
+// Keyboard/mouse, the autopilot, and anything else driving the yeti all produce
+// one of these per frame so the simulation only ever sees actions.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PlayerActions {
+    pub jump: bool,
+    pub lane_up: bool,
+    pub lane_down: bool,
+    pub focus: bool,  // Held, not pressed
+    pub accept: bool, // Answers to the level-complete gamble
+    pub decline: bool,
+}
+
+impl PlayerActions {
+    pub fn from_input(input: &FrameInput) -> Self {
+        Self {
+            jump: input.key_pressed(KeyCode::Space) || input.mouse_pressed(MouseButton::Left),
+            lane_up: input.key_pressed(KeyCode::Up),
+            lane_down: input.key_pressed(KeyCode::Down),
+            focus: input.key_down(KeyCode::LeftShift) || input.key_down(KeyCode::RightShift),
+            accept: input.key_pressed(KeyCode::Y),
+            decline: input.key_pressed(KeyCode::N),
+        }
+    }
+
+    // Keyboard-only bindings, for when players share one keyboard
+    pub fn from_keys(input: &FrameInput, jump_keys: &[KeyCode]) -> Self {
+        Self {
+            jump: jump_keys.iter().any(|&key| input.key_pressed(key)),
+            ..Self::default()
+        }
+    }
+}
+
+// Dev mode's input latency readout. Input is read once at the top of a
+// frame, and what it changed reaches the screen when that frame is swapped
+// in, which next_frame() waits for. So the time from one frame's start to
+// the next is how long a press took to show, after up to a frame spent
+// waiting to be read. The display's own lag comes on top; that's what the
+// calibration screen is for.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyProbe {
+    pending: Option<f64>,   // Start of the frame that read a jump press
+    samples: VecDeque<f32>, // Read to swapped in, in ms, oldest first
+    frame_ms: f32,
+}
+
+impl LatencyProbe {
+    const HISTORY: usize = 30;
+
+    // Call at the top of every frame
+    pub fn poll(&mut self, dt: f32, input: &FrameInput) {
+        let now = get_time();
+        if let Some(pressed) = self.pending.take() {
+            if self.samples.len() == Self::HISTORY {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(((now - pressed) * 1000.0) as f32);
+        }
+        if PlayerActions::from_input(input).jump {
+            self.pending = Some(now);
+        }
+        self.frame_ms = dt * 1000.0;
+    }
+
+    pub fn last_ms(&self) -> Option<f32> {
+        self.samples.back().copied()
+    }
+
+    pub fn average_ms(&self) -> Option<f32> {
+        (!self.samples.is_empty())
+            .then(|| self.samples.iter().sum::<f32>() / self.samples.len() as f32)
+    }
+
+    pub fn worst_ms(&self) -> Option<f32> {
+        self.samples.iter().copied().reduce(f32::max)
+    }
+
+    // The most a press can wait before it's read
+    pub fn frame_ms(&self) -> f32 {
+        self.frame_ms
+    }
+}
+
+// Apply this frame's typing and backspaces to `text`, keeping it under
+// `max_graphemes`. Returns whether anything changed.
+pub fn edit_text(input: &FrameInput, text: &mut String, max_graphemes: usize) -> bool {
+    let mut changed = false;
+
+    if input.key_pressed(KeyCode::Backspace) {
+        changed |= delete_grapheme(text);
+    }
+
+    for &ch in input.typed() {
+        if !ch.is_control() {
+            changed |= push_char(text, ch, max_graphemes);
+        }
+    }
+
+    changed
+}
+
+// Remove a whole grapheme so "é" or "日" goes in one press
+fn delete_grapheme(text: &mut String) -> bool {
+    match text.grapheme_indices(true).next_back() {
+        Some((index, _)) => {
+            text.truncate(index);
+            true
+        }
+        None => false,
+    }
+}
+
+fn push_char(text: &mut String, ch: char, max_graphemes: usize) -> bool {
+    text.push(ch);
+    // A combining mark can join the last grapheme, so count afterwards
+    if text.graphemes(true).count() > max_graphemes {
+        text.pop();
+        false
+    } else {
+        true
+    }
+}
+
+// A key on the on-screen keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtualKey {
+    Char(char), // Lowercase; shift decides how it's typed
+    Shift,
+    Backspace,
+    Space,
+    Done,
+    Cancel,
+}
+
+// The on-screen keyboard for touchscreens. It shows with TOUCH_ENABLED, or
+// once the screen has been touched until a real key gets pressed.
+#[derive(Debug, Clone, Default)]
+pub struct TouchKeyboard {
+    pub shift: bool, // One-shot, like a phone's
+    touched: bool,
+}
+
+impl TouchKeyboard {
+    pub fn update(&mut self, input: &FrameInput) {
+        if input.touched {
+            self.touched = true;
+        } else if !input.pressed.is_empty() {
+            self.touched = false;
+        }
+    }
+
+    pub fn is_shown(&self) -> bool {
+        TOUCH_ENABLED || self.touched
+    }
+
+    // Types a tapped key into `text`. Done and Cancel are left to the screen.
+    pub fn press(&mut self, key: VirtualKey, text: &mut String, max_graphemes: usize) {
+        match key {
+            VirtualKey::Char(ch) => {
+                let ch = if self.shift {
+                    ch.to_ascii_uppercase()
+                } else {
+                    ch
+                };
+                push_char(text, ch, max_graphemes);
+                self.shift = false;
+            }
+            VirtualKey::Shift => self.shift = !self.shift,
+            VirtualKey::Backspace => {
+                delete_grapheme(text);
+            }
+            VirtualKey::Space => {
+                push_char(text, ' ', max_graphemes);
+            }
+            VirtualKey::Done | VirtualKey::Cancel => {}
+        }
+    }
+}
+
+// Controller rumble. Macroquad reads no gamepads and has no rumble call, so
+// a backend has to come from elsewhere; until one does, it's NoHaptics.
+pub trait Haptics {
+    // `strength` from 0 to 1; a new pulse replaces one still going
+    fn rumble(&mut self, strength: f32, seconds: f32);
+}
+
+#[derive(Debug, Default)]
+pub struct NoHaptics;
+
+impl Haptics for NoHaptics {
+    fn rumble(&mut self, _strength: f32, _seconds: f32) {}
+}
@@ -0,0 +1,189 @@
+use crate::api::ApiError;
+use crate::entities::{ItemCatalog, ItemType};
+use chrono::{DateTime, Utc};
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::time::Duration;
+
+const API_BASE_URL: &str = "https://api.github.com";
+const TIMEOUT_SECONDS: u64 = 5;
+const PER_PAGE: u32 = 10; // Of each kind of event, newest first
+
+// The repo events that turn up in a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoEventKind {
+    CiPassed,
+    CiFailed,
+    PrMerged,
+}
+
+impl RepoEventKind {
+    // The mapping into the item catalog: the item an event spawns as
+    pub fn item_id(self) -> &'static str {
+        match self {
+            RepoEventKind::CiPassed => "ci_pass",
+            RepoEventKind::CiFailed => "ci_fail",
+            RepoEventKind::PrMerged => "pr_merged",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoEvent {
+    pub id: String, // Unique within the repo, so a later poll doesn't repeat it
+    pub kind: RepoEventKind,
+    pub headline: String, // What happened, for the feedback box
+    pub at: DateTime<Utc>,
+}
+
+impl RepoEvent {
+    // None if a mod pack has replaced the catalog without the item
+    pub fn item_type(&self, catalog: &ItemCatalog) -> Option<ItemType> {
+        catalog.find(self.kind.item_id())
+    }
+}
+
+// "owner/name", and nothing that could wander off to another API path
+pub fn is_valid_repo(repo: &str) -> bool {
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part != "."
+            && part != ".."
+            && part
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+    };
+    matches!(repo.split_once('/'), Some((owner, name)) if valid_part(owner) && valid_part(name))
+}
+
+// Reads a repo's finished workflow runs and merged pull requests. A token
+// is only needed for private repos, or to get past the hourly limit on
+// anonymous requests.
+#[derive(Clone)]
+pub struct GithubClient {
+    client: Client,
+    repo: String,
+    token: Option<String>,
+}
+
+impl GithubClient {
+    pub fn new(repo: &str, token: Option<&str>) -> Self {
+        // GitHub turns away requests without a user agent
+        let client = Client::builder()
+            .user_agent("YetiSetGo/1.0")
+            .timeout(Duration::from_secs(TIMEOUT_SECONDS))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        Self {
+            client,
+            repo: repo.to_string(),
+            token: token.map(str::to_string),
+        }
+    }
+
+    // Newest first
+    pub async fn recent_events(&self) -> Result<Vec<RepoEvent>, ApiError> {
+        let mut events = self.finished_runs().await?;
+        events.extend(self.merged_pulls().await?);
+        events.sort_by_key(|event| Reverse(event.at));
+        Ok(events)
+    }
+
+    // Cancelled and skipped runs say nothing either way, so they're left out
+    async fn finished_runs(&self) -> Result<Vec<RepoEvent>, ApiError> {
+        let path = format!("actions/runs?status=completed&per_page={}", PER_PAGE);
+        let runs: WorkflowRuns = self.get(&path).await?;
+        Ok(runs
+            .workflow_runs
+            .into_iter()
+            .filter_map(|run| {
+                let (kind, verb) = match run.conclusion.as_deref() {
+                    Some("success") => (RepoEventKind::CiPassed, "passed"),
+                    Some("failure") => (RepoEventKind::CiFailed, "failed"),
+                    _ => return None,
+                };
+                let workflow = run.name.unwrap_or_else(|| "CI".to_string());
+                let headline = match run.head_branch {
+                    Some(branch) => format!("{} {} on {}", workflow, verb, branch),
+                    None => format!("{} {}", workflow, verb),
+                };
+                Some(RepoEvent {
+                    id: format!("run-{}", run.id),
+                    kind,
+                    headline,
+                    at: run.updated_at,
+                })
+            })
+            .collect())
+    }
+
+    // Closed pull requests, of which only the merged ones count
+    async fn merged_pulls(&self) -> Result<Vec<RepoEvent>, ApiError> {
+        let path = format!(
+            "pulls?state=closed&sort=updated&direction=desc&per_page={}",
+            PER_PAGE
+        );
+        let pulls: Vec<PullRequest> = self.get(&path).await?;
+        Ok(pulls
+            .into_iter()
+            .filter_map(|pull| {
+                Some(RepoEvent {
+                    id: format!("pr-{}", pull.number),
+                    kind: RepoEventKind::PrMerged,
+                    headline: format!("PR #{} merged: {}", pull.number, pull.title),
+                    at: pull.merged_at?,
+                })
+            })
+            .collect())
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError> {
+        let url = format!("{}/repos/{}/{}", API_BASE_URL, self.repo, path);
+        let mut request = self
+            .client
+            .get(&url)
+            .header(ACCEPT, "application/vnd.github+json");
+        if let Some(token) = &self.token {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::ServerError(response.status().as_u16()));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRuns {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRun {
+    id: u64,
+    name: Option<String>,
+    head_branch: Option<String>,
+    conclusion: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullRequest {
+    number: u64,
+    title: String,
+    merged_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,3 @@
+// Optional hookups to services the player already uses. Nothing here talks
+// to the leaderboard server; each integration has its own client.
+pub mod github;
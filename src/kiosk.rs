@@ -0,0 +1,110 @@
+use macroquad::prelude::*;
+use qrcodegen::{QrCode, QrCodeEcc};
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+const FILE_NAME: &str = "kiosk.json";
+const QR_ROTATE_TIME: f32 = 8.0;
+const QR_BORDER: i32 = 2; // Quiet zone, in modules
+
+// kiosk.json. Its presence is what turns booth mode on.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KioskConfig {
+    pub idle_reset: f32, // Seconds without input before going back to the menu
+    pub leaderboard_url: Option<String>,
+}
+
+impl Default for KioskConfig {
+    fn default() -> Self {
+        Self {
+            idle_reset: 30.0,
+            leaderboard_url: None,
+        }
+    }
+}
+
+// Same places as yeti.toml: the working directory, then next to settings.json
+pub fn load_config() -> Option<KioskConfig> {
+    let mut paths = vec![PathBuf::from(FILE_NAME)];
+    if let Some(dir) = dirs::config_dir() {
+        paths.push(dir.join("yeti-set-go").join(FILE_NAME));
+    }
+
+    let (path, contents) = paths
+        .into_iter()
+        .find_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))?;
+    match serde_json::from_str(&contents) {
+        Ok(config) => {
+            println!("Kiosk mode on ({})", path.display());
+            Some(config)
+        }
+        Err(e) => {
+            println!("Ignoring {}: {}, using kiosk defaults", path.display(), e);
+            Some(KioskConfig::default())
+        }
+    }
+}
+
+// Booth mode while the game runs: the idle clock and the QR codes the menu
+// cycles through
+pub struct Kiosk {
+    pub config: KioskConfig,
+    pub idle_time: f32,
+    codes: Vec<(&'static str, Texture2D)>,
+}
+
+impl Kiosk {
+    pub fn new(config: KioskConfig) -> Self {
+        let mut links = Vec::new();
+        if let Some(url) = &config.leaderboard_url {
+            links.push(("Scan for the leaderboard", url.as_str()));
+        }
+        links.push(("Scan to play at home", env!("CARGO_PKG_REPOSITORY")));
+
+        let codes = links
+            .into_iter()
+            .filter_map(|(label, url)| Some((label, qr_texture(url)?)))
+            .collect();
+        Self {
+            config,
+            idle_time: 0.0,
+            codes,
+        }
+    }
+
+    // The code to show at `time`, with its caption
+    pub fn current_code(&self, time: f32) -> Option<&(&'static str, Texture2D)> {
+        if self.codes.is_empty() {
+            return None;
+        }
+        let index = (time / QR_ROTATE_TIME) as usize % self.codes.len();
+        self.codes.get(index)
+    }
+}
+
+// One pixel per module; drawn scaled up with nearest filtering
+fn qr_texture(url: &str) -> Option<Texture2D> {
+    let qr = match QrCode::encode_text(url, QrCodeEcc::Medium) {
+        Ok(qr) => qr,
+        Err(e) => {
+            println!("Can't make a QR code for {}: {:?}", url, e);
+            return None;
+        }
+    };
+
+    let size = qr.size() + QR_BORDER * 2;
+    let mut image = Image::gen_image_color(size as u16, size as u16, WHITE);
+    for y in 0..qr.size() {
+        for x in 0..qr.size() {
+            if qr.get_module(x, y) {
+                image.set_pixel((x + QR_BORDER) as u32, (y + QR_BORDER) as u32, BLACK);
+            }
+        }
+    }
+
+    let texture = Texture2D::from_image(&image);
+    texture.set_filter(FilterMode::Nearest);
+    Some(texture)
+}
@@ -0,0 +1,47 @@
+// The game as a library. The simulation is public and runs without a window:
+// the game state and its update loop, entities, spawning, scoring, and the
+// leaderboard client and records. So are the pieces a frontend draws and
+// plays it with, which main.rs, the windowed game, puts together each frame.
+// YetiGame wraps the same pieces for embedding the game in another
+// macroquad app.
+
+// The simulation
+pub mod entities;
+pub mod game;
+pub mod highscores;
+pub mod scoring;
+pub mod version;
+
+// What a frontend loads, draws and plays it with
+pub mod assets;
+pub mod audio;
+pub mod capture;
+pub mod cli;
+pub mod colors;
+pub mod config;
+pub mod crash;
+pub mod design;
+pub mod dev_mode;
+pub mod input;
+pub mod kiosk;
+pub mod overrides;
+pub mod perf;
+pub mod presence;
+pub mod profiles;
+pub mod settings;
+pub mod themes;
+pub mod tween;
+pub mod ui;
+
+mod api;
+mod atlas;
+mod changelog;
+mod integrations;
+mod mods;
+mod screens;
+mod telemetry;
+mod text_cache;
+mod widget;
+
+pub use config::window_conf;
+pub use widget::{WidgetInput, YetiGame};
@@ -1,39 +1,141 @@
-mod api;
-mod assets;
-mod colors;
-mod config;
-mod design;
-mod dev_mode;
-mod entities;
-mod game;
-mod highscores;
-mod ui;
-
-use assets::load_assets;
-use config::window_conf;
-use dev_mode::DevMode;
-use game::Game;
+use yeti_core::assets::load_assets;
+use yeti_core::audio::{Music, Voice};
+use yeti_core::capture::Capture;
+use yeti_core::dev_mode::{self, DevMode};
+use yeti_core::game::admin::AdminMode;
+use yeti_core::game::{replay, Game, GameState};
+use yeti_core::input::FrameInput;
+use yeti_core::kiosk::{self, KioskConfig};
+use yeti_core::perf::PerfOverlay;
+use yeti_core::presence::Presence;
+use yeti_core::ui::{Renderer, Viewport};
+use yeti_core::{cli, config, crash, overrides, profiles, window_conf};
 use macroquad::prelude::*;
-use ui::Renderer;
 
+// The standalone game: the window, the simulation under it, and the desktop
+// extras (dev mode, captures, presence) around both
 #[macroquad::main(window_conf)]
 async fn main() {
+    crash::install_hook();
+    overrides::loaded(); // Report yeti.toml before anything reads it
+    let args = cli::args();
     let mut game = Game::new();
-    let assets = load_assets().await;
+    game.settings.apply();
+    game.run_seed = args.seed;
+    if let Some(difficulty) = args.difficulty {
+        game.run_difficulty = difficulty;
+    }
+    // Nothing to send it with when the API is off; it stays on disk
+    if game.api.client.is_enabled() {
+        game.pending_crash_report = crash::pending_report();
+    }
+    if let Some(config) = kiosk::load_config().or_else(|| args.kiosk.then(KioskConfig::default)) {
+        game.kiosk = Some(kiosk::Kiosk::new(config));
+    }
+    if args.admin {
+        game.admin = Some(AdminMode::new());
+    }
+    game.api.start_sync();
+    game.check_for_update();
+    // Closing the window goes through Game::request_quit so nothing is lost
+    prevent_quit();
+    // Shared machines pick who's playing first. Booths sign in per run instead.
+    if let Some(name) = &args.profile {
+        match profiles::find_or_create(name) {
+            Ok(profile) => game.select_profile(Some(profile)),
+            Err(e) => eprintln!("Can't play as {}: {}", name, e),
+        }
+    } else if game.kiosk.is_none() && !profiles::list().is_empty() {
+        game.set_state(GameState::Profiles);
+    }
+    // --fullscreen is just for this launch: window_conf opened the window
+    // that way and the saved setting is left alone, until F11 or the
+    // settings screen changes it
+    let mut launch_fullscreen = args.fullscreen;
+
+    // Scripted launches go straight in and leave the consent prompt for next time
+    let mut scripted = false;
+    if let Some(path) = &args.replay {
+        match replay::load_run(path) {
+            Ok(playback) => {
+                game.start_spectating(playback);
+                scripted = true;
+            }
+            Err(e) => eprintln!("Can't play {}: {}", path.display(), e),
+        }
+    }
+    if args.benchmark {
+        game.start_benchmark();
+        scripted = true;
+    }
+    // Booth visitors didn't install anything, so there's nothing new to them
+    if !scripted && game.kiosk.is_none() {
+        game.show_whats_new_after_update();
+    }
+    // First launch: nothing is recorded until the player answers
+    if !scripted && game.settings.telemetry.is_none() {
+        game.push_state(GameState::TelemetryConsent);
+    }
+    let assets = load_assets(&game.catalog, &game.levels, &game.mods).await;
     game.textures = assets.textures;
+    game.sprites = assets.sprites;
     game.fonts = assets.fonts;
 
     let mut dev_mode = DevMode::new();
     dev_mode.mock_game.textures = game.textures.clone();
+    dev_mode.mock_game.sprites = game.sprites.clone();
     dev_mode.mock_game.fonts = game.fonts.clone();
     
     let renderer = Renderer::new();
+    let viewport = Viewport::new();
+    let mut perf_overlay = PerfOverlay::new();
+    let mut capture = Capture::new();
+    let mut presence = Presence::new();
+    let mut music = Music::load().await;
+    game.voice = Voice::load(&game.catalog).await;
 
     loop {
+        let frame_start = get_time();
         let dt = get_frame_time();
+        perf_overlay.record_frame(dt);
+
+        // Alt+Enter would also read as Enter on the current screen, so the
+        // game skips its update on the frame the window mode flips
+        let alt_down = is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt);
+        let toggle_fullscreen =
+            is_key_pressed(KeyCode::F11) || (alt_down && is_key_pressed(KeyCode::Enter));
+        if toggle_fullscreen {
+            let fullscreen = !(game.settings.fullscreen || launch_fullscreen);
+            launch_fullscreen = false;
+            game.settings.set_fullscreen(fullscreen);
+            game.settings.save();
+        }
+
+        // Booth operators leave with Ctrl+Shift+Q, which nobody hits by accident
+        let ctrl_down = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+        let shift_down = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        if game.kiosk.is_some() && ctrl_down && shift_down && is_key_pressed(KeyCode::Q) {
+            break;
+        }
+
+        // Booth visitors can't close the window at all
+        if is_quit_requested() && game.kiosk.is_none() {
+            game.request_quit();
+        }
+        if game.quit_requested {
+            break;
+        }
+
+        if is_key_pressed(KeyCode::F3) {
+            game.settings.perf_overlay = !game.settings.perf_overlay;
+            game.settings.save();
+        }
+
+        viewport.begin();
         
         // Check for dev mode toggle (D key) - only if dev mode is enabled in config
-        if config::DEV_MODE_ENABLED && is_key_pressed(KeyCode::D) {
+        let dev_keys = config::DEV_MODE_ENABLED && game.kiosk.is_none();
+        if dev_keys && is_key_pressed(KeyCode::D) {
             dev_mode.toggle();
         }
         
@@ -42,10 +144,15 @@ async fn main() {
             dev_mode.handle_input();
             
             // Override game state for dev mode
-            dev_mode.mock_game.state = dev_mode.get_current_game_state();
+            dev_mode.mock_game.scenes.reset(dev_mode.get_current_scenes());
             
             // Draw the mock game or custom screens
-            if matches!(dev_mode.current_screen, dev_mode::DevScreen::TypographyShowcase | dev_mode::DevScreen::ColorShowcase) {
+            if matches!(
+                dev_mode.current_screen,
+                dev_mode::DevScreen::TypographyShowcase
+                    | dev_mode::DevScreen::ColorShowcase
+                    | dev_mode::DevScreen::ConfigOverrides
+            ) {
                 dev_mode.draw_custom_screen(&game.fonts);
             } else {
                 renderer.draw(&dev_mode.mock_game);
@@ -53,12 +160,64 @@ async fn main() {
             
             // Draw dev mode overlay
             dev_mode.draw_dev_overlay(&game.fonts);
+
+            if game.settings.perf_overlay {
+                perf_overlay.draw(&dev_mode.mock_game, &game.fonts);
+            }
         } else {
             // Normal game loop
-            game.update(dt);
+            game.autopilot = dev_mode.autopilot;
+            let input = FrameInput::poll();
+            if dev_keys {
+                dev_mode.handle_live_input(&mut game, &input, dt);
+            }
+            if !toggle_fullscreen {
+                if let Some(dt) = dev_mode.simulation_dt(dt) {
+                    game.update(dt, input);
+                }
+            }
+            crash::record_context(&game);
+            presence.update(&game, dt);
+            music.update(&game, dt);
+            game.voice.update(dt, game.settings.voice_volume);
             renderer.draw(&game);
+            dev_mode.draw_live_overlay(&game, &game.fonts);
+
+            if game.settings.perf_overlay {
+                perf_overlay.draw(&game, &game.fonts);
+            }
+        }
+        // --benchmark quits once the report is printed, or the run is abandoned
+        let benchmark_done = game
+            .benchmark
+            .as_ref()
+            .is_none_or(|benchmark| benchmark.report.is_some());
+        if args.benchmark && benchmark_done {
+            break;
+        }
+        capture.draw(&game, dt);
+        viewport.present(game.settings.crt_filter);
+        capture.grab(&game, &viewport, dt);
+        if std::mem::take(&mut game.share_card_requested) {
+            capture.share_card(&game);
+        }
+        if std::mem::take(&mut game.leaderboard.export_requested) {
+            capture.export_leaderboard(&game);
+        }
+        let photo_requested = game
+            .photo
+            .as_mut()
+            .is_some_and(|photo| std::mem::take(&mut photo.export_requested));
+        if photo_requested {
+            capture.save_photo(&game, &renderer);
+        }
+
+        // The benchmark measures uncapped frames
+        if game.state() != GameState::Benchmark {
+            game.settings.limit_frame(frame_start);
         }
-        
         next_frame().await;
     }
+
+    game.shutdown();
 }
@@ -0,0 +1,190 @@
+use crate::assets::YETI_FRAMES;
+use crate::config::{ITEM_HEIGHT, ITEM_WIDTH};
+use crate::entities::{ItemCatalog, ItemDefinition};
+use crate::game::flavor::FlavorLines;
+use image::RgbaImage;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MODS_DIR: &str = "mods";
+const MANIFEST: &str = "mod.json";
+const SKIN_FRAME_SIZE: (u32, u32) = (60, 60); // Same as the built-in yeti frames
+
+// mods/<pack>/mod.json. Image paths are relative to the pack's folder.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    name: String,
+    #[serde(default)]
+    items: Vec<ModItem>,
+    #[serde(default)]
+    skin: HashMap<String, String>, // Yeti frame name -> image, e.g. "yeti_jump"
+    #[serde(default)]
+    flavor: FlavorLines, // Extra main menu lines, shaped like flavor.json
+}
+
+// An item definition minus the parts only the embedded assets can provide
+#[derive(Debug, Deserialize)]
+struct ModItem {
+    id: String,
+    name: String,
+    good: bool,
+    #[serde(default = "default_weight")]
+    weight: f32,
+    texture_file: String,
+    feedback: String,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+// One folder under mods/, for the mods screen. A pack with any problem is
+// skipped whole, so half of it never ends up in a run.
+#[derive(Debug, Clone)]
+pub struct ModPack {
+    pub name: String,
+    pub folder: String,
+    pub items: Vec<String>,                // Names of the items it added
+    pub skin_frames: Vec<String>,          // Yeti frames it replaced
+    pub flavor: FlavorLines,               // Main menu lines it added
+    pub error: Option<String>,             // Why it was skipped
+    pub sprites: Vec<(String, RgbaImage)>, // Atlas name and image, for load_assets
+}
+
+// Packs from each of `dirs` in turn. Items go straight into the catalog;
+// sprites wait for the atlas.
+pub fn load_mods(catalog: &mut ItemCatalog, dirs: &[PathBuf]) -> Vec<ModPack> {
+    let mut packs = Vec::new();
+
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        let mut folders: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.join(MANIFEST).is_file())
+            .collect();
+        folders.sort();
+
+        for folder in folders {
+            let pack = load_pack(&folder, catalog);
+            match &pack.error {
+                Some(error) => println!("Skipped mod {}: {}", pack.folder, error),
+                None => println!(
+                    "Loaded mod {} ({} items, {} skin frames)",
+                    pack.name,
+                    pack.items.len(),
+                    pack.skin_frames.len()
+                ),
+            }
+            packs.push(pack);
+        }
+    }
+
+    packs
+}
+
+// mods/ in the working directory, then next to settings.json
+pub fn mod_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![PathBuf::from(MODS_DIR)];
+    if let Some(dir) = dirs::config_dir() {
+        dirs.push(dir.join("yeti-set-go").join(MODS_DIR));
+    }
+    dirs
+}
+
+fn load_pack(folder: &Path, catalog: &mut ItemCatalog) -> ModPack {
+    let folder_name = folder
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut pack = ModPack {
+        name: folder_name.clone(),
+        folder: folder_name,
+        items: Vec::new(),
+        skin_frames: Vec::new(),
+        flavor: FlavorLines::default(),
+        error: None,
+        sprites: Vec::new(),
+    };
+
+    if let Err(error) = read_pack(folder, &mut pack, catalog) {
+        pack.items.clear();
+        pack.skin_frames.clear();
+        pack.flavor = FlavorLines::default();
+        pack.sprites.clear();
+        pack.error = Some(error);
+    }
+    pack
+}
+
+// Everything is checked before the catalog is touched
+fn read_pack(folder: &Path, pack: &mut ModPack, catalog: &mut ItemCatalog) -> Result<(), String> {
+    let text = fs::read_to_string(folder.join(MANIFEST)).map_err(|e| e.to_string())?;
+    let manifest: Manifest =
+        serde_json::from_str(&text).map_err(|e| format!("Invalid {}: {}", MANIFEST, e))?;
+    pack.name = manifest.name;
+
+    let mut definitions = Vec::new();
+    for item in manifest.items {
+        let texture = format!("mod:{}:{}", pack.folder, item.id);
+        let image = load_image(folder, &item.texture_file)?;
+        check_size(
+            &item.texture_file,
+            &image,
+            (ITEM_WIDTH as u32, ITEM_HEIGHT as u32),
+        )?;
+
+        pack.items.push(item.name.clone());
+        pack.sprites.push((texture.clone(), image));
+        definitions.push(ItemDefinition {
+            id: item.id,
+            name: item.name,
+            good: item.good,
+            weight: item.weight.max(0.0),
+            texture,
+            texture_file: item.texture_file,
+            feedback: item.feedback,
+            voice_clip: None,
+        });
+    }
+
+    let mut skin: Vec<_> = manifest.skin.into_iter().collect();
+    skin.sort();
+    for (frame, file) in skin {
+        if !YETI_FRAMES.iter().any(|(name, _)| *name == frame) {
+            return Err(format!("Unknown skin frame '{}'", frame));
+        }
+        let image = load_image(folder, &file)?;
+        check_size(&file, &image, SKIN_FRAME_SIZE)?;
+        pack.skin_frames.push(frame.clone());
+        pack.sprites.push((frame, image));
+    }
+
+    catalog.add_items(definitions).map_err(|e| e.to_string())?;
+    pack.flavor = manifest.flavor;
+    Ok(())
+}
+
+fn load_image(folder: &Path, file: &str) -> Result<RgbaImage, String> {
+    image::open(folder.join(file))
+        .map(|image| image.to_rgba8())
+        .map_err(|e| format!("Can't load {}: {}", file, e))
+}
+
+fn check_size(file: &str, image: &RgbaImage, (width, height): (u32, u32)) -> Result<(), String> {
+    if image.dimensions() == (width, height) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} is {}x{}, expected {}x{}",
+            file,
+            image.width(),
+            image.height(),
+            width,
+            height
+        ))
+    }
+}
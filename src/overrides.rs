@@ -0,0 +1,272 @@
+use crate::config::*;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+const FILE_NAME: &str = "yeti.toml";
+
+// The gameplay values in config.rs, with anything from yeti.toml layered on
+// top. Screen geometry, sprite sizes and lane count stay compile-time since
+// layouts, textures and the replay format depend on them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameConfig {
+    pub lane_spacing: f32,
+    pub lane_shift_speed: f32,
+    pub jump_velocity: f32,
+    pub gravity: f32,
+    pub collision_grace_margin: f32,
+    pub stumble_velocity: f32,
+    pub stumble_gravity: f32,
+    pub stumble_drift_speed: f32,
+    pub stumble_spin_speed: f32,
+    pub initial_spawn_rate: f32,
+    pub min_spawn_rate: f32,
+    pub speed_increase_per_level: f32,
+    pub base_item_speed: f32,
+    pub spawn_rate_scale: f32,
+    pub item_speed_scale: f32,
+    pub pipeline_base_speed: f32,
+    pub pipeline_speed_increase: f32,
+    pub attract_mode_idle_time: f32,
+    pub good_item_probability: f32,
+    pub adaptive_spawn_range: f32,
+    pub adaptive_good_item_range: f32,
+    pub collision_grace_time: f32,
+    pub level_complete_display_time: f32,
+    pub death_sequence_time: f32,
+    pub death_slow_motion_scale: f32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            lane_spacing: LANE_SPACING,
+            lane_shift_speed: LANE_SHIFT_SPEED,
+            jump_velocity: JUMP_VELOCITY,
+            gravity: GRAVITY,
+            collision_grace_margin: COLLISION_GRACE_MARGIN,
+            stumble_velocity: STUMBLE_VELOCITY,
+            stumble_gravity: STUMBLE_GRAVITY,
+            stumble_drift_speed: STUMBLE_DRIFT_SPEED,
+            stumble_spin_speed: STUMBLE_SPIN_SPEED,
+            initial_spawn_rate: INITIAL_SPAWN_RATE,
+            min_spawn_rate: MIN_SPAWN_RATE,
+            speed_increase_per_level: SPEED_INCREASE_PER_LEVEL,
+            base_item_speed: BASE_ITEM_SPEED,
+            spawn_rate_scale: SPAWN_RATE_SCALE,
+            item_speed_scale: ITEM_SPEED_SCALE,
+            pipeline_base_speed: PIPELINE_BASE_SPEED,
+            pipeline_speed_increase: PIPELINE_SPEED_INCREASE,
+            attract_mode_idle_time: ATTRACT_MODE_IDLE_TIME,
+            good_item_probability: GOOD_ITEM_PROBABILITY,
+            adaptive_spawn_range: ADAPTIVE_SPAWN_RANGE,
+            adaptive_good_item_range: ADAPTIVE_GOOD_ITEM_RANGE,
+            collision_grace_time: COLLISION_GRACE_TIME,
+            level_complete_display_time: LEVEL_COMPLETE_DISPLAY_TIME,
+            death_sequence_time: DEATH_SEQUENCE_TIME,
+            death_slow_motion_scale: DEATH_SLOW_MOTION_SCALE,
+        }
+    }
+}
+
+// One key yeti.toml may set, and the range it has to stay inside
+pub struct Overridable {
+    pub key: &'static str,
+    pub min: f32,
+    pub max: f32,
+    slot: fn(&mut GameConfig) -> &mut f32,
+}
+
+impl Overridable {
+    pub fn value(&self, config: &GameConfig) -> f32 {
+        let mut config = *config;
+        *(self.slot)(&mut config)
+    }
+}
+
+const fn key(
+    key: &'static str,
+    min: f32,
+    max: f32,
+    slot: fn(&mut GameConfig) -> &mut f32,
+) -> Overridable {
+    Overridable {
+        key,
+        min,
+        max,
+        slot,
+    }
+}
+
+pub const OVERRIDABLE: [Overridable; 25] = [
+    key("lane_spacing", 12.0, 60.0, |c| &mut c.lane_spacing),
+    key("lane_shift_speed", 50.0, 1000.0, |c| {
+        &mut c.lane_shift_speed
+    }),
+    key("jump_velocity", -800.0, -150.0, |c| &mut c.jump_velocity),
+    key("gravity", 200.0, 2500.0, |c| &mut c.gravity),
+    key("collision_grace_margin", 0.0, 12.0, |c| {
+        &mut c.collision_grace_margin
+    }),
+    key("stumble_velocity", -600.0, 0.0, |c| &mut c.stumble_velocity),
+    key("stumble_gravity", 200.0, 4000.0, |c| &mut c.stumble_gravity),
+    key("stumble_drift_speed", 0.0, 200.0, |c| {
+        &mut c.stumble_drift_speed
+    }),
+    key("stumble_spin_speed", -20.0, 20.0, |c| {
+        &mut c.stumble_spin_speed
+    }),
+    key("initial_spawn_rate", 0.3, 6.0, |c| {
+        &mut c.initial_spawn_rate
+    }),
+    key("min_spawn_rate", 0.2, 3.0, |c| &mut c.min_spawn_rate),
+    key("speed_increase_per_level", 0.0, 100.0, |c| {
+        &mut c.speed_increase_per_level
+    }),
+    key("base_item_speed", 50.0, 600.0, |c| &mut c.base_item_speed),
+    key("spawn_rate_scale", 0.25, 4.0, |c| &mut c.spawn_rate_scale),
+    key("item_speed_scale", 0.25, 4.0, |c| &mut c.item_speed_scale),
+    key("pipeline_base_speed", 0.0, 400.0, |c| {
+        &mut c.pipeline_base_speed
+    }),
+    key("pipeline_speed_increase", 0.0, 50.0, |c| {
+        &mut c.pipeline_speed_increase
+    }),
+    key("attract_mode_idle_time", 5.0, 600.0, |c| {
+        &mut c.attract_mode_idle_time
+    }),
+    key("good_item_probability", 0.0, 1.0, |c| {
+        &mut c.good_item_probability
+    }),
+    key("adaptive_spawn_range", 0.0, 0.5, |c| {
+        &mut c.adaptive_spawn_range
+    }),
+    key("adaptive_good_item_range", 0.0, 0.3, |c| {
+        &mut c.adaptive_good_item_range
+    }),
+    key("collision_grace_time", 0.0, 3.0, |c| {
+        &mut c.collision_grace_time
+    }),
+    key("level_complete_display_time", 0.5, 10.0, |c| {
+        &mut c.level_complete_display_time
+    }),
+    key("death_sequence_time", 0.2, 5.0, |c| {
+        &mut c.death_sequence_time
+    }),
+    key("death_slow_motion_scale", 0.05, 1.0, |c| {
+        &mut c.death_slow_motion_scale
+    }),
+];
+
+// What startup found, kept around for the dev mode screen
+#[derive(Debug, Clone, Default)]
+pub struct LoadedConfig {
+    pub values: GameConfig,
+    pub source: Option<PathBuf>,
+    pub overridden: Vec<&'static str>,
+    pub problems: Vec<String>, // Lines that were skipped, and why
+}
+
+impl LoadedConfig {
+    pub fn is_overridden(&self, key: &str) -> bool {
+        self.overridden.contains(&key)
+    }
+}
+
+static LOADED: OnceLock<LoadedConfig> = OnceLock::new();
+
+// Read once, the first time anything asks
+pub fn loaded() -> &'static LoadedConfig {
+    LOADED.get_or_init(load)
+}
+
+pub fn config() -> &'static GameConfig {
+    &loaded().values
+}
+
+// The working directory wins, so a portable install can carry its own file;
+// otherwise it sits next to settings.json
+fn config_paths() -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(FILE_NAME)];
+    if let Some(dir) = dirs::config_dir() {
+        paths.push(dir.join("yeti-set-go").join(FILE_NAME));
+    }
+    paths
+}
+
+fn load() -> LoadedConfig {
+    let Some((path, contents)) = config_paths()
+        .into_iter()
+        .find_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))
+    else {
+        return LoadedConfig::default();
+    };
+
+    let mut loaded = parse(&contents);
+    println!(
+        "Loaded {} override(s) from {}",
+        loaded.overridden.len(),
+        path.display()
+    );
+    for problem in &loaded.problems {
+        println!("{}: {}", path.display(), problem);
+    }
+    loaded.source = Some(path);
+    loaded
+}
+
+// The flat subset of TOML the file needs: `key = number` lines and comments.
+// [section] headers are allowed for grouping but don't change the keys.
+fn parse(contents: &str) -> LoadedConfig {
+    let mut loaded = LoadedConfig::default();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() || (line.starts_with('[') && line.ends_with(']')) {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            loaded
+                .problems
+                .push(format!("line {}: expected `key = value`", line_number));
+            continue;
+        };
+        let key = key.trim();
+
+        let Some(entry) = OVERRIDABLE.iter().find(|entry| entry.key == key) else {
+            loaded
+                .problems
+                .push(format!("line {}: unknown key `{}`", line_number, key));
+            continue;
+        };
+
+        let value = match value.trim().replace('_', "").parse::<f32>() {
+            Ok(value) if value.is_finite() => value,
+            _ => {
+                loaded.problems.push(format!(
+                    "line {}: `{}` is not a number",
+                    line_number,
+                    value.trim()
+                ));
+                continue;
+            }
+        };
+
+        if !(entry.min..=entry.max).contains(&value) {
+            loaded.problems.push(format!(
+                "line {}: {} = {} is outside {}..={}, keeping the default",
+                line_number, key, value, entry.min, entry.max
+            ));
+            continue;
+        }
+
+        *(entry.slot)(&mut loaded.values) = value;
+        if !loaded.is_overridden(entry.key) {
+            loaded.overridden.push(entry.key);
+        }
+    }
+
+    loaded
+}
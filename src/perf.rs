@@ -0,0 +1,149 @@
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{ColorTheme, DesignContext, GameFonts, TypographyStyle, UIComponent};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+use std::collections::VecDeque;
+
+const HISTORY_FRAMES: usize = 120;
+const GRAPH_CEILING_MS: f32 = 50.0;
+const TARGET_FRAME_MS: f32 = 1000.0 / 60.0;
+
+const PANEL_WIDTH: f32 = 170.0;
+const PANEL_HEIGHT: f32 = 96.0;
+const GRAPH_HEIGHT: f32 = 24.0;
+
+// F3 performance overlay. Works on whichever Game is being drawn, so it
+// covers dev mode's mock game as well as real runs.
+pub struct PerfOverlay {
+    frame_times: VecDeque<f32>, // Milliseconds, oldest first
+}
+
+impl Default for PerfOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PerfOverlay {
+    pub fn new() -> Self {
+        Self {
+            frame_times: VecDeque::with_capacity(HISTORY_FRAMES),
+        }
+    }
+
+    pub fn record_frame(&mut self, dt: f32) {
+        if self.frame_times.len() == HISTORY_FRAMES {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(dt * 1000.0);
+    }
+
+    pub fn draw(&self, game: &Game, fonts: &GameFonts) {
+        let x = SCREEN_WIDTH - PANEL_WIDTH - 6.0;
+        let y = 6.0;
+
+        draw_rectangle(x, y, PANEL_WIDTH, PANEL_HEIGHT, ThemeProvider::overlay());
+
+        let average_ms = self.average_frame_ms();
+        let fps_text = format!("FPS {}  ({:.1} ms)", get_fps(), average_ms);
+        UIComponent::draw_text(
+            &fps_text,
+            x + 6.0,
+            y + 14.0,
+            TypographyStyle::UICaption,
+            frame_theme(average_ms),
+            fonts,
+        );
+        self.draw_graph(x + 6.0, y + 20.0, PANEL_WIDTH - 12.0);
+
+        let mut line_y = y + 20.0 + GRAPH_HEIGHT + 14.0;
+        let mut line = |text: &str, theme: ColorTheme| {
+            UIComponent::draw_text(
+                text,
+                x + 6.0,
+                line_y,
+                TypographyStyle::UICaption,
+                theme,
+                fonts,
+            );
+            line_y += 14.0;
+        };
+
+        line(
+            &format!(
+                "Items {}  Particles {}",
+                game.items.len(),
+                game.particles.len()
+            ),
+            ColorTheme::Primary,
+        );
+        line(
+            &format!("Draw calls ~{}", estimated_draw_calls(game)),
+            ColorTheme::Primary,
+        );
+        line(&api_status(game), ColorTheme::Technical);
+    }
+
+    fn draw_graph(&self, x: f32, y: f32, width: f32) {
+        let palette = DesignContext::palette();
+        let bar_width = width / HISTORY_FRAMES as f32;
+
+        draw_rectangle_lines(x, y, width, GRAPH_HEIGHT, 1.0, METAL);
+        let target_y = y + GRAPH_HEIGHT - TARGET_FRAME_MS / GRAPH_CEILING_MS * GRAPH_HEIGHT;
+        draw_line(x, target_y, x + width, target_y, 1.0, METAL);
+
+        for (i, ms) in self.frame_times.iter().enumerate() {
+            let height = (ms / GRAPH_CEILING_MS).min(1.0) * GRAPH_HEIGHT;
+            let color = match frame_theme(*ms) {
+                ColorTheme::Success => palette.success(),
+                ColorTheme::Warning => palette.warning(),
+                _ => palette.error(),
+            };
+            draw_rectangle(
+                x + i as f32 * bar_width,
+                y + GRAPH_HEIGHT - height,
+                bar_width.max(1.0),
+                height,
+                color,
+            );
+        }
+    }
+
+    fn average_frame_ms(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32
+    }
+}
+
+fn frame_theme(ms: f32) -> ColorTheme {
+    if ms <= TARGET_FRAME_MS + 1.0 {
+        ColorTheme::Success
+    } else if ms <= TARGET_FRAME_MS * 2.0 {
+        ColorTheme::Warning
+    } else {
+        ColorTheme::Error
+    }
+}
+
+// macroquad batches consecutive draws that share a texture, so this counts
+// texture switches in playfield order plus one batch for shapes and text.
+// Items and yetis all come from the sprite atlas, so they share one batch.
+// Close enough to spot a runaway, not an exact GPU count.
+fn estimated_draw_calls(game: &Game) -> usize {
+    let particles = usize::from(!game.particles.is_empty());
+    game.current_level.background_layers.len() + 1 + 1 + particles + 1
+}
+
+fn api_status(game: &Game) -> String {
+    if !game.api.client.is_enabled() {
+        "API offline (local only)".to_string()
+    } else if game.api.loading {
+        "API syncing...".to_string()
+    } else {
+        "API idle".to_string()
+    }
+}
@@ -0,0 +1,235 @@
+use crate::design::format_thousands;
+use crate::game::{Game, GameState};
+
+// Discord only accepts an update every 15 seconds or so. Screen and level
+// changes go out straight away; score ticks wait for the next window.
+const SCORE_UPDATE_INTERVAL: f32 = 15.0;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Activity {
+    details: String, // Top line: what the player is doing
+    state: String,   // Second line: level and score
+    started_at: Option<i64>,
+}
+
+// Rich presence for the running game. The activity text is always worked out,
+// but it only leaves the process in builds with the `discord` feature and
+// with the setting on.
+pub struct Presence {
+    last_sent: Option<Activity>,
+    since_update: f32,
+    last_level: u32,
+    run_started_at: Option<i64>,
+    #[cfg(feature = "discord")]
+    sender: std::sync::mpsc::Sender<Option<Activity>>,
+}
+
+impl Default for Presence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Presence {
+    pub fn new() -> Self {
+        Self {
+            last_sent: None,
+            since_update: 0.0,
+            last_level: 0,
+            run_started_at: None,
+            #[cfg(feature = "discord")]
+            sender: discord::spawn(),
+        }
+    }
+
+    pub fn update(&mut self, game: &Game, dt: f32) {
+        self.since_update += dt;
+
+        let in_run = is_run_state(game.state()) && !game.is_demo;
+        if in_run && self.run_started_at.is_none() {
+            self.run_started_at = Some(chrono::Utc::now().timestamp());
+        } else if game.state() == GameState::MainMenu {
+            self.run_started_at = None;
+        }
+
+        let activity = if game.settings.discord_presence {
+            Some(activity_for(game, self.run_started_at))
+        } else {
+            None
+        };
+
+        let changed = match (&self.last_sent, &activity) {
+            (Some(last), Some(next)) => {
+                last.details != next.details
+                    || self.last_level != game.run.level
+                    || (last.state != next.state && self.since_update >= SCORE_UPDATE_INTERVAL)
+            }
+            (None, None) => false,
+            _ => true,
+        };
+        if !changed {
+            return;
+        }
+
+        self.since_update = 0.0;
+        self.last_level = game.run.level;
+        self.publish(activity.clone());
+        self.last_sent = activity;
+    }
+
+    #[cfg(feature = "discord")]
+    fn publish(&self, activity: Option<Activity>) {
+        let _ = self.sender.send(activity);
+    }
+
+    #[cfg(not(feature = "discord"))]
+    fn publish(&self, _activity: Option<Activity>) {}
+}
+
+fn is_run_state(state: GameState) -> bool {
+    matches!(
+        state,
+        GameState::Playing | GameState::Paused | GameState::Dying | GameState::LevelComplete
+    )
+}
+
+fn activity_for(game: &Game, started_at: Option<i64>) -> Activity {
+    let score_line = format!(
+        "Level {}, {} pts",
+        game.run.level,
+        format_thousands(u64::from(game.run.score))
+    );
+
+    let (details, state) = if game.is_demo {
+        ("In the menus".to_string(), "Watching the demo".to_string())
+    } else {
+        match game.state() {
+            GameState::Playing | GameState::Dying => {
+                let details = if game.versus.is_some() {
+                    "Versus: dodging merge conflicts".to_string()
+                } else if let Some(challenge) = &game.challenge {
+                    format!("Challenge {}", challenge.code())
+                } else {
+                    "Dodging merge conflicts".to_string()
+                };
+                (details, score_line)
+            }
+            GameState::Paused | GameState::FeedbackHistory | GameState::QuitConfirm => {
+                ("Paused".to_string(), score_line)
+            }
+            GameState::PhotoMode => ("Lining up a photo".to_string(), score_line),
+            GameState::LevelComplete => ("Shipped a release".to_string(), score_line),
+            GameState::GameOver
+            | GameState::RunSummary
+            | GameState::NameInput
+            | GameState::VersusResults => ("Build failed".to_string(), score_line),
+            GameState::ViewingLeaderboard | GameState::LeaderboardDetail => (
+                "Checking the leaderboard".to_string(),
+                leaderboard_line(game),
+            ),
+            GameState::MainMenu
+            | GameState::Challenge
+            | GameState::Settings
+            | GameState::Calibration
+            | GameState::Mutators
+            | GameState::Benchmark
+            | GameState::Mods
+            | GameState::TelemetryConsent
+            | GameState::Profiles
+            | GameState::SignIn
+            | GameState::WhatsNew => ("In the menus".to_string(), leaderboard_line(game)),
+        }
+    };
+
+    Activity {
+        details,
+        state,
+        started_at: if is_run_state(game.state()) {
+            started_at
+        } else {
+            None
+        },
+    }
+}
+
+fn leaderboard_line(game: &Game) -> String {
+    match game.leaderboard.board.scores.first() {
+        Some(top) => format!("High score {} pts", format_thousands(u64::from(top.score))),
+        None => "No high score yet".to_string(),
+    }
+}
+
+#[cfg(feature = "discord")]
+mod discord {
+    use super::Activity;
+    use discord_rich_presence::{activity, DiscordIpc, DiscordIpcClient};
+    use std::env;
+    use std::sync::mpsc;
+    use std::thread;
+
+    fn application_id() -> Option<String> {
+        // Compile-time id first, like the API key
+        if let Some(id) = option_env!("DISCORD_APPLICATION_ID") {
+            if !id.is_empty() {
+                return Some(id.to_string());
+            }
+        }
+        env::var("DISCORD_APPLICATION_ID").ok()
+    }
+
+    // The IPC socket blocks, so it lives on its own thread. Only the newest
+    // activity matters; a failed send drops the connection and the next
+    // update tries again, which covers Discord being started later.
+    pub fn spawn() -> mpsc::Sender<Option<Activity>> {
+        let (sender, receiver) = mpsc::channel::<Option<Activity>>();
+
+        thread::spawn(move || {
+            let Some(id) = application_id() else {
+                println!("Discord presence disabled: DISCORD_APPLICATION_ID is not set");
+                return;
+            };
+            let mut client: Option<DiscordIpcClient> = None;
+
+            while let Ok(mut update) = receiver.recv() {
+                while let Ok(newer) = receiver.try_recv() {
+                    update = newer;
+                }
+
+                if client.is_none() {
+                    let mut fresh = DiscordIpcClient::new(&id);
+                    if fresh.connect().is_err() {
+                        continue; // Discord isn't running
+                    }
+                    client = Some(fresh);
+                }
+                let Some(connected) = client.as_mut() else {
+                    continue;
+                };
+
+                let result = match &update {
+                    Some(next) => {
+                        let mut payload = activity::Activity::new()
+                            .details(next.details.as_str())
+                            .state(next.state.as_str());
+                        if let Some(start) = next.started_at {
+                            payload = payload.timestamps(activity::Timestamps::new().start(start));
+                        }
+                        connected.set_activity(payload)
+                    }
+                    None => connected.clear_activity(),
+                };
+                if let Err(e) = result {
+                    println!("Discord presence update failed: {}", e);
+                    let _ = connected.close();
+                    client = None;
+                }
+            }
+
+            if let Some(mut connected) = client {
+                let _ = connected.close();
+            }
+        });
+
+        sender
+    }
+}
@@ -0,0 +1,180 @@
+use crate::game::mutators::Mutator;
+use crate::highscores::{sanitize_name, validate_name};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+const PROFILE_FILE: &str = "profile.json";
+// Crashes needed before one item counts as a nemesis rather than bad luck
+const NEMESIS_MIN_DEATHS: u32 = 5;
+
+// Totals across every solo run played on the profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileStats {
+    pub runs: u32,
+    pub time_played: f32, // Seconds, counting runs only
+    pub best_score: u32,
+    pub best_level: u32,
+    pub items_collected: u32,
+    pub items_dodged: u32,
+    pub deaths: u32,                        // Runs ended by a crash, terrain included
+    pub item_deaths: BTreeMap<String, u32>, // Of those, by the id of the item hit
+}
+
+impl ProfileStats {
+    // The id of the item that has ended the most runs, and its share of all
+    // the crashes
+    pub fn nemesis(&self) -> Option<(&str, f32)> {
+        if self.deaths < NEMESIS_MIN_DEATHS {
+            return None;
+        }
+        self.item_deaths
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .map(|(id, count)| (id.as_str(), *count as f32 / self.deaths as f32))
+    }
+}
+
+// A named player on a shared machine. Each one lives in its own folder under
+// profiles/, next to a settings.json of its own.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub name: String,
+    #[serde(skip)]
+    pub folder: String,
+    pub stats: ProfileStats,
+    pub unlocked: Vec<Mutator>,
+}
+
+impl Profile {
+    pub fn is_unlocked(&self, mutator: Mutator) -> bool {
+        self.unlocked.contains(&mutator)
+    }
+
+    // A crash that ended a run: `item` is the id of what was hit, None for
+    // terrain. Saved along with the run by record_run().
+    pub fn record_death(&mut self, item: Option<&str>) {
+        self.stats.deaths += 1;
+        if let Some(id) = item {
+            *self.stats.item_deaths.entry(id.to_string()).or_default() += 1;
+        }
+    }
+
+    // Returns the mutators this run unlocked
+    pub fn record_run(
+        &mut self,
+        score: u32,
+        level: u32,
+        seconds: f32,
+        collected: u32,
+        dodged: u32,
+    ) -> Vec<Mutator> {
+        let stats = &mut self.stats;
+        stats.runs += 1;
+        stats.time_played += seconds;
+        stats.best_score = stats.best_score.max(score);
+        stats.best_level = stats.best_level.max(level);
+        stats.items_collected += collected;
+        stats.items_dodged += dodged;
+
+        let unlocked: Vec<Mutator> = Mutator::ALL
+            .into_iter()
+            .filter(|mutator| !self.is_unlocked(*mutator) && level >= mutator.unlock_level())
+            .collect();
+        self.unlocked.extend(&unlocked);
+        self.save();
+        unlocked
+    }
+
+    pub fn save(&self) {
+        let Some(dir) = profile_dir(&self.folder) else {
+            return;
+        };
+
+        let result = fs::create_dir_all(&dir).and_then(|_| {
+            let contents = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+            fs::write(dir.join(PROFILE_FILE), contents)
+        });
+        if let Err(e) = result {
+            println!("Failed to save profile {}: {}", self.name, e);
+        }
+    }
+}
+
+// Every profile with a readable file, by name
+pub fn list() -> Vec<Profile> {
+    let Some(entries) = profiles_dir().and_then(|dir| fs::read_dir(dir).ok()) else {
+        return Vec::new();
+    };
+
+    let mut profiles: Vec<Profile> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let contents = fs::read_to_string(entry.path().join(PROFILE_FILE)).ok()?;
+            let mut profile: Profile = serde_json::from_str(&contents).ok()?;
+            profile.folder = entry.file_name().to_string_lossy().into_owned();
+            Some(profile)
+        })
+        .collect();
+    profiles.sort_by_key(|profile| profile.name.to_lowercase());
+    profiles
+}
+
+pub fn create(name: &str) -> Result<Profile, String> {
+    let name = validate_name(name)?;
+    let folder = folder_name(&name);
+    if profile_dir(&folder).is_some_and(|dir| dir.exists()) {
+        return Err(format!("There's already a profile called {}", name));
+    }
+
+    let profile = Profile {
+        name,
+        folder,
+        ..Profile::default()
+    };
+    profile.save();
+    Ok(profile)
+}
+
+// For --profile: names match the way folders do, so "sam" finds "Sam"
+pub fn find_or_create(name: &str) -> Result<Profile, String> {
+    let folder = folder_name(&sanitize_name(name));
+    match list().into_iter().find(|profile| profile.folder == folder) {
+        Some(profile) => Ok(profile),
+        None => create(name),
+    }
+}
+
+pub fn profile_dir(folder: &str) -> Option<PathBuf> {
+    profiles_dir().map(|dir| dir.join(folder))
+}
+
+fn profiles_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("yeti-set-go").join("profiles"))
+}
+
+// Case-insensitive and safe on every filesystem, so "Sam" and "sam" share one
+fn folder_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() {
+                ch.to_lowercase().next().unwrap_or(ch)
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+// Everything the profile screen needs between visits
+#[derive(Debug, Clone, Default)]
+pub struct ProfileMenu {
+    pub profiles: Vec<Profile>,
+    pub cursor: usize,              // 0 is the guest, then profiles in order
+    pub name_input: Option<String>, // Some while a new profile is being named
+    pub error: Option<String>,
+}
@@ -0,0 +1,23 @@
+// Level bonuses and the end-of-run total. The leaderboard's plausibility
+// check leans on the same numbers.
+
+pub fn calculate_level_score_bonus(level: u32) -> u32 {
+    // Bonus points for completing a level
+    50 + (level * 25)
+}
+
+pub fn calculate_total_score_with_bonuses(
+    base_score: u32,
+    level: u32,
+    checks_completed: u32,
+) -> u32 {
+    let level_bonus = if level > 1 {
+        (1..level).map(calculate_level_score_bonus).sum::<u32>()
+    } else {
+        0
+    };
+
+    let completion_bonus = checks_completed * 2; // Small bonus for each check completed
+
+    base_score + level_bonus + completion_bonus
+}
@@ -0,0 +1,49 @@
+use super::playing::draw_run;
+use crate::game::benchmark;
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::benchmark as benchmark_ui;
+use macroquad::prelude::*;
+
+pub struct BenchmarkScreen;
+
+impl Screen for BenchmarkScreen {
+    fn update(&self, game: &mut Game, dt: f32) {
+        let finished = game
+            .benchmark
+            .as_ref()
+            .is_none_or(|benchmark| benchmark.report.is_some());
+
+        // Escape abandons a run in progress; any of these closes the results
+        if game.input.key_pressed(KeyCode::Escape)
+            || (finished
+                && (game.input.key_pressed(KeyCode::Space)
+                    || game.input.key_pressed(KeyCode::Enter)))
+        {
+            game.benchmark = None;
+            game.reset_game();
+            return;
+        }
+        if finished {
+            return;
+        }
+
+        benchmark::fill_scene(game);
+        game.update_items(dt);
+        game.update_pipeline_animation(dt);
+        game.yeti.update_sprite(&game.sprites, game.clock.time());
+
+        let report = game
+            .benchmark
+            .as_mut()
+            .and_then(|benchmark| benchmark.record(dt));
+        if let Some(report) = report {
+            println!("[benchmark] {}", report.summary());
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        draw_run(game);
+        benchmark_ui::draw_benchmark(game);
+    }
+}
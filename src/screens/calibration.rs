@@ -0,0 +1,53 @@
+use crate::game::calibration::Calibration;
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::input::PlayerActions;
+use crate::ui::calibration;
+use macroquad::prelude::*;
+
+// The tap test, opened from the input lead row in settings
+pub struct CalibrationScreen;
+
+impl Screen for CalibrationScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.calibration = Some(Calibration::new());
+    }
+
+    fn on_exit(&self, game: &mut Game) {
+        game.calibration = None;
+    }
+
+    fn update(&self, game: &mut Game, dt: f32) {
+        if game.input.key_pressed(KeyCode::Escape) {
+            game.pop_state();
+            return;
+        }
+        let Some(test) = &mut game.calibration else {
+            return;
+        };
+
+        if game.input.key_pressed(KeyCode::R) {
+            *test = Calibration::new();
+            return;
+        }
+        // Taps are read the way jumps are, so they're late in the same way
+        test.update(dt, PlayerActions::from_input(&game.input).jump);
+
+        if !test.is_finished() || !game.input.key_pressed(KeyCode::Enter) {
+            return;
+        }
+        if let Some(lead) = test.lead_ms() {
+            game.settings.input_lead_ms = lead;
+            game.settings.save();
+        }
+        game.pop_state();
+    }
+
+    fn draw(&self, game: &Game) {
+        calibration::draw_calibration(game);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,60 @@
+use crate::game::challenge::Challenge;
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::challenge;
+use macroquad::prelude::*;
+
+pub struct ChallengeScreen;
+
+impl Screen for ChallengeScreen {
+    fn on_enter(&self, game: &mut Game) {
+        // Drop anything typed elsewhere so it doesn't land in the code field
+        clear_input_queue();
+        game.challenge_menu.code_input.clear();
+        game.challenge_menu.error = None;
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        let menu = &mut game.challenge_menu;
+
+        if game.input.key_pressed(KeyCode::Escape) {
+            game.reset_game();
+            return;
+        }
+
+        if game.input.key_pressed(KeyCode::Left) {
+            menu.draft = Challenge::new(menu.draft.seed, menu.draft.difficulty.prev());
+        } else if game.input.key_pressed(KeyCode::Right) {
+            menu.draft = Challenge::new(menu.draft.seed, menu.draft.difficulty.next());
+        } else if game.input.key_pressed(KeyCode::Tab) {
+            menu.draft = Challenge::random(menu.draft.difficulty);
+        }
+
+        if game.input.key_pressed(KeyCode::Backspace) {
+            menu.code_input.pop();
+            menu.error = None;
+        }
+
+        for &ch in game.input.typed() {
+            if ch.is_ascii_alphanumeric() && menu.code_input.len() < 8 {
+                menu.code_input.push(ch.to_ascii_uppercase());
+                menu.error = None;
+            }
+        }
+
+        if game.input.key_pressed(KeyCode::Enter) {
+            if menu.code_input.is_empty() {
+                let draft = menu.draft;
+                game.start_challenge(draft);
+            } else if let Some(challenge) = Challenge::from_code(&menu.code_input) {
+                game.start_challenge(challenge);
+            } else {
+                menu.error = Some("That code doesn't look right".to_string());
+            }
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        challenge::draw_challenge_menu(game);
+    }
+}
@@ -0,0 +1,15 @@
+use super::playing::draw_run;
+use crate::game::scene::Screen;
+use crate::game::Game;
+
+pub struct DyingScreen;
+
+impl Screen for DyingScreen {
+    fn update(&self, game: &mut Game, dt: f32) {
+        game.update_death_sequence(dt);
+    }
+
+    fn draw(&self, game: &Game) {
+        draw_run(game);
+    }
+}
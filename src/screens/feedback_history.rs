@@ -0,0 +1,39 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::feedback_history;
+use macroquad::prelude::*;
+
+pub struct FeedbackHistoryScreen;
+
+impl Screen for FeedbackHistoryScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.feedback.history_scroll = 0;
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.input.key_pressed(KeyCode::Escape) || game.input.key_pressed(KeyCode::H) {
+            game.pop_state();
+            return;
+        }
+
+        let max_scroll = game
+            .feedback
+            .history
+            .len()
+            .saturating_sub(feedback_history::VISIBLE_LINES);
+        let feedback = &mut game.feedback;
+        if game.input.key_pressed(KeyCode::Up) {
+            feedback.history_scroll = feedback.history_scroll.saturating_sub(1);
+        } else if game.input.key_pressed(KeyCode::Down) {
+            feedback.history_scroll = (feedback.history_scroll + 1).min(max_scroll);
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        feedback_history::draw_feedback_history(&game.feedback, &game.fonts);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
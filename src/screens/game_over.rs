@@ -0,0 +1,38 @@
+use crate::game::scene::Screen;
+use crate::game::{Game, GameState};
+use crate::ui::{effects, menu};
+use macroquad::prelude::*;
+
+pub struct GameOverScreen;
+
+impl Screen for GameOverScreen {
+    fn update(&self, game: &mut Game, dt: f32) {
+        // The first press while the final score counts up just finishes it
+        game.tally.update(dt);
+        if game.tally.is_counting() && game.input.any_pressed() {
+            game.tally.finish();
+            return;
+        }
+
+        if game.input.key_pressed(KeyCode::Space) {
+            game.set_state(GameState::RunSummary);
+        } else if game.input.key_pressed(KeyCode::L) {
+            game.set_state(GameState::ViewingLeaderboard);
+        } else if game.input.key_pressed(KeyCode::C) {
+            game.share_card_requested = true;
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        menu::draw_game_over(
+            &game.run,
+            game.tally.value(),
+            game.is_new_high_score,
+            game.run_rank(),
+            game.run_category(),
+            game.challenge.as_ref(),
+            &game.fonts,
+        );
+        effects::draw_particles(&game.particles);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::game::scene::Screen;
+use crate::game::{Game, GameState, GhostStatus};
+use crate::ui::leaderboard::{self, LIST_VISIBLE_HEIGHT, ROW_HEIGHT};
+use crate::ui::widgets;
+use macroquad::prelude::*;
+
+pub struct LeaderboardScreen;
+
+impl Screen for LeaderboardScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.leaderboard.ghost_status = GhostStatus::Idle;
+        game.leaderboard.cursor = game.leaderboard.open_at.take().unwrap_or(0);
+        game.leaderboard.scroll = 0.0;
+        if let Some(admin) = &mut game.admin {
+            admin.confirming = false;
+            admin.notice = None;
+        }
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        // Removal takes a second key, and nothing else reacts while it asks
+        if let Some(admin) = &mut game.admin {
+            if admin.confirming {
+                if widgets::key_pressed(&game.input, KeyCode::Y) {
+                    admin.confirming = false;
+                    game.remove_selected_entry();
+                } else if widgets::key_pressed(&game.input, KeyCode::N)
+                    || widgets::key_pressed(&game.input, KeyCode::Escape)
+                {
+                    admin.confirming = false;
+                }
+                return;
+            }
+            if widgets::key_pressed(&game.input, KeyCode::Delete)
+                && game.leaderboard.selected().is_some()
+            {
+                admin.confirming = true;
+                return;
+            }
+        }
+
+        if widgets::key_pressed(&game.input, KeyCode::Escape)
+            || widgets::key_pressed(&game.input, KeyCode::Space)
+        {
+            game.set_state(GameState::MainMenu);
+            return;
+        }
+
+        if widgets::key_pressed(&game.input, KeyCode::Tab) {
+            let next = game.leaderboard.category.next();
+            game.leaderboard.show_category(next);
+        }
+
+        // The board on show, for organizers announcing winners
+        if widgets::key_pressed(&game.input, KeyCode::E) {
+            game.leaderboard.export_requested = true;
+        }
+
+        // Hardcore runs don't upload replays, so the ghost is always the standard board's
+        if widgets::key_pressed(&game.input, KeyCode::G) && game.leaderboard.category.is_standard()
+        {
+            game.fetch_ghost_run();
+        }
+
+        let count = game.leaderboard.shown().scores.len();
+        if count == 0 {
+            return;
+        }
+
+        if widgets::key_pressed(&game.input, KeyCode::Up) {
+            game.leaderboard.cursor = game.leaderboard.cursor.saturating_sub(1);
+        }
+        if widgets::key_pressed(&game.input, KeyCode::Down) {
+            game.leaderboard.cursor = (game.leaderboard.cursor + 1).min(count - 1);
+        }
+        game.leaderboard.cursor = game.leaderboard.cursor.min(count - 1);
+
+        // A click on one of the footer's buttons isn't a click on a row
+        if game.input.mouse_pressed(MouseButton::Left)
+            && widgets::button_at(game.input.mouse_position()).is_none()
+        {
+            let (x, y) = game.input.mouse_position();
+            if let Some(row) = leaderboard::row_at(&game.leaderboard, x, y) {
+                game.leaderboard.cursor = row;
+                game.push_state(GameState::LeaderboardDetail);
+            }
+        } else if widgets::key_pressed(&game.input, KeyCode::Enter) {
+            game.push_state(GameState::LeaderboardDetail);
+        }
+
+        // Keep the selected row inside the visible part of the list
+        let row_offset = game.leaderboard.cursor as f32 * ROW_HEIGHT;
+        if row_offset < game.leaderboard.scroll {
+            game.leaderboard.scroll = row_offset;
+        } else if row_offset > game.leaderboard.scroll + LIST_VISIBLE_HEIGHT {
+            game.leaderboard.scroll = row_offset - LIST_VISIBLE_HEIGHT;
+        }
+
+        // Clamp scroll to reasonable bounds
+        game.leaderboard.scroll = game.leaderboard.scroll.clamp(0.0, 400.0);
+    }
+
+    fn draw(&self, game: &Game) {
+        let admin = game.admin.as_ref();
+        leaderboard::draw_leaderboard_view(&game.leaderboard, &game.api, admin, &game.fonts);
+    }
+}
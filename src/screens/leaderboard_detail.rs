@@ -0,0 +1,55 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::{leaderboard, widgets};
+use macroquad::prelude::*;
+
+pub struct LeaderboardDetailScreen;
+
+impl Screen for LeaderboardDetailScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.leaderboard.report_confirming = false;
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        // The list can shrink under us when a sync lands
+        if game.leaderboard.selected().is_none() {
+            game.pop_state();
+            return;
+        }
+
+        // Reporting takes a second key, so a stray R doesn't send anything
+        if game.leaderboard.report_confirming {
+            if widgets::key_pressed(&game.input, KeyCode::Y) {
+                game.report_selected_entry();
+                game.leaderboard.report_confirming = false;
+            } else if widgets::key_pressed(&game.input, KeyCode::N)
+                || widgets::key_pressed(&game.input, KeyCode::Escape)
+            {
+                game.leaderboard.report_confirming = false;
+            }
+            return;
+        }
+
+        if widgets::key_pressed(&game.input, KeyCode::R) && game.can_report_selected() {
+            game.leaderboard.report_confirming = true;
+            return;
+        }
+
+        let close = widgets::key_pressed(&game.input, KeyCode::Escape)
+            || widgets::key_pressed(&game.input, KeyCode::Enter)
+            || widgets::key_pressed(&game.input, KeyCode::Space)
+            || game.input.mouse_pressed(MouseButton::Left);
+        if close {
+            game.pop_state();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        let can_report = game.can_report_selected();
+        leaderboard::draw_entry_detail(&game.leaderboard, can_report, &game.textures, &game.fonts);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
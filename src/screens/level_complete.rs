@@ -0,0 +1,72 @@
+use crate::design::CountUp;
+use crate::game::modifier::LevelModifier;
+use crate::game::mutators::Mutator;
+use crate::game::scene::Screen;
+use crate::game::{scoring, Game, GameState};
+use crate::overrides::config;
+use crate::ui::level_complete;
+
+pub struct LevelCompleteScreen;
+
+impl Screen for LevelCompleteScreen {
+    fn on_enter(&self, game: &mut Game) {
+        // Solo runs only; versus scores are head to head
+        // Practice keeps no score, so there's nothing to gamble
+        game.wager_pending = game.versus.is_none() && game.practice.is_none();
+
+        // The level just finished is one behind the one about to start
+        let bonus = scoring::calculate_level_score_bonus(game.run.level - 1);
+        game.tally = CountUp::between(0, bonus);
+    }
+
+    fn update(&self, game: &mut Game, dt: f32) {
+        game.level_complete_timer -= dt;
+        game.tally.update(dt);
+        if game.input.any_pressed() {
+            game.tally.finish();
+        }
+
+        // The celebration waits on an answer to the gamble
+        if game.wager_pending {
+            let actions = game.answer_wager();
+            if actions.accept {
+                game.level_modifier = LevelModifier::unreviewed();
+                game.wager_pending = false;
+            } else if actions.decline {
+                game.wager_pending = false;
+            }
+        }
+
+        if game.level_complete_timer <= 0.0 && !game.wager_pending {
+            game.set_state(GameState::Playing);
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        // The timer keeps running past zero while the gamble waits on an answer
+        let elapsed = config().level_complete_display_time - game.level_complete_timer;
+        let mirror = game.mutators.contains(Mutator::Mirror);
+        level_complete::draw_celebration(
+            &game.particles,
+            &game.sprites,
+            game.yeti.x,
+            mirror,
+            elapsed,
+        );
+
+        let fonts = &game.fonts;
+        level_complete::draw_level_complete(
+            &game.level_complete_message,
+            &game.level_complete_submessage,
+            elapsed,
+            fonts,
+        );
+        level_complete::draw_bonus_tally(&game.run, game.tally.value(), fonts);
+        level_complete::draw_wager(game.wager_pending, &game.level_modifier, fonts);
+    }
+
+    fn on_exit(&self, game: &mut Game) {
+        // Leftover confetti shouldn't rain on the next level
+        game.particles.clear();
+    }
+}
@@ -0,0 +1,135 @@
+use crate::design::DesignContext;
+use crate::game::scene::Screen;
+use crate::game::{Game, GameState};
+use crate::overrides::config;
+use crate::ui::{leaderboard, menu, widgets};
+use chrono::Timelike;
+use macroquad::prelude::*;
+
+pub struct MainMenuScreen;
+
+impl Screen for MainMenuScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.menu_time = 0.0;
+        game.menu_idle_time = 0.0;
+        game.leaderboard.mini_scroll = 0.0;
+        game.leaderboard.mini_hovered = false;
+        game.leaderboard.mini_focused = false;
+        game.menu_vignette.reset();
+        game.flavor.next_line(chrono::Local::now().hour());
+    }
+
+    fn update(&self, game: &mut Game, dt: f32) {
+        game.menu_time += dt;
+        game.menu_idle_time += dt;
+        game.leaderboard.update_rank_toast(dt);
+        // Only scroll once the menu has been up for a few seconds
+        if game.menu_time > 3.0 {
+            game.leaderboard.scroll_mini_board(game.clock.delta());
+        }
+        if !DesignContext::reduced_motion() {
+            game.menu_vignette.update(dt, &game.catalog, &game.sprites);
+        }
+
+        game.repo_feed.update(dt);
+        if game.repo_feed.poll_due() {
+            game.poll_repo_events();
+        }
+
+        if game.input.any_pressed() {
+            game.menu_idle_time = 0.0;
+        }
+
+        if game.pending_crash_report.is_some() {
+            if widgets::key_pressed(&game.input, KeyCode::Y) {
+                game.answer_crash_prompt(true);
+                return;
+            } else if widgets::key_pressed(&game.input, KeyCode::N) {
+                game.answer_crash_prompt(false);
+                return;
+            }
+        }
+
+        if update_mini_board(game) {
+            return;
+        }
+
+        if game.kiosk.is_some() {
+            update_kiosk_menu(game);
+            return;
+        }
+
+        if widgets::key_pressed(&game.input, KeyCode::Space) {
+            game.start_game();
+        } else if widgets::key_pressed(&game.input, KeyCode::V) {
+            game.start_versus();
+        } else if widgets::key_pressed(&game.input, KeyCode::T) {
+            game.start_practice();
+        } else if game.input.key_pressed(KeyCode::Left) {
+            game.step_practice_level(-1);
+        } else if game.input.key_pressed(KeyCode::Right) {
+            game.step_practice_level(1);
+        } else if widgets::key_pressed(&game.input, KeyCode::C) {
+            game.set_state(GameState::Challenge);
+        } else if widgets::key_pressed(&game.input, KeyCode::U) {
+            game.set_state(GameState::Mutators);
+        } else if widgets::key_pressed(&game.input, KeyCode::O) {
+            game.set_state(GameState::Mods);
+        } else if widgets::key_pressed(&game.input, KeyCode::P) {
+            game.set_state(GameState::Profiles);
+        } else if widgets::key_pressed(&game.input, KeyCode::M) {
+            game.cycle_lane_setting();
+        } else if widgets::key_pressed(&game.input, KeyCode::S) {
+            game.push_state(GameState::Settings);
+        } else if widgets::key_pressed(&game.input, KeyCode::L) {
+            game.set_state(GameState::ViewingLeaderboard);
+        } else if widgets::key_pressed(&game.input, KeyCode::W) {
+            game.show_whats_new(None);
+        } else if game.menu_idle_time >= config().attract_mode_idle_time {
+            game.start_demo();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        menu::draw_main_menu(game);
+    }
+}
+
+// The mini board opens up under the mouse, or with Tab, and a click or
+// Enter goes on to the full board. True when it took the input.
+fn update_mini_board(game: &mut Game) -> bool {
+    let (x, y) = game.input.mouse_position();
+    let area = leaderboard::mini_board_rect(game.leaderboard.mini_expanded());
+    game.leaderboard.mini_hovered =
+        area.contains(vec2(x, y)) && !game.leaderboard.board.scores.is_empty();
+
+    if widgets::key_pressed(&game.input, KeyCode::Tab) {
+        game.leaderboard.mini_focused = !game.leaderboard.mini_focused;
+        return true;
+    }
+    let clicked = game.leaderboard.mini_hovered && game.input.mouse_pressed(MouseButton::Left);
+    let entered =
+        game.leaderboard.mini_focused && widgets::key_pressed(&game.input, KeyCode::Enter);
+    if clicked || entered {
+        game.view_own_entry();
+        return true;
+    }
+    if game.leaderboard.mini_focused && widgets::key_pressed(&game.input, KeyCode::Escape) {
+        game.leaderboard.mini_focused = false;
+        return true;
+    }
+    false
+}
+
+// Booths only get the keys that don't change anything for the next visitor
+fn update_kiosk_menu(game: &mut Game) {
+    if widgets::key_pressed(&game.input, KeyCode::Space) {
+        game.set_state(GameState::SignIn);
+    } else if widgets::key_pressed(&game.input, KeyCode::V) {
+        game.start_versus();
+    } else if widgets::key_pressed(&game.input, KeyCode::L) {
+        game.set_state(GameState::ViewingLeaderboard);
+    } else if game.menu_idle_time >= config().attract_mode_idle_time {
+        game.start_demo();
+    }
+}
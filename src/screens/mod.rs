@@ -0,0 +1,59 @@
+mod benchmark;
+mod calibration;
+mod challenge;
+mod dying;
+mod feedback_history;
+mod game_over;
+mod leaderboard;
+mod leaderboard_detail;
+mod level_complete;
+mod main_menu;
+mod mods;
+mod mutators;
+mod name_input;
+mod paused;
+mod photo_mode;
+mod playing;
+mod profiles;
+mod quit_confirm;
+mod run_summary;
+mod settings;
+mod sign_in;
+mod telemetry_consent;
+mod versus_results;
+mod whats_new;
+
+use crate::game::scene::Screen;
+use crate::game::GameState;
+
+impl GameState {
+    // The single place a state is mapped to its behaviour and visuals
+    pub fn screen(self) -> &'static dyn Screen {
+        match self {
+            GameState::MainMenu => &main_menu::MainMenuScreen,
+            GameState::Playing => &playing::PlayingScreen,
+            GameState::Paused => &paused::PausedScreen,
+            GameState::Dying => &dying::DyingScreen,
+            GameState::LevelComplete => &level_complete::LevelCompleteScreen,
+            GameState::GameOver => &game_over::GameOverScreen,
+            GameState::RunSummary => &run_summary::RunSummaryScreen,
+            GameState::NameInput => &name_input::NameInputScreen,
+            GameState::ViewingLeaderboard => &leaderboard::LeaderboardScreen,
+            GameState::LeaderboardDetail => &leaderboard_detail::LeaderboardDetailScreen,
+            GameState::VersusResults => &versus_results::VersusResultsScreen,
+            GameState::Challenge => &challenge::ChallengeScreen,
+            GameState::Settings => &settings::SettingsScreen,
+            GameState::Calibration => &calibration::CalibrationScreen,
+            GameState::FeedbackHistory => &feedback_history::FeedbackHistoryScreen,
+            GameState::Mutators => &mutators::MutatorsScreen,
+            GameState::Benchmark => &benchmark::BenchmarkScreen,
+            GameState::Mods => &mods::ModsScreen,
+            GameState::TelemetryConsent => &telemetry_consent::TelemetryConsentScreen,
+            GameState::Profiles => &profiles::ProfilesScreen,
+            GameState::SignIn => &sign_in::SignInScreen,
+            GameState::WhatsNew => &whats_new::WhatsNewScreen,
+            GameState::QuitConfirm => &quit_confirm::QuitConfirmScreen,
+            GameState::PhotoMode => &photo_mode::PhotoModeScreen,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::mods;
+use macroquad::prelude::*;
+
+pub struct ModsScreen;
+
+impl Screen for ModsScreen {
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.input.key_pressed(KeyCode::Escape) || game.input.key_pressed(KeyCode::O) {
+            game.reset_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        mods::draw_mods(game);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::game::mutators::Mutator;
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::mutators;
+use macroquad::prelude::*;
+
+pub struct MutatorsScreen;
+
+impl Screen for MutatorsScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.mutator_cursor = 0;
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        let options = Mutator::ALL.len();
+
+        if game.input.key_pressed(KeyCode::Escape) {
+            game.reset_game();
+            return;
+        }
+
+        if game.input.key_pressed(KeyCode::Up) {
+            game.mutator_cursor = (game.mutator_cursor + options - 1) % options;
+        } else if game.input.key_pressed(KeyCode::Down) {
+            game.mutator_cursor = (game.mutator_cursor + 1) % options;
+        }
+
+        if game.input.key_pressed(KeyCode::Space) {
+            let mutator = Mutator::ALL[game.mutator_cursor];
+            if game.mutator_unlocked(mutator) {
+                game.mutator_selection.toggle(mutator);
+            }
+        } else if game.input.key_pressed(KeyCode::Enter) {
+            game.start_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        mutators::draw_mutators_menu(game);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::highscores::{validate_name, MAX_NAME_LENGTH};
+use crate::input::{self, VirtualKey};
+use crate::ui::{name_input, widgets};
+use macroquad::prelude::*;
+
+pub struct NameInputScreen;
+
+impl Screen for NameInputScreen {
+    fn on_enter(&self, game: &mut Game) {
+        // Don't let the keys that got us here land in the name
+        clear_input_queue();
+        if let Some(profile) = &game.profile {
+            if game.player_name_input.is_empty() {
+                game.player_name_input = profile.name.clone();
+            }
+        }
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        handle_name_input(game);
+    }
+
+    fn draw(&self, game: &Game) {
+        name_input::draw_name_input(
+            &game.player_name_input,
+            game.run.score,
+            &game.touch_keyboard,
+            game.clock.time(),
+            &game.textures,
+            &game.fonts,
+        );
+    }
+}
+
+fn handle_name_input(game: &mut Game) {
+    let tapped = name_input::tapped_key(&game.touch_keyboard, &game.input);
+    if widgets::key_pressed(&game.input, KeyCode::Escape) || tapped == Some(VirtualKey::Cancel) {
+        game.reset_game();
+        return;
+    }
+
+    input::edit_text(&game.input, &mut game.player_name_input, MAX_NAME_LENGTH);
+    if let Some(key) = tapped {
+        game.touch_keyboard
+            .press(key, &mut game.player_name_input, MAX_NAME_LENGTH);
+    }
+
+    let submit =
+        widgets::key_pressed(&game.input, KeyCode::Enter) || tapped == Some(VirtualKey::Done);
+    if submit && validate_name(&game.player_name_input).is_ok() {
+        game.submit_high_score();
+    }
+}
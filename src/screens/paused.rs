@@ -0,0 +1,30 @@
+use crate::game::scene::Screen;
+use crate::game::{Game, GameState};
+use crate::ui::pause;
+use macroquad::prelude::*;
+
+pub struct PausedScreen;
+
+impl Screen for PausedScreen {
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.input.key_pressed(KeyCode::Escape) || game.input.key_pressed(KeyCode::P) {
+            game.pop_state();
+        } else if game.input.key_pressed(KeyCode::S) && game.kiosk.is_none() {
+            game.push_state(GameState::Settings);
+        } else if game.input.key_pressed(KeyCode::H) {
+            game.push_state(GameState::FeedbackHistory);
+        } else if game.input.key_pressed(KeyCode::C) {
+            game.push_state(GameState::PhotoMode);
+        } else if game.input.key_pressed(KeyCode::Q) && game.kiosk.is_none() {
+            game.reset_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        pause::draw_pause_overlay(game.kiosk.is_some(), &game.textures, &game.fonts);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,82 @@
+use crate::game::photo::PhotoMode;
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::photo;
+use macroquad::prelude::*;
+
+const ZOOM_PER_SECOND: f32 = 2.0; // While + or - is held
+const ZOOM_PER_NOTCH: f32 = 1.25;
+
+// A free camera over the paused run. The run stays frozen underneath, since
+// only the top screen updates; the renderer draws it through the camera.
+pub struct PhotoModeScreen;
+
+impl Screen for PhotoModeScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.photo = Some(PhotoMode::new());
+    }
+
+    fn on_exit(&self, game: &mut Game) {
+        game.photo = None;
+    }
+
+    fn update(&self, game: &mut Game, dt: f32) {
+        if game.input.key_pressed(KeyCode::Escape) || game.input.key_pressed(KeyCode::C) {
+            game.pop_state();
+            return;
+        }
+        let Some(photo) = game.photo.as_mut() else {
+            return;
+        };
+
+        let mut direction = Vec2::ZERO;
+        if game.input.key_down(KeyCode::Left) || game.input.key_down(KeyCode::A) {
+            direction.x -= 1.0;
+        }
+        if game.input.key_down(KeyCode::Right) || game.input.key_down(KeyCode::D) {
+            direction.x += 1.0;
+        }
+        if game.input.key_down(KeyCode::Up) || game.input.key_down(KeyCode::W) {
+            direction.y -= 1.0;
+        }
+        if game.input.key_down(KeyCode::Down) || game.input.key_down(KeyCode::S) {
+            direction.y += 1.0;
+        }
+        photo.pan(direction.normalize_or_zero(), dt);
+
+        if game.input.mouse_down(MouseButton::Left) {
+            let mouse = Vec2::from(game.input.mouse_position());
+            if let Some(from) = photo.drag_from {
+                photo.drag(mouse - from);
+            }
+            photo.drag_from = Some(mouse);
+        } else {
+            photo.drag_from = None;
+        }
+
+        if game.input.key_down(KeyCode::Equal) || game.input.key_down(KeyCode::KpAdd) {
+            photo.zoom_by(ZOOM_PER_SECOND.powf(dt));
+        }
+        if game.input.key_down(KeyCode::Minus) || game.input.key_down(KeyCode::KpSubtract) {
+            photo.zoom_by(ZOOM_PER_SECOND.powf(-dt));
+        }
+        let wheel = game.input.wheel();
+        if wheel != 0.0 {
+            photo.zoom_by(ZOOM_PER_NOTCH.powf(wheel.signum()));
+        }
+
+        if game.input.key_pressed(KeyCode::H) {
+            photo.show_hud = !photo.show_hud;
+        }
+        if game.input.key_pressed(KeyCode::F) {
+            photo.filter = photo.filter.next();
+        }
+        if game.input.key_pressed(KeyCode::Enter) {
+            photo.export_requested = true;
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        photo::draw_photo_controls(game);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::game::mutators::Mutator;
+use crate::game::scene::Screen;
+use crate::game::{physics, scoring, spawning, Game, GameState};
+use crate::ui::{effects, hud, playfield, viewport};
+use macroquad::prelude::*;
+
+pub struct PlayingScreen;
+
+impl Screen for PlayingScreen {
+    fn update(&self, game: &mut Game, dt: f32) {
+        // Spectators can only leave; the ghost plays itself
+        if game.spectating.is_some() {
+            if game.input.key_pressed(KeyCode::Escape) {
+                game.stop_spectating();
+                return;
+            }
+        } else if game.input.key_pressed(KeyCode::Escape) || game.input.key_pressed(KeyCode::P) {
+            game.push_state(GameState::Paused);
+            return;
+        }
+
+        // The yeti always moves in real time; focus slows everything around it
+        game.update_yeti(dt);
+        let world_dt = dt * game.focus.time_scale();
+        game.update_items(world_dt);
+        scoring::update_item_scoring(game, world_dt);
+        if game.state() != GameState::Playing {
+            return; // A missed item failed an unreviewed push
+        }
+        spawning::spawn_items(game, world_dt);
+        physics::attract_items(game, world_dt);
+        physics::check_collisions(game);
+        game.check_level_completion();
+        game.update_pipeline_animation(world_dt);
+        game.speed_events.tick(world_dt);
+        game.update_collision_grace(world_dt);
+        game.update_feedback(dt);
+        game.update_next_item();
+        game.stats.tick(dt, game.run.score);
+        game.speedrun.tick(dt);
+        game.update_objectives(dt);
+        game.update_practice();
+        game.hud_score.set(game.run.score);
+        game.hud_score.update(dt);
+    }
+
+    fn draw(&self, game: &Game) {
+        draw_run(game);
+    }
+}
+
+// Shared with the screens that keep the run visible behind them
+pub fn draw_run(game: &Game) {
+    let mirror = game.mutators.contains(Mutator::Mirror);
+    viewport::mirrored(mirror, || {
+        playfield::draw_game_objects(game);
+        effects::draw_particles(&game.particles);
+    });
+    effects::draw_score_popups(&game.score_popups, mirror, &game.fonts);
+    hud::draw_game_ui(game);
+}
@@ -0,0 +1,87 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::highscores::MAX_NAME_LENGTH;
+use crate::input;
+use crate::profiles;
+use crate::ui::profiles as profiles_ui;
+use macroquad::prelude::*;
+
+pub struct ProfilesScreen;
+
+impl Screen for ProfilesScreen {
+    fn on_enter(&self, game: &mut Game) {
+        let menu = &mut game.profile_menu;
+        menu.profiles = profiles::list();
+        menu.name_input = None;
+        menu.error = None;
+        // Start on whoever is playing now
+        menu.cursor = game
+            .profile
+            .as_ref()
+            .and_then(|current| {
+                menu.profiles
+                    .iter()
+                    .position(|profile| profile.folder == current.folder)
+            })
+            .map_or(0, |index| index + 1);
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.profile_menu.name_input.is_some() {
+            handle_name_input(game);
+            return;
+        }
+
+        let menu = &mut game.profile_menu;
+        let options = menu.profiles.len() + 1;
+
+        if game.input.key_pressed(KeyCode::Escape) {
+            game.reset_game();
+        } else if game.input.key_pressed(KeyCode::Up) {
+            menu.cursor = (menu.cursor + options - 1) % options;
+        } else if game.input.key_pressed(KeyCode::Down) {
+            menu.cursor = (menu.cursor + 1) % options;
+        } else if game.input.key_pressed(KeyCode::N) {
+            // Drop the N itself so it doesn't start the name
+            clear_input_queue();
+            menu.name_input = Some(String::new());
+            menu.error = None;
+        } else if game.input.key_pressed(KeyCode::Enter) || game.input.key_pressed(KeyCode::Space) {
+            let profile = menu
+                .cursor
+                .checked_sub(1)
+                .and_then(|index| menu.profiles.get(index).cloned());
+            game.select_profile(profile);
+            game.reset_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        profiles_ui::draw_profiles(game);
+    }
+}
+
+fn handle_name_input(game: &mut Game) {
+    let menu = &mut game.profile_menu;
+    let Some(name) = &mut menu.name_input else {
+        return;
+    };
+
+    if game.input.key_pressed(KeyCode::Escape) {
+        menu.name_input = None;
+        menu.error = None;
+        return;
+    }
+
+    if input::edit_text(&game.input, name, MAX_NAME_LENGTH) {
+        menu.error = None;
+    }
+
+    if game.input.key_pressed(KeyCode::Enter) {
+        let name = name.clone();
+        match game.create_profile(&name) {
+            Ok(()) => game.reset_game(),
+            Err(error) => game.profile_menu.error = Some(error),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::quit_confirm;
+use macroquad::prelude::*;
+
+// Closing the window mid-run, when the player wants to be asked
+pub struct QuitConfirmScreen;
+
+impl Screen for QuitConfirmScreen {
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.input.key_pressed(KeyCode::Y) || game.input.key_pressed(KeyCode::Enter) {
+            game.quit_requested = true;
+        } else if game.input.key_pressed(KeyCode::N) || game.input.key_pressed(KeyCode::Escape) {
+            game.pop_state();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        quit_confirm::draw_quit_confirm(game.run.score, &game.fonts);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
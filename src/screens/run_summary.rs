@@ -0,0 +1,36 @@
+use crate::game::scene::Screen;
+use crate::game::{Game, GameState};
+use crate::ui::run_summary;
+use macroquad::prelude::*;
+
+pub struct RunSummaryScreen;
+
+impl Screen for RunSummaryScreen {
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.run_wants_name() && game.input.key_pressed(KeyCode::Space) {
+            game.set_state(GameState::NameInput);
+        } else if game.input.key_pressed(KeyCode::Space) || game.input.key_pressed(KeyCode::Escape)
+        {
+            game.reset_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        let splits =
+            (game.settings.speedrun_timer && game.speedrun_eligible()).then_some(&game.speedrun);
+        run_summary::draw_run_summary(
+            &game.run,
+            &game.stats,
+            &game.catalog,
+            game.nemesis(),
+            splits,
+            &game.fonts,
+        );
+        run_summary::draw_instructions(
+            game.kiosk.is_some(),
+            game.challenge.is_some(),
+            game.is_new_high_score,
+            &game.fonts,
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::game::scene::Screen;
+use crate::game::{Game, GameState};
+use crate::settings::SettingOption;
+use crate::ui::settings;
+use macroquad::prelude::*;
+
+pub struct SettingsScreen;
+
+impl Screen for SettingsScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.settings_cursor = 0;
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        let options = SettingOption::ALL.len();
+
+        if game.input.key_pressed(KeyCode::Escape) {
+            game.pop_state();
+            return;
+        }
+
+        if game.input.key_pressed(KeyCode::Up) {
+            game.settings_cursor = (game.settings_cursor + options - 1) % options;
+        } else if game.input.key_pressed(KeyCode::Down) {
+            game.settings_cursor = (game.settings_cursor + 1) % options;
+        }
+
+        // Left and right nudge the lead; Enter measures it instead
+        let option = SettingOption::ALL[game.settings_cursor];
+        let confirm =
+            game.input.key_pressed(KeyCode::Enter) || game.input.key_pressed(KeyCode::Space);
+        if option == SettingOption::InputLead && confirm {
+            game.push_state(GameState::Calibration);
+            return;
+        }
+
+        let direction = if game.input.key_pressed(KeyCode::Left) {
+            -1
+        } else if game.input.key_pressed(KeyCode::Right) || confirm {
+            1
+        } else {
+            0
+        };
+
+        if direction != 0 {
+            option.adjust(&mut game.settings, direction);
+            game.settings.apply();
+            game.settings.save();
+            if option == SettingOption::Telemetry {
+                game.apply_telemetry_consent();
+            }
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        settings::draw_settings(game);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,40 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::highscores::{validate_name, MAX_NAME_LENGTH};
+use crate::input::{self, VirtualKey};
+use crate::ui::{name_input, sign_in};
+use macroquad::prelude::*;
+
+// Kiosk mode asks who's playing before the run, so the score can be posted
+// the moment it ends
+pub struct SignInScreen;
+
+impl Screen for SignInScreen {
+    fn on_enter(&self, _game: &mut Game) {
+        // Don't let the Space that got us here land in the name
+        clear_input_queue();
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        let tapped = name_input::tapped_key(&game.touch_keyboard, &game.input);
+        if game.input.key_pressed(KeyCode::Escape) || tapped == Some(VirtualKey::Cancel) {
+            game.reset_game();
+            return;
+        }
+
+        input::edit_text(&game.input, &mut game.player_name_input, MAX_NAME_LENGTH);
+        if let Some(key) = tapped {
+            game.touch_keyboard
+                .press(key, &mut game.player_name_input, MAX_NAME_LENGTH);
+        }
+
+        let start = game.input.key_pressed(KeyCode::Enter) || tapped == Some(VirtualKey::Done);
+        if start && validate_name(&game.player_name_input).is_ok() {
+            game.start_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        sign_in::draw_sign_in(game);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::telemetry_consent;
+use macroquad::prelude::*;
+
+pub struct TelemetryConsentScreen;
+
+impl Screen for TelemetryConsentScreen {
+    fn update(&self, game: &mut Game, _dt: f32) {
+        // Esc counts as a no; the question never comes back either way
+        let answer = if game.input.key_pressed(KeyCode::Y) {
+            Some(true)
+        } else if game.input.key_pressed(KeyCode::N) || game.input.key_pressed(KeyCode::Escape) {
+            Some(false)
+        } else {
+            None
+        };
+
+        if let Some(consent) = answer {
+            game.settings.telemetry = Some(consent);
+            game.settings.save();
+            game.apply_telemetry_consent();
+            game.pop_state();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        telemetry_consent::draw_telemetry_consent(game);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,22 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::versus;
+use macroquad::prelude::*;
+
+pub struct VersusResultsScreen;
+
+impl Screen for VersusResultsScreen {
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.input.key_pressed(KeyCode::Space) {
+            game.start_versus();
+        } else if game.input.key_pressed(KeyCode::Escape) {
+            game.reset_game();
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        if let Some(state) = &game.versus {
+            versus::draw_versus_results(state, game.run.level, &game.fonts);
+        }
+    }
+}
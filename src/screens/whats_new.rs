@@ -0,0 +1,40 @@
+use crate::game::scene::Screen;
+use crate::game::Game;
+use crate::ui::whats_new;
+use macroquad::prelude::*;
+
+pub struct WhatsNewScreen;
+
+impl Screen for WhatsNewScreen {
+    fn on_enter(&self, game: &mut Game) {
+        game.whats_new_scroll = 0;
+    }
+
+    fn update(&self, game: &mut Game, _dt: f32) {
+        if game.input.key_pressed(KeyCode::Escape)
+            || game.input.key_pressed(KeyCode::Enter)
+            || game.input.key_pressed(KeyCode::W)
+        {
+            game.pop_state();
+            return;
+        }
+
+        let max_scroll = game
+            .whats_new
+            .len()
+            .saturating_sub(whats_new::VISIBLE_LINES);
+        if game.input.key_pressed(KeyCode::Up) {
+            game.whats_new_scroll = game.whats_new_scroll.saturating_sub(1);
+        } else if game.input.key_pressed(KeyCode::Down) {
+            game.whats_new_scroll = (game.whats_new_scroll + 1).min(max_scroll);
+        }
+    }
+
+    fn draw(&self, game: &Game) {
+        whats_new::draw_whats_new(game);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
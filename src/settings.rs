@@ -0,0 +1,475 @@
+use crate::cli;
+use crate::colors::Palette;
+use crate::design::{truncate_graphemes, DesignContext};
+use crate::highscores::BoardStanding;
+use crate::profiles;
+use crate::themes::UiTheme;
+use macroquad::time::get_time;
+use macroquad::window::{screen_dpi_scale, set_fullscreen};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Player preferences, saved as JSON in the platform config directory.
+// Missing or unreadable files just fall back to the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub ui_theme: UiTheme,
+    pub colorblind_mode: bool, // Safe palette plus check/cross badges on items
+    pub high_contrast: bool,
+    pub reduced_motion: bool,
+    pub flash_reduction: bool, // Photosensitive-safe fades in place of flashes
+    pub text_scale: f32,
+    pub ui_scale: f32,    // 0.0 for automatic, from the display's DPI
+    pub crt_filter: bool, // Scanlines and a curved tube over the finished frame
+    pub fullscreen: bool,
+    pub vsync: bool,        // Only read when the window opens
+    pub frame_cap: u32,     // 0 for uncapped
+    pub input_lead_ms: u32, // Slack on the jump windows for laggy displays
+    pub perf_overlay: bool,
+    pub discord_presence: bool, // Only used in builds with the `discord` feature
+    pub replay_upload: bool,    // Share new high score runs as watchable ghosts
+    pub music_volume: f32,      // 0.0 to 1.0
+    pub voice_volume: f32,      // 0.0 to 1.0, for the yeti's quips
+    pub confirm_quit: bool,     // Closing the window mid-run asks first
+    // Controller rumble on crashes, close calls and level ups. Not on the
+    // settings screen while NoHaptics is the only backend.
+    pub rumble: bool,
+    pub adaptive_difficulty: bool, // Rubber-banding; adaptive runs don't rank
+    pub item_magnet: bool,      // Assist: nearby good items drift in; flagged on the score
+    pub hardcore: bool,         // No grace margin or assists; ranks on its own board
+    pub trail_length: f32,      // Seconds of motion trail behind the yeti, 0.0 for none
+    pub speedrun_timer: bool,   // Run clock and splits on the HUD
+    pub build_log: bool,        // CI-style event ticker along the bottom of the HUD
+    pub github_events: bool,    // Weave the repo's real CI runs and merges into runs
+    pub github_repo: Option<String>, // "owner/name"; only set by editing the file
+    pub speedrun_best: Vec<f64>, // Splits of the fastest any% finish
+    pub telemetry: Option<bool>, // None until the first-run consent screen is answered
+    pub update_check: bool,     // Ask the server for a newer release at startup
+    pub last_seen_version: Option<String>, // For showing "What's new" once per update
+    pub board_standing: Option<BoardStanding>, // For rank-change news on the main menu
+    #[serde(skip)]
+    profile: Option<String>, // Profile folder these belong to; None for the guest
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            ui_theme: UiTheme::Brand,
+            colorblind_mode: false,
+            high_contrast: false,
+            reduced_motion: false,
+            flash_reduction: false,
+            text_scale: 1.0,
+            ui_scale: 0.0,
+            crt_filter: false,
+            fullscreen: false,
+            vsync: true,
+            frame_cap: 0,
+            input_lead_ms: 0,
+            perf_overlay: false,
+            discord_presence: true,
+            replay_upload: false,
+            music_volume: 0.7,
+            voice_volume: 0.6,
+            confirm_quit: true,
+            rumble: true,
+            adaptive_difficulty: false,
+            item_magnet: false,
+            hardcore: false,
+            trail_length: TRAIL_LENGTHS[1],
+            speedrun_timer: false,
+            build_log: false,
+            github_events: false,
+            github_repo: None,
+            speedrun_best: Vec::new(),
+            telemetry: None,
+            update_check: true,
+            last_seen_version: None,
+            board_standing: None,
+            profile: None,
+        }
+    }
+}
+
+const TEXT_SCALES: [f32; 3] = [1.0, 1.25, 1.5];
+const UI_SCALES: [f32; 4] = [0.0, 1.0, 1.25, 1.5]; // Automatic first
+const FRAME_CAPS: [u32; 5] = [0, 30, 60, 120, 144];
+const TRAIL_LENGTHS: [f32; 3] = [0.0, 0.12, 0.25]; // Off, short, long
+const INPUT_LEAD_STEP_MS: u32 = 10;
+const GITHUB_TOKEN_FILE: &str = "github_token";
+pub const MAX_INPUT_LEAD_MS: u32 = 150; // Past this it's a different game, not calibration
+
+impl Settings {
+    // The guest's settings, which are also the ones the window opens with
+    pub fn load() -> Self {
+        Self::load_for(None)
+    }
+
+    pub fn load_for(profile: Option<&str>) -> Self {
+        let Some(path) = settings_path(profile) else {
+            return Self::default();
+        };
+
+        let mut settings = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                println!(
+                    "Ignoring unreadable settings file {}: {}",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        settings.profile = profile.map(str::to_string);
+        settings
+    }
+
+    // A copy that saves into `profile`'s folder from now on
+    pub fn for_profile(&self, profile: Option<&str>) -> Self {
+        Self {
+            profile: profile.map(str::to_string),
+            ..self.clone()
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = settings_path(self.profile.as_deref()) else {
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let contents = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+                fs::write(&path, contents)
+            });
+        if let Err(e) = result {
+            println!("Failed to save settings: {}", e);
+        }
+    }
+
+    // Push the presentation options into the design system
+    pub fn apply(&self) {
+        let palette = if self.colorblind_mode {
+            Palette::ColorblindSafe
+        } else {
+            Palette::Standard
+        };
+        DesignContext::set(DesignContext {
+            palette,
+            high_contrast: self.high_contrast,
+            reduced_motion: self.reduced_motion,
+            flash_reduction: self.flash_reduction,
+            text_scale: self.text_scale,
+            ui_scale: self.effective_ui_scale(),
+            theme: self.ui_theme,
+        });
+    }
+
+    // High-DPI displays are mostly small ones, where the 640x270 layout ends
+    // up tiny, so automatic follows the DPI in quarter steps
+    pub fn effective_ui_scale(&self) -> f32 {
+        if self.ui_scale > 0.0 {
+            return self.ui_scale;
+        }
+        ((screen_dpi_scale() * 4.0).round() / 4.0).clamp(1.0, UI_SCALES[UI_SCALES.len() - 1])
+    }
+
+    // For private repos. It's kept in its own file beside settings.json so
+    // it never goes wherever the settings do, crash reports included.
+    pub fn github_token(&self) -> Option<String> {
+        let path = settings_path(self.profile.as_deref())?.with_file_name(GITHUB_TOKEN_FILE);
+        let token = fs::read_to_string(path).ok()?;
+        let token = token.trim();
+        (!token.is_empty()).then(|| token.to_string())
+    }
+
+    // Only an explicit yes counts
+    pub fn telemetry_enabled(&self) -> bool {
+        self.telemetry == Some(true)
+    }
+
+    // Embedded, the window is the host's, so only the saved setting changes
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.fullscreen = fullscreen;
+        if !cli::args().embedded {
+            set_fullscreen(fullscreen);
+        }
+    }
+
+    // Sleep off whatever is left of the frame budget when a cap is set.
+    // `frame_start` is macroquad's get_time() from the top of the frame.
+    pub fn limit_frame(&self, frame_start: f64) {
+        if self.frame_cap == 0 || cfg!(target_arch = "wasm32") {
+            return;
+        }
+        let budget = 1.0 / self.frame_cap as f64;
+        let remaining = budget - (get_time() - frame_start);
+        if remaining > 0.0 {
+            std::thread::sleep(Duration::from_secs_f64(remaining));
+        }
+    }
+}
+
+fn settings_path(profile: Option<&str>) -> Option<PathBuf> {
+    match profile {
+        Some(folder) => profiles::profile_dir(folder).map(|dir| dir.join("settings.json")),
+        None => dirs::config_dir().map(|dir| dir.join("yeti-set-go").join("settings.json")),
+    }
+}
+
+// One row on the settings screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingOption {
+    Theme,
+    ColorblindMode,
+    HighContrast,
+    ReducedMotion,
+    FlashReduction,
+    TextSize,
+    UiScale,
+    CrtFilter,
+    Fullscreen,
+    VSync,
+    FrameCap,
+    InputLead,
+    PerfOverlay,
+    ReplayUpload,
+    MusicVolume,
+    VoiceVolume,
+    ConfirmQuit,
+    AdaptiveDifficulty,
+    ItemMagnet,
+    Hardcore,
+    YetiTrail,
+    SpeedrunTimer,
+    BuildLog,
+    GithubEvents,
+    Telemetry,
+    UpdateCheck,
+    #[cfg(feature = "discord")]
+    DiscordPresence,
+}
+
+impl SettingOption {
+    pub const ALL: &'static [SettingOption] = &[
+        SettingOption::Theme,
+        SettingOption::ColorblindMode,
+        SettingOption::HighContrast,
+        SettingOption::ReducedMotion,
+        SettingOption::FlashReduction,
+        SettingOption::TextSize,
+        SettingOption::UiScale,
+        SettingOption::CrtFilter,
+        SettingOption::Fullscreen,
+        SettingOption::VSync,
+        SettingOption::FrameCap,
+        SettingOption::InputLead,
+        SettingOption::PerfOverlay,
+        SettingOption::ReplayUpload,
+        SettingOption::MusicVolume,
+        SettingOption::VoiceVolume,
+        SettingOption::ConfirmQuit,
+        SettingOption::AdaptiveDifficulty,
+        SettingOption::ItemMagnet,
+        SettingOption::Hardcore,
+        SettingOption::YetiTrail,
+        SettingOption::SpeedrunTimer,
+        SettingOption::BuildLog,
+        SettingOption::GithubEvents,
+        SettingOption::Telemetry,
+        SettingOption::UpdateCheck,
+        #[cfg(feature = "discord")]
+        SettingOption::DiscordPresence,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingOption::Theme => "Theme",
+            SettingOption::ColorblindMode => "Colorblind mode",
+            SettingOption::HighContrast => "High contrast",
+            SettingOption::ReducedMotion => "Reduced motion",
+            SettingOption::FlashReduction => "Flash reduction",
+            SettingOption::TextSize => "Text size",
+            SettingOption::UiScale => "UI scale",
+            SettingOption::CrtFilter => "CRT filter",
+            SettingOption::Fullscreen => "Fullscreen (F11)",
+            SettingOption::VSync => "VSync (on restart)",
+            SettingOption::FrameCap => "Frame cap",
+            SettingOption::InputLead => "Input lead (ENTER: calibrate)",
+            SettingOption::PerfOverlay => "Performance overlay (F3)",
+            SettingOption::ReplayUpload => "Upload high score replays",
+            SettingOption::MusicVolume => "Music volume",
+            SettingOption::VoiceVolume => "Voice volume",
+            SettingOption::ConfirmQuit => "Confirm quitting mid-run",
+            SettingOption::AdaptiveDifficulty => "Adaptive difficulty (unranked)",
+            SettingOption::ItemMagnet => "Item magnet (assist)",
+            SettingOption::Hardcore => "Hardcore (own board)",
+            SettingOption::YetiTrail => "Yeti trail",
+            SettingOption::SpeedrunTimer => "Speedrun timer",
+            SettingOption::BuildLog => "Build log ticker",
+            SettingOption::GithubEvents => "GitHub repo events",
+            SettingOption::Telemetry => "Share gameplay stats",
+            SettingOption::UpdateCheck => "Check for updates",
+            #[cfg(feature = "discord")]
+            SettingOption::DiscordPresence => "Discord presence",
+        }
+    }
+
+    pub fn value_text(&self, settings: &Settings) -> String {
+        match self {
+            SettingOption::Theme => settings.ui_theme.name().to_uppercase(),
+            SettingOption::ColorblindMode => on_off(settings.colorblind_mode).to_string(),
+            SettingOption::HighContrast => on_off(settings.high_contrast).to_string(),
+            SettingOption::ReducedMotion => on_off(settings.reduced_motion).to_string(),
+            SettingOption::FlashReduction => on_off(settings.flash_reduction).to_string(),
+            SettingOption::TextSize => format!("{}%", (settings.text_scale * 100.0).round()),
+            SettingOption::UiScale => {
+                let percent = (settings.effective_ui_scale() * 100.0).round();
+                if settings.ui_scale > 0.0 {
+                    format!("{}%", percent)
+                } else {
+                    format!("AUTO ({}%)", percent)
+                }
+            }
+            SettingOption::CrtFilter => on_off(settings.crt_filter).to_string(),
+            SettingOption::Fullscreen => on_off(settings.fullscreen).to_string(),
+            SettingOption::VSync => on_off(settings.vsync).to_string(),
+            SettingOption::PerfOverlay => on_off(settings.perf_overlay).to_string(),
+            SettingOption::ReplayUpload => on_off(settings.replay_upload).to_string(),
+            SettingOption::MusicVolume => volume_text(settings.music_volume),
+            SettingOption::VoiceVolume => volume_text(settings.voice_volume),
+            SettingOption::ConfirmQuit => on_off(settings.confirm_quit).to_string(),
+            SettingOption::AdaptiveDifficulty => on_off(settings.adaptive_difficulty).to_string(),
+            SettingOption::ItemMagnet => on_off(settings.item_magnet).to_string(),
+            SettingOption::Hardcore => on_off(settings.hardcore).to_string(),
+            SettingOption::YetiTrail => {
+                match TRAIL_LENGTHS
+                    .iter()
+                    .position(|length| *length == settings.trail_length)
+                {
+                    Some(0) => "OFF".to_string(),
+                    Some(1) => "SHORT".to_string(),
+                    Some(_) => "LONG".to_string(),
+                    None => format!("{:.2}s", settings.trail_length),
+                }
+            }
+            SettingOption::SpeedrunTimer => on_off(settings.speedrun_timer).to_string(),
+            SettingOption::BuildLog => on_off(settings.build_log).to_string(),
+            SettingOption::GithubEvents => match (&settings.github_repo, settings.github_events) {
+                (_, false) => "OFF".to_string(),
+                (Some(repo), true) => truncate_graphemes(repo, 20),
+                (None, true) => "NO REPO SET".to_string(),
+            },
+            SettingOption::Telemetry => on_off(settings.telemetry_enabled()).to_string(),
+            SettingOption::UpdateCheck => on_off(settings.update_check).to_string(),
+            #[cfg(feature = "discord")]
+            SettingOption::DiscordPresence => on_off(settings.discord_presence).to_string(),
+            SettingOption::FrameCap => match settings.frame_cap {
+                0 => "OFF".to_string(),
+                cap => format!("{} FPS", cap),
+            },
+            SettingOption::InputLead => match settings.input_lead_ms {
+                0 => "OFF".to_string(),
+                lead => format!("{} MS", lead),
+            },
+        }
+    }
+
+    // Step the option left (-1) or right (+1); toggles ignore the direction
+    pub fn adjust(&self, settings: &mut Settings, direction: i32) {
+        match self {
+            SettingOption::Theme => settings.ui_theme = settings.ui_theme.step(direction),
+            SettingOption::ColorblindMode => settings.colorblind_mode = !settings.colorblind_mode,
+            SettingOption::HighContrast => settings.high_contrast = !settings.high_contrast,
+            SettingOption::ReducedMotion => settings.reduced_motion = !settings.reduced_motion,
+            SettingOption::FlashReduction => settings.flash_reduction = !settings.flash_reduction,
+            SettingOption::TextSize => {
+                let current = TEXT_SCALES
+                    .iter()
+                    .position(|scale| *scale >= settings.text_scale)
+                    .unwrap_or(0);
+                let next = (current as i32 + direction).rem_euclid(TEXT_SCALES.len() as i32);
+                settings.text_scale = TEXT_SCALES[next as usize];
+            }
+            SettingOption::UiScale => {
+                let current = UI_SCALES
+                    .iter()
+                    .position(|scale| *scale == settings.ui_scale)
+                    .unwrap_or(0);
+                let next = (current as i32 + direction).rem_euclid(UI_SCALES.len() as i32);
+                settings.ui_scale = UI_SCALES[next as usize];
+            }
+            SettingOption::CrtFilter => settings.crt_filter = !settings.crt_filter,
+            SettingOption::Fullscreen => settings.set_fullscreen(!settings.fullscreen),
+            SettingOption::VSync => settings.vsync = !settings.vsync,
+            SettingOption::PerfOverlay => settings.perf_overlay = !settings.perf_overlay,
+            SettingOption::ReplayUpload => settings.replay_upload = !settings.replay_upload,
+            SettingOption::MusicVolume => step_volume(&mut settings.music_volume, direction),
+            SettingOption::VoiceVolume => step_volume(&mut settings.voice_volume, direction),
+            SettingOption::ConfirmQuit => settings.confirm_quit = !settings.confirm_quit,
+            SettingOption::AdaptiveDifficulty => {
+                settings.adaptive_difficulty = !settings.adaptive_difficulty
+            }
+            SettingOption::ItemMagnet => settings.item_magnet = !settings.item_magnet,
+            SettingOption::Hardcore => settings.hardcore = !settings.hardcore,
+            SettingOption::YetiTrail => {
+                let current = TRAIL_LENGTHS
+                    .iter()
+                    .position(|length| *length == settings.trail_length)
+                    .unwrap_or(0);
+                let next = (current as i32 + direction).rem_euclid(TRAIL_LENGTHS.len() as i32);
+                settings.trail_length = TRAIL_LENGTHS[next as usize];
+            }
+            SettingOption::SpeedrunTimer => settings.speedrun_timer = !settings.speedrun_timer,
+            SettingOption::BuildLog => settings.build_log = !settings.build_log,
+            SettingOption::GithubEvents => settings.github_events = !settings.github_events,
+            SettingOption::Telemetry => settings.telemetry = Some(!settings.telemetry_enabled()),
+            SettingOption::UpdateCheck => settings.update_check = !settings.update_check,
+            #[cfg(feature = "discord")]
+            SettingOption::DiscordPresence => {
+                settings.discord_presence = !settings.discord_presence
+            }
+            SettingOption::FrameCap => {
+                let current = FRAME_CAPS
+                    .iter()
+                    .position(|cap| *cap == settings.frame_cap)
+                    .unwrap_or(0);
+                let next = (current as i32 + direction).rem_euclid(FRAME_CAPS.len() as i32);
+                settings.frame_cap = FRAME_CAPS[next as usize];
+            }
+            // Stops at the ends; the calibration screen sets it to the millisecond
+            SettingOption::InputLead => {
+                let lead = settings.input_lead_ms as i32 + direction * INPUT_LEAD_STEP_MS as i32;
+                settings.input_lead_ms = lead.clamp(0, MAX_INPUT_LEAD_MS as i32) as u32;
+            }
+        }
+    }
+}
+
+fn volume_text(volume: f32) -> String {
+    if volume <= 0.0 {
+        "OFF".to_string()
+    } else {
+        format!("{}%", (volume * 100.0).round())
+    }
+}
+
+// Stops at the ends rather than wrapping from loud to silent
+fn step_volume(volume: &mut f32, direction: i32) {
+    let steps = (*volume * 10.0).round() as i32 + direction;
+    *volume = steps.clamp(0, 10) as f32 / 10.0;
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
@@ -0,0 +1,162 @@
+use crate::entities::ItemType;
+use crate::game::events::GameEvent;
+use crate::game::versus::PLAYER_ONE;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const MAX_BUFFERED: usize = 500; // Oldest events are dropped past this while offline
+const BATCH_SIZE: usize = 50;
+
+// What gets shared once the player opts in. Nothing here identifies the
+// player: no names, no scores tied to a leaderboard entry, no machine details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TelemetryEvent {
+    Session {
+        seconds: f32,
+        runs: u32,
+    },
+    Run {
+        level: u32, // Level reached
+        seconds: f32,
+        lanes: usize,
+        difficulty: String,
+        mutators: u8,
+        death: Option<String>, // Item id that ended the run
+    },
+}
+
+// The file on disk. The session still running is written alongside the
+// queue so the next launch can close it out even if this one never says
+// goodbye.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Buffer {
+    events: Vec<TelemetryEvent>,
+    open_session: Option<(f32, u32)>, // (seconds, runs)
+}
+
+// Gameplay events waiting to be sent, kept on disk until the server has them
+#[derive(Debug, Default)]
+pub struct Telemetry {
+    buffer: Buffer,
+    session_seconds: f32,
+    session_runs: u32,
+    death: Option<ItemType>, // What crashed into player one this run
+    in_flight: usize,        // Events in the batch being sent, 0 when idle
+}
+
+impl Telemetry {
+    // Only reads the buffer with consent; without it there is nothing to read
+    pub fn load(consented: bool) -> Self {
+        let mut telemetry = Self::default();
+        if !consented {
+            return telemetry;
+        }
+
+        if let Some(contents) = buffer_path().and_then(|path| fs::read_to_string(path).ok()) {
+            telemetry.buffer = serde_json::from_str(&contents).unwrap_or_default();
+        }
+        if let Some((seconds, runs)) = telemetry.buffer.open_session.take() {
+            telemetry.push(TelemetryEvent::Session { seconds, runs });
+        }
+        telemetry
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        self.session_seconds += dt;
+    }
+
+    pub fn handle_event(&mut self, event: &GameEvent) {
+        if let GameEvent::Crashed { item_type, player } = event {
+            if *player == PLAYER_ONE {
+                self.death = Some(*item_type);
+            }
+        }
+    }
+
+    // The crash recorded since the last run ended, if any
+    pub fn take_death(&mut self) -> Option<ItemType> {
+        self.death.take()
+    }
+
+    pub fn record_run(&mut self, event: TelemetryEvent) {
+        self.session_runs += 1;
+        self.push(event);
+        self.save();
+    }
+
+    // The next batch to send, unless one is already on its way
+    pub fn take_batch(&mut self) -> Option<Vec<TelemetryEvent>> {
+        if self.in_flight > 0 || self.buffer.events.is_empty() {
+            return None;
+        }
+        let count = self.buffer.events.len().min(BATCH_SIZE);
+        self.in_flight = count;
+        Some(self.buffer.events[..count].to_vec())
+    }
+
+    // A failed batch stays at the front of the queue for next time
+    pub fn batch_finished(&mut self, delivered: bool) {
+        if delivered {
+            self.buffer
+                .events
+                .drain(..self.in_flight.min(self.buffer.events.len()));
+            self.save();
+        }
+        self.in_flight = 0;
+    }
+
+    // A clean exit closes the session now instead of leaving it for the next launch
+    pub fn end_session(&mut self) {
+        self.push(TelemetryEvent::Session {
+            seconds: self.session_seconds,
+            runs: self.session_runs,
+        });
+        self.session_seconds = 0.0;
+        self.session_runs = 0;
+        self.save();
+    }
+
+    // Opting out throws away anything not yet sent
+    pub fn discard(&mut self) {
+        *self = Self::default();
+        if let Some(path) = buffer_path() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    fn push(&mut self, event: TelemetryEvent) {
+        self.buffer.events.push(event);
+        let excess = self.buffer.events.len().saturating_sub(MAX_BUFFERED);
+        // Never drop events that are part of the batch in flight
+        if excess > 0 && self.in_flight == 0 {
+            self.buffer.events.drain(..excess);
+        }
+    }
+
+    fn save(&mut self) {
+        let Some(path) = buffer_path() else {
+            return;
+        };
+
+        self.buffer.open_session =
+            (self.session_seconds > 0.0).then_some((self.session_seconds, self.session_runs));
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let contents =
+                    serde_json::to_string(&self.buffer).map_err(std::io::Error::other)?;
+                fs::write(&path, contents)
+            });
+        if let Err(e) = result {
+            println!("Failed to save telemetry: {}", e);
+        }
+    }
+}
+
+fn buffer_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("yeti-set-go").join("telemetry.json"))
+}
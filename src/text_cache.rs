@@ -0,0 +1,236 @@
+use crate::design::{self, GameFonts, TypographyStyle};
+use macroquad::miniquad::{BlendFactor, BlendState, BlendValue, Equation, PipelineParams};
+use macroquad::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// Dynamic strings (scores, timers) get a fresh entry whenever their content
+// changes, so both maps are dropped wholesale once they grow past these
+const MAX_MEASUREMENTS: usize = 1024;
+const MAX_RENDERED: usize = 64;
+const RENDER_PADDING: f32 = 2.0; // Room for glyphs that overhang their advance
+
+// Font size comes from the style and the current text scale, so changing
+// the scale setting naturally misses the old entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct TextKey {
+    style: TypographyStyle,
+    size: u16,
+    has_font: bool,
+}
+
+struct RenderedText {
+    target: RenderTarget,
+    offset_y: f32, // Baseline, from the top of the unpadded text
+}
+
+// Measurements for every string the UI draws, and pre-rendered textures for
+// static titles and headings so they skip per-glyph layout every frame
+#[derive(Default)]
+struct TextCache {
+    measurements: HashMap<TextKey, HashMap<String, TextDimensions>>,
+    rendered: HashMap<TextKey, HashMap<String, RenderedText>>,
+    material: Option<Result<Material, ()>>,
+}
+
+thread_local! {
+    static TEXT_CACHE: RefCell<TextCache> = RefCell::new(TextCache::default());
+}
+
+pub fn measure(
+    text: &str,
+    style: TypographyStyle,
+    font: Option<&Font>,
+    size: u16,
+    fonts: &GameFonts,
+) -> TextDimensions {
+    let key = TextKey {
+        style,
+        size,
+        has_font: font.is_some(),
+    };
+    TEXT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some(dimensions) = cache
+            .measurements
+            .get(&key)
+            .and_then(|strings| strings.get(text))
+        {
+            return *dimensions;
+        }
+
+        if cache.measurements.values().map(HashMap::len).sum::<usize>() >= MAX_MEASUREMENTS {
+            cache.measurements.clear();
+        }
+        let dimensions = design::measure_text_runs(text, font, size, 1.0, fonts);
+        cache
+            .measurements
+            .entry(key)
+            .or_default()
+            .insert(text.to_string(), dimensions);
+        dimensions
+    })
+}
+
+// Draw text that rarely changes from a texture rendered the first time it's
+// seen. Rendered white and tinted here, so palette changes reuse it. Falls
+// back to drawing the glyphs directly if the texture can't be made.
+pub fn draw_static(
+    text: &str,
+    x: f32,
+    y: f32,
+    style: TypographyStyle,
+    color: Color,
+    fonts: &GameFonts,
+) {
+    let params = style.get_params(fonts, WHITE);
+    let dimensions = style.measure_text(text, fonts);
+    let key = TextKey {
+        style,
+        size: params.font_size,
+        has_font: params.font.is_some(),
+    };
+
+    TEXT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let cached = cache
+            .rendered
+            .get(&key)
+            .is_some_and(|strings| strings.contains_key(text));
+        if !cached {
+            let Some(rendered) = cache.render(text, params.clone(), dimensions, fonts) else {
+                design::draw_text_runs(text, x, y, TextParams { color, ..params }, fonts);
+                return;
+            };
+            if cache.rendered.values().map(HashMap::len).sum::<usize>() >= MAX_RENDERED {
+                cache.rendered.clear();
+            }
+            cache
+                .rendered
+                .entry(key)
+                .or_default()
+                .insert(text.to_string(), rendered);
+        }
+
+        let rendered = &cache.rendered[&key][text];
+        let texture = &rendered.target.texture;
+        draw_texture_ex(
+            texture,
+            // Whole pixels, so nearest filtering copies the texture 1:1
+            (x - RENDER_PADDING).round(),
+            (y - rendered.offset_y - RENDER_PADDING).round(),
+            color,
+            DrawTextureParams {
+                dest_size: Some(vec2(texture.width(), texture.height())),
+                flip_y: true, // Render targets come out upside down
+                ..Default::default()
+            },
+        );
+    });
+}
+
+impl TextCache {
+    fn render(
+        &mut self,
+        text: &str,
+        params: TextParams,
+        dimensions: TextDimensions,
+        fonts: &GameFonts,
+    ) -> Option<RenderedText> {
+        let material = self.material()?.clone();
+        if dimensions.width <= 0.0 {
+            return None;
+        }
+
+        let width = (dimensions.width + RENDER_PADDING * 2.0).ceil();
+        let height = (dimensions.height + RENDER_PADDING * 2.0).ceil();
+        let target = render_target(width as u32, height as u32);
+        target.texture.set_filter(FilterMode::Nearest);
+
+        let mut camera = Camera2D::from_display_rect(Rect::new(0.0, 0.0, width, height));
+        camera.render_target = Some(target.clone());
+
+        // Whatever camera the frame is using (normally the viewport's) comes
+        // back afterwards, with everything drawn so far already flushed to it
+        push_camera_state();
+        set_camera(&camera);
+        clear_background(Color::new(1.0, 1.0, 1.0, 0.0));
+        gl_use_material(&material);
+        design::draw_text_runs(
+            text,
+            RENDER_PADDING,
+            RENDER_PADDING + dimensions.offset_y,
+            params,
+            fonts,
+        );
+        gl_use_default_material();
+        pop_camera_state();
+
+        Some(RenderedText {
+            target,
+            offset_y: dimensions.offset_y,
+        })
+    }
+
+    // The default pipeline blends alpha like color, which squares it on a
+    // transparent target and thins out antialiased edges. This one keeps
+    // glyph coverage as-is. Built on first use, once a GL context exists.
+    fn material(&mut self) -> Option<&Material> {
+        self.material
+            .get_or_insert_with(|| {
+                load_material(
+                    ShaderSource::Glsl {
+                        vertex: VERTEX_SHADER,
+                        fragment: FRAGMENT_SHADER,
+                    },
+                    MaterialParams {
+                        pipeline_params: PipelineParams {
+                            color_blend: Some(BlendState::new(
+                                Equation::Add,
+                                BlendFactor::Value(BlendValue::SourceAlpha),
+                                BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
+                            )),
+                            alpha_blend: Some(BlendState::new(
+                                Equation::Add,
+                                BlendFactor::One,
+                                BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
+                            )),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )
+                .map_err(|e| println!("Text cache disabled, material failed: {}", e))
+            })
+            .as_ref()
+            .ok()
+    }
+}
+
+// macroquad's own default shader; only the blending differs
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying lowp vec2 uv;
+varying lowp vec4 color;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    color = color0 / 255.0;
+    uv = texcoord;
+}"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 100
+varying lowp vec4 color;
+varying lowp vec2 uv;
+
+uniform sampler2D Texture;
+
+void main() {
+    gl_FragColor = color * texture2D(Texture, uv);
+}"#;
@@ -0,0 +1,225 @@
+use crate::colors::*;
+use crate::design::{ColorTheme, DesignContext, ThemeColors};
+use macroquad::prelude::{Color, BLACK, LIGHTGRAY, WHITE};
+use serde::{Deserialize, Serialize};
+
+// The UI color sets the player can pick in settings. The playfield keeps its
+// art whichever is picked; menus, panels and text are drawn from the set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UiTheme {
+    #[default]
+    Brand,
+    HighContrast,
+    Light,
+}
+
+impl UiTheme {
+    pub const ALL: [UiTheme; 3] = [UiTheme::Brand, UiTheme::HighContrast, UiTheme::Light];
+
+    pub fn name(self) -> &'static str {
+        self.set().name
+    }
+
+    pub fn set(self) -> &'static ThemeSet {
+        match self {
+            UiTheme::Brand => &BRAND,
+            UiTheme::HighContrast => &HIGH_CONTRAST,
+            UiTheme::Light => &LIGHT,
+        }
+    }
+
+    // Left (-1) or right (+1) through ALL, wrapping
+    pub fn step(self, direction: i32) -> Self {
+        let current = Self::ALL
+            .iter()
+            .position(|theme| *theme == self)
+            .unwrap_or(0);
+        let next = (current as i32 + direction).rem_euclid(Self::ALL.len() as i32);
+        Self::ALL[next as usize]
+    }
+}
+
+// Everything a theme decides. Success, warning and error aren't here: they
+// follow the colorblind palette instead, so they read the same in any theme.
+#[derive(Debug, Clone, Copy)]
+pub struct ThemeSet {
+    pub name: &'static str,
+    pub primary: ThemeColors,   // Text on the backdrop
+    pub secondary: ThemeColors, // Text on panels and input boxes
+    pub neutral: ThemeColors,
+    pub technical: ThemeColors,
+    pub backdrop: Color,       // Behind full-screen menus, over the game
+    pub overlay: Color,        // Dims the game behind popups
+    pub surface: Color,        // Inside panels and input boxes
+    pub surface_border: Color, // Around them
+    pub highlight: Color,      // Selected rows and focused fields
+}
+
+const BRAND: ThemeSet = ThemeSet {
+    name: "Fluree",
+    primary: ThemeColors {
+        foreground: ICE_BLUE,
+        background: DEEP,
+        accent: VIBRANT_BLUE,
+        border: PEAK,
+    },
+    secondary: ThemeColors {
+        foreground: DEEP,
+        background: ICE_BLUE,
+        accent: PLUM,
+        border: PURPLE,
+    },
+    neutral: ThemeColors {
+        foreground: GREY,
+        background: METAL,
+        accent: GREY,
+        border: GREY,
+    },
+    technical: ThemeColors {
+        foreground: TEAL,
+        background: Color::new(0.1, 0.1, 0.2, 0.8),
+        accent: VIBRANT_BLUE,
+        border: METAL,
+    },
+    backdrop: secondary_palette::BACKGROUND,
+    overlay: BACKGROUND_OVERLAY,
+    surface: UI_BACKGROUND,
+    surface_border: UI_BORDER,
+    highlight: UI_HIGHLIGHT,
+};
+
+const HIGH_CONTRAST: ThemeSet = ThemeSet {
+    name: "High contrast",
+    primary: ThemeColors {
+        foreground: WHITE,
+        background: BLACK,
+        accent: WARNING_YELLOW,
+        border: WHITE,
+    },
+    secondary: ThemeColors {
+        foreground: BLACK,
+        background: WHITE,
+        accent: BLACK,
+        border: BLACK,
+    },
+    neutral: ThemeColors {
+        foreground: LIGHTGRAY,
+        background: BLACK,
+        accent: LIGHTGRAY,
+        border: LIGHTGRAY,
+    },
+    technical: ThemeColors {
+        foreground: TEAL,
+        background: BLACK,
+        accent: WARNING_YELLOW,
+        border: WHITE,
+    },
+    backdrop: Color::new(0.0, 0.0, 0.0, 0.9),
+    overlay: Color::new(0.0, 0.0, 0.0, 0.9),
+    surface: WHITE,
+    surface_border: WARNING_YELLOW,
+    highlight: WARNING_YELLOW,
+};
+
+const LIGHT: ThemeSet = ThemeSet {
+    name: "Light",
+    primary: ThemeColors {
+        foreground: DEEP,
+        background: ICE_BLUE,
+        accent: FLUREE_SAFE_BLUE,
+        border: PURPLE,
+    },
+    secondary: ThemeColors {
+        foreground: DEEP,
+        background: WHITE,
+        accent: PLUM,
+        border: PURPLE,
+    },
+    neutral: ThemeColors {
+        foreground: METAL,
+        background: PEAK,
+        accent: METAL,
+        border: METAL,
+    },
+    technical: ThemeColors {
+        foreground: PURPLE,
+        background: Color::new(0.9, 0.95, 1.0, 0.8),
+        accent: FLUREE_SAFE_BLUE,
+        border: METAL,
+    },
+    backdrop: Color::new(ICE_BLUE.r, ICE_BLUE.g, ICE_BLUE.b, 0.85),
+    overlay: Color::new(ICE_BLUE.r, ICE_BLUE.g, ICE_BLUE.b, 0.8),
+    surface: WHITE,
+    surface_border: DEEP,
+    highlight: FLUREE_SAFE_BLUE,
+};
+
+// Where the ui modules get their colors: text through ColorTheme, and the
+// backdrops, panels and highlights around it from here, all out of the
+// theme picked in settings
+pub struct ThemeProvider;
+
+impl ThemeProvider {
+    pub fn current() -> &'static ThemeSet {
+        DesignContext::current().theme.set()
+    }
+
+    // Success, warning and error come out of the colorblind palette, with
+    // a faint wash of the same color behind
+    pub fn colors(theme: ColorTheme) -> ThemeColors {
+        let set = Self::current();
+        let status = |color: Color| ThemeColors {
+            foreground: color,
+            background: Color { a: 0.1, ..color },
+            accent: color,
+            border: color,
+        };
+        let palette = DesignContext::palette();
+        match theme {
+            ColorTheme::Primary => set.primary,
+            ColorTheme::Secondary => set.secondary,
+            ColorTheme::Neutral => set.neutral,
+            ColorTheme::Technical => set.technical,
+            ColorTheme::Success => status(palette.success()),
+            ColorTheme::Warning => status(palette.warning()),
+            ColorTheme::Error => status(palette.error()),
+        }
+    }
+
+    pub fn backdrop() -> Color {
+        DesignContext::backdrop(Self::current().backdrop)
+    }
+
+    pub fn overlay() -> Color {
+        DesignContext::backdrop(Self::current().overlay)
+    }
+
+    pub fn surface() -> Color {
+        Self::current().surface
+    }
+
+    pub fn surface_border() -> Color {
+        Self::current().surface_border
+    }
+
+    pub fn highlight() -> Color {
+        Self::current().highlight
+    }
+
+    // Selected rows: the highlight, faded so the text on it still reads
+    pub fn selection(alpha: f32) -> Color {
+        Color {
+            a: alpha,
+            ..Self::highlight()
+        }
+    }
+
+    // Draw with another theme for a moment, for the dev color showcase
+    pub fn preview(theme: UiTheme, draw: impl FnOnce()) {
+        let context = DesignContext::current();
+        DesignContext::set(DesignContext { theme, ..context });
+        draw();
+        DesignContext::set(context);
+    }
+}
@@ -0,0 +1,214 @@
+// Easing curves and the tweens built on them, so each bit of UI motion
+// doesn't redo its own lerp. Time is whatever the caller passes in: a
+// frame's dt to update(), or a clock reading to sample().
+
+use std::f32::consts::TAU;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ease {
+    Linear,
+    InCubic,
+    #[default]
+    OutCubic, // Quick to start, settling gently into place
+    InOutCubic,
+    OutBack,    // Overshoots a little, then settles
+    OutElastic, // Springs past the end a few times before it stops
+}
+
+impl Ease {
+    // Progress from 0 to 1 along the curve. Back and elastic go past 1 on
+    // the way, but every curve starts at 0 and ends at 1.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Ease::Linear => t,
+            Ease::InCubic => t.powi(3),
+            Ease::OutCubic => 1.0 - (1.0 - t).powi(3),
+            Ease::InOutCubic => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+            Ease::OutBack => {
+                const OVERSHOOT: f32 = 1.70158;
+                1.0 + (OVERSHOOT + 1.0) * (t - 1.0).powi(3) + OVERSHOOT * (t - 1.0).powi(2)
+            }
+            Ease::OutElastic => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * TAU / 3.0).sin() + 1.0
+                }
+            }
+        }
+    }
+}
+
+// One value moving between two others over a duration, after an optional
+// delay, during which it holds the starting value
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tween {
+    from: f32,
+    to: f32,
+    duration: f32,
+    delay: f32,
+    ease: Ease,
+    elapsed: f32,
+}
+
+impl Tween {
+    pub fn new(from: f32, to: f32, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            ..Self::default()
+        }
+    }
+
+    // A value that stays put, for a pause in a timeline
+    pub fn hold(value: f32, duration: f32) -> Self {
+        Self::new(value, value, duration)
+    }
+
+    pub fn ease(self, ease: Ease) -> Self {
+        Self { ease, ..self }
+    }
+
+    pub fn delay(self, seconds: f32) -> Self {
+        Self {
+            delay: seconds,
+            ..self
+        }
+    }
+
+    // Delay included
+    pub fn length(&self) -> f32 {
+        self.delay + self.duration
+    }
+
+    pub fn target(&self) -> f32 {
+        self.to
+    }
+
+    // The value `seconds` in, for callers keeping their own clock
+    pub fn sample(&self, seconds: f32) -> f32 {
+        let t = if self.duration > 0.0 {
+            (seconds - self.delay) / self.duration
+        } else if seconds >= self.delay {
+            1.0
+        } else {
+            0.0
+        };
+        self.from + (self.to - self.from) * self.ease.apply(t)
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.length());
+    }
+
+    pub fn finish(&mut self) {
+        self.elapsed = self.length();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.length()
+    }
+
+    pub fn value(&self) -> f32 {
+        self.sample(self.elapsed)
+    }
+}
+
+// Tweens played one after another. A step can carry a cue, handed back by
+// update() on the frame that step ends, for whatever should happen then;
+// each cue comes back once, in order, however big the frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline<C = ()> {
+    steps: Vec<(Tween, Option<C>)>,
+    elapsed: f32,
+    ended: usize, // Steps already over, and so cued
+}
+
+impl<C> Default for Timeline<C> {
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            elapsed: 0.0,
+            ended: 0,
+        }
+    }
+}
+
+impl<C: Clone> Timeline<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn then(mut self, tween: Tween) -> Self {
+        self.steps.push((tween, None));
+        self
+    }
+
+    // Cues the end of the step added last
+    pub fn cue(mut self, cue: C) -> Self {
+        if let Some((_, slot)) = self.steps.last_mut() {
+            *slot = Some(cue);
+        }
+        self
+    }
+
+    pub fn length(&self) -> f32 {
+        self.steps.iter().map(|(tween, _)| tween.length()).sum()
+    }
+
+    pub fn update(&mut self, dt: f32) -> Vec<C> {
+        self.elapsed = (self.elapsed + dt).min(self.length());
+        self.take_cues()
+    }
+
+    pub fn finish(&mut self) -> Vec<C> {
+        self.elapsed = self.length();
+        self.take_cues()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.length()
+    }
+
+    pub fn value(&self) -> f32 {
+        self.sample(self.elapsed)
+    }
+
+    // The value `seconds` in: the step running then, or where the last left off
+    pub fn sample(&self, seconds: f32) -> f32 {
+        let mut start = 0.0;
+        for (tween, _) in &self.steps {
+            if seconds < start + tween.length() {
+                return tween.sample(seconds - start);
+            }
+            start += tween.length();
+        }
+        self.steps
+            .last()
+            .map_or(0.0, |(tween, _)| tween.sample(tween.length()))
+    }
+
+    fn take_cues(&mut self) -> Vec<C> {
+        let mut cues = Vec::new();
+        let mut end = 0.0;
+        for (index, (tween, cue)) in self.steps.iter().enumerate() {
+            end += tween.length();
+            if end > self.elapsed {
+                break;
+            }
+            if index >= self.ended {
+                cues.extend(cue.clone());
+                self.ended = index + 1;
+            }
+        }
+        cues
+    }
+}
@@ -0,0 +1,106 @@
+use crate::config::*;
+use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
+use crate::game::benchmark::BENCHMARK_SECONDS;
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+pub fn draw_benchmark(game: &Game) {
+    let Some(benchmark) = &game.benchmark else {
+        return;
+    };
+
+    let Some(report) = benchmark.report else {
+        // Progress strip along the bottom while it runs
+        let status = if benchmark.is_warming_up() {
+            "BENCHMARK  warming up...".to_string()
+        } else {
+            format!(
+                "BENCHMARK  {:.1} / {:.0} s  //  {} items  {} particles",
+                benchmark.progress() * BENCHMARK_SECONDS,
+                BENCHMARK_SECONDS,
+                game.items.len(),
+                game.particles.len()
+            )
+        };
+        draw_rectangle(
+            0.0,
+            SCREEN_HEIGHT - 22.0,
+            SCREEN_WIDTH,
+            22.0,
+            ThemeProvider::overlay(),
+        );
+        draw_rectangle(
+            0.0,
+            SCREEN_HEIGHT - 3.0,
+            SCREEN_WIDTH * benchmark.progress(),
+            3.0,
+            ThemeProvider::highlight(),
+        );
+        UIComponent::draw_text(
+            &status,
+            8.0,
+            SCREEN_HEIGHT - 8.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Warning,
+            &game.fonts,
+        );
+        return;
+    };
+
+    let panel_w = 300.0;
+    let panel_h = 130.0;
+    let panel_x = (SCREEN_WIDTH - panel_w) / 2.0;
+    let panel_y = (SCREEN_HEIGHT - panel_h) / 2.0;
+
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    draw_rectangle(
+        panel_x - 3.0,
+        panel_y - 3.0,
+        panel_w + 6.0,
+        panel_h + 6.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(panel_x, panel_y, panel_w, panel_h, ThemeProvider::surface());
+
+    UIComponent::draw_text(
+        "Benchmark results",
+        panel_x + 16.0,
+        panel_y + 28.0,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Secondary,
+        &game.fonts,
+    );
+
+    let lines = [
+        format!("Frames measured: {}", report.frames),
+        format!("Average: {:.2} ms", report.average_ms),
+        format!("95th percentile: {:.2} ms", report.p95_ms),
+        format!("Worst: {:.2} ms", report.worst_ms),
+    ];
+    let mut y = panel_y + 50.0;
+    for line in &lines {
+        UIComponent::draw_text(
+            line,
+            panel_x + 16.0,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Secondary,
+            &game.fonts,
+        );
+        y += 16.0;
+    }
+
+    GameText::instructions(
+        "[SPACE] Menu  //  Also printed to stdout",
+        panel_x + 16.0,
+        panel_y + panel_h - 8.0,
+        &game.fonts,
+    );
+}
@@ -0,0 +1,122 @@
+use crate::config::*;
+use crate::design::{ColorTheme, FlashPolicy, GameText, TypographyStyle, UIComponent};
+use crate::game::calibration::{Calibration, TAPS};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const PANEL_W: f32 = 360.0;
+const PANEL_H: f32 = 190.0;
+const MARKER_RADIUS: f32 = 18.0;
+const TAP_DOT_RADIUS: f32 = 3.0;
+const TAP_DOT_GAP: f32 = 12.0;
+
+pub fn draw_calibration(game: &Game) {
+    let Some(test) = &game.calibration else {
+        return;
+    };
+    let panel_x = (SCREEN_WIDTH - PANEL_W) / 2.0;
+    let panel_y = (SCREEN_HEIGHT - PANEL_H) / 2.0;
+    let center_x = SCREEN_WIDTH / 2.0;
+
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    draw_rectangle(
+        panel_x - 3.0,
+        panel_y - 3.0,
+        PANEL_W + 6.0,
+        PANEL_H + 6.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(panel_x, panel_y, PANEL_W, PANEL_H, ThemeProvider::surface());
+
+    UIComponent::draw_text(
+        "Calibrate input",
+        panel_x + 16.0,
+        panel_y + 28.0,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Secondary,
+        &game.fonts,
+    );
+
+    draw_marker(test, center_x, panel_y + 74.0);
+    draw_tap_dots(test, center_x, panel_y + 106.0);
+
+    let (line, detail) = match (test.lead_ms(), test.spread_ms()) {
+        (Some(lead), Some(spread)) if test.is_finished() => (
+            if lead == 0 {
+                "Your taps land on the beat: no lead needed".to_string()
+            } else {
+                format!("Your taps land {} ms late", lead)
+            },
+            format!("Give or take {} ms", spread),
+        ),
+        _ => (
+            "Tap [SPACE] each time the marker flashes".to_string(),
+            format!("Lead now {} ms", game.settings.input_lead_ms),
+        ),
+    };
+    UIComponent::draw_text_centered(
+        &line,
+        center_x,
+        panel_y + 132.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Secondary,
+        &game.fonts,
+    );
+    UIComponent::draw_text_centered(
+        &detail,
+        center_x,
+        panel_y + 148.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Neutral,
+        &game.fonts,
+    );
+
+    let instructions = if test.is_finished() {
+        "[ENTER] Use it  //  [R] Again  //  [ESC] Back"
+    } else {
+        "[R] Restart  //  [ESC] Back"
+    };
+    GameText::instructions(
+        instructions,
+        panel_x + 16.0,
+        panel_y + PANEL_H - 12.0,
+        &game.fonts,
+    );
+}
+
+// Lit on each beat and fading until the next. It has to flash for the
+// test to work, so flash reduction softens it rather than stilling it.
+fn draw_marker(test: &Calibration, x: f32, y: f32) {
+    let pulse = if test.is_finished() {
+        0.0
+    } else {
+        test.pulse()
+    };
+    let color = FlashPolicy::tint(
+        ThemeProvider::surface_border(),
+        ThemeProvider::highlight(),
+        pulse,
+    );
+    draw_circle(x, y, MARKER_RADIUS, color);
+    draw_circle_lines(x, y, MARKER_RADIUS, 2.0, ThemeProvider::surface_border());
+}
+
+// One dot per tap the test wants, filled as they come in
+fn draw_tap_dots(test: &Calibration, center_x: f32, y: f32) {
+    let left = center_x - (TAPS - 1) as f32 * TAP_DOT_GAP / 2.0;
+    for tap in 0..TAPS {
+        let x = left + tap as f32 * TAP_DOT_GAP;
+        if tap < test.taps() {
+            draw_circle(x, y, TAP_DOT_RADIUS, ThemeProvider::highlight());
+        } else {
+            draw_circle_lines(x, y, TAP_DOT_RADIUS, 1.0, ThemeProvider::surface_border());
+        }
+    }
+}
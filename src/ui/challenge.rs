@@ -0,0 +1,175 @@
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{
+    draw_text_runs, ordinal_suffix, truncate_graphemes, ColorTheme, GameText, Spacing,
+    TypographyStyle, UIComponent,
+};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use crate::ui::leaderboard::MINI_NAME_LENGTH;
+use macroquad::prelude::*;
+
+pub fn draw_challenge_menu(game: &Game) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    GameText::heading_centered("RACE A FRIEND", SCREEN_WIDTH / 2.0, 40.0, &game.fonts);
+
+    let menu = &game.challenge_menu;
+    let left_x = 40.0;
+    let mut y = 80.0;
+
+    // Your code to share
+    GameText::ui_secondary("YOUR CODE", left_x, y, &game.fonts);
+    y += Spacing::Large.as_f32();
+    UIComponent::draw_text(
+        &menu.draft.code(),
+        left_x,
+        y,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Warning,
+        &game.fonts,
+    );
+    y += Spacing::Medium.as_f32() + 4.0;
+    let difficulty_text = format!("< {} >", menu.draft.difficulty.name());
+    UIComponent::draw_text(
+        &difficulty_text,
+        left_x,
+        y,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        &game.fonts,
+    );
+    y += Spacing::XLarge.as_f32();
+
+    // Friend's code entry
+    GameText::ui_secondary("FRIEND'S CODE", left_x, y, &game.fonts);
+    y += Spacing::Small.as_f32();
+
+    let box_width = 180.0;
+    let box_height = 28.0;
+    draw_rectangle(
+        left_x - 2.0,
+        y - 2.0,
+        box_width + 4.0,
+        box_height + 4.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(left_x, y, box_width, box_height, ThemeProvider::surface());
+
+    let input_display = if menu.code_input.is_empty() {
+        "XXXX-XXXX".to_string()
+    } else if menu.code_input.len() > 4 {
+        format!("{}-{}", &menu.code_input[..4], &menu.code_input[4..])
+    } else {
+        menu.code_input.clone()
+    };
+    let input_theme = if menu.code_input.is_empty() {
+        ColorTheme::Neutral
+    } else {
+        ColorTheme::Secondary
+    };
+    UIComponent::draw_text(
+        &input_display,
+        left_x + Spacing::Small.as_f32(),
+        y + 20.0,
+        TypographyStyle::UIInput,
+        input_theme,
+        &game.fonts,
+    );
+    y += box_height + Spacing::Medium.as_f32();
+
+    if let Some(error) = &menu.error {
+        UIComponent::draw_text(
+            error,
+            left_x,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Error,
+            &game.fonts,
+        );
+    }
+
+    draw_challenge_board(game, SCREEN_WIDTH - 260.0, 80.0);
+
+    let instructions = "[ENTER] Play  [</>] Difficulty  [TAB] New Code  [ESC] Menu";
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, &game.fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 20.0,
+        &game.fonts,
+    );
+}
+
+fn draw_challenge_board(game: &Game, x: f32, y: f32) {
+    let menu = &game.challenge_menu;
+
+    let Some(code) = &menu.board_code else {
+        UIComponent::draw_text(
+            "Share your code, then",
+            x,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+        UIComponent::draw_text(
+            "compare scores here!",
+            x,
+            y + Spacing::Medium.as_f32(),
+            TypographyStyle::BodySmall,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+        return;
+    };
+
+    let title = format!("-- {} --", code);
+    UIComponent::draw_text(
+        &title,
+        x,
+        y,
+        TypographyStyle::BodyMedium,
+        ColorTheme::Warning,
+        &game.fonts,
+    );
+
+    let line_height = 18.0;
+    for (i, high_score) in menu.board.iter().take(7).enumerate() {
+        let entry_y = y + Spacing::Large.as_f32() + i as f32 * line_height;
+        let text = format!(
+            "{} {} - {}",
+            ordinal_suffix(i + 1),
+            truncate_graphemes(high_score.display_name(), MINI_NAME_LENGTH),
+            high_score.score
+        );
+        let color = match i {
+            0 => MEDAL_GOLD,
+            1 => MEDAL_SILVER,
+            2 => MEDAL_BRONZE,
+            _ => TEXT_LIGHT,
+        };
+        let params = TypographyStyle::BodySmall.get_params(&game.fonts, color);
+        draw_text_runs(&text, x, entry_y, params, &game.fonts);
+    }
+
+    if menu.board_loading {
+        UIComponent::draw_text(
+            "Syncing challenge board...",
+            x,
+            y + Spacing::Large.as_f32() + 7.0 * line_height,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::config::*;
+use macroquad::miniquad::{UniformDesc, UniformType};
+use macroquad::prelude::*;
+
+// The retro filter: the finished frame goes through this on its way to the
+// window, as scanlines on a slightly curved tube with its colors a little
+// out of register. Needs a GL context, so the viewport builds it.
+pub fn load_crt_material() -> Option<Material> {
+    let material = load_material(
+        ShaderSource::Glsl {
+            vertex: VERTEX_SHADER,
+            fragment: FRAGMENT_SHADER,
+        },
+        MaterialParams {
+            uniforms: vec![UniformDesc::new("Lines", UniformType::Float1)],
+            ..Default::default()
+        },
+    )
+    .map_err(|e| println!("CRT filter disabled, material failed: {}", e))
+    .ok()?;
+    // One scanline per row of the virtual screen, however big the window
+    material.set_uniform("Lines", SCREEN_HEIGHT);
+    Some(material)
+}
+
+// macroquad's own default shader
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying lowp vec2 uv;
+varying lowp vec4 color;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    color = color0 / 255.0;
+    uv = texcoord;
+}"#;
+
+// Bends the lookup outward from the middle, so the corners fall off the
+// tube into black, then splits red and blue apart toward the edges and
+// darkens the gap between each row
+const FRAGMENT_SHADER: &str = r#"#version 100
+precision mediump float;
+
+varying lowp vec4 color;
+varying lowp vec2 uv;
+
+uniform sampler2D Texture;
+uniform float Lines;
+
+const float CURVE = 0.06;
+const float ABERRATION = 0.0015;
+const float SCANLINE_DEPTH = 0.25;
+const float VIGNETTE = 0.18;
+
+void main() {
+    vec2 centered = uv * 2.0 - 1.0;
+    centered *= 1.0 + CURVE * centered.yx * centered.yx;
+    vec2 bent = centered * 0.5 + 0.5;
+    if (bent.x < 0.0 || bent.x > 1.0 || bent.y < 0.0 || bent.y > 1.0) {
+        gl_FragColor = vec4(0.0, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    vec2 shift = centered * ABERRATION;
+    vec3 pixel = vec3(
+        texture2D(Texture, bent + shift).r,
+        texture2D(Texture, bent).g,
+        texture2D(Texture, bent - shift).b
+    );
+    float scanline = 1.0 - SCANLINE_DEPTH * (0.5 + 0.5 * cos(bent.y * Lines * 6.2831853));
+    float vignette = 1.0 - VIGNETTE * dot(centered, centered);
+    gl_FragColor = vec4(pixel * scanline * vignette, 1.0) * color;
+}"#;
@@ -0,0 +1,83 @@
+use crate::config::*;
+use crate::design::{draw_text_runs, DesignContext, GameFonts, TypographyStyle};
+use crate::entities::Particle;
+use crate::game::effects::{FatalHit, ScorePopup};
+use macroquad::prelude::*;
+
+pub fn draw_particles(particles: &[Particle]) {
+    for particle in particles {
+        let color = Color::new(
+            particle.color.r,
+            particle.color.g,
+            particle.color.b,
+            particle.color.a * particle.lifetime.alpha(),
+        );
+        draw_rectangle_ex(
+            particle.body.x,
+            particle.body.y,
+            particle.body.width,
+            particle.body.height,
+            DrawRectangleParams {
+                offset: vec2(0.5, 0.5),
+                rotation: particle.motion.rotation,
+                color,
+            },
+        );
+    }
+}
+
+// Drawn outside the mirror, which would flip the digits too; only where
+// they sit is mirrored
+pub fn draw_score_popups(popups: &[ScorePopup], mirrored: bool, fonts: &GameFonts) {
+    let style = TypographyStyle::BodySmall;
+    for popup in popups {
+        let color = Color {
+            a: popup.alpha(),
+            ..DesignContext::palette().success()
+        };
+        let width = style.measure_text(&popup.text, fonts).width;
+        let x = if mirrored {
+            SCREEN_WIDTH - popup.x
+        } else {
+            popup.x
+        };
+        draw_text_runs(
+            &popup.text,
+            x - width / 2.0,
+            popup.y + popup.rise(),
+            style.get_params(fonts, color),
+            fonts,
+        );
+    }
+}
+
+// The culprit boxed in the error color on the game-over freeze-frame, named
+// above. Mirrored runs froze mirrored, so the box follows.
+pub fn draw_fatal_hit(hit: &FatalHit, name: &str, mirrored: bool, fonts: &GameFonts) {
+    const PADDING: f32 = 4.0;
+    let color = DesignContext::palette().error();
+    let mut bounds = hit.bounds;
+    if mirrored {
+        bounds.x = SCREEN_WIDTH - bounds.x - bounds.w;
+    }
+    draw_rectangle_lines(
+        bounds.x - PADDING,
+        bounds.y - PADDING,
+        bounds.w + PADDING * 2.0,
+        bounds.h + PADDING * 2.0,
+        2.0,
+        color,
+    );
+
+    let label = format!("Killed by: {}", name);
+    let style = TypographyStyle::BodySmall;
+    let width = style.measure_text(&label, fonts).width;
+    let x = (bounds.center().x - width / 2.0).clamp(PADDING, SCREEN_WIDTH - width - PADDING);
+    draw_text_runs(
+        &label,
+        x,
+        bounds.y - PADDING * 2.0,
+        style.get_params(fonts, color),
+        fonts,
+    );
+}
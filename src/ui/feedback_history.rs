@@ -0,0 +1,109 @@
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{
+    draw_text_runs, format_duration, ColorTheme, DesignContext, GameFonts, GameText,
+    TypographyStyle, UIComponent,
+};
+use crate::game::feedback::FeedbackState;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+pub const VISIBLE_LINES: usize = 10;
+const ROW_HEIGHT: f32 = 16.0;
+const PANEL_CHROME: f32 = 76.0; // Title and instructions around the rows
+const TIME_COLUMN: f32 = 44.0;
+
+pub fn draw_feedback_history(feedback: &FeedbackState, fonts: &GameFonts) {
+    let row_height = ROW_HEIGHT * DesignContext::type_scale().min(1.15);
+    let panel_w = 480.0;
+    let panel_h = PANEL_CHROME + VISIBLE_LINES as f32 * row_height;
+    let panel_x = (SCREEN_WIDTH - panel_w) / 2.0;
+    let panel_y = (SCREEN_HEIGHT - panel_h) / 2.0;
+
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    draw_rectangle(
+        panel_x - 3.0,
+        panel_y - 3.0,
+        panel_w + 6.0,
+        panel_h + 6.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(panel_x, panel_y, panel_w, panel_h, ThemeProvider::surface());
+
+    UIComponent::draw_text(
+        "Feedback history",
+        panel_x + 16.0,
+        panel_y + 28.0,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Secondary,
+        fonts,
+    );
+
+    let top = panel_y + 56.0;
+    if feedback.history.is_empty() {
+        UIComponent::draw_text(
+            "Nothing yet. The yeti has been quiet.",
+            panel_x + 16.0,
+            top,
+            TypographyStyle::BodySmall,
+            ColorTheme::Secondary,
+            fonts,
+        );
+    }
+
+    let text_width = panel_w - 32.0 - TIME_COLUMN;
+    let mut y = top;
+    for line in feedback
+        .history
+        .lines()
+        .skip(feedback.history_scroll)
+        .take(VISIBLE_LINES)
+    {
+        let time_params = TypographyStyle::BodySmall.get_params(fonts, TEXT_SECONDARY);
+        draw_text_runs(
+            &format_duration(line.time),
+            panel_x + 16.0,
+            y,
+            time_params,
+            fonts,
+        );
+
+        let message = TypographyStyle::BodySmall.fit_text(&line.text, text_width, fonts);
+        let params = TypographyStyle::BodySmall.get_params(fonts, line.severity.color());
+        draw_text_runs(&message, panel_x + 16.0 + TIME_COLUMN, y, params, fonts);
+        y += row_height;
+    }
+
+    // Arrows on the right edge when there's more to scroll to
+    let arrow_x = panel_x + panel_w - 14.0;
+    if feedback.history_scroll > 0 {
+        draw_triangle(
+            vec2(arrow_x, top - 12.0),
+            vec2(arrow_x - 4.0, top - 6.0),
+            vec2(arrow_x + 4.0, top - 6.0),
+            ThemeProvider::surface_border(),
+        );
+    }
+    if feedback.history_scroll + VISIBLE_LINES < feedback.history.len() {
+        let bottom = top + (VISIBLE_LINES - 1) as f32 * row_height;
+        draw_triangle(
+            vec2(arrow_x, bottom + 2.0),
+            vec2(arrow_x - 4.0, bottom - 4.0),
+            vec2(arrow_x + 4.0, bottom - 4.0),
+            ThemeProvider::surface_border(),
+        );
+    }
+
+    GameText::instructions(
+        "[UP/DOWN] Scroll  //  [ESC] or [H] Back",
+        panel_x + 16.0,
+        panel_y + panel_h - 12.0,
+        fonts,
+    );
+}
@@ -0,0 +1,216 @@
+use crate::colors::*;
+use crate::design::DesignContext;
+use crate::entities::{Item, Yeti};
+use crate::game::Game;
+use crate::overrides::config;
+use macroquad::miniquad::{BlendFactor, BlendState, BlendValue, Equation, PipelineParams};
+use macroquad::prelude::*;
+use std::cell::RefCell;
+use std::f64::consts::TAU;
+
+const GLOW_TEXTURE_SIZE: u16 = 64;
+const ITEM_GLOW_SCALE: f32 = 1.1; // Glow radius, in item widths
+const RIM_GLOW_SCALE: f32 = 0.8; // Rim light radius, in yeti heights
+const GOOD_ALPHA: f32 = 0.35;
+const BAD_ALPHA: f32 = 0.5;
+const RIM_ALPHA: f32 = 0.6;
+const PULSE_PERIOD: f64 = 0.8; // Seconds per throb of a bad item
+const PULSE_FLOOR: f32 = 0.3; // Share of the glow left at the bottom of a throb
+const REDUCED_PULSE_FLOOR: f32 = 0.7;
+const REDUCED_STRENGTH: f32 = 0.5; // Every glow with flash reduction on
+
+// A soft white disc fading out to its edge, and the additive pipeline that
+// lights up whatever is under it. Built on first use, once a GL context
+// exists, like the text cache.
+struct Glow {
+    texture: Texture2D,
+    material: Material,
+}
+
+thread_local! {
+    static GLOW: RefCell<Option<Result<Glow, ()>>> = const { RefCell::new(None) };
+}
+
+// Halos under the playfield's objects, all in one pass so the material
+// only switches once: a steady green or blue one behind good items, a red
+// throb behind bad ones, and a rim light on a yeti still inside its grace
+// frames after a hit
+pub fn draw_glows(game: &Game) {
+    // Photo filters recolor everything with their own material, and
+    // switching away here would drop it for the rest of the scene
+    let filtered = game
+        .photo
+        .as_ref()
+        .is_some_and(|photo| photo.filter.tones().is_some());
+    if filtered {
+        return;
+    }
+
+    GLOW.with(|glow| {
+        let mut glow = glow.borrow_mut();
+        let Ok(glow) = glow.get_or_insert_with(load_glow) else {
+            return;
+        };
+
+        gl_use_material(&glow.material);
+        let strength = strength();
+        let pulse = pulse(game.clock.time());
+        for item in &game.items {
+            let mut color = item_glow_color(item, pulse);
+            color.a *= strength;
+            draw_item_glow(glow, item, color);
+        }
+
+        let grace = game.collision_grace / config().collision_grace_time;
+        if grace > 0.0 {
+            let color = Color {
+                a: RIM_ALPHA * grace.min(1.0) * strength,
+                ..ICE_BLUE
+            };
+            draw_rim_light(glow, &game.yeti, color);
+            if let Some(versus) = &game.versus {
+                draw_rim_light(glow, &versus.player_two, color);
+            }
+        }
+        gl_use_default_material();
+    });
+}
+
+fn item_glow_color(item: &Item, pulse: f32) -> Color {
+    let palette = DesignContext::palette();
+    if item.is_good {
+        // Halfway to teal, so the standard green reads as a cooler glow
+        let success = palette.success();
+        Color::new(
+            (success.r + TEAL.r) / 2.0,
+            (success.g + TEAL.g) / 2.0,
+            (success.b + TEAL.b) / 2.0,
+            GOOD_ALPHA,
+        )
+    } else {
+        Color {
+            a: BAD_ALPHA * pulse,
+            ..palette.error()
+        }
+    }
+}
+
+fn draw_item_glow(glow: &Glow, item: &Item, color: Color) {
+    let body = item.body;
+    let center = vec2(body.x + body.width / 2.0, body.y - body.height / 2.0);
+    draw_disc(glow, center, body.width * ITEM_GLOW_SCALE, color);
+}
+
+fn draw_rim_light(glow: &Glow, yeti: &Yeti, color: Color) {
+    let center = vec2(yeti.x + yeti.width / 2.0, yeti.y - yeti.height / 2.0);
+    draw_disc(glow, center, yeti.height * RIM_GLOW_SCALE, color);
+}
+
+fn draw_disc(glow: &Glow, center: Vec2, radius: f32, color: Color) {
+    draw_texture_ex(
+        &glow.texture,
+        center.x - radius,
+        center.y - radius,
+        color,
+        DrawTextureParams {
+            dest_size: Some(vec2(radius * 2.0, radius * 2.0)),
+            ..Default::default()
+        },
+    );
+}
+
+// Flash reduction dims every glow
+fn strength() -> f32 {
+    if DesignContext::current().flash_reduction {
+        REDUCED_STRENGTH
+    } else {
+        1.0
+    }
+}
+
+// A bad item's throb, from 0.0 to 1.0. Reduced motion holds it steady, and
+// flash reduction keeps it from dipping far.
+fn pulse(time: f64) -> f32 {
+    let context = DesignContext::current();
+    if context.reduced_motion {
+        return 1.0;
+    }
+    let floor = if context.flash_reduction {
+        REDUCED_PULSE_FLOOR
+    } else {
+        PULSE_FLOOR
+    };
+    let wave = 0.5 + 0.5 * (time * TAU / PULSE_PERIOD).sin() as f32;
+    floor + (1.0 - floor) * wave
+}
+
+fn load_glow() -> Result<Glow, ()> {
+    let material = load_material(
+        ShaderSource::Glsl {
+            vertex: VERTEX_SHADER,
+            fragment: FRAGMENT_SHADER,
+        },
+        MaterialParams {
+            pipeline_params: PipelineParams {
+                color_blend: Some(BlendState::new(
+                    Equation::Add,
+                    BlendFactor::Value(BlendValue::SourceAlpha),
+                    BlendFactor::One,
+                )),
+                // The frame underneath stays opaque
+                alpha_blend: Some(BlendState::new(
+                    Equation::Add,
+                    BlendFactor::Zero,
+                    BlendFactor::One,
+                )),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+    .map_err(|e| println!("Glow disabled, material failed: {}", e))?;
+
+    // Quadratic falloff, so the edge fades in without a visible rim
+    let size = GLOW_TEXTURE_SIZE;
+    let mut image = Image::gen_image_color(size, size, Color::new(1.0, 1.0, 1.0, 0.0));
+    let half = size as f32 / 2.0;
+    for y in 0..size as u32 {
+        for x in 0..size as u32 {
+            let offset = vec2(x as f32 + 0.5 - half, y as f32 + 0.5 - half);
+            let falloff = (1.0 - offset.length() / half).max(0.0);
+            image.set_pixel(x, y, Color::new(1.0, 1.0, 1.0, falloff * falloff));
+        }
+    }
+    let texture = Texture2D::from_image(&image);
+    texture.set_filter(FilterMode::Linear);
+
+    Ok(Glow { texture, material })
+}
+
+// macroquad's own default shader; only the blending differs
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying lowp vec2 uv;
+varying lowp vec4 color;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    color = color0 / 255.0;
+    uv = texcoord;
+}"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 100
+varying lowp vec4 color;
+varying lowp vec2 uv;
+
+uniform sampler2D Texture;
+
+void main() {
+    gl_FragColor = color * texture2D(Texture, uv);
+}"#;
@@ -1,114 +1,650 @@
+use super::panel;
 use crate::colors::*;
 use crate::config::*;
 use crate::design::ColorTheme;
+use crate::design::DesignContext;
 use crate::design::UIComponent;
-use crate::design::{GameText, Spacing, TypographyStyle};
+use crate::design::{
+    draw_text_runs, format_split, format_split_delta, GameFonts, GameText, SafeArea, Spacing,
+    TypographyStyle,
+};
+use crate::entities::{Item, ItemCatalog};
+use crate::game::build_log::BuildLog;
+use crate::game::feedback::{Feedback, FeedbackQueue, Severity};
+use crate::game::focus::Focus;
+use crate::game::objectives::{self, Objectives};
+use crate::game::run::RunState;
+use crate::game::spawning::{SpawnSchedule, LOOK_AHEAD_SECONDS};
+use crate::game::speed_events::{SpeedEventKind, WARNING_TIME};
+use crate::game::speedrun::SpeedrunTimer;
 use crate::game::Game;
+use crate::themes::ThemeProvider;
 use macroquad::prelude::*;
+use std::collections::HashMap;
+
+// The bottom corners' panels, so the build log can sit between them
+const OBJECTIVE_WIDTH: f32 = 150.0;
+const INCOMING_LABEL_WIDTH: f32 = 44.0;
+const INCOMING_SLOT_WIDTH: f32 = 54.0;
+const INCOMING_SLOTS: usize = 3;
+const INCOMING_WIDTH: f32 = INCOMING_LABEL_WIDTH + INCOMING_SLOTS as f32 * INCOMING_SLOT_WIDTH;
+const GHOST_BANNER_HEIGHT: f32 = 18.0;
 
 pub fn draw_game_ui(game: &Game) {
-    draw_score_panel(game);
-    draw_feedback_message(game);
+    // The feedback box would cover the top lane in versus mode
+    if let Some(versus) = &game.versus {
+        super::versus::draw_versus_hud(versus, game.run.level, &game.fonts);
+        return;
+    }
+
+    if game.focus.is_active() {
+        // Cool wash over the play field while time is slowed
+        let tint = Color::new(VIBRANT_BLUE.r, VIBRANT_BLUE.g, VIBRANT_BLUE.b, 0.08);
+        draw_rectangle(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT, tint);
+    }
+
+    // Each panel gets only what it shows. They stack down from under the
+    // radar, and up from the bottom edge or the ghost banner along it.
+    let fonts = &game.fonts;
+    let top = radar_height(game.lane_count) + SafeArea::top() + Spacing::XSmall.as_f32();
+    let bottom = if game.spectating.is_some() {
+        SafeArea::bottom() - GHOST_BANNER_HEIGHT
+    } else {
+        SafeArea::bottom()
+    };
+
+    draw_radar(&game.spawn_schedule, &game.catalog, game.lane_count);
+
+    // Clear of the radar, which runs across the top edge
+    let x = SafeArea::left() + Spacing::XSmall.as_f32();
+    let world = &game.current_level.world;
+    let y = draw_run_progress(&game.run, world, game.hud_score.value(), x, top, fonts);
+    draw_focus_meter(&game.focus, x, y + Spacing::Medium.as_f32(), fonts);
+    draw_badges(&run_badges(game), x, y + Spacing::Medium.as_f32(), fonts);
+
+    draw_feedback_panel(&game.feedback.queue, &game.textures, top, fonts);
+    let incoming: Vec<(&Item, f32)> = game
+        .incoming_items()
+        .take(INCOMING_SLOTS)
+        .map(|item| (item, game.seconds_until_reached(item)))
+        .collect();
+    draw_incoming_strip(&incoming, bottom, fonts);
+    draw_objective(&game.objectives, bottom, fonts);
+
+    // A speed event's banner takes the build log's slot while it's up
+    match game.speed_events.banner() {
+        Some((kind, until)) => draw_speed_banner(kind, until, bottom, fonts),
+        None if game.settings.build_log => {
+            draw_build_log(&game.build_log, game.clock.time(), bottom, fonts)
+        }
+        None => {}
+    }
+    if game.settings.speedrun_timer && game.speedrun_eligible() {
+        draw_speedrun_timer(&game.speedrun, top, fonts);
+    }
+
+    if let Some(playback) = &game.spectating {
+        draw_ghost_banner(&playback.name, playback.score, fonts);
+    }
 }
 
-fn draw_score_panel(game: &Game) {
-    let x = 15.0;
-    let mut y = 30.0;
+fn draw_ghost_banner(name: &str, score: u32, fonts: &GameFonts) {
+    let text = format!("GHOST RUN // {} // {} pts  [ESC] Leave", name, score);
+    let height = GHOST_BANNER_HEIGHT;
+    let y = SCREEN_HEIGHT - height;
+    draw_rectangle(0.0, y, SCREEN_WIDTH, height, ThemeProvider::overlay());
+    UIComponent::draw_text_centered(
+        &text,
+        SCREEN_WIDTH / 2.0,
+        y + 13.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Technical,
+        fonts,
+    );
+}
+
+// Checks, level and score down the top-left corner. Returns the score
+// line's y, for what goes under it.
+fn draw_run_progress(
+    run: &RunState,
+    world: &str,
+    shown_score: u32,
+    x: f32,
+    top: f32,
+    fonts: &GameFonts,
+) -> f32 {
+    let mut y = top;
 
     // Progress display
     let progress_text = format!(
         "{} / {} Passing Checks",
-        game.checks_completed, game.checks_required
+        run.checks_completed, run.checks_required
     );
-    GameText::score(&progress_text, x, y, &game.fonts);
+    GameText::score(&progress_text, x, y, fonts);
     y += Spacing::Large.as_f32();
 
     // Level display
-    let level_text = format!("Level: {}", game.level);
-    GameText::ui_label(&level_text, x, y, &game.fonts);
+    let level_text = if world.is_empty() {
+        format!("Level: {}", run.level)
+    } else {
+        format!("Level: {} ({})", run.level, world)
+    };
+    GameText::ui_label(&level_text, x, y, fonts);
     y += Spacing::Medium.as_f32();
 
     // Score display
-    let score_text = format!("Score: {}", game.score);
-    GameText::ui_label(&score_text, x, y, &game.fonts);
-}
-
-fn draw_feedback_message(game: &Game) {
-    if !game.feedback_message.is_empty() && game.feedback_timer > 0.0 {
-        let box_x = SCREEN_WIDTH - FEEDBACK_BOX_WIDTH - 20.0;
-        let box_y = 20.0;
-
-        // Draw black border
-        draw_rectangle(
-            box_x - 3.0,
-            box_y - 3.0,
-            FEEDBACK_BOX_WIDTH + 6.0,
-            FEEDBACK_BOX_HEIGHT + 6.0,
-            UI_BORDER,
+    let score_text = format!("Score: {}", shown_score);
+    GameText::ui_label(&score_text, x, y, fonts);
+    y
+}
+
+// Whatever changed the rules of this run, one caption each
+fn run_badges(game: &Game) -> Vec<(String, ColorTheme)> {
+    let mut badges = Vec::new();
+
+    // Took the level-complete gamble: bigger points, no missed PRs
+    if !game.level_modifier.is_standard() {
+        let badge = format!("NO REVIEW x{:.1}", game.level_modifier.score_multiplier);
+        badges.push((badge, ColorTheme::Warning));
+    }
+
+    if !game.mutators.is_empty() {
+        let names: Vec<&str> = game.mutators.iter().map(|m| m.badge()).collect();
+        let text = format!(
+            "{} x{:.2}",
+            names.join(" "),
+            game.mutators.score_multiplier()
         );
+        badges.push((text, ColorTheme::Technical));
+    }
+
+    if game.adaptive.active {
+        badges.push(("ADAPTIVE // UNRANKED".to_string(), ColorTheme::Neutral));
+    }
+
+    if let Some(practice) = &game.practice {
+        let text = format!("PRACTICE // {} REWINDS", practice.rewinds);
+        badges.push((text, ColorTheme::Neutral));
+    }
+
+    if game.assisted {
+        badges.push(("ITEM MAGNET // ASSISTED".to_string(), ColorTheme::Neutral));
+    }
 
-        // Draw white background
-        draw_rectangle(
-            box_x,
-            box_y,
-            FEEDBACK_BOX_WIDTH,
-            FEEDBACK_BOX_HEIGHT,
-            UI_BACKGROUND,
+    // Attract mode has its own banner, so only flag dev-enabled autopilot here
+    if game.autopilot && !game.is_demo {
+        badges.push(("AUTOPILOT".to_string(), ColorTheme::Warning));
+    }
+    badges
+}
+
+// One line each, under the line at `y`
+fn draw_badges(badges: &[(String, ColorTheme)], x: f32, mut y: f32, fonts: &GameFonts) {
+    for (text, theme) in badges {
+        y += Spacing::Medium.as_f32();
+        UIComponent::draw_text(text, x, y, TypographyStyle::UICaption, *theme, fonts);
+    }
+}
+
+fn draw_focus_meter(focus: &Focus, x: f32, y: f32, fonts: &GameFonts) {
+    const BAR_WIDTH: f32 = 60.0;
+    const BAR_HEIGHT: f32 = 5.0;
+
+    let label = "FOCUS [SHIFT]";
+    UIComponent::draw_text(
+        label,
+        x,
+        y,
+        TypographyStyle::UICaption,
+        ColorTheme::Primary,
+        fonts,
+    );
+
+    let label_width = TypographyStyle::UICaption.measure_text(label, fonts).width;
+    let bar_x = x + label_width + 6.0;
+    let bar_y = y - BAR_HEIGHT - 1.0;
+    let fill = if focus.is_active() {
+        VIBRANT_BLUE
+    } else {
+        ICE_BLUE
+    };
+    draw_rectangle(bar_x, bar_y, BAR_WIDTH, BAR_HEIGHT, DEEP);
+    draw_rectangle(bar_x, bar_y, BAR_WIDTH * focus.fraction(), BAR_HEIGHT, fill);
+    draw_rectangle_lines(bar_x, bar_y, BAR_WIDTH, BAR_HEIGHT, 1.0, ICE_BLUE);
+}
+
+const RADAR_LANE_HEIGHT: f32 = 5.0;
+
+fn radar_height(lane_count: usize) -> f32 {
+    4.0 + RADAR_LANE_HEIGHT * lane_count as f32
+}
+
+// A thin bar across the top of the play field with a dot for each item that
+// hasn't come on screen yet: the left end is the screen edge, the right end
+// is as far ahead as spawning plans. Lanes stack bottom to top.
+fn draw_radar(schedule: &SpawnSchedule, catalog: &ItemCatalog, lane_count: usize) {
+    const DOT_RADIUS: f32 = 2.5;
+
+    let height = radar_height(lane_count);
+    draw_rectangle(0.0, 0.0, SCREEN_WIDTH, height, ThemeProvider::overlay());
+
+    let palette = DesignContext::palette();
+    for (planned, seconds) in schedule.upcoming() {
+        let x = SafeArea::left()
+            + (seconds / LOOK_AHEAD_SECONDS).min(1.0) * (SafeArea::right() - SafeArea::left());
+        let y = height - 2.0 - RADAR_LANE_HEIGHT * (planned.lane as f32 + 0.5);
+        let color = if catalog.get(planned.item_type).good {
+            palette.success()
+        } else {
+            palette.error()
+        };
+        draw_circle(x, y, DOT_RADIUS, color);
+    }
+}
+
+// The next few items with how long until each reaches the yeti, in the
+// bottom corner so it never fights the feedback box
+fn draw_incoming_strip(incoming: &[(&Item, f32)], bottom: f32, fonts: &GameFonts) {
+    const ICON_SIZE: f32 = 16.0;
+    const HEIGHT: f32 = 24.0;
+
+    let x = SafeArea::right() - INCOMING_WIDTH;
+    let y = bottom - HEIGHT;
+
+    draw_rectangle(x, y, INCOMING_WIDTH, HEIGHT, ThemeProvider::overlay());
+    UIComponent::draw_text(
+        "NEXT",
+        x + 6.0,
+        y + 16.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Primary,
+        fonts,
+    );
+
+    let palette = DesignContext::palette();
+    for (slot, (item, seconds)) in incoming.iter().enumerate() {
+        let slot_x = x + INCOMING_LABEL_WIDTH + slot as f32 * INCOMING_SLOT_WIDTH;
+        let icon_y = y + (HEIGHT - ICON_SIZE) / 2.0;
+        let kind_color = if item.is_good {
+            palette.success()
+        } else {
+            palette.error()
+        };
+
+        match &item.sprite {
+            Some(sprite) => sprite.draw(
+                slot_x,
+                icon_y,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(ICON_SIZE, ICON_SIZE)),
+                    ..Default::default()
+                },
+            ),
+            None => draw_rectangle(slot_x, icon_y, ICON_SIZE, ICON_SIZE, kind_color),
+        }
+        // Good/bad underline, so the kind never relies on the sprite alone
+        draw_rectangle(slot_x, icon_y + ICON_SIZE + 1.0, ICON_SIZE, 2.0, kind_color);
+
+        UIComponent::draw_text(
+            &format!("{:.1}s", seconds),
+            slot_x + ICON_SIZE + 4.0,
+            y + 16.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Primary,
+            fonts,
         );
+    }
+}
+
+// The live sprint in the bottom-left corner, opposite the incoming strip,
+// with a bar for progress and a thinner one for the time left
+fn draw_objective(objectives: &Objectives, bottom: f32, fonts: &GameFonts) {
+    const HEIGHT: f32 = 24.0;
+    const BAR_HEIGHT: f32 = 3.0;
 
-        // Draw technical feedback with word wrapping
-        let text_x = box_x + 10.0;
-        let text_y = box_y + 20.0;
-        let line_height = Spacing::Medium.as_f32();
-
-        let words: Vec<&str> = game.feedback_message.split_whitespace().collect();
-        let mut current_line = String::new();
-        let mut y_offset = 0.0;
-
-        for word in words {
-            let test_line = if current_line.is_empty() {
-                word.to_string()
-            } else {
-                format!("{} {}", current_line, word)
-            };
-
-            let test_width = TypographyStyle::CodeLarge
-                .measure_text(&test_line, &game.fonts)
-                .width;
-
-            if test_width <= FEEDBACK_BOX_WIDTH - 20.0 {
-                current_line = test_line;
-            } else {
-                if !current_line.is_empty() {
-                    // GameText::technical_feedback(&current_line, text_x, text_y + y_offset, &game.fonts);
-                    UIComponent::draw_text(
-                        &current_line,
-                        text_x,
-                        text_y + y_offset,
-                        TypographyStyle::CodeLarge,
-                        ColorTheme::Secondary,
-                        &game.fonts,
-                    );
-                    y_offset += line_height;
-                }
-                current_line = word.to_string();
+    let (text, fraction, time_fraction, theme) = match (&objectives.current, &objectives.completed)
+    {
+        (Some(objective), _) => (
+            objective.description(),
+            objective.fraction(),
+            objective.time_left / objectives::TIME_LIMIT,
+            ColorTheme::Primary,
+        ),
+        (None, Some((description, _))) => (
+            format!("DONE: {}", description),
+            1.0,
+            0.0,
+            ColorTheme::Success,
+        ),
+        (None, None) => return,
+    };
+
+    let x = SafeArea::left();
+    let y = bottom - HEIGHT;
+    draw_rectangle(x, y, OBJECTIVE_WIDTH, HEIGHT, ThemeProvider::overlay());
+
+    let label = if objectives.chain > 0 {
+        format!("SPRINT // CHAIN {}", objectives.chain)
+    } else {
+        "SPRINT".to_string()
+    };
+    UIComponent::draw_text(
+        &label,
+        x + 6.0,
+        y + 9.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Technical,
+        fonts,
+    );
+    UIComponent::draw_text(
+        &text,
+        x + 6.0,
+        y + 18.0,
+        TypographyStyle::UICaption,
+        theme,
+        fonts,
+    );
+
+    let palette = DesignContext::palette();
+    let bar_width = OBJECTIVE_WIDTH - 12.0;
+    let bar_y = y + HEIGHT - BAR_HEIGHT - 2.0;
+    draw_rectangle(x + 6.0, bar_y, bar_width, BAR_HEIGHT, DEEP);
+    draw_rectangle(
+        x + 6.0,
+        bar_y,
+        bar_width * fraction,
+        BAR_HEIGHT,
+        palette.success(),
+    );
+    draw_rectangle(
+        x,
+        y,
+        OBJECTIVE_WIDTH * time_fraction.clamp(0.0, 1.0),
+        1.0,
+        ICE_BLUE,
+    );
+}
+
+// The build log between the sprint and the incoming strip: the newest line
+// slides up into place as the one before it fades out above. `now` is the
+// run clock the lines were stamped with.
+fn draw_build_log(build_log: &BuildLog, now: f64, bottom: f32, fonts: &GameFonts) {
+    const HEIGHT: f32 = 24.0;
+    const BASELINE: f32 = 16.0;
+    const SLIDE_TIME: f64 = 0.25;
+
+    let gap = Spacing::XSmall.as_f32();
+    let x = SafeArea::left() + OBJECTIVE_WIDTH + gap;
+    let width = SafeArea::right() - INCOMING_WIDTH - gap - x;
+    let y = bottom - HEIGHT;
+    draw_rectangle(x, y, width, HEIGHT, ThemeProvider::overlay());
+
+    let Some(newest) = build_log.newest() else {
+        UIComponent::draw_text(
+            "$ build --watch",
+            x + 6.0,
+            y + BASELINE,
+            TypographyStyle::CodeSmall,
+            ColorTheme::Technical,
+            fonts,
+        );
+        return;
+    };
+    let slide = if DesignContext::reduced_motion() {
+        1.0
+    } else {
+        ((now - newest.at) / SLIDE_TIME).clamp(0.0, 1.0) as f32
+    };
+
+    let lines = [
+        (build_log.previous(), -slide, 1.0 - slide),
+        (Some(newest), 1.0 - slide, slide),
+    ];
+    for (line, offset, alpha) in lines {
+        let Some(line) = line else {
+            continue;
+        };
+        if alpha <= 0.0 {
+            continue;
+        }
+        let text = TypographyStyle::CodeSmall.fit_text(&line.text, width - 12.0, fonts);
+        let color = Color {
+            a: alpha,
+            ..line.severity.color()
+        };
+        let params = TypographyStyle::CodeSmall.get_params(fonts, color);
+        draw_text_runs(
+            &text,
+            x + 6.0,
+            y + BASELINE + offset * HEIGHT / 2.0,
+            params,
+            fonts,
+        );
+    }
+}
+
+// The any% clock and the latest split against the best, just left of center
+// at the top, between the score panel and the feedback box
+fn draw_speedrun_timer(timer: &SpeedrunTimer, top: f32, fonts: &GameFonts) {
+    const WIDTH: f32 = 110.0;
+    const HEIGHT: f32 = 24.0;
+
+    let x = SCREEN_WIDTH / 2.0 - WIDTH - Spacing::Small.as_f32();
+    let y = top;
+    draw_rectangle(x, y, WIDTH, HEIGHT, ThemeProvider::overlay());
+
+    let (clock, clock_theme) = if timer.is_new_best() {
+        (
+            format!("ANY% {} PB!", format_split(timer.elapsed())),
+            ColorTheme::Success,
+        )
+    } else {
+        (
+            format!("ANY% {}", format_split(timer.elapsed())),
+            ColorTheme::Primary,
+        )
+    };
+    UIComponent::draw_text(
+        &clock,
+        x + 6.0,
+        y + 9.0,
+        TypographyStyle::UICaption,
+        clock_theme,
+        fonts,
+    );
+
+    let latest = timer.splits().len().checked_sub(1);
+    let (split, split_theme) = match latest {
+        Some(index) => {
+            let time = format_split(timer.splits()[index]);
+            match timer.delta(index) {
+                Some(delta) => (
+                    format!("L{} {} {}", index + 1, time, format_split_delta(delta)),
+                    split_theme(delta),
+                ),
+                None => (format!("L{} {}", index + 1, time), ColorTheme::Neutral),
             }
         }
+        None => match timer.best().last() {
+            Some(best) => (format!("PB {}", format_split(*best)), ColorTheme::Neutral),
+            None => ("NO PB YET".to_string(), ColorTheme::Neutral),
+        },
+    };
+    UIComponent::draw_text(
+        &split,
+        x + 6.0,
+        y + 18.0,
+        TypographyStyle::UICaption,
+        split_theme,
+        fonts,
+    );
+}
+
+// Ahead of the best is good news
+pub fn split_theme(delta: f64) -> ColorTheme {
+    if delta <= 0.0 {
+        ColorTheme::Success
+    } else {
+        ColorTheme::Error
+    }
+}
+
+// A speed event coming up or under way, bottom center between the sprint
+// and the incoming strip. The warning counts down with a draining bar.
+// `until` is None once it's under way
+fn draw_speed_banner(kind: SpeedEventKind, until: Option<f32>, bottom: f32, fonts: &GameFonts) {
+    const WIDTH: f32 = 140.0;
+    const HEIGHT: f32 = 24.0;
+
+    let x = SCREEN_WIDTH / 2.0 - WIDTH / 2.0;
+    let y = bottom - HEIGHT;
+    draw_rectangle(x, y, WIDTH, HEIGHT, ThemeProvider::overlay());
 
-        if !current_line.is_empty() {
-            // GameText::technical_feedback(&current_line, text_x, text_y + y_offset, &game.fonts);
-            UIComponent::draw_text(
-                &current_line,
-                text_x,
-                text_y + y_offset,
-                TypographyStyle::CodeLarge,
-                ColorTheme::Secondary,
-                &game.fonts,
-            );
+    let title = match until {
+        Some(seconds) => format!("{} IN {:.1}s", kind.title(), seconds),
+        None => kind.title().to_string(),
+    };
+    let theme = match kind {
+        SpeedEventKind::Rush => ColorTheme::Warning,
+        SpeedEventKind::Freeze => ColorTheme::Primary,
+    };
+    UIComponent::draw_text_centered(
+        &title,
+        SCREEN_WIDTH / 2.0,
+        y + 9.0,
+        TypographyStyle::UICaption,
+        theme,
+        fonts,
+    );
+    UIComponent::draw_text_centered(
+        kind.description(),
+        SCREEN_WIDTH / 2.0,
+        y + 18.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Technical,
+        fonts,
+    );
+
+    if let Some(seconds) = until {
+        let fraction = (seconds / WARNING_TIME).clamp(0.0, 1.0);
+        draw_rectangle(x, y, WIDTH * fraction, 1.0, ICE_BLUE);
+    }
+}
+
+// The most important message gets the full box; anything else on the go
+// shows as a one-line strip underneath it
+fn draw_feedback_panel(
+    queue: &FeedbackQueue,
+    textures: &HashMap<String, Texture2D>,
+    top: f32,
+    fonts: &GameFonts,
+) {
+    let mut messages = queue.messages();
+    let Some(first) = messages.next() else {
+        return;
+    };
+
+    let size = FeedbackSize::current();
+    let box_x = SafeArea::right() - size.width - Spacing::Small.as_f32();
+    let mut y = top;
+    let area = Rect::new(box_x, y, size.width, size.box_height);
+    draw_feedback_frame(textures, area, first.severity);
+    draw_feedback_text(first, area, fonts);
+    y += size.box_height + FEEDBACK_GAP;
+
+    for message in messages {
+        let strip = Rect::new(box_x, y, size.width, size.strip_height);
+        draw_feedback_frame(textures, strip, message.severity);
+        let text = TypographyStyle::CodeSmall.fit_text(&message.text, size.width - 20.0, fonts);
+        UIComponent::draw_text(
+            &text,
+            box_x + 10.0,
+            y + size.strip_height - 5.0,
+            TypographyStyle::CodeSmall,
+            ColorTheme::Secondary,
+            fonts,
+        );
+        y += size.strip_height + FEEDBACK_GAP;
+    }
+}
+
+// The feedback box grows with the UI scale so wrapped text still fits, but
+// never past half the screen, or it would run into the score panel
+struct FeedbackSize {
+    width: f32,
+    box_height: f32,
+    strip_height: f32,
+}
+
+impl FeedbackSize {
+    fn current() -> Self {
+        let scale = DesignContext::current().ui_scale;
+        Self {
+            width: (FEEDBACK_BOX_WIDTH * scale).min(SCREEN_WIDTH / 2.0),
+            box_height: FEEDBACK_BOX_HEIGHT * scale,
+            strip_height: FEEDBACK_STRIP_HEIGHT * scale,
         }
     }
 }
 
+// Framed panel with a severity-colored edge
+fn draw_feedback_frame(textures: &HashMap<String, Texture2D>, area: Rect, severity: Severity) {
+    panel::draw_panel(textures, area, ColorTheme::Secondary);
+    draw_rectangle(area.x, area.y, 4.0, area.h, severity.color());
+}
+
+// Technical feedback, word wrapped inside the box at `area`
+fn draw_feedback_text(feedback: &Feedback, area: Rect, fonts: &GameFonts) {
+    let text_x = area.x + 10.0;
+    let text_y = area.y + 20.0 * DesignContext::current().ui_scale;
+    let line_height = Spacing::Medium.as_f32();
+
+    let words: Vec<&str> = feedback.text.split_whitespace().collect();
+    let mut current_line = String::new();
+    let mut y_offset = 0.0;
+
+    for word in words {
+        let test_line = if current_line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", current_line, word)
+        };
+
+        let test_width = TypographyStyle::CodeLarge
+            .measure_text(&test_line, fonts)
+            .width;
+
+        if test_width <= area.w - 20.0 {
+            current_line = test_line;
+        } else {
+            if !current_line.is_empty() {
+                UIComponent::draw_text(
+                    &current_line,
+                    text_x,
+                    text_y + y_offset,
+                    TypographyStyle::CodeLarge,
+                    ColorTheme::Secondary,
+                    fonts,
+                );
+                y_offset += line_height;
+            }
+            current_line = word.to_string();
+        }
+    }
+
+    if !current_line.is_empty() {
+        UIComponent::draw_text(
+            &current_line,
+            text_x,
+            text_y + y_offset,
+            TypographyStyle::CodeLarge,
+            ColorTheme::Secondary,
+            fonts,
+        );
+    }
+}
+
 pub fn draw_instructions(game: &Game) {
     let instructions = "SPACE or Click to Jump | Collect Good Items | Avoid Bad Items";
     GameText::instructions(instructions, 10.0, SCREEN_HEIGHT - 20.0, &game.fonts);
@@ -0,0 +1,51 @@
+use crate::colors::IDENTICON_COLORS;
+use macroquad::prelude::*;
+
+const GRID: usize = 5; // Cells across and down; the right two mirror the left
+const HALF: usize = GRID.div_ceil(2);
+
+// A little mirrored pattern in one color, the same for a name every time
+// and on every machine, so players can pick out their own rows at a glance
+pub struct Identicon {
+    color: Color,
+    cells: [[bool; HALF]; GRID], // By row, the left half and the middle
+}
+
+impl Identicon {
+    pub fn new(name: &str) -> Self {
+        let hash = fnv1a(&name.to_lowercase());
+        let color = IDENTICON_COLORS[(hash % IDENTICON_COLORS.len() as u64) as usize];
+        let mut cells = [[false; HALF]; GRID];
+        for (index, cell) in cells.iter_mut().flatten().enumerate() {
+            *cell = hash >> (8 + index) & 1 == 1;
+        }
+        Self { color, cells }
+    }
+
+    // `size` is the width and height; (x, y) is the top left
+    pub fn draw(&self, x: f32, y: f32, size: f32, alpha: f32) {
+        let cell = size / GRID as f32;
+        let color = Color::new(self.color.r, self.color.g, self.color.b, alpha);
+        draw_rectangle(x, y, size, size, Color::new(0.0, 0.0, 0.0, 0.25 * alpha));
+        for (row, half) in self.cells.iter().enumerate() {
+            for (column, filled) in half.iter().enumerate() {
+                if !filled {
+                    continue;
+                }
+                let cell_y = y + row as f32 * cell;
+                draw_rectangle(x + column as f32 * cell, cell_y, cell, cell, color);
+                let mirror = GRID - 1 - column;
+                if mirror != column {
+                    draw_rectangle(x + mirror as f32 * cell, cell_y, cell, cell, color);
+                }
+            }
+        }
+    }
+}
+
+// Stable across runs and Rust versions, unlike the standard library's hasher
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
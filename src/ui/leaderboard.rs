@@ -1,153 +1,600 @@
+use super::identicon::Identicon;
+use super::panel;
+use super::widgets;
 use crate::colors::*;
 use crate::config::*;
-use crate::design::ordinal_suffix;
-use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
-use crate::game::Game;
+use crate::design::{
+    draw_text_runs, format_duration, format_thousands, ordinal_suffix, truncate_graphemes,
+};
+use crate::design::{
+    Anchor, Columns, ColorTheme, DesignContext, GameFonts, GameText, TypographyStyle,
+};
+use crate::design::UIComponent;
+use crate::game::admin::AdminMode;
+use crate::game::api_state::ApiState;
+use crate::game::leaderboard::LeaderboardState;
+use crate::game::{GhostStatus, ReportStatus};
+use crate::highscores::{Category, HighScore, Leaderboard};
+use crate::themes::ThemeProvider;
 use macroquad::prelude::*;
+use std::collections::HashMap;
+
+// Row layout, shared with the screen for cursor scrolling and click hit-tests
+pub const LIST_TOP: f32 = 100.0;
+pub const ROW_HEIGHT: f32 = 25.0;
+pub const LIST_VISIBLE_HEIGHT: f32 = 75.0;
+const HEADER_Y: f32 = LIST_TOP - 20.0;
+
+// The table between the margins, its columns as shares of its width
+const TABLE: Columns<5> = Columns::new(40.0, SCREEN_WIDTH - 90.0, [0.02, 0.145, 0.47, 0.65, 0.71]);
+
+#[derive(Debug, Clone, Copy)]
+enum Column {
+    Rank,
+    Name,
+    Score,
+    Level,
+    Badges,
+}
 
-pub fn draw_leaderboard_view(game: &Game) {
-    // Background
-    draw_rectangle(
-        0.0,
-        0.0,
-        SCREEN_WIDTH,
-        SCREEN_HEIGHT,
-        secondary_palette::BACKGROUND,
-    );
+fn column_x(column: Column) -> f32 {
+    TABLE.x(column as usize)
+}
+
+// The menu's mini leaderboard only has room for short names
+pub const MINI_NAME_LENGTH: usize = 12;
+pub const MINI_X: f32 = SCREEN_WIDTH - 240.0;
+pub const MINI_Y: f32 = SCREEN_HEIGHT / 2.0 - 50.0;
+
+// Opened up, it frames the top ten in tighter rows
+const MINI_TOP_ROWS: usize = 10;
+const MINI_TOP_ROW_HEIGHT: f32 = 15.0;
+const MINI_PANEL_WIDTH: f32 = 230.0;
+const MINI_PANEL_HEIGHT: f32 = 196.0;
+
+// Identicons beside each name, sized for the row they sit in
+const ROW_ICON_SIZE: f32 = 10.0;
+const MINI_ICON_SIZE: f32 = 8.0;
+const MINI_ICON_SPACE: f32 = MINI_ICON_SIZE + 4.0; // Pushes the mini board's text over
+
+// Level times that fit across the entry detail panel
+const MAX_SPLITS: usize = 6;
+
+// "#3", or "#3=" when others have the same score
+fn rank_label(board: &Leaderboard, index: usize) -> String {
+    let (rank, shared) = board.rank_at(index).unwrap_or((index + 1, false));
+    format!("#{}{}", rank, if shared { "=" } else { "" })
+}
+
+// The menu's mini board says "3rd", or "3rd=", and needs the rank itself
+// for the medal colors
+fn mini_rank(board: &Leaderboard, index: usize) -> (usize, String) {
+    let (rank, shared) = board.rank_at(index).unwrap_or((index + 1, false));
+    let label = format!("{}{}", ordinal_suffix(rank), if shared { "=" } else { "" });
+    (rank, label)
+}
+
+fn medal_color(rank: usize) -> Color {
+    match rank {
+        1 => MEDAL_GOLD,
+        2 => MEDAL_SILVER,
+        3 => MEDAL_BRONZE,
+        _ => TEXT_LIGHT,
+    }
+}
+
+// What the mouse has to be over to open the mini board, and to keep it open
+pub fn mini_board_rect(expanded: bool) -> Rect {
+    if expanded {
+        Rect::new(MINI_X - 8.0, MINI_Y - 40.0, MINI_PANEL_WIDTH, MINI_PANEL_HEIGHT)
+    } else {
+        Rect::new(MINI_X - 4.0, MINI_Y - 14.0, 220.0, 120.0)
+    }
+}
+
+// The entry under a point on screen, if any
+pub fn row_at(leaderboard: &LeaderboardState, x: f32, y: f32) -> Option<usize> {
+    if !(TABLE.left()..=TABLE.right()).contains(&x) {
+        return None;
+    }
+    let offset = y - (LIST_TOP - leaderboard.scroll) + ROW_HEIGHT / 2.0;
+    if offset < 0.0 {
+        return None;
+    }
+    let row = (offset / ROW_HEIGHT) as usize;
+    (row < leaderboard.shown().scores.len()).then_some(row)
+}
 
+pub fn draw_leaderboard_view(
+    leaderboard: &LeaderboardState,
+    api: &ApiState,
+    admin: Option<&AdminMode>,
+    fonts: &GameFonts,
+) {
+    // The renderer draws the gradient behind
     // Title
-    GameText::heading_centered("!! SWEATY YETIS !!", SCREEN_WIDTH / 2.0, 40.0, &game.fonts);
+    let title = Anchor::TopCenter.inset(0.0, 40.0);
+    GameText::heading_centered("!! SWEATY YETIS !!", title.x, title.y, fonts);
+    draw_category_tabs(leaderboard.category, fonts);
 
     // Headers
-    GameText::ui_secondary("RANK", 50.0, 80.0, &game.fonts);
-    GameText::ui_secondary("NAME", 120.0, 80.0, &game.fonts);
-    GameText::ui_secondary("SCORE", 300.0, 80.0, &game.fonts);
-    GameText::ui_secondary("LEVEL", 400.0, 80.0, &game.fonts);
+    for (header, column) in [
+        ("RANK", Column::Rank),
+        ("NAME", Column::Name),
+        ("SCORE", Column::Score),
+        ("LEVEL", Column::Level),
+    ] {
+        GameText::ui_secondary(header, column_x(column), HEADER_Y, fonts);
+    }
+
+    draw_board_rows(leaderboard, fonts);
+
+    // No scores message or loading indicator
+    let empty_message = if api.loading {
+        Some("Loading leaderboard...")
+    } else if leaderboard.category == Category::Hardcore {
+        Some("No hardcore runs yet. Turn it on in Settings.")
+    } else {
+        None
+    };
+    if let (true, Some(message)) = (leaderboard.shown().scores.is_empty(), empty_message) {
+        let center = Anchor::Center.inset(0.0, 0.0);
+        UIComponent::draw_text_centered(
+            message,
+            center.x,
+            center.y,
+            TypographyStyle::BodyLarge,
+            ColorTheme::Secondary,
+            fonts,
+        );
+    }
 
-    // Leaderboard entries
-    let start_y = 100.0 - game.leaderboard_scroll;
-    let line_height = 25.0;
+    // Instructions
+    let instructions = Anchor::BottomCenter.inset(0.0, 60.0);
+    widgets::key_buttons_centered(
+        &[
+            ("[UP]/[DOWN] Select", None),
+            ("[ENTER] Details", Some(KeyCode::Enter)),
+            ("[TAB] Board", Some(KeyCode::Tab)),
+            ("[SPACE] Menu", Some(KeyCode::Space)),
+        ],
+        instructions.x,
+        instructions.y,
+        TypographyStyle::CodeMedium,
+        ColorTheme::Technical,
+        fonts,
+    );
+
+    draw_board_tools(leaderboard, fonts);
 
-    for (i, high_score) in game.leaderboard.scores.iter().enumerate() {
+    // Scroll indicator
+    if leaderboard.shown().scores.len() > 8 {
+        let scroll_progress = leaderboard.scroll / 400.0;
+        let indicator_height = 100.0;
+        let indicator_y = 100.0 + scroll_progress * (SCREEN_HEIGHT - 200.0 - indicator_height);
+
+        draw_rectangle(
+            SCREEN_WIDTH - 10.0,
+            indicator_y,
+            6.0,
+            indicator_height,
+            ThemeProvider::highlight(),
+        );
+    }
+
+    if let Some(admin) = admin {
+        let server = api.client.is_enabled();
+        draw_admin_overlay(leaderboard, admin, server, fonts);
+    }
+}
+
+// The shown board's entries, scrolled, the cursor's row highlighted
+fn draw_board_rows(leaderboard: &LeaderboardState, fonts: &GameFonts) {
+    let start_y = LIST_TOP - leaderboard.scroll;
+    let line_height = ROW_HEIGHT;
+
+    for (i, high_score) in leaderboard.shown().scores.iter().enumerate() {
         let y = start_y + (i as f32 * line_height);
 
         // Skip if outside visible area
-        if !(80.0..=SCREEN_HEIGHT - 20.0).contains(&y) {
+        if !(HEADER_Y..=SCREEN_HEIGHT - 20.0).contains(&y) {
             continue;
         }
 
+        if i == leaderboard.cursor {
+            let highlight = ThemeProvider::selection(0.25);
+            draw_rectangle(TABLE.left(), y - 10.0, TABLE.width(), 22.0, highlight);
+        }
+
         // Determine color theme based on rank
-        let theme = match i {
-            0..=2 => ColorTheme::Primary,
+        let board = leaderboard.shown();
+        let theme = match board.rank_at(i).map_or(i + 1, |(rank, _)| rank) {
+            1..=3 => ColorTheme::Primary,
             _ => ColorTheme::Neutral,
         };
 
         // Rank
-        let rank_text = format!("#{}", i + 1);
+        let rank_text = rank_label(board, i);
         UIComponent::draw_text(
             &rank_text,
-            50.0,
+            column_x(Column::Rank),
             y + 5.0,
             TypographyStyle::BodyMedium,
             theme,
-            &game.fonts,
+            fonts,
         );
 
+        let icon_x = column_x(Column::Name) - ROW_ICON_SIZE - 6.0;
+        Identicon::new(high_score.display_name()).draw(icon_x, y - 4.0, ROW_ICON_SIZE, 1.0);
+
         // Name (truncate if too long)
-        let name = if high_score.name.len() > 15 {
-            format!("{}...", &high_score.name[..12])
-        } else {
-            high_score.name.clone()
-        };
+        let name = truncate_graphemes(high_score.display_name(), 15);
         UIComponent::draw_text(
             &name,
-            120.0,
+            column_x(Column::Name),
             y + 5.0,
             TypographyStyle::BodyMedium,
             theme,
-            &game.fonts,
+            fonts,
         );
 
         // Score
         let score_text = format!("{}", high_score.score);
         UIComponent::draw_text(
             &score_text,
-            300.0,
+            column_x(Column::Score),
             y + 5.0,
             TypographyStyle::BodyMedium,
             theme,
-            &game.fonts,
+            fonts,
         );
 
         // Level
         let level_text = format!("{}", high_score.level);
         UIComponent::draw_text(
             &level_text,
-            400.0,
+            column_x(Column::Level),
             y + 5.0,
             TypographyStyle::BodyMedium,
             theme,
-            &game.fonts,
+            fonts,
         );
 
+        draw_entry_badges(high_score, column_x(Column::Badges), y, fonts);
+
         // Date (right aligned, smaller)
         let date_str = high_score.timestamp.format("%m/%d").to_string();
-        let date_size = TypographyStyle::UICaption.measure_text(&date_str, &game.fonts);
+        let date_size = TypographyStyle::UICaption.measure_text(&date_str, fonts);
         UIComponent::draw_text(
             &date_str,
-            SCREEN_WIDTH - 60.0 - date_size.width,
+            TABLE.right() - 10.0 - date_size.width,
             y + 5.0,
             TypographyStyle::UICaption,
             ColorTheme::Neutral,
-            &game.fonts,
+            fonts,
         );
     }
+}
 
-    // No scores message or loading indicator
-    if game.leaderboard.scores.is_empty() && game.api_loading {
+// A tag in the corner while admin mode is on, and the question before an
+// entry is removed. `server` is whether removing it reaches the server.
+fn draw_admin_overlay(
+    leaderboard: &LeaderboardState,
+    admin: &AdminMode,
+    server: bool,
+    fonts: &GameFonts,
+) {
+    let style = TypographyStyle::UICaption;
+    let status = admin.notice.as_deref().unwrap_or("[DEL] Remove entry");
+    let tag = style.fit_text(&format!("ADMIN  {}", status), 170.0, fonts);
+    UIComponent::draw_text(&tag, 8.0, 16.0, style, ColorTheme::Error, fonts);
+
+    let Some(entry) = leaderboard.selected().filter(|_| admin.confirming) else {
+        return;
+    };
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    GameText::heading_centered("Remove this entry?", SCREEN_WIDTH / 2.0, 90.0, fonts);
+
+    let summary = format!(
+        "{} {} - {} pts, level {}",
+        rank_label(leaderboard.shown(), leaderboard.cursor),
+        truncate_graphemes(entry.display_name(), 20),
+        format_thousands(entry.score as u64),
+        entry.level
+    );
+    let reach = if entry.id.is_some() && server {
+        "It's deleted from the server too. This can't be undone."
+    } else {
+        "Only this machine's board has it."
+    };
+    for (line, y) in [(summary.as_str(), 130.0), (reach, 146.0)] {
         UIComponent::draw_text_centered(
-            "Loading leaderboard...",
+            line,
             SCREEN_WIDTH / 2.0,
-            SCREEN_HEIGHT / 2.0,
-            TypographyStyle::BodyLarge,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Neutral,
+            fonts,
+        );
+    }
+    UIComponent::draw_text_centered(
+        "[Y] Remove  //  [N] Keep",
+        SCREEN_WIDTH / 2.0,
+        180.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        fonts,
+    );
+}
+
+// One tab per board under the title, the one on show underlined
+fn draw_category_tabs(shown_category: Category, fonts: &GameFonts) {
+    for (index, category) in Category::ALL.iter().enumerate() {
+        let x = SCREEN_WIDTH / 2.0 + (index as f32 - 0.5) * 120.0;
+        let label = category.name().to_uppercase();
+        let shown = *category == shown_category;
+        let theme = if shown {
+            ColorTheme::Primary
+        } else {
+            ColorTheme::Neutral
+        };
+        UIComponent::draw_text_centered(
+            &label,
+            x,
+            62.0,
+            TypographyStyle::UICaption,
+            theme,
+            fonts,
+        );
+        if shown {
+            let width = TypographyStyle::UICaption.measure_text(&label, fonts).width;
+            draw_rectangle(x - width / 2.0, 67.0, width, 2.0, ThemeProvider::highlight());
+        }
+    }
+}
+
+// The CSV export, and on the standard board the ghost run too
+fn draw_board_tools(leaderboard: &LeaderboardState, fonts: &GameFonts) {
+    let export = ("[E] Export CSV", Some(KeyCode::E));
+    let row = Anchor::BottomCenter.inset(0.0, 42.0);
+    if !leaderboard.category.is_standard() {
+        widgets::key_buttons_centered(
+            &[export],
+            row.x,
+            row.y,
+            TypographyStyle::UICaption,
+            ColorTheme::Technical,
+            fonts,
+        );
+        return;
+    }
+
+    let (ghost_text, ghost_key, ghost_theme) = match leaderboard.ghost_status {
+        GhostStatus::Idle => (
+            "[G] Watch the top ghost run (experimental)",
+            Some(KeyCode::G),
+            ColorTheme::Technical,
+        ),
+        GhostStatus::Loading => ("Downloading ghost run...", None, ColorTheme::Technical),
+        GhostStatus::Unavailable => ("No ghost run uploaded yet", None, ColorTheme::Warning),
+    };
+    widgets::key_buttons_centered(
+        &[(ghost_text, ghost_key), export],
+        row.x,
+        row.y,
+        TypographyStyle::UICaption,
+        ghost_theme,
+        fonts,
+    );
+}
+
+// `can_report` is whether the selected entry can be reported from here
+pub fn draw_entry_detail(
+    leaderboard: &LeaderboardState,
+    can_report: bool,
+    textures: &HashMap<String, Texture2D>,
+    fonts: &GameFonts,
+) {
+    let Some(entry) = leaderboard.selected() else {
+        return;
+    };
+
+    let run_time = entry
+        .duration
+        .or(entry.run.as_ref().map(|run| run.duration));
+    let duration = run_time
+        .map(format_duration)
+        .unwrap_or_else(|| "not recorded".to_string());
+    let mut lines = vec![
+        format!("Score: {}  //  Level {}", entry.score, entry.level),
+        format!("Date: {}", entry.timestamp.format("%Y-%m-%d %H:%M UTC")),
+        format!("Run time: {}", duration),
+    ];
+    if !entry.mutators.is_empty() {
+        let names: Vec<&str> = entry.mutators.iter().map(|m| m.name()).collect();
+        lines.push(format!("Mutators: {}", names.join(", ")));
+    }
+    if entry.assisted {
+        lines.push("Assist: item magnet".to_string());
+    }
+    if entry.category == Category::Hardcore {
+        lines.push("Hardcore: no grace margin, no assists".to_string());
+    }
+    if let Some(run) = &entry.run {
+        lines.push(format!(
+            "Items: {} collected  //  {} dodged",
+            run.collected, run.dodged
+        ));
+        if !run.level_times.is_empty() {
+            let splits: Vec<String> = run
+                .level_times
+                .iter()
+                .take(MAX_SPLITS)
+                .map(|seconds| format_duration(*seconds))
+                .collect();
+            lines.push(format!("Levels cleared at: {}", splits.join("  ")));
+        }
+        if !run.version.is_empty() {
+            lines.push(format!("Played on v{}", run.version));
+        }
+    }
+
+    // Room for the sparkline and footer under however many lines there are
+    let panel_w = 360.0;
+    let panel_h = 132.0 + lines.len() as f32 * 16.0;
+    let panel_x = (SCREEN_WIDTH - panel_w) / 2.0;
+    let panel_y = (SCREEN_HEIGHT - panel_h) / 2.0;
+
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    let panel = Rect::new(panel_x, panel_y, panel_w, panel_h);
+    panel::draw_panel(textures, panel, ColorTheme::Secondary);
+
+    let x = panel_x + 16.0;
+    let mut y = panel_y + 28.0;
+
+    let rank = rank_label(leaderboard.shown(), leaderboard.cursor);
+    let title = format!("{}  {}", rank, entry.display_name());
+    UIComponent::draw_text(
+        &title,
+        x,
+        y,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Secondary,
+        fonts,
+    );
+    y += 24.0;
+
+    for line in &lines {
+        UIComponent::draw_text(
+            line,
+            x,
+            y,
+            TypographyStyle::BodySmall,
             ColorTheme::Secondary,
-            &game.fonts,
+            fonts,
         );
+        y += 16.0;
     }
 
-    // Instructions
-    GameText::instructions(
-        "[UP]/[DOWN] Scroll  //  [SPACE] Return to Menu",
-        SCREEN_WIDTH / 2.0
-            - TypographyStyle::CodeMedium
-                .measure_text(
-                    "[UP]/[DOWN] Scroll  //  [SPACE] Return to Menu",
-                    &game.fonts,
-                )
-                .width
-                / 2.0,
-        SCREEN_HEIGHT - 60.0,
-        &game.fonts,
+    let spark_y = y + 4.0;
+    let spark_h = panel_y + panel_h - 24.0 - spark_y;
+    if entry.score_history.len() >= 2 {
+        draw_sparkline(&entry.score_history, x, spark_y, panel_w - 32.0, spark_h);
+    } else {
+        UIComponent::draw_text(
+            "No run stats for this entry",
+            x,
+            spark_y + spark_h / 2.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            fonts,
+        );
+    }
+
+    let status = leaderboard.report_status(entry);
+    let close = ("[ESC] Close", Some(KeyCode::Escape));
+    let (footer, theme) = if leaderboard.report_confirming {
+        (
+            vec![
+                ("Report this name to the moderators?", None),
+                ("[Y] Yes", Some(KeyCode::Y)),
+                ("[N] No", Some(KeyCode::N)),
+            ],
+            ColorTheme::Warning,
+        )
+    } else {
+        match status {
+            Some(ReportStatus::Sending) => (
+                vec![("Sending report...", None), close],
+                ColorTheme::Neutral,
+            ),
+            Some(ReportStatus::Sent) => {
+                (vec![("Reported, thanks", None), close], ColorTheme::Success)
+            }
+            Some(ReportStatus::Failed) => (
+                vec![
+                    ("Couldn't send the report", None),
+                    ("[R] Retry", Some(KeyCode::R)),
+                    close,
+                ],
+                ColorTheme::Error,
+            ),
+            None if can_report => (
+                vec![("[R] Report name", Some(KeyCode::R)), close],
+                ColorTheme::Secondary,
+            ),
+            None => (vec![close], ColorTheme::Secondary),
+        }
+    };
+    widgets::key_buttons(
+        &footer,
+        x,
+        panel_y + panel_h - 8.0,
+        TypographyStyle::UICaption,
+        theme,
+        fonts,
     );
+}
 
-    // Scroll indicator
-    if game.leaderboard.scores.len() > 8 {
-        let scroll_progress = game.leaderboard_scroll / 400.0;
-        let indicator_height = 100.0;
-        let indicator_y = 100.0 + scroll_progress * (SCREEN_HEIGHT - 200.0 - indicator_height);
+// A small tag per mutator the run was played with, then one if it had the
+// item magnet assist, left to right from `x`
+fn draw_entry_badges(entry: &HighScore, x: f32, y: f32, fonts: &GameFonts) {
+    let palette = DesignContext::palette();
+    let mut badges: Vec<(&str, Color)> = entry
+        .mutators
+        .iter()
+        .map(|mutator| (mutator.badge(), palette.warning()))
+        .collect();
+    if entry.assisted {
+        badges.push(("AST", PEAK));
+    }
 
-        draw_rectangle(
-            SCREEN_WIDTH - 10.0,
-            indicator_y,
-            6.0,
-            indicator_height,
-            UI_HIGHLIGHT,
+    let mut x = x;
+    for (text, color) in badges {
+        let width = TypographyStyle::UICaption.measure_text(text, fonts).width + 6.0;
+        draw_rectangle(x, y - 6.0, width, 13.0, color);
+        UIComponent::draw_text(
+            text,
+            x + 3.0,
+            y + 4.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Secondary,
+            fonts,
         );
+        x += width + 3.0;
     }
 }
 
+fn draw_sparkline(values: &[u32], x: f32, y: f32, width: f32, height: f32) {
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as f32;
+    let step = width / (values.len() - 1) as f32;
+
+    let point = |i: usize| vec2(x + i as f32 * step, y + height - values[i] as f32 / max * height);
+
+    for i in 1..values.len() {
+        let (from, to) = (point(i - 1), point(i));
+        draw_line(from.x, from.y, to.x, to.y, 1.5, ThemeProvider::highlight());
+    }
+
+    // Mark where the run ended
+    let end = point(values.len() - 1);
+    draw_circle(end.x, end.y, 2.5, EMBER);
+}
+
 // pub fn draw_mini_leaderboard(game: &Game, x: f32, y: f32) {
-//     let top_3 = game.leaderboard.get_top_3();
+//     let top_3 = game.leaderboard.board.get_top_3();
 
 //     if top_3.is_empty() {
 //         let message = "No high scores yet!";
@@ -180,8 +627,13 @@ pub fn draw_leaderboard_view(game: &Game) {
 //     }
 // }
 
-pub fn draw_scrolling_mini_leaderboard(game: &Game, x: f32, y: f32) {
-    if game.leaderboard.scores.is_empty() {
+pub fn draw_scrolling_mini_leaderboard(
+    leaderboard: &LeaderboardState,
+    x: f32,
+    y: f32,
+    fonts: &GameFonts,
+) {
+    if leaderboard.board.scores.is_empty() {
         return;
     }
 
@@ -192,49 +644,45 @@ pub fn draw_scrolling_mini_leaderboard(game: &Game, x: f32, y: f32) {
         y,
         TypographyStyle::BodyMedium,
         ColorTheme::Warning,
-        &game.fonts,
+        fonts,
     );
 
     // Create a clipping area for scrolling effect
     let visible_height = 80.0; // Height to show 3-4 entries
     let line_height = 20.0;
 
-    let num_scores = game.leaderboard.scores.len();
+    let num_scores = leaderboard.board.scores.len();
     let total_height = num_scores as f32 * line_height;
     let max_visible_entries = (visible_height / line_height).ceil() as usize + 1;
 
     // Only scroll if we have more entries than can fit
     if num_scores <= max_visible_entries {
         // Static display - no scrolling needed
-        for (i, high_score) in game.leaderboard.scores.iter().enumerate() {
+        for (i, high_score) in leaderboard.board.scores.iter().enumerate() {
             let entry_y = y + 25.0 + (i as f32 * line_height);
 
-            let rank_string = ordinal_suffix(i + 1);
+            let (rank, rank_string) = mini_rank(&leaderboard.board, i);
             let text = format!(
                 "{} {} - {}",
                 rank_string.as_str(),
-                high_score.name,
+                truncate_graphemes(high_score.display_name(), MINI_NAME_LENGTH),
                 high_score.score
             );
-            let text_color = match i {
-                0 => MEDAL_GOLD,
-                1 => MEDAL_SILVER,
-                2 => MEDAL_BRONZE,
-                _ => TEXT_LIGHT,
-            };
-
-            let params = TypographyStyle::BodySmall.get_params(&game.fonts, text_color);
-            draw_text_ex(&text, x, entry_y, params);
+            let text_color = medal_color(rank);
+
+            draw_mini_identicon(high_score, x, entry_y, 1.0);
+            let params = TypographyStyle::BodySmall.get_params(fonts, text_color);
+            draw_text_runs(&text, x + MINI_ICON_SPACE, entry_y, params, fonts);
         }
     } else {
         // Scrolling display with seamless looping
-        let wrapped_scroll = game.mini_leaderboard_scroll % total_height;
+        let wrapped_scroll = leaderboard.mini_scroll % total_height;
 
         let mut entries_drawn = 0;
 
         // Draw two cycles of entries to ensure seamless looping
         for cycle in 0..2 {
-            for (i, high_score) in game.leaderboard.scores.iter().enumerate() {
+            for (i, high_score) in leaderboard.board.scores.iter().enumerate() {
                 let entry_y = y + 25.0 + (i as f32 * line_height) + (cycle as f32 * total_height)
                     - wrapped_scroll;
 
@@ -243,20 +691,15 @@ pub fn draw_scrolling_mini_leaderboard(game: &Game, x: f32, y: f32) {
                     && entry_y <= y + visible_height + 20.0
                     && entries_drawn < max_visible_entries
                 {
-                    let rank_string = ordinal_suffix(i + 1);
+                    let (rank, rank_string) = mini_rank(&leaderboard.board, i);
 
                     let text = format!(
                         "{} {} - {}",
                         rank_string.as_str(),
-                        high_score.name,
+                        truncate_graphemes(high_score.display_name(), MINI_NAME_LENGTH),
                         high_score.score
                     );
-                    let text_color = match i {
-                        0 => MEDAL_GOLD,
-                        1 => MEDAL_SILVER,
-                        2 => MEDAL_BRONZE,
-                        _ => TEXT_LIGHT,
-                    };
+                    let text_color = medal_color(rank);
 
                     // Fade effect for entries at edges
                     let fade_alpha = if entry_y < y + 30.0 || entry_y > y + visible_height {
@@ -271,11 +714,93 @@ pub fn draw_scrolling_mini_leaderboard(game: &Game, x: f32, y: f32) {
                         text_color.b,
                         text_color.a * fade_alpha,
                     );
-                    let params = TypographyStyle::BodySmall.get_params(&game.fonts, faded_color);
-                    draw_text_ex(&text, x, entry_y, params);
+                    draw_mini_identicon(high_score, x, entry_y, fade_alpha);
+                    let params = TypographyStyle::BodySmall.get_params(fonts, faded_color);
+                    draw_text_runs(&text, x + MINI_ICON_SPACE, entry_y, params, fonts);
                     entries_drawn += 1;
                 }
             }
         }
     }
 }
+
+// The mini board while it's hovered or focused: the top ten, the player's
+// own entry (`own`, by index) picked out, and the way through to the full board
+pub fn draw_expanded_mini_leaderboard(
+    leaderboard: &LeaderboardState,
+    own: Option<usize>,
+    textures: &HashMap<String, Texture2D>,
+    fonts: &GameFonts,
+) {
+    let board = &leaderboard.board;
+    if board.scores.is_empty() {
+        return;
+    }
+    let panel = mini_board_rect(true);
+    panel::draw_panel(textures, panel, ColorTheme::Primary);
+
+    let left = panel.x + 8.0;
+    let right = panel.x + panel.w - 8.0;
+    UIComponent::draw_text(
+        "-- TOP 10 --",
+        left,
+        panel.y + 16.0,
+        TypographyStyle::BodyMedium,
+        ColorTheme::Warning,
+        fonts,
+    );
+
+    for (i, entry) in board.scores.iter().take(MINI_TOP_ROWS).enumerate() {
+        let baseline = panel.y + 36.0 + i as f32 * MINI_TOP_ROW_HEIGHT;
+        if own == Some(i) {
+            let highlight = ThemeProvider::selection(0.35);
+            draw_rectangle(left - 4.0, baseline - 11.0, panel.w - 8.0, 14.0, highlight);
+        }
+
+        let (rank, rank_string) = mini_rank(board, i);
+        let text = format!(
+            "{} {}",
+            rank_string,
+            truncate_graphemes(entry.display_name(), MINI_NAME_LENGTH)
+        );
+        let style = TypographyStyle::BodySmall;
+        draw_mini_identicon(entry, left, baseline, 1.0);
+        draw_text_runs(
+            &text,
+            left + MINI_ICON_SPACE,
+            baseline,
+            style.get_params(fonts, medal_color(rank)),
+            fonts,
+        );
+
+        let score = format_thousands(entry.score as u64);
+        let score_width = style.measure_text(&score, fonts).width;
+        draw_text_runs(
+            &score,
+            right - score_width,
+            baseline,
+            style.get_params(fonts, medal_color(rank)),
+            fonts,
+        );
+    }
+
+    let hint = if own.is_some() {
+        "Click / [ENTER]: your entry"
+    } else {
+        "Click / [ENTER]: full board"
+    };
+    UIComponent::draw_text(
+        hint,
+        left,
+        panel.y + panel.h - 8.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Primary,
+        fonts,
+    );
+}
+
+// Sits on the text's baseline, ahead of the rank
+fn draw_mini_identicon(entry: &HighScore, x: f32, baseline: f32, alpha: f32) {
+    let top = baseline - MINI_ICON_SIZE;
+    Identicon::new(entry.display_name()).draw(x, top, MINI_ICON_SIZE, alpha);
+}
@@ -1,69 +1,169 @@
-use crate::colors;
+use super::{effects, viewport};
+use crate::atlas::SpriteAtlas;
 use crate::colors::*;
 use crate::config::*;
-use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
-use crate::game::Game;
+use crate::design::{ColorTheme, DesignContext, GameFonts, GameText, TypographyStyle, UIComponent};
+use crate::entities::Particle;
+use crate::game::modifier::LevelModifier;
+use crate::game::run::RunState;
+use crate::overrides::config;
+use crate::themes::ThemeProvider;
+use crate::tween::{Ease, Tween};
 use macroquad::prelude::*;
 
-pub fn draw_level_complete(game: &Game) {
+const BAR_WIDTH: f32 = 200.0;
+const BAR_HEIGHT: f32 = 8.0;
+const BAR_Y: f32 = SCREEN_HEIGHT / 2.0 + 64.0;
+
+// The dimmed run with confetti and the yeti hopping about, `elapsed` seconds
+// into the screen
+pub fn draw_celebration(
+    particles: &[Particle],
+    sprites: &SpriteAtlas,
+    yeti_x: f32,
+    mirror: bool,
+    elapsed: f32,
+) {
     // Semi-transparent overlay
     draw_rectangle(
         0.0,
         0.0,
         SCREEN_WIDTH,
         SCREEN_HEIGHT,
-        secondary_palette::BACKGROUND,
+        ThemeProvider::backdrop(),
     );
 
+    effects::draw_particles(particles);
+    viewport::mirrored(mirror, || draw_cheering_yeti(sprites, yeti_x, elapsed));
+}
+
+pub fn draw_level_complete(message: &str, submessage: &str, elapsed: f32, fonts: &GameFonts) {
+    let display_time = config().level_complete_display_time;
+    let progress = Tween::new(0.0, 1.0, display_time)
+        .ease(Ease::InOutCubic)
+        .sample(elapsed);
+
     // Main message
     GameText::heading_centered(
-        &game.level_complete_message,
+        message,
         SCREEN_WIDTH / 2.0,
         SCREEN_HEIGHT / 2.0 - 20.0,
-        &game.fonts,
+        fonts,
     );
 
     // Submessage
-    if !game.level_complete_submessage.is_empty() {
+    if !submessage.is_empty() {
         UIComponent::draw_text_centered(
-            &game.level_complete_submessage,
+            submessage,
             SCREEN_WIDTH / 2.0,
             SCREEN_HEIGHT / 2.0,
             TypographyStyle::CodeLarge,
             ColorTheme::Primary,
-            &game.fonts,
+            fonts,
         );
     }
 
-    // Show score bonus
-    let level = game.level - 1; // We've already incremented level
+    // Animated progress indicator
+    let bar_x = SCREEN_WIDTH / 2.0 - BAR_WIDTH / 2.0;
+
+    // Background bar
+    draw_rectangle(bar_x, BAR_Y, BAR_WIDTH, BAR_HEIGHT, METAL);
+
+    // Progress bar
+    draw_rectangle(
+        bar_x,
+        BAR_Y,
+        BAR_WIDTH * progress,
+        BAR_HEIGHT,
+        DesignContext::palette().success(),
+    );
+}
+
+// The score bonus, counting up to `shown_bonus`
+pub fn draw_bonus_tally(run: &RunState, shown_bonus: u32, fonts: &GameFonts) {
+    let level = run.level - 1; // We've already incremented level
     let bonus = crate::game::scoring::calculate_level_score_bonus(level);
-    let bonus_text = format!("+{} Level Bonus!", bonus);
+
+    let bonus_text = format!("+{} Level Bonus!", shown_bonus);
     UIComponent::draw_text_centered(
         &bonus_text,
         SCREEN_WIDTH / 2.0,
         SCREEN_HEIGHT / 2.0 + 30.0,
         TypographyStyle::BodyLarge,
         ColorTheme::Success,
-        &game.fonts,
+        fonts,
     );
 
-    // Animated progress indicator
-    let progress = 1.0 - (game.level_complete_timer / 2.5);
-    let bar_width = 200.0;
-    let bar_height = 8.0;
-    let bar_x = SCREEN_WIDTH / 2.0 - bar_width / 2.0;
-    let bar_y = SCREEN_HEIGHT / 2.0 + 60.0;
+    // The bonus is already in the score, so count from the pre-bonus total
+    let shown_score = run.score - bonus + shown_bonus;
+    let score_text = format!("Score: {}", shown_score);
+    UIComponent::draw_text_centered(
+        &score_text,
+        SCREEN_WIDTH / 2.0,
+        SCREEN_HEIGHT / 2.0 + 50.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        fonts,
+    );
+}
 
-    // Background bar
-    draw_rectangle(bar_x, bar_y, bar_width, bar_height, METAL);
+// The gamble under the progress bar, or the rule it left in place
+pub fn draw_wager(pending: bool, modifier: &LevelModifier, fonts: &GameFonts) {
+    let y = BAR_Y + BAR_HEIGHT + 18.0;
+    if pending {
+        UIComponent::draw_text_centered(
+            "Push to prod without review?",
+            SCREEN_WIDTH / 2.0,
+            y,
+            TypographyStyle::BodyLarge,
+            ColorTheme::Warning,
+            fonts,
+        );
+        UIComponent::draw_text_centered(
+            "[Y] +50% score, but one missed PR fails the build   [N] Play it safe",
+            SCREEN_WIDTH / 2.0,
+            y + 16.0,
+            TypographyStyle::BodySmall,
+            ColorTheme::Primary,
+            fonts,
+        );
+    } else if !modifier.is_standard() {
+        UIComponent::draw_text_centered(
+            "No review. Don't miss a single PR.",
+            SCREEN_WIDTH / 2.0,
+            y,
+            TypographyStyle::BodyLarge,
+            ColorTheme::Warning,
+            fonts,
+        );
+    }
+}
 
-    // Progress bar
-    draw_rectangle(
-        bar_x,
-        bar_y,
-        bar_width * progress,
-        bar_height,
-        colors::SUCCESS_GREEN,
-    );
+fn draw_cheering_yeti(sprites: &SpriteAtlas, yeti_x: f32, elapsed: f32) {
+    // Little celebratory hops with a squash on each landing
+    let hop = if DesignContext::reduced_motion() {
+        0.0
+    } else {
+        (elapsed * 7.0).sin().abs()
+    };
+    let squash = 1.0 - (1.0 - hop).powi(4) * 0.12;
+    let width = YETI_WIDTH * (2.0 - squash);
+    let height = YETI_HEIGHT * squash;
+
+    let x = yeti_x + (YETI_WIDTH - width) / 2.0;
+    let y = GROUND_Y - height - hop * 14.0;
+
+    if let Some(sprite) = sprites.get("yeti_cheer") {
+        sprite.draw(
+            x,
+            y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(width, height)),
+                ..Default::default()
+            },
+        );
+    } else {
+        draw_rectangle(x, y, width, height, VIBRANT_BLUE);
+    }
 }
@@ -1,33 +1,60 @@
-use super::leaderboard;
+use super::leaderboard::{self, MINI_NAME_LENGTH, MINI_X, MINI_Y};
+use super::widgets;
+use crate::api::CommunityStats;
+use crate::changelog::CURRENT_VERSION;
 use crate::colors::*;
 use crate::config::*;
-use crate::design::{ColorTheme, GameText, Spacing, TypographyStyle, UIComponent};
+use crate::design::{
+    draw_text_runs, format_thousands, truncate_graphemes, Anchor, ColorTheme, DesignContext, FlashPolicy,
+    GameFonts, GameText, Spacing, Stack, TypographyStyle, UIComponent,
+};
+use crate::game::challenge::Challenge;
+use crate::game::menu_vignette::VIGNETTE_GROUND_Y;
+use crate::game::run::RunState;
 use crate::game::Game;
+use crate::highscores::{Category, RankChange};
+use crate::kiosk::Kiosk;
+use crate::themes::ThemeProvider;
+use crate::tween::{Ease, Tween};
 use macroquad::prelude::*;
 
+// The left column's distance from the edge of the screen
+const MENU_MARGIN: f32 = 40.0;
+
+// How far above its place the title springs in from, on each visit
+const TITLE_DROP: f32 = 30.0;
+const TITLE_DROP_SECONDS: f32 = 0.9;
+
 pub fn draw_main_menu(game: &Game) {
     draw_rectangle(
         0.0,
         0.0,
         SCREEN_WIDTH,
         SCREEN_HEIGHT,
-        secondary_palette::BACKGROUND,
+        ThemeProvider::backdrop(),
     );
+    draw_vignette(game);
 
-    // Game title using new design system
-    GameText::title_centered(
-        "Yeti, Set, Go!",
-        SCREEN_WIDTH / 2.0,
-        SCREEN_HEIGHT / 2.0 - 80.0,
-        &game.fonts,
-    );
+    // Game title using new design system, springing in and then with a
+    // gentle bob
+    let motion = if DesignContext::reduced_motion() {
+        0.0
+    } else {
+        let drop = Tween::new(-TITLE_DROP, 0.0, TITLE_DROP_SECONDS).ease(Ease::OutElastic);
+        drop.sample(game.menu_time) + (game.menu_time * 2.0).sin() * 2.0
+    };
+    let title = Anchor::Center.inset(0.0, -80.0 + motion);
+    GameText::title_centered("Yeti, Set, Go!", title.x, title.y, &game.fonts);
 
     // Instructions on the left side - using technical styling for code-like content
-    let left_x = 40.0;
-    let mut y_offset = SCREEN_HEIGHT / 2.0 - 50.0;
+    let left_x = MENU_MARGIN;
+    let mut column = Stack::down(Anchor::CenterLeft.inset(left_x, -50.0));
 
-    // Mini leaderboard on the right side with scrolling
-    leaderboard::draw_scrolling_mini_leaderboard(game, SCREEN_WIDTH - 240.0, y_offset);
+    // Mini leaderboard on the right side with scrolling, unless it's open
+    if !game.leaderboard.mini_expanded() {
+        let board = &game.leaderboard;
+        leaderboard::draw_scrolling_mini_leaderboard(board, MINI_X, MINI_Y, &game.fonts);
+    }
 
     let subtitle = [
         (0.0, "A CI/CD Pipeline Runner"),
@@ -35,18 +62,18 @@ pub fn draw_main_menu(game: &Game) {
     ];
 
     for (x_offset, line) in subtitle {
+        let at = column.place(Spacing::Medium);
         UIComponent::draw_text(
             line,
-            left_x + x_offset,
-            y_offset,
+            at.x + x_offset,
+            at.y,
             TypographyStyle::BodyMedium,
             ColorTheme::Primary,
             &game.fonts,
         );
-        y_offset += Spacing::Medium.as_f32();
     }
 
-    y_offset += Spacing::Large.as_f32();
+    column.skip(Spacing::Large);
 
     // Game instructions - technical content
     let game_instructions = [
@@ -57,28 +84,235 @@ pub fn draw_main_menu(game: &Game) {
     ];
 
     for instruction in game_instructions {
-        GameText::instructions(instruction, left_x, y_offset, &game.fonts);
-        y_offset += Spacing::Medium.as_f32();
+        let at = column.place(Spacing::Medium);
+        GameText::instructions(instruction, at.x, at.y, &game.fonts);
     }
 
     // Controls - highlighted
-    UIComponent::draw_text(
-        "[SPACE]: Start  //  [L]: Leaderboard",
-        left_x,
-        SCREEN_HEIGHT - 60.0,
+    let controls = Anchor::BottomLeft.inset(left_x, 60.0);
+    widgets::key_buttons(
+        &[
+            ("[SPACE]: Start", Some(KeyCode::Space)),
+            ("[L]: Leaderboard", Some(KeyCode::L)),
+        ],
+        controls.x,
+        controls.y,
         TypographyStyle::CodeMedium,
         ColorTheme::Primary,
         &game.fonts,
     );
 
+    match &game.kiosk {
+        Some(kiosk) => draw_kiosk_footer(kiosk, game.menu_time, left_x, &game.fonts),
+        None => draw_menu_options(game, left_x),
+    }
+
+    if game.pending_crash_report.is_some() {
+        draw_crash_prompt(&game.fonts);
+    } else if let Some((change, _)) = &game.leaderboard.rank_toast {
+        draw_rank_toast(change, &game.fonts);
+    } else {
+        let community = game.leaderboard.community_stats;
+        let lines = ticker_lines(game.flavor.current(), game.nemesis(), community);
+        draw_ticker(&lines, game.menu_time, &game.fonts);
+    }
+
+    // Over everything else, the way the hover reaches it
+    if game.leaderboard.mini_expanded() {
+        let own = game.own_entry_index();
+        let board = &game.leaderboard;
+        leaderboard::draw_expanded_mini_leaderboard(board, own, &game.textures, &game.fonts);
+    }
+}
+
+// Snowfall and the yeti's run along the bottom, under the menu text and
+// faded back so it never competes with it
+fn draw_vignette(game: &Game) {
+    const DASH_SPACING: f32 = 24.0;
+    const TINT: Color = Color::new(1.0, 1.0, 1.0, 0.55);
+
+    if DesignContext::reduced_motion() {
+        return;
+    }
+    let vignette = &game.menu_vignette;
+
+    let snow_color = Color::new(ICE_BLUE.r, ICE_BLUE.g, ICE_BLUE.b, 0.45);
+    for flake in &vignette.snow {
+        draw_circle(flake.x, flake.y, flake.size, snow_color);
+    }
+
+    let ground_color = Color::new(PEAK.r, PEAK.g, PEAK.b, 0.35);
+    draw_line(
+        0.0,
+        VIGNETTE_GROUND_Y,
+        SCREEN_WIDTH,
+        VIGNETTE_GROUND_Y,
+        1.0,
+        ground_color,
+    );
+    let mut dash_x = -(vignette.scroll % DASH_SPACING);
+    while dash_x < SCREEN_WIDTH {
+        draw_line(
+            dash_x,
+            VIGNETTE_GROUND_Y + 2.0,
+            dash_x + 8.0,
+            VIGNETTE_GROUND_Y + 2.0,
+            1.0,
+            ground_color,
+        );
+        dash_x += DASH_SPACING;
+    }
+
+    let palette = DesignContext::palette();
+    for item in &vignette.items {
+        let body = item.body;
+        let top = body.y - body.height;
+        match &item.sprite {
+            Some(sprite) => sprite.draw(
+                body.x,
+                top,
+                TINT,
+                DrawTextureParams {
+                    dest_size: Some(vec2(body.width, body.height)),
+                    ..Default::default()
+                },
+            ),
+            None => {
+                let color = if item.is_good {
+                    palette.success()
+                } else {
+                    palette.error()
+                };
+                draw_rectangle(
+                    body.x,
+                    top,
+                    body.width,
+                    body.height,
+                    Color { a: TINT.a, ..color },
+                );
+            }
+        }
+    }
+
+    let yeti = &vignette.yeti;
+    match &yeti.sprite {
+        Some(sprite) => sprite.draw(
+            yeti.x,
+            yeti.y - yeti.height,
+            TINT,
+            DrawTextureParams {
+                dest_size: Some(vec2(yeti.width, yeti.height)),
+                ..Default::default()
+            },
+        ),
+        None => draw_rectangle(
+            yeti.x,
+            yeti.y - yeti.height,
+            yeti.width,
+            yeti.height,
+            Color {
+                a: TINT.a,
+                ..VIBRANT_BLUE
+            },
+        ),
+    }
+}
+
+// Everything a booth visitor shouldn't reach: modes, lanes, settings and
+// the per-player extras
+fn draw_menu_options(game: &Game, left_x: f32) {
+    let mutators_text = if game.mutator_selection.is_empty() {
+        "[U]: Mutators".to_string()
+    } else {
+        format!(
+            "[U]: Mutators x{:.2}",
+            game.mutator_selection.score_multiplier()
+        )
+    };
+    let practice_text = format!("[T]: Practice Lv {}", game.practice_level);
+    let modes = Anchor::BottomLeft.inset(left_x, 40.0);
+    widgets::key_buttons(
+        &[
+            ("[V]: 2P Versus", Some(KeyCode::V)),
+            ("[C]: Race a Friend", Some(KeyCode::C)),
+            (&mutators_text, Some(KeyCode::U)),
+            (&practice_text, Some(KeyCode::T)),
+        ],
+        modes.x,
+        modes.y,
+        TypographyStyle::CodeSmall,
+        ColorTheme::Primary,
+        &game.fonts,
+    );
+
+    let lanes_text = match game.lane_setting {
+        1 => "[M]: Lanes 1 (Classic)".to_string(),
+        lanes => format!("[M]: Lanes {} (Up/Down)", lanes),
+    };
+    let mods_text = format!("[O]: Mods ({})", game.mods.len());
+    let mut options = vec![
+        (lanes_text.as_str(), Some(KeyCode::M)),
+        ("[S]: Settings", Some(KeyCode::S)),
+        ("[P]: Profile", Some(KeyCode::P)),
+    ];
+    // Only worth the space once someone has installed something
+    if !game.mods.is_empty() {
+        options.push((&mods_text, Some(KeyCode::O)));
+    }
+    let setup = Anchor::BottomLeft.inset(left_x, 20.0);
+    widgets::key_buttons(
+        &options,
+        setup.x,
+        setup.y,
+        TypographyStyle::CodeSmall,
+        ColorTheme::Primary,
+        &game.fonts,
+    );
+
+    // Above the mini leaderboard, clear of the title
+    let version_text = format!("v{}", CURRENT_VERSION);
+    let version_row = [
+        (version_text.as_str(), None),
+        ("[W]: What's New", Some(KeyCode::W)),
+    ];
+    let version_width = widgets::row_width(&version_row, TypographyStyle::UICaption, &game.fonts);
+    let version = Anchor::TopRight.inset(version_width + 12.0, 16.0);
+    widgets::key_buttons(
+        &version_row,
+        version.x,
+        version.y,
+        TypographyStyle::UICaption,
+        ColorTheme::Neutral,
+        &game.fonts,
+    );
+    if let Some(latest) = &game.update_available {
+        let banner = format!("v{} available", latest.short());
+        UIComponent::draw_text(
+            &banner,
+            version.x,
+            version.y + 14.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Success,
+            &game.fonts,
+        );
+    }
+
     // Personal best in bottom left
-    let personal_best = game.leaderboard.get_local_best_score();
-    if personal_best > 0 {
-        let personal_text = format!("Your Best: {}", personal_best);
+    let personal_best = game.personal_best();
+    if personal_best > 0 || game.profile.is_some() {
+        let personal_text = match &game.profile {
+            Some(profile) => format!(
+                "{}'s Best: {}",
+                truncate_graphemes(&profile.name, MINI_NAME_LENGTH),
+                personal_best
+            ),
+            None => format!("Your Best: {}", personal_best),
+        };
+        let corner = Anchor::BottomRight.inset(240.0, 60.0);
         UIComponent::draw_text(
             &personal_text,
-            SCREEN_WIDTH - 240.0,
-            SCREEN_HEIGHT - 60.0,
+            corner.x,
+            corner.y,
             TypographyStyle::BodyMedium,
             ColorTheme::Warning,
             &game.fonts,
@@ -86,63 +320,218 @@ pub fn draw_main_menu(game: &Game) {
     }
 }
 
-pub fn draw_game_over(game: &Game) {
+// The QR code takes the personal best's corner; nobody at a booth has one
+fn draw_kiosk_footer(kiosk: &Kiosk, menu_time: f32, left_x: f32, fonts: &GameFonts) {
+    const PIXELS_PER_MODULE: f32 = 2.0; // Whole pixels keep the code scannable
+
+    let versus = Anchor::BottomLeft.inset(left_x, 40.0);
+    widgets::key_button(
+        "[V]: 2P Versus",
+        KeyCode::V,
+        versus.x,
+        versus.y,
+        TypographyStyle::CodeSmall,
+        ColorTheme::Primary,
+        fonts,
+    );
+
+    let Some((caption, texture)) = kiosk.current_code(menu_time) else {
+        return;
+    };
+    let size = texture.width() * PIXELS_PER_MODULE;
+    let Vec2 { x, y } = Anchor::BottomRight.inset(size + 16.0, size + 12.0);
+    draw_texture_ex(
+        texture,
+        x,
+        y,
+        WHITE,
+        DrawTextureParams {
+            dest_size: Some(vec2(size, size)),
+            ..Default::default()
+        },
+    );
+    let caption_width = TypographyStyle::UICaption
+        .measure_text(caption, fonts)
+        .width;
+    UIComponent::draw_text(
+        caption,
+        x - caption_width - 8.0,
+        y + size - 4.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Warning,
+        fonts,
+    );
+}
+
+// Takes the ticker's place until answered
+fn draw_crash_prompt(fonts: &GameFonts) {
     draw_rectangle(
         0.0,
         0.0,
         SCREEN_WIDTH,
-        SCREEN_HEIGHT,
-        secondary_palette::BACKGROUND,
+        22.0,
+        ThemeProvider::overlay(),
+    );
+    widgets::key_buttons_centered(
+        &[
+            ("Last session crashed. Send an anonymous report?", None),
+            ("[Y] Send", Some(KeyCode::Y)),
+            ("[N] Discard", Some(KeyCode::N)),
+        ],
+        SCREEN_WIDTH / 2.0,
+        15.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Warning,
+        fonts,
+    );
+}
+
+// Also in the ticker's place, for a few seconds after a sync moves the
+// player's best on the global board
+fn draw_rank_toast(change: &RankChange, fonts: &GameFonts) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        22.0,
+        ThemeProvider::overlay(),
+    );
+    let theme = match change {
+        RankChange::Climbed(_) => ColorTheme::Success,
+        RankChange::Dropped { .. } => ColorTheme::Warning,
+    };
+    UIComponent::draw_text_centered(
+        &format!("LEADERBOARD // {}", change.message()),
+        SCREEN_WIDTH / 2.0,
+        15.0,
+        TypographyStyle::UICaption,
+        theme,
+        fonts,
+    );
+}
+
+// The visit's flavor line, the player's nemesis, then the community stats
+// once they've loaded
+fn ticker_lines(
+    flavor: &str,
+    nemesis: Option<(&str, f32)>,
+    community: Option<CommunityStats>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !flavor.is_empty() {
+        lines.push(flavor.to_string());
+    }
+    if let Some((name, share)) = nemesis {
+        lines.push(format!(
+            "YOUR NEMESIS // {} ({:.0}% of deaths)",
+            name,
+            share * 100.0
+        ));
+    }
+    if let Some(stats) = community {
+        lines.push(format!(
+            "COMMUNITY // {} runs played worldwide",
+            format_thousands(stats.games_played)
+        ));
+        lines.push(format!(
+            "COMMUNITY // {} vulns dodged by all players",
+            format_thousands(stats.items_dodged)
+        ));
+        if stats.games_played > 0 {
+            let average = stats.items_dodged as f32 / stats.games_played as f32;
+            lines.push(format!("COMMUNITY // {:.1} dodges per run on average", average));
+        }
+    }
+    lines
+}
+
+// Worldwide totals across the top, one line at a time
+fn draw_ticker(lines: &[String], menu_time: f32, fonts: &GameFonts) {
+    const SECONDS_PER_LINE: f32 = 5.0;
+
+    if lines.is_empty() {
+        return;
+    }
+
+    let index = (menu_time / SECONDS_PER_LINE) as usize % lines.len();
+    UIComponent::draw_text_centered(
+        &lines[index],
+        SCREEN_WIDTH / 2.0,
+        14.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Technical,
+        fonts,
     );
+}
 
+// `shown_score` is the final score as far as it has counted up, and `rank`
+// where the run places on `category`'s board and whether that's only the
+// cached board's estimate
+pub fn draw_game_over(
+    run: &RunState,
+    shown_score: u32,
+    new_high_score: bool,
+    rank: Option<(usize, bool)>,
+    category: Category,
+    challenge: Option<&Challenge>,
+    fonts: &GameFonts,
+) {
+    // The renderer draws the blurred run behind
     // Game over title
     GameText::title_centered(
         "GAME OVER",
         SCREEN_WIDTH / 2.0,
         SCREEN_HEIGHT / 2.0 - 80.0,
-        &game.fonts,
+        fonts,
     );
 
     // Show level reached
-    let level_text = format!("Reached Level {}", game.level);
+    let level_text = format!("Reached Level {}", run.level);
     UIComponent::draw_text_centered(
         &level_text,
         SCREEN_WIDTH / 2.0,
         SCREEN_HEIGHT / 2.0 - 45.0,
         TypographyStyle::CodeLarge,
         ColorTheme::Primary,
-        &game.fonts,
+        fonts,
     );
 
     // Final score
-    let final_score_text = format!("Final Score: {}", game.score);
+    let final_score_text = format!("Final Score: {}", shown_score);
     GameText::score(
         &final_score_text,
         SCREEN_WIDTH / 2.0
             - TypographyStyle::BodyLarge
-                .measure_text(&final_score_text, &game.fonts)
+                .measure_text(&final_score_text, fonts)
                 .width
                 / 2.0,
         SCREEN_HEIGHT / 2.0 - 15.0,
-        &game.fonts,
+        fonts,
     );
 
     // High score notification
-    if game.is_new_high_score {
+    if new_high_score {
         GameText::success_message(
             "* NEW HIGH SCORE *",
             SCREEN_WIDTH / 2.0,
             SCREEN_HEIGHT / 2.0 + 15.0,
-            &game.fonts,
+            fonts,
         );
     }
 
-    // Show rank if applicable
-    if let Some(rank) = game.leaderboard.get_rank(game.score) {
-        let rank_text = if game.is_new_high_score {
-            format!("Leaderboard Rank: #{}", rank)
+    // Show rank if applicable, on the board the run went to. Until the
+    // server has counted, it's only an estimate from the cached board.
+    if let Some((rank, local)) = rank {
+        let rank_text = match (new_high_score, category.is_standard()) {
+            (true, true) => format!("Leaderboard Rank: #{}", rank),
+            (true, false) => format!("Hardcore Rank: #{}", rank),
+            (false, true) => format!("Would rank #{} on leaderboard", rank),
+            (false, false) => format!("Would rank #{} on the hardcore board", rank),
+        };
+        let rank_text = if local {
+            format!("{} (local)", rank_text)
         } else {
-            format!("Would rank #{} on leaderboard", rank)
+            rank_text
         };
         UIComponent::draw_text_centered(
             &rank_text,
@@ -150,24 +539,64 @@ pub fn draw_game_over(game: &Game) {
             SCREEN_HEIGHT / 2.0 + 35.0,
             TypographyStyle::BodySmall,
             ColorTheme::Primary,
-            &game.fonts,
+            fonts,
+        );
+    }
+
+    if let Some(challenge) = challenge {
+        let challenge_text = format!("Challenge {} ({})", challenge.code(), challenge.difficulty.name());
+        UIComponent::draw_text_centered(
+            &challenge_text,
+            SCREEN_WIDTH / 2.0,
+            SCREEN_HEIGHT / 2.0 + 55.0,
+            TypographyStyle::BodySmall,
+            ColorTheme::Warning,
+            fonts,
         );
     }
 
     // Instructions
-    let instructions = if game.is_new_high_score {
-        "Press [SPACE] to enter your name!"
-    } else {
-        "Press [SPACE] to play again or [L] for leaderboard"
-    };
+    let instructions = "[SPACE] Run summary   [L] Leaderboard   [C] Copy share card";
     GameText::instructions(
         instructions,
         SCREEN_WIDTH / 2.0
             - TypographyStyle::CodeMedium
-                .measure_text(instructions, &game.fonts)
+                .measure_text(instructions, fonts)
                 .width
                 / 2.0,
         SCREEN_HEIGHT - 50.0,
+        fonts,
+    );
+}
+
+pub fn draw_attract_overlay(game: &Game) {
+    // Banner along the bottom so the demo run stays visible behind it
+    let banner_height = 36.0;
+    let banner_y = SCREEN_HEIGHT - banner_height;
+    draw_rectangle(
+        0.0,
+        banner_y,
+        SCREEN_WIDTH,
+        banner_height,
+        ThemeProvider::overlay(),
+    );
+
+    UIComponent::draw_text(
+        "DEMO",
+        Spacing::Medium.as_f32(),
+        banner_y + 24.0,
+        TypographyStyle::BodyMedium,
+        ColorTheme::Warning,
         &game.fonts,
     );
+
+    // Slow blink on the call to action
+    let prompt = "Press [SPACE] to play!";
+    let color = Color {
+        a: FlashPolicy::blink(game.clock.time(), 1.2, 2.0 / 3.0),
+        ..ColorTheme::Primary.get_colors().foreground
+    };
+    let width = TypographyStyle::CodeLarge.measure_text(prompt, &game.fonts).width;
+    let params = TypographyStyle::CodeLarge.get_params(&game.fonts, color);
+    draw_text_runs(prompt, (SCREEN_WIDTH - width) / 2.0, banner_y + 24.0, params, &game.fonts);
 }
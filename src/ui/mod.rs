@@ -1,8 +1,35 @@
 pub mod renderer;
+pub mod benchmark;
+pub mod calibration;
+pub mod crt;
 pub mod menu;
+pub mod mods;
+pub mod mutators;
 pub mod hud;
+pub mod identicon;
 pub mod level_complete;
 pub mod name_input;
+pub mod run_summary;
 pub mod leaderboard;
+pub mod versus;
+pub mod challenge;
+pub mod effects;
+pub mod playfield;
+pub mod panel;
+pub mod pause;
+pub mod photo;
+pub mod profiles;
+pub mod quit_confirm;
+pub mod feedback_history;
+pub mod glow;
+pub mod settings;
+pub mod share_card;
+pub mod sign_in;
+pub mod telemetry_consent;
+pub mod transition;
+pub mod viewport;
+pub mod widgets;
+pub mod whats_new;
 
-pub use renderer::Renderer;
\ No newline at end of file
+pub use renderer::Renderer;
+pub use viewport::Viewport;
\ No newline at end of file
@@ -0,0 +1,111 @@
+use crate::config::*;
+use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const LIST_TOP: f32 = 80.0;
+const ROW_HEIGHT: f32 = 30.0;
+const MAX_ROWS: usize = 5;
+
+pub fn draw_mods(game: &Game) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    GameText::heading_centered("MODS", SCREEN_WIDTH / 2.0, 40.0, &game.fonts);
+
+    let left_x = 60.0;
+    let max_width = SCREEN_WIDTH - left_x * 2.0;
+
+    if game.mods.is_empty() {
+        UIComponent::draw_text_centered(
+            "No mods found. Drop a pack folder with a mod.json into mods/",
+            SCREEN_WIDTH / 2.0,
+            LIST_TOP + 20.0,
+            TypographyStyle::BodySmall,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+    }
+
+    let mut y = LIST_TOP;
+    for pack in game.mods.iter().take(MAX_ROWS) {
+        let (title_theme, detail, detail_theme) = match &pack.error {
+            Some(error) => (
+                ColorTheme::Neutral,
+                format!("Skipped: {}", error),
+                ColorTheme::Error,
+            ),
+            None => (ColorTheme::Primary, pack_summary(pack), ColorTheme::Neutral),
+        };
+
+        let title = format!("{} ({})", pack.name, pack.folder);
+        UIComponent::draw_text(
+            &TypographyStyle::BodyMedium.fit_text(&title, max_width, &game.fonts),
+            left_x,
+            y,
+            TypographyStyle::BodyMedium,
+            title_theme,
+            &game.fonts,
+        );
+        UIComponent::draw_text(
+            &TypographyStyle::UICaption.fit_text(&detail, max_width - 16.0, &game.fonts),
+            left_x + 16.0,
+            y + 12.0,
+            TypographyStyle::UICaption,
+            detail_theme,
+            &game.fonts,
+        );
+        y += ROW_HEIGHT;
+    }
+
+    if game.mods.len() > MAX_ROWS {
+        UIComponent::draw_text(
+            &format!(
+                "...and {} more, see the console",
+                game.mods.len() - MAX_ROWS
+            ),
+            left_x,
+            y,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+    }
+
+    let instructions = "[ESC] Menu";
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, &game.fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 20.0,
+        &game.fonts,
+    );
+}
+
+fn pack_summary(pack: &crate::mods::ModPack) -> String {
+    let mut parts = Vec::new();
+    if !pack.items.is_empty() {
+        parts.push(format!("Items: {}", pack.items.join(", ")));
+    }
+    if !pack.skin_frames.is_empty() {
+        parts.push(format!("Yeti skin ({} frames)", pack.skin_frames.len()));
+    }
+    let flavor_lines = pack.flavor.line_count();
+    if flavor_lines > 0 {
+        parts.push(format!("Menu lines: {}", flavor_lines));
+    }
+    if parts.is_empty() {
+        "Nothing to load".to_string()
+    } else {
+        parts.join("  //  ")
+    }
+}
@@ -0,0 +1,121 @@
+use crate::config::*;
+use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
+use crate::game::mutators::Mutator;
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const LIST_TOP: f32 = 86.0;
+const ROW_HEIGHT: f32 = 34.0;
+
+pub fn draw_mutators_menu(game: &Game) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    GameText::heading_centered("MUTATORS", SCREEN_WIDTH / 2.0, 40.0, &game.fonts);
+
+    let left_x = 80.0;
+    let right_x = SCREEN_WIDTH - 80.0;
+    let mut y = LIST_TOP;
+
+    for (i, mutator) in Mutator::ALL.into_iter().enumerate() {
+        let active = game.mutator_selection.contains(mutator);
+        let unlocked = game.mutator_unlocked(mutator);
+
+        if i == game.mutator_cursor {
+            let highlight = ThemeProvider::selection(0.25);
+            draw_rectangle(
+                left_x - 10.0,
+                y - 16.0,
+                right_x - left_x + 20.0,
+                ROW_HEIGHT - 2.0,
+                highlight,
+            );
+        }
+
+        let (check, theme) = if !unlocked {
+            ("[-]", ColorTheme::Neutral)
+        } else if active {
+            ("[X]", ColorTheme::Primary)
+        } else {
+            ("[ ]", ColorTheme::Neutral)
+        };
+        let label = format!("{} {}", check, mutator.name());
+        UIComponent::draw_text(
+            &label,
+            left_x,
+            y,
+            TypographyStyle::BodyMedium,
+            theme,
+            &game.fonts,
+        );
+
+        let multiplier = format!("x{:.2}", mutator.score_multiplier());
+        let multiplier_theme = if mutator.score_multiplier() >= 1.0 {
+            ColorTheme::Success
+        } else {
+            ColorTheme::Warning
+        };
+        let multiplier_width = TypographyStyle::BodyMedium
+            .measure_text(&multiplier, &game.fonts)
+            .width;
+        UIComponent::draw_text(
+            &multiplier,
+            right_x - multiplier_width,
+            y,
+            TypographyStyle::BodyMedium,
+            multiplier_theme,
+            &game.fonts,
+        );
+
+        let description = if unlocked {
+            mutator.description().to_string()
+        } else {
+            format!("Reach level {} to unlock", mutator.unlock_level())
+        };
+        UIComponent::draw_text(
+            &description,
+            left_x + 28.0,
+            y + 12.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+        y += ROW_HEIGHT;
+    }
+
+    // Everything switched on multiplies together
+    let total_text = if game.mutator_selection.is_empty() {
+        "No mutators: standard rules".to_string()
+    } else {
+        format!(
+            "Score multiplier: x{:.2}",
+            game.mutator_selection.score_multiplier()
+        )
+    };
+    UIComponent::draw_text_centered(
+        &total_text,
+        SCREEN_WIDTH / 2.0,
+        y + 8.0,
+        TypographyStyle::BodyLarge,
+        ColorTheme::Warning,
+        &game.fonts,
+    );
+
+    let instructions = "[UP/DOWN] Select  [SPACE] Toggle  [ENTER] Play  [ESC] Menu";
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, &game.fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 20.0,
+        &game.fonts,
+    );
+}
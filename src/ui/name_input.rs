@@ -1,99 +1,129 @@
+use super::{panel, widgets};
 use crate::colors::*;
 use crate::config::*;
-use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
-use crate::game::Game;
+use crate::design::{
+    draw_text_runs, ColorTheme, DesignContext, FlashPolicy, GameFonts, GameText, TypographyStyle,
+    UIComponent,
+};
+use crate::highscores::validate_name;
+use crate::input::{FrameInput, TouchKeyboard, VirtualKey};
+use crate::themes::ThemeProvider;
 use macroquad::prelude::*;
+use std::collections::HashMap;
+
+// On-screen keyboard, in key units; a unit is one letter key plus its gap
+const KEY_UNIT: f32 = 31.0;
+const KEY_GAP: f32 = 3.0;
+const KEY_HEIGHT: f32 = 20.0;
+const KEYBOARD_ROWS: usize = 5;
+const KEYBOARD_TOP: f32 = SCREEN_HEIGHT - KEYBOARD_ROWS as f32 * (KEY_HEIGHT + KEY_GAP) - 4.0;
+
+// `time` drives the cursor's blink
+pub fn draw_name_input(
+    name: &str,
+    score: u32,
+    touch_keyboard: &TouchKeyboard,
+    time: f64,
+    textures: &HashMap<String, Texture2D>,
+    fonts: &GameFonts,
+) {
+    // The keyboard takes the bottom half, so everything else moves up
+    let keyboard = touch_keyboard.is_shown();
 
-pub fn draw_name_input(game: &Game) {
     // Semi-transparent overlay
     draw_rectangle(
         0.0,
         0.0,
         SCREEN_WIDTH,
         SCREEN_HEIGHT,
-        main_palette::BACKGROUND,
+        DesignContext::backdrop(Color {
+            a: 0.7,
+            ..ColorTheme::Secondary.get_colors().background
+        }),
     );
 
     // Celebration message
     GameText::success_message(
         "* NEW HIGH SCORE! *",
         SCREEN_WIDTH / 2.0,
-        SCREEN_HEIGHT / 2.0 - 80.0,
-        &game.fonts,
+        if keyboard {
+            28.0
+        } else {
+            SCREEN_HEIGHT / 2.0 - 80.0
+        },
+        fonts,
     );
 
     // Score display
-    let score_text = format!("Score: {}", game.score);
+    let score_text = format!("Score: {}", score);
     GameText::score(
         &score_text,
         SCREEN_WIDTH / 2.0
             - TypographyStyle::BodyLarge
-                .measure_text(&score_text, &game.fonts)
+                .measure_text(&score_text, fonts)
                 .width
                 / 2.0,
-        SCREEN_HEIGHT / 2.0 - 40.0,
-        &game.fonts,
+        if keyboard {
+            54.0
+        } else {
+            SCREEN_HEIGHT / 2.0 - 40.0
+        },
+        fonts,
     );
 
     // Name input prompt
-    UIComponent::draw_text_centered(
-        "Enter your name:",
-        SCREEN_WIDTH / 2.0,
-        SCREEN_HEIGHT / 2.0,
-        TypographyStyle::BodyLarge,
-        ColorTheme::Secondary,
-        &game.fonts,
-    );
+    if !keyboard {
+        UIComponent::draw_text_centered(
+            "Enter your name:",
+            SCREEN_WIDTH / 2.0,
+            SCREEN_HEIGHT / 2.0,
+            TypographyStyle::BodyLarge,
+            ColorTheme::Secondary,
+            fonts,
+        );
+    }
 
     // Input box
     let box_width = 300.0;
     let box_height = 40.0;
     let box_x = SCREEN_WIDTH / 2.0 - box_width / 2.0;
-    let box_y = SCREEN_HEIGHT / 2.0 + 20.0;
-
-    // Input box border
-    draw_rectangle(
-        box_x - 2.0,
-        box_y - 2.0,
-        box_width + 4.0,
-        box_height + 4.0,
-        main_palette::PRIMARY,
-    );
+    let box_y = if keyboard {
+        66.0
+    } else {
+        SCREEN_HEIGHT / 2.0 + 20.0
+    };
 
-    // Input box background
-    draw_rectangle(box_x, box_y, box_width, box_height, PEAK);
+    // Input box
+    let input_box = Rect::new(box_x, box_y, box_width, box_height);
+    panel::draw_panel(textures, input_box, ColorTheme::Secondary);
 
     // Input text
-    let input_display = if game.player_name_input.is_empty() {
-        "Type here...".to_string()
+    let input_display = if name.is_empty() {
+        "Type here..."
     } else {
-        game.player_name_input.clone()
+        name
     };
 
     UIComponent::draw_text(
-        &input_display,
+        input_display,
         box_x + 10.0,
         box_y + 25.0,
         TypographyStyle::UIInput,
         ColorTheme::Secondary,
-        &game.fonts,
+        fonts,
     );
 
     // Blinking cursor
-    if !game.player_name_input.is_empty() {
-        let cursor_time = get_time() % 1.0;
-        if cursor_time < 0.5 {
-            let text_width = TypographyStyle::UIInput
-                .measure_text(&game.player_name_input, &game.fonts)
-                .width;
-            UIComponent::draw_text(
-                "|",
-                box_x + 10.0 + text_width,
-                box_y + 25.0,
-                TypographyStyle::UIInput,
-                ColorTheme::Secondary,
-                &game.fonts,
-            );
+    if !name.is_empty() {
+        let opacity = FlashPolicy::blink(time, 1.0, 0.5);
+        if opacity > 0.0 {
+            let text_width = TypographyStyle::UIInput.measure_text(name, fonts).width;
+            let color = Color {
+                a: opacity,
+                ..ColorTheme::Secondary.get_colors().foreground
+            };
+            let params = TypographyStyle::UIInput.get_params(fonts, color);
+            draw_text_runs("|", box_x + 10.0 + text_width, box_y + 25.0, params, fonts);
         }
     }
 
@@ -109,16 +139,125 @@ pub fn draw_name_input(game: &Game) {
     //     &game.fonts,
     // );
 
+    // The hint turns into whatever is stopping Enter from working
+    let problem = match validate_name(name) {
+        Err(problem) if !name.is_empty() => Some(problem),
+        _ => None,
+    };
+    let (hint, theme) = match problem {
+        Some(problem) => (problem, ColorTheme::Error),
+        None => ("Type your name", ColorTheme::Secondary),
+    };
     UIComponent::draw_text(
-        "Type your name and press [ENTER]",
-        SCREEN_WIDTH / 2.0
-            - TypographyStyle::CodeMedium
-                .measure_text("Type your name and press [ENTER]", &game.fonts)
-                .width
-                / 2.0,
-        SCREEN_HEIGHT / 2.0 + 80.0,
+        hint,
+        SCREEN_WIDTH / 2.0 - TypographyStyle::CodeMedium.measure_text(hint, fonts).width / 2.0,
+        box_y + box_height + 12.0,
         TypographyStyle::CodeMedium,
+        theme,
+        fonts,
+    );
+
+    if keyboard {
+        draw_touch_keyboard(touch_keyboard.shift, "SKIP", fonts);
+        return;
+    }
+    widgets::key_buttons_centered(
+        &[
+            ("[ENTER] Submit", Some(KeyCode::Enter)),
+            ("[ESC] Skip", Some(KeyCode::Escape)),
+        ],
+        SCREEN_WIDTH / 2.0,
+        SCREEN_HEIGHT / 2.0 + 96.0,
+        TypographyStyle::CodeSmall,
         ColorTheme::Secondary,
-        &game.fonts,
+        fonts,
     );
 }
+
+// Every key with where it sits, shared with the screens for hit-testing
+fn keyboard_layout() -> Vec<(Rect, VirtualKey)> {
+    let letters = |row: &str| -> Vec<(VirtualKey, f32)> {
+        row.chars().map(|ch| (VirtualKey::Char(ch), 1.0)).collect()
+    };
+    let mut shift_row = vec![(VirtualKey::Shift, 1.5)];
+    shift_row.extend(letters("zxcvbnm"));
+    shift_row.push((VirtualKey::Backspace, 1.5));
+    let rows = [
+        letters("1234567890"),
+        letters("qwertyuiop"),
+        letters("asdfghjkl-"),
+        shift_row,
+        vec![
+            (VirtualKey::Cancel, 2.0),
+            (VirtualKey::Space, 6.0),
+            (VirtualKey::Done, 2.0),
+        ],
+    ];
+
+    let mut keys = Vec::new();
+    for (row_index, row) in rows.iter().enumerate() {
+        let units: f32 = row.iter().map(|(_, units)| units).sum();
+        let mut x = (SCREEN_WIDTH - units * KEY_UNIT) / 2.0;
+        let y = KEYBOARD_TOP + row_index as f32 * (KEY_HEIGHT + KEY_GAP);
+        for (key, units) in row {
+            let width = units * KEY_UNIT - KEY_GAP;
+            keys.push((Rect::new(x, y, width, KEY_HEIGHT), *key));
+            x += units * KEY_UNIT;
+        }
+    }
+    keys
+}
+
+// The on-screen key tapped this frame, if the keyboard is up
+pub fn tapped_key(touch_keyboard: &TouchKeyboard, input: &FrameInput) -> Option<VirtualKey> {
+    if !touch_keyboard.is_shown() || !input.mouse_pressed(MouseButton::Left) {
+        return None;
+    }
+    let (x, y) = input.mouse_position();
+    keyboard_layout()
+        .into_iter()
+        .find(|(rect, _)| rect.contains(vec2(x, y)))
+        .map(|(_, key)| key)
+}
+
+// Also used by the kiosk sign-in, which is where booth players type
+pub fn draw_touch_keyboard(shift: bool, cancel_label: &str, fonts: &GameFonts) {
+    let (mouse_x, mouse_y) = super::viewport::mouse_position();
+
+    for (rect, key) in keyboard_layout() {
+        let label = match key {
+            VirtualKey::Char(ch) if shift => ch.to_ascii_uppercase().to_string(),
+            VirtualKey::Char(ch) => ch.to_string(),
+            VirtualKey::Shift => "SHIFT".to_string(),
+            VirtualKey::Backspace => "DEL".to_string(),
+            VirtualKey::Space => "SPACE".to_string(),
+            VirtualKey::Done => "DONE".to_string(),
+            VirtualKey::Cancel => cancel_label.to_string(),
+        };
+        let lit = (key == VirtualKey::Shift && shift) || key == VirtualKey::Done;
+        let fill = if rect.contains(vec2(mouse_x, mouse_y)) {
+            ThemeProvider::highlight()
+        } else if lit {
+            VIBRANT_BLUE
+        } else {
+            ThemeProvider::surface()
+        };
+
+        draw_rectangle(
+            rect.x - 1.0,
+            rect.y - 1.0,
+            rect.w + 2.0,
+            rect.h + 2.0,
+            ThemeProvider::surface_border(),
+        );
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, fill);
+        UIComponent::draw_text_centered(
+            &label,
+            rect.x + rect.w / 2.0,
+            rect.y + 14.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Secondary,
+            fonts,
+        );
+    }
+}
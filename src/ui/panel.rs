@@ -0,0 +1,73 @@
+use crate::design::ColorTheme;
+use macroquad::prelude::*;
+use std::collections::HashMap;
+
+const FRAME_TEXTURE: &str = "ui_frame";
+const FRAME_EDGE: f32 = 3.0; // Pixels of border art on each side of ui_frame
+
+// A texture drawn at any size by cutting it into a three by three grid: the
+// corners stay as drawn and the edges stretch along their length. Only the
+// border is drawn; the middle of ui_frame has a label baked into it, so
+// panels fill their own.
+pub struct NineSlice<'a> {
+    texture: &'a Texture2D,
+    edge: f32,
+}
+
+impl<'a> NineSlice<'a> {
+    pub fn new(texture: &'a Texture2D, edge: f32) -> Self {
+        Self { texture, edge }
+    }
+
+    pub fn draw(&self, outer: Rect, tint: Color) {
+        let edge = self.edge;
+        // Per axis: where each slice starts and how long it is, in the
+        // texture and then on screen
+        let slices = |size: f32, start: f32, length: f32| {
+            [
+                (0.0, edge, start, edge),
+                (edge, size - edge * 2.0, start + edge, length - edge * 2.0),
+                (size - edge, edge, start + length - edge, edge),
+            ]
+        };
+        let columns = slices(self.texture.width(), outer.x, outer.w);
+        let rows = slices(self.texture.height(), outer.y, outer.h);
+
+        for (row, (source_y, source_h, y, h)) in rows.into_iter().enumerate() {
+            for (column, (source_x, source_w, x, w)) in columns.into_iter().enumerate() {
+                if row == 1 && column == 1 {
+                    continue;
+                }
+                draw_texture_ex(
+                    self.texture,
+                    x,
+                    y,
+                    tint,
+                    DrawTextureParams {
+                        source: Some(Rect::new(source_x, source_y, source_w, source_h)),
+                        dest_size: Some(vec2(w, h)),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    }
+}
+
+// The frame goes around `inner`, tinted with the theme's foreground so it
+// stands out from the theme's background filling the middle. Without the
+// art it's the flat border panels had before.
+pub fn draw_panel(textures: &HashMap<String, Texture2D>, inner: Rect, theme: ColorTheme) {
+    let colors = theme.get_colors();
+    let outer = Rect::new(
+        inner.x - FRAME_EDGE,
+        inner.y - FRAME_EDGE,
+        inner.w + FRAME_EDGE * 2.0,
+        inner.h + FRAME_EDGE * 2.0,
+    );
+    match textures.get(FRAME_TEXTURE) {
+        Some(texture) => NineSlice::new(texture, FRAME_EDGE).draw(outer, colors.foreground),
+        None => draw_rectangle(outer.x, outer.y, outer.w, outer.h, colors.foreground),
+    }
+    draw_rectangle(inner.x, inner.y, inner.w, inner.h, colors.background);
+}
@@ -0,0 +1,59 @@
+use super::panel;
+use crate::design::{Anchor, ColorTheme, GameFonts, GameText, TypographyStyle, UIComponent};
+use macroquad::prelude::*;
+use std::collections::HashMap;
+
+const TAGLINE: &str = "Pipeline on hold. Grab a coffee.";
+const PANEL_MIN_WIDTH: f32 = 240.0;
+const PANEL_PADDING: f32 = 16.0;
+
+// `kiosk` is whether this is a booth, which keeps settings and quitting
+// for the operator
+pub fn draw_pause_overlay(kiosk: bool, textures: &HashMap<String, Texture2D>, fonts: &GameFonts) {
+    // The renderer has drawn the run behind, blurred and dimmed
+
+    let controls = if kiosk {
+        "[ESC] or [P] Resume  //  [H] History  //  [C] Photo"
+    } else {
+        "[ESC] or [P] Resume  //  [S] Settings  //  [H] History  //  [C] Photo  //  [Q] Quit"
+    };
+
+    // Wide enough for the longest line, from above the heading to below
+    // the controls
+    let controls_width = TypographyStyle::BodySmall
+        .measure_text(controls, fonts)
+        .width;
+    let tagline_width = TypographyStyle::CodeLarge
+        .measure_text(TAGLINE, fonts)
+        .width;
+    let width = controls_width.max(tagline_width).max(PANEL_MIN_WIDTH) + PANEL_PADDING * 2.0;
+    let top = Anchor::Center.inset(-width / 2.0, -52.0);
+    panel::draw_panel(
+        textures,
+        Rect::new(top.x, top.y, width, 104.0),
+        ColorTheme::Primary,
+    );
+
+    let heading = Anchor::Center.inset(0.0, -20.0);
+    GameText::heading_centered("Paused", heading.x, heading.y, fonts);
+
+    let tagline = Anchor::Center.inset(0.0, 10.0);
+    UIComponent::draw_text_centered(
+        TAGLINE,
+        tagline.x,
+        tagline.y,
+        TypographyStyle::CodeLarge,
+        ColorTheme::Technical,
+        fonts,
+    );
+
+    let row = Anchor::Center.inset(0.0, 40.0);
+    UIComponent::draw_text_centered(
+        controls,
+        row.x,
+        row.y,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        fonts,
+    );
+}
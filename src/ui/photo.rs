@@ -0,0 +1,107 @@
+use crate::config::*;
+use crate::design::{ColorTheme, TypographyStyle, UIComponent};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::miniquad::{
+    BlendFactor, BlendState, BlendValue, Equation, PipelineParams, UniformDesc, UniformType,
+};
+use macroquad::prelude::*;
+
+// Photo mode's own controls, along the bottom. Exported photos are drawn
+// without them.
+pub fn draw_photo_controls(game: &Game) {
+    let Some(photo) = &game.photo else {
+        return;
+    };
+
+    draw_rectangle(
+        0.0,
+        SCREEN_HEIGHT - 36.0,
+        SCREEN_WIDTH,
+        36.0,
+        ThemeProvider::overlay(),
+    );
+
+    let hud = if photo.show_hud { "On" } else { "Off" };
+    let lines = [
+        format!(
+            "PHOTO MODE  //  Zoom {:.1}x  //  HUD {}  //  Filter: {}",
+            photo.zoom,
+            hud,
+            photo.filter.name()
+        ),
+        "[ARROWS] or drag Pan  //  [+/-] or wheel Zoom  //  [H] HUD  //  [F] Filter  //  \
+         [ENTER] Save  //  [ESC] Back"
+            .to_string(),
+    ];
+    for (index, line) in lines.iter().enumerate() {
+        UIComponent::draw_text_centered(
+            line,
+            SCREEN_WIDTH / 2.0,
+            SCREEN_HEIGHT - 22.0 + index as f32 * 14.0,
+            TypographyStyle::UICaption,
+            ColorTheme::Primary,
+            &game.fonts,
+        );
+    }
+}
+
+// Recolors everything drawn with it between two tones by brightness, for
+// photo mode's filters. Needs a GL context, so the renderer builds it.
+pub fn load_filter_material() -> Option<Material> {
+    load_material(
+        ShaderSource::Glsl {
+            vertex: VERTEX_SHADER,
+            fragment: FRAGMENT_SHADER,
+        },
+        MaterialParams {
+            pipeline_params: PipelineParams {
+                color_blend: Some(BlendState::new(
+                    Equation::Add,
+                    BlendFactor::Value(BlendValue::SourceAlpha),
+                    BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
+                )),
+                ..Default::default()
+            },
+            uniforms: vec![
+                UniformDesc::new("Shadow", UniformType::Float4),
+                UniformDesc::new("Highlight", UniformType::Float4),
+            ],
+            ..Default::default()
+        },
+    )
+    .map_err(|e| println!("Photo filters disabled, material failed: {}", e))
+    .ok()
+}
+
+// macroquad's own default shader
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying lowp vec2 uv;
+varying lowp vec4 color;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    color = color0 / 255.0;
+    uv = texcoord;
+}"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 100
+varying lowp vec4 color;
+varying lowp vec2 uv;
+
+uniform sampler2D Texture;
+uniform lowp vec4 Shadow;
+uniform lowp vec4 Highlight;
+
+void main() {
+    lowp vec4 pixel = color * texture2D(Texture, uv);
+    lowp float luma = dot(pixel.rgb, vec3(0.299, 0.587, 0.114));
+    gl_FragColor = vec4(mix(Shadow.rgb, Highlight.rgb, luma), pixel.a);
+}"#;
@@ -0,0 +1,179 @@
+use super::glow;
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{DesignContext, FlashPolicy};
+use crate::entities::{Feature, Item, Yeti};
+use crate::game::Game;
+use crate::overrides::config;
+use macroquad::prelude::*;
+
+enum Drawable<'a> {
+    Yeti(&'a Yeti),
+    Item(&'a Item),
+}
+
+pub fn draw_game_objects(game: &Game) {
+    draw_terrain(game);
+    glow::draw_glows(game);
+
+    let mut drawables = vec![Drawable::Yeti(&game.yeti)];
+    if let Some(versus) = &game.versus {
+        drawables.push(Drawable::Yeti(&versus.player_two));
+    }
+    drawables.extend(game.items.iter().map(Drawable::Item));
+
+    // Painter's order by lane baseline: back lanes first, nearer lanes on top.
+    // The sort is stable, so within a lane the yeti stays under its items.
+    drawables.sort_by(|a, b| baseline(a).total_cmp(&baseline(b)));
+
+    for drawable in drawables {
+        match drawable {
+            Drawable::Yeti(yeti) => draw_yeti(game, yeti),
+            Drawable::Item(item) => draw_item(game, item),
+        }
+    }
+}
+
+// Gaps cut the track away down to the bottom of the screen; platforms sit
+// on top of it with a lit edge to land on
+fn draw_terrain(game: &Game) {
+    let ground_y = game.yeti.ground_y;
+    for segment in &game.terrain.segments {
+        match segment.feature {
+            Feature::Gap => draw_rectangle(
+                segment.x,
+                ground_y,
+                segment.width,
+                SCREEN_HEIGHT - ground_y,
+                DEEP,
+            ),
+            Feature::Platform { height } => {
+                let top = ground_y - height;
+                draw_rectangle(segment.x, top, segment.width, height, METAL);
+                draw_line(segment.x, top, segment.x + segment.width, top, 2.0, TEAL);
+            }
+        }
+    }
+}
+
+fn baseline(drawable: &Drawable) -> f32 {
+    match drawable {
+        Drawable::Yeti(yeti) => yeti.ground_y,
+        Drawable::Item(item) => item.body.y,
+    }
+}
+
+fn draw_yeti(game: &Game, yeti: &Yeti) {
+    if !DesignContext::reduced_motion() {
+        draw_trail(game, yeti);
+    }
+    if !yeti.is_stumbling {
+        draw_landing_marker(game, yeti);
+    }
+
+    let yeti_tint = if yeti.is_stumbling {
+        FlashPolicy::tint(
+            WHITE,
+            EMBER,
+            game.collision_grace / config().collision_grace_time,
+        )
+    } else {
+        WHITE
+    };
+
+    if let Some(sprite) = &yeti.sprite {
+        sprite.draw(
+            yeti.x,
+            yeti.y - yeti.height,
+            yeti_tint,
+            DrawTextureParams {
+                rotation: yeti.rotation,
+                ..Default::default()
+            },
+        );
+    } else {
+        draw_rectangle_ex(
+            yeti.x + yeti.width / 2.0,
+            yeti.y - yeti.height / 2.0,
+            yeti.width,
+            yeti.height,
+            DrawRectangleParams {
+                offset: vec2(0.5, 0.5),
+                rotation: yeti.rotation,
+                color: VIBRANT_BLUE,
+            },
+        );
+    }
+}
+
+// A streak from the yeti's recent positions, thinning and fading with age
+fn draw_trail(game: &Game, yeti: &Yeti) {
+    const ALPHA: f32 = 0.35;
+
+    let length = game.settings.trail_length;
+    for pair in yeti.trail.windows(2) {
+        let ((from, _), (to, age)) = (pair[0], pair[1]);
+        let fade = 1.0 - (age / length).min(1.0);
+        let color = Color {
+            a: ALPHA * fade,
+            ..ICE_BLUE
+        };
+        draw_line(from.x, from.y, to.x, to.y, yeti.width * 0.5 * fade, color);
+    }
+}
+
+// A faint tick on the ground where the current jump will come down
+fn draw_landing_marker(game: &Game, yeti: &Yeti) {
+    const HALF_WIDTH: f32 = 6.0;
+    const COLOR: Color = Color::new(1.0, 1.0, 1.0, 0.3);
+
+    let Some(x) = yeti.landing_x(game.gravity(), game.item_speed()) else {
+        return;
+    };
+    let y = yeti.ground_y - game.terrain.height_at(x).unwrap_or(0.0);
+    draw_line(x - HALF_WIDTH, y, x + HALF_WIDTH, y, 1.0, COLOR);
+    draw_line(x, y - 3.0, x, y, 1.0, COLOR);
+}
+
+pub fn draw_item(game: &Game, item: &Item) {
+    let body = item.body;
+    if let Some(sprite) = &item.sprite {
+        sprite.draw(
+            body.x,
+            body.y - body.height,
+            WHITE,
+            DrawTextureParams::default(),
+        );
+    } else {
+        let palette = DesignContext::palette();
+        let color = if item.is_good {
+            palette.success()
+        } else {
+            palette.error()
+        };
+        draw_rectangle(body.x, body.y - body.height, body.width, body.height, color);
+    }
+
+    if game.settings.colorblind_mode {
+        draw_item_badge(item);
+    }
+}
+
+// A shape on top of the sprite so good and bad never rely on hue alone
+fn draw_item_badge(item: &Item) {
+    const RADIUS: f32 = 5.0;
+    let palette = DesignContext::palette();
+    let cx = item.body.right() - RADIUS;
+    let cy = item.body.y - item.body.height + RADIUS;
+
+    draw_circle(cx, cy, RADIUS + 1.0, DEEP);
+    if item.is_good {
+        draw_circle(cx, cy, RADIUS, palette.success());
+        draw_line(cx - 2.5, cy, cx - 0.5, cy + 2.0, 1.5, WHITE);
+        draw_line(cx - 0.5, cy + 2.0, cx + 2.5, cy - 2.0, 1.5, WHITE);
+    } else {
+        draw_circle(cx, cy, RADIUS, palette.error());
+        draw_line(cx - 2.0, cy - 2.0, cx + 2.0, cy + 2.0, 1.5, WHITE);
+        draw_line(cx - 2.0, cy + 2.0, cx + 2.0, cy - 2.0, 1.5, WHITE);
+    }
+}
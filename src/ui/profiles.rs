@@ -0,0 +1,146 @@
+use crate::config::*;
+use crate::design::{
+    format_thousands, ColorTheme, GameText, Spacing, TypographyStyle, UIComponent,
+};
+use crate::game::mutators::Mutator;
+use crate::game::Game;
+use crate::profiles::Profile;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const LIST_TOP: f32 = 76.0;
+const ROW_HEIGHT: f32 = 22.0;
+const MAX_ROWS: usize = 5;
+
+pub fn draw_profiles(game: &Game) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    GameText::heading_centered("WHO'S PLAYING?", SCREEN_WIDTH / 2.0, 40.0, &game.fonts);
+
+    let menu = &game.profile_menu;
+    let left_x = 80.0;
+    let right_x = SCREEN_WIDTH - 80.0;
+
+    // Keep the cursor in view once there are more profiles than rows
+    let rows = menu.profiles.len() + 1;
+    let first = menu
+        .cursor
+        .saturating_sub(MAX_ROWS - 1)
+        .min(rows.saturating_sub(MAX_ROWS));
+
+    let mut y = LIST_TOP;
+    for row in first..rows.min(first + MAX_ROWS) {
+        if row == menu.cursor && menu.name_input.is_none() {
+            let highlight = ThemeProvider::selection(0.25);
+            draw_rectangle(
+                left_x - 10.0,
+                y - 15.0,
+                right_x - left_x + 20.0,
+                ROW_HEIGHT - 2.0,
+                highlight,
+            );
+        }
+
+        let (name, summary) = match row.checked_sub(1).map(|index| &menu.profiles[index]) {
+            Some(profile) => (profile.name.as_str(), profile_summary(profile)),
+            None => ("Guest", "Best and unlocks aren't kept".to_string()),
+        };
+        UIComponent::draw_text(
+            name,
+            left_x,
+            y,
+            TypographyStyle::BodyMedium,
+            ColorTheme::Primary,
+            &game.fonts,
+        );
+        let summary_width = TypographyStyle::UICaption
+            .measure_text(&summary, &game.fonts)
+            .width;
+        UIComponent::draw_text(
+            &summary,
+            right_x - summary_width,
+            y,
+            TypographyStyle::UICaption,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+        y += ROW_HEIGHT;
+    }
+
+    let instructions = match &menu.name_input {
+        Some(name) => {
+            draw_name_box(game, name, y + Spacing::Small.as_f32());
+            "[ENTER] Create  [ESC] Cancel"
+        }
+        None => "[UP/DOWN] Select  [ENTER] Play  [N] New Profile  [ESC] Menu",
+    };
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, &game.fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 20.0,
+        &game.fonts,
+    );
+}
+
+fn profile_summary(profile: &Profile) -> String {
+    let unlocked = profile.unlocked.len();
+    format!(
+        "Best {}  //  {} runs  //  {}/{} mutators",
+        format_thousands(profile.stats.best_score as u64),
+        profile.stats.runs,
+        unlocked,
+        Mutator::ALL.len()
+    )
+}
+
+fn draw_name_box(game: &Game, name: &str, y: f32) {
+    let left_x = 80.0;
+    let box_width = 240.0;
+    let box_height = 26.0;
+
+    GameText::ui_secondary("NEW PROFILE", left_x, y + 8.0, &game.fonts);
+    let box_x = left_x + 110.0;
+    draw_rectangle(
+        box_x - 2.0,
+        y - 2.0,
+        box_width + 4.0,
+        box_height + 4.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(box_x, y, box_width, box_height, ThemeProvider::surface());
+
+    let (text, theme) = if name.is_empty() {
+        ("Type a name...", ColorTheme::Neutral)
+    } else {
+        (name, ColorTheme::Secondary)
+    };
+    UIComponent::draw_text(
+        text,
+        box_x + Spacing::Small.as_f32(),
+        y + 19.0,
+        TypographyStyle::UIInput,
+        theme,
+        &game.fonts,
+    );
+
+    if let Some(error) = &game.profile_menu.error {
+        UIComponent::draw_text(
+            error,
+            box_x,
+            y + box_height + 14.0,
+            TypographyStyle::BodySmall,
+            ColorTheme::Error,
+            &game.fonts,
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::config::*;
+use crate::design::{ColorTheme, GameFonts, GameText, TypographyStyle, UIComponent};
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+pub fn draw_quit_confirm(score: u32, fonts: &GameFonts) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+
+    GameText::heading_centered("Quit mid-run?", SCREEN_WIDTH / 2.0, 90.0, fonts);
+
+    let detail = format!(
+        "The run ends here at {} points and is scored like any other.",
+        score
+    );
+    UIComponent::draw_text_centered(
+        &detail,
+        SCREEN_WIDTH / 2.0,
+        130.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Neutral,
+        fonts,
+    );
+    UIComponent::draw_text_centered(
+        "Turn this question off in Settings.",
+        SCREEN_WIDTH / 2.0,
+        146.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Neutral,
+        fonts,
+    );
+
+    UIComponent::draw_text_centered(
+        "[Y] Quit  //  [N] Keep playing",
+        SCREEN_WIDTH / 2.0,
+        180.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        fonts,
+    );
+}
@@ -1,72 +1,286 @@
-use super::{hud, leaderboard, level_complete, menu, name_input};
+use super::transition::Transition;
+use super::{effects, hud, menu, photo, playfield, viewport, widgets};
 use crate::colors::*;
 use crate::config::*;
+use crate::design::DesignContext;
+use crate::game::mutators::Mutator;
 use crate::game::{Game, GameState};
+use crate::themes::ThemeProvider;
 use macroquad::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::f32::consts::TAU;
 
-pub struct Renderer;
+// The frozen run is drawn this many times smaller, then stretched back over
+// the screen, which blurs it without a shader
+const BLUR_SCALE: f32 = 5.0;
+const FROZEN_DIM: f32 = 0.6; // Share of the theme's overlay left over a frozen frame
+const GRADIENT_BANDS: usize = 54;
+const GRADIENT_DRIFT: f32 = 0.08; // Cycles a second
+const GRADIENT_GLOW: f32 = 0.35; // How far the bands lean toward the highlight
+
+// What a screen has drawn behind it, in place of the screens beneath
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backdrop {
+    FrozenRun,  // The run as it stopped, blurred and dimmed
+    DeathFrame, // The moment of the crash, dimmed, with the culprit picked out
+    Gradient,   // Slow bands in the theme's colors
+}
+
+fn backdrop(state: GameState, game: &Game) -> Option<Backdrop> {
+    match state {
+        GameState::GameOver if game.fatal_hit.is_some() => Some(Backdrop::DeathFrame),
+        GameState::Paused | GameState::GameOver => Some(Backdrop::FrozenRun),
+        GameState::ViewingLeaderboard => Some(Backdrop::Gradient),
+        _ => None,
+    }
+}
+
+pub struct Renderer {
+    // The screens drawn last frame, to notice when they change
+    shown: RefCell<Vec<GameState>>,
+    transition: Cell<Option<Transition>>,
+    photo_filter: Option<Material>, // None if the shader didn't build
+    blur_target: RenderTarget,
+    blur_camera: Camera2D,
+    death_frame: RenderTarget, // Taken on the first frame of the death sequence
+    death_camera: Camera2D,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Renderer {
     pub fn new() -> Self {
-        Self
+        let blur_target = render_target(
+            (SCREEN_WIDTH / BLUR_SCALE) as u32,
+            (SCREEN_HEIGHT / BLUR_SCALE) as u32,
+        );
+        blur_target.texture.set_filter(FilterMode::Linear);
+        let mut blur_camera =
+            Camera2D::from_display_rect(Rect::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT));
+        blur_camera.render_target = Some(blur_target.clone());
+
+        let death_frame = render_target(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32);
+        death_frame.texture.set_filter(FilterMode::Linear);
+        let mut death_camera =
+            Camera2D::from_display_rect(Rect::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT));
+        death_camera.render_target = Some(death_frame.clone());
+
+        Self {
+            shown: RefCell::new(Vec::new()),
+            transition: Cell::new(None),
+            photo_filter: photo::load_filter_material(),
+            blur_target,
+            blur_camera,
+            death_frame,
+            death_camera,
+        }
     }
 
     pub fn draw(&self, game: &Game) {
         gl_use_default_material();
-        self.draw_background(game);
-        self.draw_pipeline(game);
+        if game.state() == GameState::Dying && !self.shown.borrow().contains(&GameState::Dying) {
+            self.capture_death_frame(game);
+        }
+
+        if game.photo.is_some() {
+            self.draw_photo(game);
+        } else {
+            viewport::mirrored(game.mutators.contains(Mutator::Mirror), || {
+                self.draw_background(game);
+                self.draw_pipeline(game);
+            });
+        }
+
+        let transition = self.update_transition(game);
+        let visible = game.scenes.visible();
+        // A backdrop covers the screens beneath it, so drawing starts there
+        let covered = visible
+            .iter()
+            .rposition(|state| backdrop(*state, game).is_some());
+        match covered.and_then(|index| backdrop(visible[index], game)) {
+            Some(Backdrop::FrozenRun) => self.draw_frozen_run(game),
+            Some(Backdrop::DeathFrame) => self.draw_death_frame(game),
+            Some(Backdrop::Gradient) => draw_gradient(),
+            None => {}
+        }
+
+        for (index, state) in visible.iter().enumerate().skip(covered.unwrap_or(0)) {
+            // Only the screen arriving moves; anything under an overlay stays put
+            let offset = match transition {
+                Some(transition) if index + 1 == visible.len() => transition.screen_offset(),
+                _ => Vec2::ZERO,
+            };
+            // Buttons under an overlay are covered, so only the top screen's count
+            widgets::clear();
+            viewport::shifted(offset, || state.screen().draw(game));
+        }
+
+        if game.is_demo {
+            menu::draw_attract_overlay(game);
+        }
+
+        if let Some(transition) = transition {
+            transition.draw_cover();
+        }
+        widgets::end_frame();
+    }
+
+    // The paused run through photo mode's camera and filter, minus photo
+    // mode's controls. Exports call this too, into a bigger target.
+    pub fn draw_photo(&self, game: &Game) {
+        let Some(photo) = &game.photo else {
+            return;
+        };
+
+        viewport::transformed(photo.view(), || {
+            if let (Some((shadow, highlight)), Some(material)) =
+                (photo.filter.tones(), &self.photo_filter)
+            {
+                material.set_uniform("Shadow", shadow.to_vec());
+                material.set_uniform("Highlight", highlight.to_vec());
+                gl_use_material(material);
+            }
+            viewport::mirrored(game.mutators.contains(Mutator::Mirror), || {
+                self.draw_background(game);
+                self.draw_pipeline(game);
+                playfield::draw_game_objects(game);
+                effects::draw_particles(&game.particles);
+            });
+            gl_use_default_material();
+        });
 
-        if matches!(game.state, GameState::Playing) {
-            self.draw_game_objects(game);
+        // The HUD stays unfiltered and unzoomed, so it still reads
+        if photo.show_hud {
             hud::draw_game_ui(game);
         }
+    }
 
-        match game.state {
-            GameState::MainMenu => menu::draw_main_menu(game),
-            GameState::GameOver => menu::draw_game_over(game),
-            GameState::Playing => {
-                // hud::draw_instructions(game);
+    // Starts a transition when the visible screens change, and returns the
+    // one still running, if any
+    fn update_transition(&self, game: &Game) -> Option<Transition> {
+        let visible = game.scenes.visible();
+        let mut shown = self.shown.borrow_mut();
+        if shown.as_slice() != visible {
+            // Nothing to move from on the very first frame
+            if !shown.is_empty() {
+                self.transition
+                    .set(Transition::between(&shown, visible, game));
             }
-            GameState::LevelComplete => level_complete::draw_level_complete(game),
-            GameState::NameInput => name_input::draw_name_input(game),
-            GameState::ViewingLeaderboard => leaderboard::draw_leaderboard_view(game),
+            *shown = visible.to_vec();
         }
+
+        let transition = self
+            .transition
+            .get()
+            .filter(|transition| !transition.is_finished());
+        self.transition.set(transition);
+        transition
+    }
+
+    // Rendered small into the blur target, then stretched back up
+    fn draw_frozen_run(&self, game: &Game) {
+        push_camera_state();
+        set_camera(&self.blur_camera);
+        self.draw_run_scene(game);
+        pop_camera_state();
+
+        draw_frozen(&self.blur_target);
+    }
+
+    // The playfield as the crash left it, the item that caused it included
+    fn capture_death_frame(&self, game: &Game) {
+        push_camera_state();
+        set_camera(&self.death_camera);
+        self.draw_run_scene(game);
+        if let Some(item) = game.fatal_hit.as_ref().and_then(|hit| hit.item.as_ref()) {
+            viewport::mirrored(game.mutators.contains(Mutator::Mirror), || {
+                playfield::draw_item(game, item)
+            });
+        }
+        pop_camera_state();
+    }
+
+    // The culprit is drawn again over the dim so it stands out
+    fn draw_death_frame(&self, game: &Game) {
+        let Some(hit) = &game.fatal_hit else {
+            return;
+        };
+        draw_frozen(&self.death_frame);
+
+        let mirror = game.mutators.contains(Mutator::Mirror);
+        if let Some(item) = &hit.item {
+            viewport::mirrored(mirror, || playfield::draw_item(game, item));
+        }
+        let name = hit.cause.name(&game.catalog);
+        effects::draw_fatal_hit(hit, &name, mirror, &game.fonts);
+    }
+
+    // Scenery, the run's objects and particles, without the HUD
+    fn draw_run_scene(&self, game: &Game) {
+        viewport::mirrored(game.mutators.contains(Mutator::Mirror), || {
+            self.draw_background(game);
+            self.draw_pipeline(game);
+            playfield::draw_game_objects(game);
+            effects::draw_particles(&game.particles);
+        });
     }
 
     fn draw_background(&self, game: &Game) {
-        if let Some(bg_texture) = game.textures.get("background") {
-            let scale_x = SCREEN_WIDTH / bg_texture.width();
-            let scale_y = SCREEN_HEIGHT / bg_texture.height();
-            let scale = scale_x.min(scale_y);
-
-            let scaled_width = bg_texture.width() * scale;
-            let scaled_height = bg_texture.height() * scale;
-
-            let offset_x = (SCREEN_WIDTH - scaled_width) / 2.0;
-            let offset_y = (SCREEN_HEIGHT - scaled_height) / 2.0;
-
-            draw_texture_ex(
-                bg_texture,
-                offset_x,
-                offset_y,
-                WHITE,
-                DrawTextureParams {
-                    dest_size: Some(Vec2::new(scaled_width, scaled_height)),
-                    ..Default::default()
-                },
-            );
+        clear_background(ICE_BLUE);
 
-            if scaled_width < SCREEN_WIDTH || scaled_height < SCREEN_HEIGHT {
-                clear_background(ICE_BLUE);
+        // Each level theme stacks its layers back to front
+        for layer in &game.current_level.background_layers {
+            if let Some(texture) = game.textures.get(layer) {
+                self.draw_background_layer(texture);
             }
-        } else {
-            clear_background(ICE_BLUE);
         }
     }
 
+    fn draw_background_layer(&self, texture: &Texture2D) {
+        let scale_x = SCREEN_WIDTH / texture.width();
+        let scale_y = SCREEN_HEIGHT / texture.height();
+        let scale = scale_x.min(scale_y);
+
+        let scaled_width = texture.width() * scale;
+        let scaled_height = texture.height() * scale;
+
+        let offset_x = (SCREEN_WIDTH - scaled_width) / 2.0;
+        let offset_y = (SCREEN_HEIGHT - scaled_height) / 2.0;
+
+        draw_texture_ex(
+            texture,
+            offset_x,
+            offset_y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(Vec2::new(scaled_width, scaled_height)),
+                ..Default::default()
+            },
+        );
+    }
+
     fn draw_pipeline(&self, game: &Game) {
+        if game.versus.is_some() {
+            self.draw_pipeline_track(game, GROUND_Y);
+            if game.state() != GameState::VersusResults {
+                self.draw_pipeline_track(game, VERSUS_TOP_GROUND_Y);
+            }
+            return;
+        }
+
+        // Back lanes first so the nearer tracks overlap them
+        for lane in (0..game.lane_count).rev() {
+            self.draw_pipeline_track(game, game.lane_ground_y(lane));
+        }
+    }
+
+    fn draw_pipeline_track(&self, game: &Game, ground_y: f32) {
         if let Some(pipeline_texture) = game.textures.get("pipeline_track") {
-            let track_y = GROUND_Y + 20.0;
+            let track_y = ground_y + 20.0;
             let track_width = pipeline_texture.width();
 
             let num_tracks = ((SCREEN_WIDTH / track_width) as i32) + 2;
@@ -77,78 +291,67 @@ impl Renderer {
         } else {
             draw_line(
                 0.0,
-                GROUND_Y + 48.0,
+                ground_y + 48.0,
                 SCREEN_WIDTH,
-                GROUND_Y + 48.0,
+                ground_y + 48.0,
                 4.0,
                 METAL,
             );
         }
     }
+}
 
-    fn draw_game_objects(&self, game: &Game) {
-        self.draw_yeti(game);
-        self.draw_items(game);
-    }
-
-    fn draw_yeti(&self, game: &Game) {
-        let yeti_tint = if game.collision_grace > 0.0 {
-            EMBER
-        } else {
-            WHITE
-        };
+// A frozen frame stretched over the screen and dimmed like an overlay
+fn draw_frozen(target: &RenderTarget) {
+    draw_texture_ex(
+        &target.texture,
+        0.0,
+        0.0,
+        WHITE,
+        DrawTextureParams {
+            dest_size: Some(vec2(SCREEN_WIDTH, SCREEN_HEIGHT)),
+            flip_y: true, // Render targets come out upside down
+            ..Default::default()
+        },
+    );
+    let dim = ThemeProvider::overlay();
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        Color {
+            a: dim.a * FROZEN_DIM,
+            ..dim
+        },
+    );
+}
 
-        if let Some(texture) = &game.yeti.texture {
-            draw_texture_ex(
-                texture,
-                game.yeti.x,
-                game.yeti.y - game.yeti.height,
-                yeti_tint,
-                DrawTextureParams {
-                    dest_size: None,
-                    source: None,
-                    rotation: 0.0,
-                    flip_x: false,
-                    flip_y: false,
-                    pivot: None,
-                },
-            );
-        } else {
-            draw_rectangle(
-                game.yeti.x,
-                game.yeti.y - game.yeti.height,
-                game.yeti.width,
-                game.yeti.height,
-                VIBRANT_BLUE,
-            );
-        }
-    }
+// Horizontal bands between the theme's backdrop and its highlight, drifting
+// up the screen. They hold still with reduced motion.
+fn draw_gradient() {
+    let base = ThemeProvider::backdrop();
+    let glow = Color::from_vec(
+        base.to_vec()
+            .lerp(ThemeProvider::highlight().to_vec(), GRADIENT_GLOW),
+    );
+    let time = if DesignContext::reduced_motion() {
+        0.0
+    } else {
+        get_time() as f32
+    };
 
-    fn draw_items(&self, game: &Game) {
-        for item in &game.items {
-            if let Some(texture) = &item.texture {
-                draw_texture_ex(
-                    texture,
-                    item.x,
-                    item.y - item.height,
-                    WHITE,
-                    DrawTextureParams {
-                        dest_size: None,
-                        source: None,
-                        rotation: 0.0,
-                        flip_x: false,
-                        flip_y: false,
-                        pivot: None,
-                    },
-                );
-            } else {
-                let color = if item.is_good {
-                    SUCCESS_GREEN
-                } else {
-                    ERROR_RED
-                };
-                draw_rectangle(item.x, item.y - item.height, item.width, item.height, color);
-            }
-        }
+    let band_height = SCREEN_HEIGHT / GRADIENT_BANDS as f32;
+    for band in 0..GRADIENT_BANDS {
+        let y = band as f32 * band_height;
+        let wave = 0.5 + 0.5 * ((y / SCREEN_HEIGHT + time * GRADIENT_DRIFT) * TAU).sin();
+        let color = Color::from_vec(base.to_vec().lerp(glow.to_vec(), wave));
+        draw_rectangle(
+            0.0,
+            y,
+            SCREEN_WIDTH,
+            band_height,
+            Color { a: base.a, ..color },
+        );
     }
 }
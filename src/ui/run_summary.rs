@@ -0,0 +1,293 @@
+use super::hud::split_theme;
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{
+    format_duration, format_split, format_split_delta, ColorTheme, DesignContext, GameFonts,
+    GameText, TypographyStyle, UIComponent,
+};
+use crate::entities::ItemCatalog;
+use crate::game::run::RunState;
+use crate::game::speedrun::{SpeedrunTimer, FINISH_LEVEL, SPLIT_COUNT};
+use crate::game::stats::RunStats;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const LINE_HEIGHT: f32 = 16.0;
+const MAX_ITEM_ROWS: usize = 4;
+
+// `nemesis` as for draw_breakdown; `splits` is the speedrun timer when the
+// player has it on and the run counts
+pub fn draw_run_summary(
+    run: &RunState,
+    stats: &RunStats,
+    catalog: &ItemCatalog,
+    nemesis: Option<(&str, f32)>,
+    splits: Option<&SpeedrunTimer>,
+    fonts: &GameFonts,
+) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    GameText::heading_centered("Run Summary", SCREEN_WIDTH / 2.0, 30.0, fonts);
+
+    draw_breakdown(run, stats, catalog, nemesis, 40.0, 60.0, fonts);
+    // Speedrunners care more about their splits than the score curve
+    let graph = Rect::new(340.0, 60.0, 260.0, 140.0);
+    match splits {
+        Some(timer) => draw_splits(timer, run.level, graph, fonts),
+        None => draw_score_graph(&stats.score_history, graph, fonts),
+    }
+}
+
+// Where [SPACE] goes next: back to the menu at a booth, otherwise on to
+// posting the score if there's one to post
+pub fn draw_instructions(kiosk: bool, challenge: bool, new_high_score: bool, fonts: &GameFonts) {
+    let instructions = if kiosk {
+        "Score posted! Press [SPACE] to return to the menu"
+    } else if challenge {
+        "Press [SPACE] to post your challenge score!"
+    } else if new_high_score {
+        "Press [SPACE] to enter your name!"
+    } else {
+        "Press [SPACE] to return to the menu"
+    };
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 20.0,
+        fonts,
+    );
+}
+
+// `nemesis` is the item that's ended most of the player's runs, and its
+// share of them
+fn draw_breakdown(
+    run: &RunState,
+    stats: &RunStats,
+    catalog: &ItemCatalog,
+    nemesis: Option<(&str, f32)>,
+    x: f32,
+    y: f32,
+    fonts: &GameFonts,
+) {
+    let mut y = y;
+
+    let mut line = |text: &str, theme: ColorTheme, indent: f32| {
+        UIComponent::draw_text(
+            text,
+            x + indent,
+            y,
+            TypographyStyle::BodySmall,
+            theme,
+            fonts,
+        );
+        y += LINE_HEIGHT;
+    };
+
+    line(
+        &format!("Score {}  //  Level {}", run.score, run.level),
+        ColorTheme::Warning,
+        0.0,
+    );
+    line(
+        &format!("Time survived: {}", format_duration(stats.time_survived)),
+        ColorTheme::Primary,
+        0.0,
+    );
+    if let Some(cause) = stats.death_cause {
+        line(
+            &format!("Killed by: {}", cause.name(catalog)),
+            ColorTheme::Error,
+            0.0,
+        );
+    }
+    if let Some((name, share)) = nemesis {
+        line(
+            &format!("Your nemesis: {} ({:.0}% of deaths)", name, share * 100.0),
+            ColorTheme::Neutral,
+            0.0,
+        );
+    }
+    line(
+        &format!("Collected: {}", stats.total_collected()),
+        ColorTheme::Primary,
+        0.0,
+    );
+
+    // Most-collected first; the rest fold into one line
+    let mut collected = stats.collected.clone();
+    collected.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    for (item_type, count) in collected.iter().take(MAX_ITEM_ROWS) {
+        let name = &catalog.get(*item_type).name;
+        line(&format!("{} x{}", name, count), ColorTheme::Success, 12.0);
+    }
+    if collected.len() > MAX_ITEM_ROWS {
+        let others: u32 = collected[MAX_ITEM_ROWS..].iter().map(|(_, count)| count).sum();
+        line(&format!("Other x{}", others), ColorTheme::Neutral, 12.0);
+    }
+
+    line(
+        &format!("Dodged: {}  //  Missed: {}", stats.dodges, stats.missed),
+        ColorTheme::Primary,
+        0.0,
+    );
+    line(
+        &format!("Longest streak: {}", stats.longest_streak),
+        ColorTheme::Primary,
+        0.0,
+    );
+    line(
+        &format!(
+            "Level bonuses: +{}  //  Final bonus: +{}",
+            stats.total_level_bonus(),
+            stats.final_bonus
+        ),
+        ColorTheme::Success,
+        0.0,
+    );
+}
+
+// `history` is the run's (seconds in, score) samples
+fn draw_score_graph(history: &[(f32, u32)], area: Rect, fonts: &GameFonts) {
+    let Rect {
+        x,
+        y,
+        w: width,
+        h: height,
+    } = area;
+
+    GameText::ui_secondary("SCORE OVER TIME", x, y - 6.0, fonts);
+
+    draw_rectangle(
+        x,
+        y,
+        width,
+        height,
+        ThemeProvider::overlay(),
+    );
+    draw_line(x, y + height, x + width, y + height, 2.0, METAL);
+    draw_line(x, y, x, y + height, 2.0, METAL);
+
+    let max_time = history.last().map(|(time, _)| *time).unwrap_or(0.0).max(1.0);
+    let max_score = history.iter().map(|(_, score)| *score).max().unwrap_or(0).max(1);
+
+    let point = |(time, score): (f32, u32)| {
+        vec2(
+            x + time / max_time * width,
+            y + height - score as f32 / max_score as f32 * height,
+        )
+    };
+
+    for pair in history.windows(2) {
+        let from = point(pair[0]);
+        let to = point(pair[1]);
+        let color = DesignContext::palette().success();
+        draw_line(from.x, from.y, to.x, to.y, 2.0, color);
+    }
+
+    UIComponent::draw_text(
+        &max_score.to_string(),
+        x + 4.0,
+        y + 12.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Neutral,
+        fonts,
+    );
+    UIComponent::draw_text(
+        &format_duration(max_time),
+        x + width - 30.0,
+        y + height + 12.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Neutral,
+        fonts,
+    );
+}
+
+// One row per level of the any% category: the split, how it compares with
+// the best, and the best itself, then how the run ended. `level` is the
+// one the run ended on.
+fn draw_splits(timer: &SpeedrunTimer, level: u32, area: Rect, fonts: &GameFonts) {
+    const ROW_HEIGHT: f32 = 12.0;
+
+    let Rect {
+        x,
+        y,
+        w: width,
+        h: height,
+    } = area;
+    GameText::ui_secondary(
+        &format!("ANY% TO LEVEL {}", FINISH_LEVEL),
+        x,
+        y - 6.0,
+        fonts,
+    );
+    draw_rectangle(
+        x,
+        y,
+        width,
+        height,
+        ThemeProvider::overlay(),
+    );
+
+    let caption = |text: &str, column: f32, row_y: f32, theme: ColorTheme| {
+        UIComponent::draw_text(
+            text,
+            x + column,
+            row_y,
+            TypographyStyle::UICaption,
+            theme,
+            fonts,
+        );
+    };
+
+    let mut row_y = y + 14.0;
+    for index in 0..SPLIT_COUNT {
+        let split = timer.splits().get(index);
+        let theme = if split.is_some() {
+            ColorTheme::Primary
+        } else {
+            ColorTheme::Neutral
+        };
+        caption(&format!("L{}", index + 1), 8.0, row_y, theme);
+        let time = split.map_or("--".to_string(), |split| format_split(*split));
+        caption(&time, 40.0, row_y, theme);
+        if let Some(delta) = timer.delta(index) {
+            caption(&format_split_delta(delta), 120.0, row_y, split_theme(delta));
+        }
+        if let Some(best) = timer.best().get(index) {
+            caption(&format_split(*best), 190.0, row_y, ColorTheme::Neutral);
+        }
+        row_y += ROW_HEIGHT;
+    }
+
+    let (result, theme) = if timer.is_new_best() {
+        (
+            format!("Finished in {}  //  NEW PB!", format_split(timer.elapsed())),
+            ColorTheme::Success,
+        )
+    } else if timer.is_finished() {
+        (
+            format!("Finished in {}", format_split(timer.elapsed())),
+            ColorTheme::Primary,
+        )
+    } else {
+        (
+            format!(
+                "Crashed on level {} at {}",
+                level,
+                format_split(timer.elapsed())
+            ),
+            ColorTheme::Warning,
+        )
+    };
+    caption(&result, 8.0, y + height - 6.0, theme);
+}
@@ -0,0 +1,85 @@
+use crate::config::*;
+use crate::design::{ColorTheme, DesignContext, GameText, TypographyStyle, UIComponent};
+use crate::game::Game;
+use crate::settings::SettingOption;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const ROW_HEIGHT: f32 = 20.0;
+const PANEL_CHROME: f32 = 76.0; // Title and instructions around the rows
+
+pub fn draw_settings(game: &Game) {
+    // Rows grow with the text size, but only so far or the list won't fit
+    let rows = SettingOption::ALL.len() as f32;
+    let row_height = (ROW_HEIGHT * DesignContext::type_scale().min(1.15))
+        .min((SCREEN_HEIGHT - PANEL_CHROME - 8.0) / rows);
+
+    let panel_w = 360.0;
+    let panel_h = PANEL_CHROME + rows * row_height;
+    let panel_x = (SCREEN_WIDTH - panel_w) / 2.0;
+    let panel_y = (SCREEN_HEIGHT - panel_h) / 2.0;
+
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    draw_rectangle(
+        panel_x - 3.0,
+        panel_y - 3.0,
+        panel_w + 6.0,
+        panel_h + 6.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(panel_x, panel_y, panel_w, panel_h, ThemeProvider::surface());
+
+    UIComponent::draw_text(
+        "Settings",
+        panel_x + 16.0,
+        panel_y + 28.0,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Secondary,
+        &game.fonts,
+    );
+
+    let mut y = panel_y + 56.0;
+    for (i, option) in SettingOption::ALL.iter().enumerate() {
+        if i == game.settings_cursor {
+            let highlight = ThemeProvider::selection(0.25);
+            draw_rectangle(
+                panel_x + 8.0,
+                y - row_height + 6.0,
+                panel_w - 16.0,
+                row_height - 2.0,
+                highlight,
+            );
+        }
+
+        UIComponent::draw_text(
+            option.label(),
+            panel_x + 16.0,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Secondary,
+            &game.fonts,
+        );
+        UIComponent::draw_text(
+            &option.value_text(&game.settings),
+            panel_x + panel_w - 80.0,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Secondary,
+            &game.fonts,
+        );
+        y += row_height;
+    }
+
+    GameText::instructions(
+        "[UP/DOWN] Select  //  [ENTER] Change  //  [ESC] Back",
+        panel_x + 16.0,
+        panel_y + panel_h - 12.0,
+        &game.fonts,
+    );
+}
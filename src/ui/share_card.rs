@@ -0,0 +1,156 @@
+use crate::atlas::SpriteAtlas;
+use crate::colors::*;
+use crate::design::{draw_text_runs, measure_text_runs, GameFonts, TypographyStyle};
+use crate::game::run::RunState;
+use macroquad::prelude::*;
+
+// Social preview size. The card is laid out in virtual pixels at half that
+// and every size is doubled, so text rasterizes sharp instead of upscaled.
+pub const CARD_WIDTH: f32 = 1200.0;
+pub const CARD_HEIGHT: f32 = 630.0;
+const SCALE: f32 = 2.0;
+
+// Draws the end-of-run card into whatever camera is active; the caller owns
+// the render target. `rank` is where a score that missed the top would have
+// placed, on the hardcore board if `hardcore`.
+pub fn draw_share_card(
+    run: &RunState,
+    new_high_score: bool,
+    rank: Option<usize>,
+    hardcore: bool,
+    sprites: &SpriteAtlas,
+    fonts: &GameFonts,
+) {
+    clear_background(DEEP);
+
+    // Brand stripes top and bottom
+    draw_rectangle(0.0, 0.0, CARD_WIDTH, 6.0 * SCALE, VIBRANT_BLUE);
+    draw_rectangle(
+        0.0,
+        CARD_HEIGHT - 3.0 * SCALE,
+        CARD_WIDTH,
+        3.0 * SCALE,
+        PLUM,
+    );
+
+    let left = 30.0 * SCALE;
+    card_text(
+        "YETI SET GO",
+        left,
+        48.0 * SCALE,
+        TypographyStyle::DisplaySmall,
+        PEAK,
+        fonts,
+    );
+
+    card_text(
+        "FINAL SCORE",
+        left,
+        108.0 * SCALE,
+        TypographyStyle::UILabel,
+        GREY,
+        fonts,
+    );
+    card_text(
+        &run.score.to_string(),
+        left,
+        160.0 * SCALE,
+        TypographyStyle::DisplayLarge,
+        ICE_BLUE,
+        fonts,
+    );
+    card_text(
+        &format!("Reached Level {}", run.level),
+        left,
+        194.0 * SCALE,
+        TypographyStyle::CodeLarge,
+        VIBRANT_BLUE,
+        fonts,
+    );
+
+    if new_high_score {
+        card_text(
+            "* NEW HIGH SCORE *",
+            left,
+            222.0 * SCALE,
+            TypographyStyle::BodyMedium,
+            WARNING_YELLOW,
+            fonts,
+        );
+    } else if let Some(rank) = rank {
+        let board = if hardcore {
+            "hardcore board"
+        } else {
+            "leaderboard"
+        };
+        card_text(
+            &format!("Would rank #{} on the {}", rank, board),
+            left,
+            222.0 * SCALE,
+            TypographyStyle::BodyMedium,
+            PEAK,
+            fonts,
+        );
+    }
+
+    let date = chrono::Local::now().format("%B %-d, %Y").to_string();
+    card_text(
+        &date,
+        left,
+        290.0 * SCALE,
+        TypographyStyle::BodySmall,
+        GREY,
+        fonts,
+    );
+
+    let repository = env!("CARGO_PKG_REPOSITORY").trim_start_matches("https://");
+    let dimensions = measure_card_text(repository, TypographyStyle::BodySmall, fonts);
+    card_text(
+        repository,
+        CARD_WIDTH - left - dimensions.width,
+        290.0 * SCALE,
+        TypographyStyle::BodySmall,
+        METAL,
+        fonts,
+    );
+
+    draw_yeti(sprites);
+}
+
+fn draw_yeti(sprites: &SpriteAtlas) {
+    let size = 200.0 * SCALE;
+    let x = CARD_WIDTH - size - 40.0 * SCALE;
+    let y = 40.0 * SCALE;
+
+    // Soft spotlight so the sprite doesn't float on flat navy
+    draw_circle(x + size / 2.0, y + size / 2.0, size * 0.45, PLUM);
+
+    if let Some(sprite) = sprites.get("yeti_cheer") {
+        sprite.draw(
+            x,
+            y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(size, size)),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+fn card_text(text: &str, x: f32, y: f32, style: TypographyStyle, color: Color, fonts: &GameFonts) {
+    let mut params = style.get_params(fonts, color);
+    params.font_size = (params.font_size as f32 * SCALE) as u16;
+    draw_text_runs(text, x, y, params, fonts);
+}
+
+fn measure_card_text(text: &str, style: TypographyStyle, fonts: &GameFonts) -> TextDimensions {
+    let params = style.get_params(fonts, WHITE);
+    measure_text_runs(
+        text,
+        params.font,
+        (params.font_size as f32 * SCALE) as u16,
+        1.0,
+        fonts,
+    )
+}
@@ -0,0 +1,127 @@
+use super::name_input;
+use crate::config::*;
+use crate::design::{
+    draw_text_runs, ColorTheme, FlashPolicy, GameText, TypographyStyle, UIComponent,
+};
+use crate::game::Game;
+use crate::highscores::validate_name;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+pub fn draw_sign_in(game: &Game) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    // Everything moves up to make room for the on-screen keyboard
+    let keyboard = game.touch_keyboard.is_shown();
+    let top = if keyboard {
+        30.0
+    } else {
+        SCREEN_HEIGHT / 2.0 - 70.0
+    };
+
+    GameText::heading_centered("WHO'S UP?", SCREEN_WIDTH / 2.0, top, &game.fonts);
+
+    UIComponent::draw_text_centered(
+        "Your name or handle goes on the leaderboard, whatever you score",
+        SCREEN_WIDTH / 2.0,
+        top + if keyboard { 24.0 } else { 40.0 },
+        TypographyStyle::BodySmall,
+        ColorTheme::Neutral,
+        &game.fonts,
+    );
+
+    let box_width = 300.0;
+    let box_height = 40.0;
+    let box_x = SCREEN_WIDTH / 2.0 - box_width / 2.0;
+    let box_y = if keyboard {
+        66.0
+    } else {
+        SCREEN_HEIGHT / 2.0 - 10.0
+    };
+
+    draw_rectangle(
+        box_x - 2.0,
+        box_y - 2.0,
+        box_width + 4.0,
+        box_height + 4.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(
+        box_x,
+        box_y,
+        box_width,
+        box_height,
+        ThemeProvider::surface(),
+    );
+
+    let name = &game.player_name_input;
+    let (text, theme) = if name.is_empty() {
+        ("Type here...", ColorTheme::Neutral)
+    } else {
+        (name.as_str(), ColorTheme::Secondary)
+    };
+    UIComponent::draw_text(
+        text,
+        box_x + 10.0,
+        box_y + 25.0,
+        TypographyStyle::UIInput,
+        theme,
+        &game.fonts,
+    );
+
+    if !name.is_empty() {
+        let opacity = FlashPolicy::blink(game.clock.time(), 1.0, 0.5);
+        if opacity > 0.0 {
+            let text_width = TypographyStyle::UIInput
+                .measure_text(name, &game.fonts)
+                .width;
+            let color = Color {
+                a: opacity,
+                ..ColorTheme::Secondary.get_colors().foreground
+            };
+            let params = TypographyStyle::UIInput.get_params(&game.fonts, color);
+            draw_text_runs(
+                "|",
+                box_x + 10.0 + text_width,
+                box_y + 25.0,
+                params,
+                &game.fonts,
+            );
+        }
+    }
+
+    if let Err(problem) = validate_name(name) {
+        if !name.is_empty() {
+            UIComponent::draw_text_centered(
+                problem,
+                SCREEN_WIDTH / 2.0,
+                box_y + box_height + 20.0,
+                TypographyStyle::BodySmall,
+                ColorTheme::Error,
+                &game.fonts,
+            );
+        }
+    }
+
+    if keyboard {
+        name_input::draw_touch_keyboard(game.touch_keyboard.shift, "BACK", &game.fonts);
+        return;
+    }
+    let instructions = "[ENTER] Start  [ESC] Back";
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, &game.fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 20.0,
+        &game.fonts,
+    );
+}
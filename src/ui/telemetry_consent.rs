@@ -0,0 +1,51 @@
+use crate::config::*;
+use crate::design::{ColorTheme, GameText, TypographyStyle, UIComponent};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+const LINES: [&str; 4] = [
+    "Help tune the difficulty by sharing anonymous gameplay stats:",
+    "how long you play, the level each run reaches and what ended it.",
+    "No names or scores. Stats wait on disk while you're offline.",
+    "You can change this any time in Settings.",
+];
+
+pub fn draw_telemetry_consent(game: &Game) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+
+    GameText::heading_centered(
+        "Share gameplay stats?",
+        SCREEN_WIDTH / 2.0,
+        70.0,
+        &game.fonts,
+    );
+
+    let mut y = 110.0;
+    for line in LINES {
+        UIComponent::draw_text_centered(
+            line,
+            SCREEN_WIDTH / 2.0,
+            y,
+            TypographyStyle::BodySmall,
+            ColorTheme::Neutral,
+            &game.fonts,
+        );
+        y += 16.0;
+    }
+
+    UIComponent::draw_text_centered(
+        "[Y] Share stats  //  [N] No thanks",
+        SCREEN_WIDTH / 2.0,
+        y + 24.0,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        &game.fonts,
+    );
+}
@@ -0,0 +1,157 @@
+use crate::colors::*;
+use crate::config::*;
+use crate::design::{DesignContext, MotionDuration};
+use crate::game::{Game, GameState};
+use crate::tween::Tween;
+use macroquad::prelude::*;
+
+const IRIS_SEGMENTS: u16 = 48;
+const OVERLAY_RISE: f32 = 24.0; // How far a pushed overlay slides up from
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransitionKind {
+    Fade,                  // From a solid cover
+    Wipe,                  // A cover pulled off to the right
+    Slide { from: Vec2 },  // The new screen moves in from this offset
+    Iris { center: Vec2 }, // A circle opening on the yeti
+}
+
+// A screen change in progress. The new screen is drawn as normal; the
+// transition only covers or shifts it, so nothing of the old screen has to
+// be kept around.
+#[derive(Debug, Clone, Copy)]
+pub struct Transition {
+    kind: TransitionKind,
+    started: f64,
+    tween: Tween, // From 0 to 1, easing out
+}
+
+impl Transition {
+    // What a change between two sets of visible screens should look like.
+    // Pushing an overlay slides it in; popping one back off, and the
+    // in-run changes (a level ending, the crash), cut straight across.
+    pub fn between(previous: &[GameState], next: &[GameState], game: &Game) -> Option<Self> {
+        if next.starts_with(previous) {
+            return Some(Self::new(
+                TransitionKind::Slide {
+                    from: vec2(0.0, OVERLAY_RISE),
+                },
+                MotionDuration::Quick,
+            ));
+        }
+        if previous.starts_with(next) {
+            return None;
+        }
+
+        let from = *previous.last()?;
+        let to = *next.last()?;
+        let (kind, duration) = match (from, to) {
+            (GameState::LevelComplete | GameState::Dying, GameState::Playing)
+            | (GameState::Playing, GameState::LevelComplete | GameState::Dying) => return None,
+            (_, GameState::Playing) => {
+                let yeti = &game.yeti;
+                let center = vec2(yeti.x + yeti.width / 2.0, yeti.y - yeti.height / 2.0);
+                (TransitionKind::Iris { center }, MotionDuration::Quick)
+            }
+            (GameState::Dying, GameState::GameOver) => (TransitionKind::Fade, MotionDuration::Slow),
+            (GameState::Playing, _) => (TransitionKind::Fade, MotionDuration::Standard),
+            (_, GameState::MainMenu) => (
+                TransitionKind::Slide {
+                    from: vec2(-SCREEN_WIDTH, 0.0),
+                },
+                MotionDuration::Standard,
+            ),
+            (GameState::MainMenu, _) => (
+                TransitionKind::Slide {
+                    from: vec2(SCREEN_WIDTH, 0.0),
+                },
+                MotionDuration::Standard,
+            ),
+            _ => (TransitionKind::Wipe, MotionDuration::Standard),
+        };
+        Some(Self::new(kind, duration))
+    }
+
+    // Reduced motion keeps the timing but swaps any movement for a fade
+    fn new(kind: TransitionKind, duration: MotionDuration) -> Self {
+        let kind = if DesignContext::reduced_motion() {
+            TransitionKind::Fade
+        } else {
+            kind
+        };
+        Self {
+            kind,
+            started: get_time(),
+            tween: Tween::new(0.0, 1.0, duration.seconds()),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.seconds() >= self.tween.length()
+    }
+
+    // Where to draw the topmost screen this frame
+    pub fn screen_offset(&self) -> Vec2 {
+        match self.kind {
+            TransitionKind::Slide { from } => from * (1.0 - self.eased()),
+            _ => Vec2::ZERO,
+        }
+    }
+
+    // Anything drawn over the new screen
+    pub fn draw_cover(&self) {
+        let t = self.eased();
+        match self.kind {
+            TransitionKind::Fade => {
+                let cover = Color { a: 1.0 - t, ..DEEP };
+                draw_rectangle(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT, cover);
+            }
+            TransitionKind::Wipe => {
+                let edge = SCREEN_WIDTH * t;
+                draw_rectangle(edge, 0.0, SCREEN_WIDTH - edge, SCREEN_HEIGHT, DEEP);
+                draw_rectangle(edge, 0.0, 2.0, SCREEN_HEIGHT, VIBRANT_BLUE);
+            }
+            TransitionKind::Slide { .. } => {}
+            TransitionKind::Iris { center } => {
+                // Far enough out to reach every corner from anywhere on screen
+                let outer = vec2(SCREEN_WIDTH, SCREEN_HEIGHT).length();
+                draw_ring(center, outer * t, outer, DEEP);
+            }
+        }
+    }
+
+    // Read off the clock rather than updated, so it runs the same whatever
+    // the screens underneath do with their frame
+    fn seconds(&self) -> f32 {
+        (get_time() - self.started) as f32
+    }
+
+    fn eased(&self) -> f32 {
+        self.tween.sample(self.seconds())
+    }
+}
+
+// Everything between two circles, as one mesh
+fn draw_ring(center: Vec2, inner: f32, outer: f32, color: Color) {
+    let mut vertices = Vec::with_capacity(IRIS_SEGMENTS as usize * 2);
+    let mut indices = Vec::with_capacity(IRIS_SEGMENTS as usize * 6);
+    for segment in 0..IRIS_SEGMENTS {
+        let angle = segment as f32 / IRIS_SEGMENTS as f32 * std::f32::consts::TAU;
+        let direction = vec2(angle.cos(), angle.sin());
+        for radius in [inner, outer] {
+            let point = center + direction * radius;
+            vertices.push(Vertex::new(point.x, point.y, 0.0, 0.0, 0.0, color));
+        }
+
+        let next = (segment + 1) % IRIS_SEGMENTS;
+        let (inner_a, outer_a) = (segment * 2, segment * 2 + 1);
+        let (inner_b, outer_b) = (next * 2, next * 2 + 1);
+        indices.extend_from_slice(&[inner_a, outer_a, outer_b, inner_a, outer_b, inner_b]);
+    }
+
+    draw_mesh(&Mesh {
+        vertices,
+        indices,
+        texture: None,
+    });
+}
@@ -0,0 +1,106 @@
+use crate::config::*;
+use crate::design::{
+    ColorTheme, GameFonts, GameText, SafeArea, Spacing, TypographyStyle, UIComponent,
+};
+use crate::game::versus::{player_label, VersusState, PLAYER_ONE, PLAYER_TWO};
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+pub fn draw_versus_hud(versus: &VersusState, level: u32, fonts: &GameFonts) {
+    // Each player's score sits just above their own lane
+    let lanes = [
+        (PLAYER_TWO, SafeArea::top() + Spacing::Medium.as_f32()),
+        (PLAYER_ONE, VERSUS_TOP_GROUND_Y + Spacing::XLarge.as_f32() + 10.0),
+    ];
+
+    for (player, y) in lanes {
+        let text = format!("{}: {}", player_label(player), versus.scores[player]);
+        UIComponent::draw_text(
+            &text,
+            SafeArea::left() + Spacing::Small.as_f32(),
+            y,
+            TypographyStyle::BodyMedium,
+            ColorTheme::Secondary,
+            fonts,
+        );
+    }
+
+    let level_text = format!("Level {}", level);
+    UIComponent::draw_text_centered(
+        &level_text,
+        SCREEN_WIDTH / 2.0,
+        SafeArea::top() + Spacing::Medium.as_f32(),
+        TypographyStyle::BodyMedium,
+        ColorTheme::Secondary,
+        fonts,
+    );
+}
+
+pub fn draw_versus_results(versus: &VersusState, level: u32, fonts: &GameFonts) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::backdrop(),
+    );
+
+    let title = match versus.winner() {
+        Some(winner) => format!("{} WINS!", player_label(winner).to_uppercase()),
+        None => "DRAW".to_string(),
+    };
+    GameText::title_centered(&title, SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0 - 70.0, fonts);
+
+    if let Some(loser) = versus.loser {
+        let crash_text = format!("{} crashed the pipeline", player_label(loser));
+        UIComponent::draw_text_centered(
+            &crash_text,
+            SCREEN_WIDTH / 2.0,
+            SCREEN_HEIGHT / 2.0 - 40.0,
+            TypographyStyle::CodeLarge,
+            ColorTheme::Primary,
+            fonts,
+        );
+    }
+
+    let mut y = SCREEN_HEIGHT / 2.0;
+    for player in [PLAYER_ONE, PLAYER_TWO] {
+        let theme = if versus.winner() == Some(player) {
+            ColorTheme::Success
+        } else {
+            ColorTheme::Neutral
+        };
+        let text = format!("{}: {}", player_label(player), versus.scores[player]);
+        UIComponent::draw_text_centered(
+            &text,
+            SCREEN_WIDTH / 2.0,
+            y,
+            TypographyStyle::BodyLarge,
+            theme,
+            fonts,
+        );
+        y += Spacing::Large.as_f32();
+    }
+
+    let level_text = format!("Reached Level {}", level);
+    UIComponent::draw_text_centered(
+        &level_text,
+        SCREEN_WIDTH / 2.0,
+        y,
+        TypographyStyle::BodySmall,
+        ColorTheme::Primary,
+        fonts,
+    );
+
+    let instructions = "[SPACE] Rematch  //  [ESC] Menu";
+    GameText::instructions(
+        instructions,
+        SCREEN_WIDTH / 2.0
+            - TypographyStyle::CodeMedium
+                .measure_text(instructions, fonts)
+                .width
+                / 2.0,
+        SCREEN_HEIGHT - 30.0,
+        fonts,
+    );
+}
@@ -0,0 +1,145 @@
+use super::crt;
+use crate::config::*;
+use macroquad::prelude::*;
+use std::cell::Cell;
+
+// The game always draws at SCREEN_WIDTH x SCREEN_HEIGHT into an offscreen
+// target, which is then scaled to fit the real window with letterboxing.
+// That keeps every layout in virtual pixels whether windowed, fullscreen or
+// embedded in a corner of someone else's window.
+pub struct Viewport {
+    target: RenderTarget,
+    camera: Camera2D,
+    crt_material: Option<Material>, // None if the shader didn't build
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Viewport {
+    pub fn new() -> Self {
+        let target = render_target(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32);
+        target.texture.set_filter(FilterMode::Linear);
+
+        let mut camera =
+            Camera2D::from_display_rect(Rect::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT));
+        camera.render_target = Some(target.clone());
+
+        Self {
+            target,
+            camera,
+            crt_material: crt::load_crt_material(),
+        }
+    }
+
+    // Route the frame's drawing into the virtual screen
+    pub fn begin(&self) {
+        set_camera(&self.camera);
+    }
+
+    // Blit the virtual screen onto the whole window
+    pub fn present(&self, crt_filter: bool) {
+        let window = Rect::new(0.0, 0.0, screen_width(), screen_height());
+        self.present_in(window, crt_filter);
+    }
+
+    // Blit the virtual screen into `area` of the window, in window pixels,
+    // leaving everything outside it alone. The mouse maps through whichever
+    // area was presented last. The CRT filter only touches the window's
+    // copy; screenshots and clips come from the clean target.
+    pub fn present_in(&self, area: Rect, crt_filter: bool) {
+        set_default_camera();
+        draw_rectangle(area.x, area.y, area.w, area.h, BLACK);
+        PRESENTED.with(|presented| presented.set(Some(area)));
+
+        let (x, y, scale) = letterbox(area);
+        if crt_filter {
+            if let Some(material) = &self.crt_material {
+                gl_use_material(material);
+            }
+        }
+        draw_texture_ex(
+            &self.target.texture,
+            x,
+            y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(SCREEN_WIDTH * scale, SCREEN_HEIGHT * scale)),
+                flip_y: true, // Render targets come out upside down
+                ..Default::default()
+            },
+        );
+        gl_use_default_material();
+    }
+
+    // Raw pixels of the virtual screen, still upside down. Only complete
+    // after present() has flushed the frame's drawing into the target.
+    pub fn snapshot(&self) -> Image {
+        self.target.texture.get_texture_data()
+    }
+}
+
+// Run `draw` flipped left to right across the virtual screen, for the mirror
+// mutator. Only the world goes through here; the HUD stays readable.
+pub fn mirrored(enabled: bool, draw: impl FnOnce()) {
+    if !enabled {
+        draw();
+        return;
+    }
+
+    let flip = Mat4::from_translation(vec3(SCREEN_WIDTH, 0.0, 0.0))
+        * Mat4::from_scale(vec3(-1.0, 1.0, 1.0));
+    // SAFETY: only the model matrix stack is touched, and it's popped again
+    // before anything else can draw
+    unsafe { get_internal_gl() }.quad_gl.push_model_matrix(flip);
+    draw();
+    unsafe { get_internal_gl() }.quad_gl.pop_model_matrix();
+}
+
+// Run `draw` moved by `offset`, for screens sliding in or out
+pub fn shifted(offset: Vec2, draw: impl FnOnce()) {
+    if offset == Vec2::ZERO {
+        draw();
+        return;
+    }
+
+    // SAFETY: as in mirrored()
+    unsafe { get_internal_gl() }
+        .quad_gl
+        .push_model_matrix(Mat4::from_translation(offset.extend(0.0)));
+    draw();
+    unsafe { get_internal_gl() }.quad_gl.pop_model_matrix();
+}
+
+// Run `draw` seen through `view`, photo mode's camera
+pub fn transformed(view: Mat4, draw: impl FnOnce()) {
+    // SAFETY: as in mirrored()
+    unsafe { get_internal_gl() }.quad_gl.push_model_matrix(view);
+    draw();
+    unsafe { get_internal_gl() }.quad_gl.pop_model_matrix();
+}
+
+thread_local! {
+    static PRESENTED: Cell<Option<Rect>> = const { Cell::new(None) };
+}
+
+// Mouse position in virtual screen coordinates
+pub fn mouse_position() -> (f32, f32) {
+    let (mouse_x, mouse_y) = macroquad::input::mouse_position();
+    let area = PRESENTED
+        .with(Cell::get)
+        .unwrap_or_else(|| Rect::new(0.0, 0.0, screen_width(), screen_height()));
+    let (x, y, scale) = letterbox(area);
+    ((mouse_x - x) / scale, (mouse_y - y) / scale)
+}
+
+// Top-left corner and scale of the virtual screen inside `area`
+fn letterbox(area: Rect) -> (f32, f32, f32) {
+    let scale = (area.w / SCREEN_WIDTH).min(area.h / SCREEN_HEIGHT);
+    let x = area.x + (area.w - SCREEN_WIDTH * scale) / 2.0;
+    let y = area.y + (area.h - SCREEN_HEIGHT * scale) / 2.0;
+    (x, y, scale)
+}
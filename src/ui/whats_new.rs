@@ -0,0 +1,117 @@
+use crate::changelog::{ChangelogLine, CURRENT_VERSION};
+use crate::config::*;
+use crate::design::{ColorTheme, DesignContext, GameText, TypographyStyle, UIComponent};
+use crate::game::Game;
+use crate::themes::ThemeProvider;
+use macroquad::prelude::*;
+
+pub const VISIBLE_LINES: usize = 10;
+const ROW_HEIGHT: f32 = 16.0;
+const PANEL_CHROME: f32 = 76.0; // Title and instructions around the rows
+const ITEM_INDENT: f32 = 12.0;
+
+pub fn draw_whats_new(game: &Game) {
+    let row_height = ROW_HEIGHT * DesignContext::type_scale().min(1.15);
+    let panel_w = 480.0;
+    let panel_h = PANEL_CHROME + VISIBLE_LINES as f32 * row_height;
+    let panel_x = (SCREEN_WIDTH - panel_w) / 2.0;
+    let panel_y = (SCREEN_HEIGHT - panel_h) / 2.0;
+
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        ThemeProvider::overlay(),
+    );
+    draw_rectangle(
+        panel_x - 3.0,
+        panel_y - 3.0,
+        panel_w + 6.0,
+        panel_h + 6.0,
+        ThemeProvider::surface_border(),
+    );
+    draw_rectangle(panel_x, panel_y, panel_w, panel_h, ThemeProvider::surface());
+
+    UIComponent::draw_text(
+        "What's new",
+        panel_x + 16.0,
+        panel_y + 28.0,
+        TypographyStyle::DisplaySmall,
+        ColorTheme::Secondary,
+        &game.fonts,
+    );
+    let version = format!("You're on v{}", CURRENT_VERSION);
+    let version_width = TypographyStyle::UICaption
+        .measure_text(&version, &game.fonts)
+        .width;
+    UIComponent::draw_text(
+        &version,
+        panel_x + panel_w - 16.0 - version_width,
+        panel_y + 28.0,
+        TypographyStyle::UICaption,
+        ColorTheme::Neutral,
+        &game.fonts,
+    );
+
+    let top = panel_y + 56.0;
+    let text_width = panel_w - 32.0 - ITEM_INDENT;
+    let mut y = top;
+    for line in game
+        .whats_new
+        .iter()
+        .skip(game.whats_new_scroll)
+        .take(VISIBLE_LINES)
+    {
+        let (text, x, style, theme) = match line {
+            ChangelogLine::Version(version) => (
+                format!("Version {}", version),
+                panel_x + 16.0,
+                TypographyStyle::BodyMedium,
+                ColorTheme::Secondary,
+            ),
+            ChangelogLine::Heading(heading) => (
+                heading.to_uppercase(),
+                panel_x + 16.0,
+                TypographyStyle::UILabel,
+                ColorTheme::Success,
+            ),
+            ChangelogLine::Item(item) => (
+                format!("- {}", item),
+                panel_x + 16.0 + ITEM_INDENT,
+                TypographyStyle::BodySmall,
+                ColorTheme::Secondary,
+            ),
+        };
+        let text = style.fit_text(&text, text_width, &game.fonts);
+        UIComponent::draw_text(&text, x, y, style, theme, &game.fonts);
+        y += row_height;
+    }
+
+    // Arrows on the right edge when there's more to scroll to
+    let arrow_x = panel_x + panel_w - 14.0;
+    if game.whats_new_scroll > 0 {
+        draw_triangle(
+            vec2(arrow_x, top - 12.0),
+            vec2(arrow_x - 4.0, top - 6.0),
+            vec2(arrow_x + 4.0, top - 6.0),
+            ThemeProvider::surface_border(),
+        );
+    }
+    if game.whats_new_scroll + VISIBLE_LINES < game.whats_new.len() {
+        let bottom = top + (VISIBLE_LINES - 1) as f32 * row_height;
+        draw_triangle(
+            vec2(arrow_x, bottom + 2.0),
+            vec2(arrow_x - 4.0, bottom - 4.0),
+            vec2(arrow_x + 4.0, bottom - 4.0),
+            ThemeProvider::surface_border(),
+        );
+    }
+
+    GameText::instructions(
+        "[UP/DOWN] Scroll  //  [ESC] or [ENTER] Back",
+        panel_x + 16.0,
+        panel_y + panel_h - 12.0,
+        &game.fonts,
+    );
+}
@@ -0,0 +1,146 @@
+use super::viewport;
+use crate::design::{ColorTheme, GameFonts, TypographyStyle, UIComponent};
+use crate::input::FrameInput;
+use crate::themes::ThemeProvider;
+use macroquad::miniquad::window::set_mouse_cursor;
+use macroquad::miniquad::CursorIcon;
+use macroquad::prelude::*;
+use std::cell::{Cell, RefCell};
+
+const SEPARATOR: &str = "  //  ";
+const PADDING: f32 = 2.0; // Around the text, so the edges of a label still count
+
+thread_local! {
+    // Every button drawn since the last clear(), with the key it stands in for
+    static BUTTONS: RefCell<Vec<(Rect, KeyCode)>> = const { RefCell::new(Vec::new()) };
+    static POINTER_SHOWN: Cell<bool> = const { Cell::new(false) };
+}
+
+// Buttons are the "[KEY]: Action" labels the screens already show, made
+// clickable. Each one stands in for its key, so a screen accepts both by
+// asking key_pressed() instead of FrameInput::key_pressed(). Drawing registers where
+// each button went; the next frame's update hit-tests clicks against that.
+pub fn clear() {
+    BUTTONS.with(|buttons| buttons.borrow_mut().clear());
+}
+
+// Pointer cursor while over a button
+pub fn end_frame() {
+    let over_button = hovered_key().is_some();
+    if POINTER_SHOWN.with(|shown| shown.replace(over_button)) != over_button {
+        set_mouse_cursor(if over_button {
+            CursorIcon::Pointer
+        } else {
+            CursorIcon::Default
+        });
+    }
+}
+
+// The key pressed, or its button clicked, this frame
+pub fn key_pressed(input: &FrameInput, key: KeyCode) -> bool {
+    input.key_pressed(key)
+        || (input.mouse_pressed(MouseButton::Left)
+            && button_at(input.mouse_position()) == Some(key))
+}
+
+// The button under the mouse, if any
+pub fn hovered_key() -> Option<KeyCode> {
+    button_at(viewport::mouse_position())
+}
+
+pub fn button_at((x, y): (f32, f32)) -> Option<KeyCode> {
+    BUTTONS.with(|buttons| {
+        buttons
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(rect, _)| rect.contains(vec2(x, y)))
+            .map(|(_, key)| *key)
+    })
+}
+
+// A label that acts as `key` when clicked, highlighted while hovered.
+// Returns its width, for laying out what follows.
+pub fn key_button(
+    label: &str,
+    key: KeyCode,
+    x: f32,
+    y: f32,
+    style: TypographyStyle,
+    theme: ColorTheme,
+    fonts: &GameFonts,
+) -> f32 {
+    let size = style.measure_text(label, fonts);
+    let rect = Rect::new(
+        x - PADDING,
+        y - size.offset_y - PADDING,
+        size.width + PADDING * 2.0,
+        size.height + PADDING * 2.0,
+    );
+
+    let (mouse_x, mouse_y) = viewport::mouse_position();
+    if rect.contains(vec2(mouse_x, mouse_y)) {
+        let highlight = ThemeProvider::selection(0.25);
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, highlight);
+        draw_line(
+            x,
+            y + PADDING,
+            x + size.width,
+            y + PADDING,
+            1.0,
+            theme.get_colors().foreground,
+        );
+    }
+    UIComponent::draw_text(label, x, y, style, theme, fonts);
+
+    BUTTONS.with(|buttons| buttons.borrow_mut().push((rect, key)));
+    size.width
+}
+
+// Labels in a row with the usual " // " between them. Parts without a key
+// are plain text.
+pub fn key_buttons(
+    parts: &[(&str, Option<KeyCode>)],
+    x: f32,
+    y: f32,
+    style: TypographyStyle,
+    theme: ColorTheme,
+    fonts: &GameFonts,
+) {
+    let separator_width = style.measure_text(SEPARATOR, fonts).width;
+    let mut x = x;
+    for (index, (label, key)) in parts.iter().enumerate() {
+        if index > 0 {
+            UIComponent::draw_text(SEPARATOR, x, y, style, theme, fonts);
+            x += separator_width;
+        }
+        x += match key {
+            Some(key) => key_button(label, *key, x, y, style, theme, fonts),
+            None => {
+                UIComponent::draw_text(label, x, y, style, theme, fonts);
+                style.measure_text(label, fonts).width
+            }
+        };
+    }
+}
+
+pub fn key_buttons_centered(
+    parts: &[(&str, Option<KeyCode>)],
+    center_x: f32,
+    y: f32,
+    style: TypographyStyle,
+    theme: ColorTheme,
+    fonts: &GameFonts,
+) {
+    let width = row_width(parts, style, fonts);
+    key_buttons(parts, center_x - width / 2.0, y, style, theme, fonts);
+}
+
+pub fn row_width(
+    parts: &[(&str, Option<KeyCode>)],
+    style: TypographyStyle,
+    fonts: &GameFonts,
+) -> f32 {
+    let labels: Vec<&str> = parts.iter().map(|(label, _)| *label).collect();
+    style.measure_text(&labels.join(SEPARATOR), fonts).width
+}
@@ -0,0 +1,76 @@
+use crate::assets::load_assets;
+use crate::audio::{Music, Voice};
+use crate::cli;
+use crate::game::{Game, GameState};
+use crate::input::FrameInput;
+use crate::ui::{Renderer, Viewport};
+use macroquad::prelude::*;
+
+// What the host app tells the game each frame
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WidgetInput {
+    // Whether keys and clicks are meant for the game. Taking focus away
+    // pauses a run and stops the game reading input until it comes back.
+    pub focused: bool,
+}
+
+// The game inside another macroquad app, drawn into whatever part of the
+// window the host gives it. Nothing here touches the window itself: no
+// fullscreen, no quit handling, no frame cap. Those stay with the host.
+pub struct YetiGame {
+    game: Game,
+    renderer: Renderer,
+    viewport: Viewport,
+    music: Music,
+}
+
+impl YetiGame {
+    // Loads everything the game draws with, so await it once at startup.
+    // The host's command line is its own; the game runs with no arguments.
+    pub async fn new() -> Self {
+        cli::embed();
+        let mut game = Game::new();
+        game.settings.apply();
+        game.api.start_sync();
+        let assets = load_assets(&game.catalog, &game.levels, &game.mods).await;
+        game.textures = assets.textures;
+        game.sprites = assets.sprites;
+        game.fonts = assets.fonts;
+        game.voice = Voice::load(&game.catalog).await;
+
+        Self {
+            game,
+            renderer: Renderer::new(),
+            viewport: Viewport::new(),
+            music: Music::load().await,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, input: WidgetInput) {
+        let game = &mut self.game;
+        if input.focused {
+            game.update(dt, FrameInput::poll());
+        } else if game.state() == GameState::Playing && game.spectating.is_none() {
+            game.push_state(GameState::Paused);
+        }
+        self.music.update(game, dt);
+        game.voice.update(dt, game.settings.voice_volume);
+    }
+
+    // Draw into `rect`, in window pixels, letterboxed to keep the game's
+    // shape. The host's camera is put back afterwards.
+    pub fn draw(&self, rect: Rect) {
+        push_camera_state();
+        self.viewport.begin();
+        self.renderer.draw(&self.game);
+        let settings = &self.game.settings;
+        self.viewport.present_in(rect, settings.crt_filter);
+        pop_camera_state();
+    }
+
+    // Call when the host closes the game. A run in progress ends as if the
+    // yeti had crashed, and scores still being sent get a moment to arrive.
+    pub fn shutdown(mut self) {
+        self.game.shutdown();
+    }
+}
@@ -0,0 +1,34 @@
+// The simulation runs without a window: a seeded run on autopilot, with
+// default settings and no mods, clears a level through Game::update.
+
+use yeti_core::game::{Game, GameState};
+use yeti_core::input::FrameInput;
+use yeti_core::settings::Settings;
+
+#[test]
+fn autopilot_clears_a_level_without_a_window() {
+    // The test harness's arguments aren't the game's
+    yeti_core::cli::embed();
+
+    let mut game = Game::with_data(Settings::default(), &[]);
+    game.run_seed = Some(7);
+    game.autopilot = true;
+    game.start_game();
+    assert_eq!(game.state(), GameState::Playing);
+
+    let dt = 1.0 / 60.0;
+    for _ in 0..60 * 60 {
+        game.update(dt, FrameInput::default());
+        // Through the level complete screen and into the next level
+        if game.run.level > 1 && game.state() == GameState::Playing {
+            break;
+        }
+    }
+    assert_eq!(game.state(), GameState::Playing);
+    assert!(
+        game.run.level > 1,
+        "still on level 1 with {} points",
+        game.run.score
+    );
+    assert!(game.run.score > 0);
+}
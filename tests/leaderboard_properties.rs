@@ -0,0 +1,284 @@
+// Invariants of the leaderboard and the score formulas, checked against
+// generated boards. Names come from a short list and scores from a narrow
+// range so duplicates and ties turn up often.
+
+use proptest::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use yeti_core::highscores::{EntryKey, HighScore, Leaderboard, Moderation, BOARD_SIZE};
+use yeti_core::scoring::{calculate_level_score_bonus, calculate_total_score_with_bonuses};
+
+const NAMES: [&str; 6] = ["Yeti", "Frosty", "DevOpsGuru", "CI Bot", "Sasquatch", "Ada"];
+
+fn high_score() -> impl Strategy<Value = HighScore> {
+    let moderation = prop_oneof![
+        6 => Just(None),
+        1 => Just(Some(Moderation::Hidden)),
+        1 => Just(Some(Moderation::Sanitized)),
+    ];
+    (
+        prop::sample::select(NAMES.to_vec()),
+        0u32..400,
+        1u32..12,
+        prop::option::of(0u32..1_000),
+        moderation,
+    )
+        .prop_map(|(name, score, level, id, moderation)| {
+            let mut high_score = HighScore::new(name.to_string(), score, level);
+            high_score.id = id.map(|id| format!("_:entry{}", id));
+            high_score.moderation = moderation;
+            high_score
+        })
+}
+
+// A board as the game builds it locally, one finished run at a time
+fn local_board() -> impl Strategy<Value = Leaderboard> {
+    prop::collection::vec(high_score(), 0..40).prop_map(|scores| {
+        let mut leaderboard = Leaderboard::new();
+        for score in scores {
+            leaderboard.add_score(score);
+        }
+        leaderboard
+    })
+}
+
+// The entry a local score becomes once the server's copy lends it an id
+fn merged_key(score: &HighScore, remote: &[HighScore]) -> EntryKey {
+    if score.id.is_some() {
+        return score.entry_key();
+    }
+    remote
+        .iter()
+        .find(|other| other.id.is_some() && other.run_key() == score.run_key())
+        .map_or_else(|| score.entry_key(), HighScore::entry_key)
+}
+
+// What a merge keeps of an entry: enough to compare two boards by
+fn summary(leaderboard: &Leaderboard) -> Vec<(String, u32, Option<String>, Option<Moderation>)> {
+    leaderboard
+        .scores
+        .iter()
+        .map(|score| {
+            (
+                score.name.clone(),
+                score.score,
+                score.id.clone(),
+                score.moderation,
+            )
+        })
+        .collect()
+}
+
+fn assert_well_formed(leaderboard: &Leaderboard) -> Result<(), TestCaseError> {
+    prop_assert!(leaderboard.scores.len() <= BOARD_SIZE);
+    prop_assert!(leaderboard
+        .scores
+        .windows(2)
+        .all(|pair| pair[0].board_order(&pair[1]) != Ordering::Greater));
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn added_scores_keep_the_board_sorted_and_bounded(
+        scores in prop::collection::vec(high_score(), 0..60),
+    ) {
+        let mut leaderboard = Leaderboard::new();
+        for score in &scores {
+            leaderboard.add_score(score.clone());
+            assert_well_formed(&leaderboard)?;
+        }
+        let best = scores.iter().map(|score| score.score).max().unwrap_or(0);
+        prop_assert_eq!(leaderboard.get_local_best_score(), best);
+    }
+
+    #[test]
+    fn rank_agrees_with_high_score_check(leaderboard in local_board(), score in 0u32..450) {
+        let rank = leaderboard.get_rank(score);
+        prop_assert_eq!(rank.is_some(), leaderboard.is_high_score(score));
+
+        if let Some(rank) = rank {
+            prop_assert!((1..=BOARD_SIZE).contains(&rank));
+            let (above, below) = leaderboard.scores.split_at(rank - 1);
+            prop_assert!(above.iter().all(|entry| entry.score > score));
+            prop_assert!(below.first().is_none_or(|entry| entry.score <= score));
+        }
+    }
+
+    #[test]
+    fn a_high_score_makes_the_board(leaderboard in local_board(), score in high_score()) {
+        let mut leaderboard = leaderboard;
+        let expected = leaderboard.is_high_score(score.score);
+        let mut score = score;
+        score.id = Some("_:newcomer".to_string());
+        leaderboard.add_score(score.clone());
+        let on_board = leaderboard.scores.iter().any(|entry| entry.id == score.id);
+        prop_assert_eq!(on_board, expected);
+    }
+
+    #[test]
+    fn merging_the_same_scores_twice_changes_nothing(
+        leaderboard in local_board(),
+        remote in prop::collection::vec(high_score(), 0..40),
+    ) {
+        let mut once = leaderboard;
+        once.merge_remote_scores(remote.clone());
+        let mut twice = once.clone();
+        twice.merge_remote_scores(remote);
+        prop_assert_eq!(summary(&once), summary(&twice));
+    }
+
+    #[test]
+    fn merge_keeps_one_of_each_visible_entry(
+        leaderboard in local_board(),
+        remote in prop::collection::vec(high_score(), 0..40),
+    ) {
+        // The server's moderation wins; the last copy it sent is the one that counts
+        let mut moderation: HashMap<EntryKey, (u32, Option<Moderation>)> = HashMap::new();
+        for score in leaderboard.scores.iter().rev() {
+            moderation.insert(merged_key(score, &remote), (score.score, score.moderation));
+        }
+        for score in &remote {
+            moderation.insert(score.entry_key(), (score.score, score.moderation));
+        }
+
+        let mut merged = leaderboard.clone();
+        merged.merge_remote_scores(remote);
+        assert_well_formed(&merged)?;
+
+        let mut seen = HashSet::new();
+        for entry in &merged.scores {
+            let entry_key = entry.entry_key();
+            prop_assert!(seen.insert(entry_key.clone()), "duplicate entry {:?}", entry_key);
+            prop_assert!(!entry.is_hidden());
+            prop_assert_eq!(entry.moderation, moderation[&entry_key].1);
+        }
+
+        // Anything visible that's missing was pushed off a full board
+        let last = merged.scores.last().map_or(0, |entry| entry.score);
+        for (entry_key, (score, entry_moderation)) in &moderation {
+            if *entry_moderation == Some(Moderation::Hidden) || seen.contains(entry_key) {
+                continue;
+            }
+            prop_assert_eq!(merged.scores.len(), BOARD_SIZE);
+            prop_assert!(*score <= last);
+        }
+    }
+
+    #[test]
+    fn equal_scores_share_a_rank(leaderboard in local_board()) {
+        for (index, entry) in leaderboard.scores.iter().enumerate() {
+            let (rank, shared) = leaderboard.rank_at(index).unwrap();
+            let above = leaderboard.scores.iter().filter(|other| other.score > entry.score);
+            prop_assert_eq!(rank, above.count() + 1);
+            let equal = leaderboard.scores.iter().filter(|other| other.score == entry.score);
+            prop_assert_eq!(shared, equal.count() > 1);
+        }
+    }
+
+    #[test]
+    fn csv_export_has_a_row_per_entry_ranked_like_the_board(leaderboard in local_board()) {
+        let csv = leaderboard.to_csv();
+        let rows: Vec<&str> = csv.lines().skip(1).collect();
+        prop_assert_eq!(rows.len(), leaderboard.scores.len());
+        for (row, entry) in rows.iter().zip(&leaderboard.scores) {
+            let above = leaderboard.scores.iter().filter(|other| other.score > entry.score);
+            let expected = format!("{},", above.count() + 1);
+            prop_assert!(row.starts_with(&expected), "{} for {}", row, entry.score);
+        }
+    }
+
+    #[test]
+    fn total_score_never_drops_as_any_input_grows(
+        score in 0u32..1_000_000,
+        level in 1u32..500,
+        checks in 0u32..10_000,
+        extra in 0u32..1_000,
+    ) {
+        let total = calculate_total_score_with_bonuses(score, level, checks);
+        prop_assert!(total >= score);
+        prop_assert!(calculate_total_score_with_bonuses(score + extra, level, checks) >= total);
+        prop_assert!(calculate_total_score_with_bonuses(score, level + extra, checks) >= total);
+        prop_assert!(calculate_total_score_with_bonuses(score, level, checks + extra) >= total);
+    }
+
+    #[test]
+    fn total_score_pays_every_completed_level(level in 1u32..500) {
+        let bonuses: u32 = (1..level).map(calculate_level_score_bonus).sum();
+        prop_assert_eq!(calculate_total_score_with_bonuses(0, level, 0), bonuses);
+    }
+}
+
+#[test]
+fn empty_board_ranks_anything_first() {
+    let leaderboard = Leaderboard::new();
+    assert!(leaderboard.is_high_score(0));
+    assert_eq!(leaderboard.get_rank(0), Some(1));
+    assert_eq!(leaderboard.get_local_best_score(), 0);
+}
+
+#[test]
+fn tying_last_place_on_a_full_board_does_not_rank() {
+    let mut leaderboard = Leaderboard::new();
+    for score in 0..BOARD_SIZE as u32 {
+        leaderboard.add_score(HighScore::new("Yeti".to_string(), 100 + score, 1));
+    }
+    assert!(!leaderboard.is_high_score(100));
+    assert_eq!(leaderboard.get_rank(100), None);
+    assert_eq!(leaderboard.get_rank(101), Some(BOARD_SIZE - 1)); // Shares 101's place
+}
+
+#[test]
+fn csv_export_quotes_names_a_spreadsheet_would_misread() {
+    let mut leaderboard = Leaderboard::new();
+    leaderboard.add_score(HighScore::new("Snow, \"Yeti\"".to_string(), 300, 4));
+    leaderboard.add_score(HighScore::new("=HYPERLINK(1)".to_string(), 200, 3));
+    let csv = leaderboard.to_csv();
+    let rows: Vec<&str> = csv.lines().collect();
+    assert_eq!(rows[0], "rank,name,score,level,date,mode");
+    assert!(rows[1].starts_with("1,\"Snow, \"\"Yeti\"\"\",300,4,"));
+    assert!(rows[2].starts_with("2,'=HYPERLINK(1),200,3,"));
+    assert!(rows[2].ends_with(",Standard"));
+}
+
+#[test]
+fn ties_go_to_the_higher_level_then_the_earlier_run() {
+    let mut early = HighScore::new("Early".to_string(), 500, 4);
+    early.timestamp -= chrono::Duration::hours(1);
+    let late = HighScore::new("Late".to_string(), 500, 4);
+    let deeper = HighScore::new("Deeper".to_string(), 500, 5);
+
+    let mut leaderboard = Leaderboard::new();
+    for score in [late, deeper, early] {
+        leaderboard.add_score(score);
+    }
+    let names: Vec<&str> = leaderboard.scores.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Deeper", "Early", "Late"]);
+    assert_eq!(leaderboard.rank_at(2), Some((1, true)));
+}
+
+#[test]
+fn merge_keeps_separate_runs_that_share_a_name_and_score() {
+    let mut first = HighScore::new("Yeti".to_string(), 500, 4);
+    first.timestamp -= chrono::Duration::hours(1);
+    let second = HighScore::new("Yeti".to_string(), 500, 3);
+
+    let mut leaderboard = Leaderboard::new();
+    leaderboard.add_score(first.clone());
+    leaderboard.merge_remote_scores(vec![first, second]);
+    assert_eq!(leaderboard.scores.len(), 2);
+    assert_eq!(leaderboard.scores[1].level, 3);
+}
+
+#[test]
+fn synced_runs_pick_up_the_server_id() {
+    let local = HighScore::new("Yeti".to_string(), 500, 4);
+    let mut remote = local.clone();
+    remote.id = Some("_:entry1".to_string());
+
+    let mut leaderboard = Leaderboard::new();
+    leaderboard.add_score(local);
+    leaderboard.merge_remote_scores(vec![remote]);
+    assert_eq!(leaderboard.scores.len(), 1);
+    assert_eq!(leaderboard.scores[0].id.as_deref(), Some("_:entry1"));
+}
@@ -0,0 +1,43 @@
+// Replays are downloaded from the shared board, so decoding anything a
+// player could upload has to come back None rather than panic.
+
+use yeti_core::game::mutators::MutatorSet;
+use yeti_core::game::replay::Replay;
+
+fn with_frames(frames: &str) -> String {
+    let header = Replay::new(7, 3, MutatorSet::none()).encode();
+    format!("{}{}", header, frames)
+}
+
+fn frame(dt_bits: u32) -> String {
+    format!("{:08x}{:02x}", dt_bits, 0)
+}
+
+#[test]
+fn a_recorded_run_round_trips() {
+    let mut replay = Replay::new(42, 3, MutatorSet::none());
+    for _ in 0..120 {
+        replay.record(1.0 / 60.0);
+    }
+    assert_eq!(Replay::decode(&replay.encode()), Some(replay));
+}
+
+#[test]
+fn multibyte_text_in_the_frames_is_rejected() {
+    // Ten bytes, so it passes the length check, but not a char boundary at 8
+    assert_eq!(Replay::decode(&with_frames("abcdefg日")), None);
+    assert_eq!(Replay::decode(&with_frames("日本語x")), None);
+}
+
+#[test]
+fn frame_times_the_simulation_cannot_step_are_rejected() {
+    for dt in [f32::NAN, f32::INFINITY, 0.0, -0.016, 3600.0] {
+        assert_eq!(
+            Replay::decode(&with_frames(&frame(dt.to_bits()))),
+            None,
+            "dt {}",
+            dt
+        );
+    }
+    assert!(Replay::decode(&with_frames(&frame((1.0f32 / 60.0).to_bits()))).is_some());
+}
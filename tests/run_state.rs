@@ -0,0 +1,50 @@
+// RunState stands on its own, with no Game around it, the way the HUD and
+// level complete screen are handed it.
+
+use yeti_core::game::run::RunState;
+
+#[test]
+fn a_new_run_starts_on_level_one_with_no_score() {
+    let run = RunState::new(5);
+    assert_eq!(run.score, 0);
+    assert_eq!(run.level, 1);
+    assert_eq!(run.checks_completed, 0);
+    assert_eq!(run.checks_required, 5);
+    assert!(!run.level_done());
+}
+
+#[test]
+fn the_level_is_done_once_every_check_passes() {
+    let mut run = RunState::new(3);
+    for _ in 0..2 {
+        run.complete_check();
+    }
+    assert!(!run.level_done());
+    run.complete_check();
+    assert!(run.level_done());
+}
+
+#[test]
+fn entering_a_level_clears_its_checks_but_keeps_the_score() {
+    let mut run = RunState::new(2);
+    run.score = 1200;
+    run.complete_check();
+    run.complete_check();
+
+    run.level += 1;
+    run.enter_level(4);
+    assert_eq!(run.checks_completed, 0);
+    assert_eq!(run.checks_required, 4);
+    assert_eq!(run.score, 1200);
+    assert!(!run.level_done());
+}
+
+#[test]
+fn reset_goes_back_to_the_first_level() {
+    let mut run = RunState::new(2);
+    run.score = 800;
+    run.level = 4;
+    run.reset();
+    assert_eq!(run.score, 0);
+    assert_eq!(run.level, 1);
+}
@@ -0,0 +1,49 @@
+use yeti_core::game::speedrun::{SpeedrunTimer, SPLIT_COUNT};
+
+fn finish(timer: &mut SpeedrunTimer, seconds_per_level: f32) {
+    for _ in 0..SPLIT_COUNT {
+        timer.tick(seconds_per_level);
+        timer.split();
+    }
+}
+
+#[test]
+fn half_an_hour_of_frames_adds_up() {
+    let mut timer = SpeedrunTimer::new(Vec::new());
+    for _ in 0..30 * 60 * 60 {
+        timer.tick(1.0 / 60.0);
+    }
+    assert!((timer.elapsed() - 1800.0).abs() < 1e-3);
+}
+
+#[test]
+fn the_clock_stops_at_the_last_split() {
+    let mut timer = SpeedrunTimer::new(Vec::new());
+    finish(&mut timer, 10.0);
+    timer.tick(5.0);
+    timer.split();
+    assert!(timer.is_finished());
+    assert_eq!(timer.splits().len(), SPLIT_COUNT);
+    assert_eq!(timer.elapsed(), 90.0);
+}
+
+#[test]
+fn splits_compare_against_a_finished_best() {
+    let best: Vec<f64> = (1..=SPLIT_COUNT).map(|level| level as f64 * 10.0).collect();
+    let mut timer = SpeedrunTimer::new(best);
+    timer.tick(8.0);
+    timer.split();
+    assert_eq!(timer.delta(0), Some(-2.0));
+    assert_eq!(timer.delta(1), None);
+
+    finish(&mut timer, 9.0);
+    assert!(timer.is_new_best());
+}
+
+#[test]
+fn an_unfinished_best_is_ignored() {
+    let mut timer = SpeedrunTimer::new(vec![10.0, 20.0]);
+    assert!(timer.best().is_empty());
+    finish(&mut timer, 60.0);
+    assert!(timer.is_new_best());
+}
@@ -0,0 +1,64 @@
+use yeti_core::tween::{Ease, Timeline, Tween};
+
+const EASES: [Ease; 6] = [
+    Ease::Linear,
+    Ease::InCubic,
+    Ease::OutCubic,
+    Ease::InOutCubic,
+    Ease::OutBack,
+    Ease::OutElastic,
+];
+
+#[test]
+fn every_curve_starts_at_zero_and_ends_at_one() {
+    for ease in EASES {
+        assert!(ease.apply(0.0).abs() < 1e-6, "{:?}", ease);
+        assert!((ease.apply(1.0) - 1.0).abs() < 1e-6, "{:?}", ease);
+        assert_eq!(ease.apply(2.0), ease.apply(1.0), "{:?}", ease);
+    }
+}
+
+#[test]
+fn a_delayed_tween_holds_its_start() {
+    let mut tween = Tween::new(10.0, 20.0, 1.0).ease(Ease::Linear).delay(0.5);
+    tween.update(0.4);
+    assert_eq!(tween.value(), 10.0);
+    tween.update(0.6);
+    assert_eq!(tween.value(), 15.0);
+    tween.update(5.0);
+    assert!(tween.is_finished());
+    assert_eq!(tween.value(), 20.0);
+}
+
+#[test]
+fn timeline_steps_follow_on_and_cue_once_in_order() {
+    let mut timeline = Timeline::new()
+        .then(Tween::new(0.0, 1.0, 1.0).ease(Ease::Linear))
+        .cue("up")
+        .then(Tween::hold(1.0, 0.5))
+        .then(Tween::new(1.0, 0.0, 1.0).ease(Ease::Linear))
+        .cue("down");
+    assert_eq!(timeline.length(), 2.5);
+
+    assert!(timeline.update(0.5).is_empty());
+    assert_eq!(timeline.value(), 0.5);
+    assert_eq!(timeline.update(0.75), vec!["up"]);
+    assert_eq!(timeline.value(), 1.0);
+    assert!(timeline.update(0.5).is_empty());
+    assert_eq!(timeline.value(), 0.75);
+
+    assert_eq!(timeline.finish(), vec!["down"]);
+    assert!(timeline.is_finished());
+    assert_eq!(timeline.value(), 0.0);
+    assert!(timeline.update(1.0).is_empty());
+}
+
+#[test]
+fn one_long_frame_hands_back_every_cue() {
+    let mut timeline = Timeline::new()
+        .then(Tween::hold(0.0, 0.1))
+        .cue(1)
+        .then(Tween::hold(0.0, 0.1))
+        .cue(2);
+    assert_eq!(timeline.update(10.0), vec![1, 2]);
+}
@@ -0,0 +1,51 @@
+use yeti_core::version::Version;
+
+fn v(text: &str) -> Version {
+    Version::parse(text).unwrap()
+}
+
+#[test]
+fn numbers_compare_as_numbers() {
+    assert!(v("0.1.10").is_newer_than(&v("0.1.9")));
+    assert!(v("1.0.0").is_newer_than(&v("0.99.99")));
+    assert!(!v("1.3.0").is_newer_than(&v("1.3.0")));
+}
+
+#[test]
+fn pre_releases_come_before_their_release() {
+    let order = [
+        "1.3.0-alpha",
+        "1.3.0-alpha.1",
+        "1.3.0-alpha.beta",
+        "1.3.0-rc.2",
+        "1.3.0-rc.10",
+    ];
+    for pair in order.windows(2) {
+        assert!(
+            v(pair[1]).is_newer_than(&v(pair[0])),
+            "{} > {}",
+            pair[1],
+            pair[0]
+        );
+    }
+    assert!(v("1.3.0").is_newer_than(&v("1.3.0-rc.10")));
+    assert!(v("1.3.0-rc.1").is_newer_than(&v("1.2.9")));
+}
+
+#[test]
+fn loose_input_reads_and_junk_does_not() {
+    assert_eq!(v("v1.3"), Version::new(1, 3, 0));
+    assert_eq!(v(" 2 "), Version::new(2, 0, 0));
+    assert_eq!(v("1.2.3+build.7"), Version::new(1, 2, 3));
+    for junk in ["", "one.two", "1.2.3.4", "1.2.3-", "-rc.1"] {
+        assert_eq!(Version::parse(junk), None, "{:?}", junk);
+    }
+}
+
+#[test]
+fn display_round_trips_and_short_drops_a_zero_patch() {
+    assert_eq!(v("1.3.0-rc.1").to_string(), "1.3.0-rc.1");
+    assert_eq!(v("1.3.0").short(), "1.3");
+    assert_eq!(v("1.3.2").short(), "1.3.2");
+    assert_eq!(Version::current().to_string(), env!("CARGO_PKG_VERSION"));
+}